@@ -15,6 +15,12 @@ extern "C" {
 
 /**
  * Creates EVMC Dora VM.
+ *
+ * The LLVM code generation can be configured with `evmc_set_option`:
+ * - `opt-level`: the optimization level, "0", "1", "2" (default) or "3".
+ * - `target-cpu`: the target CPU e.g., "x86-64-v3", "native" denotes the host CPU (default).
+ * - `target-features`: the comma separated target features e.g., "+avx2,+bmi2", "native"
+ *   denotes the host CPU features (default).
  */
 EVMC_EXPORT struct evmc_vm* evmc_create_doravm(void);
 
@@ -8,7 +8,7 @@ use dora::primitives::{
 };
 use dora::runtime::call::{CallMessage, CallResult};
 use dora::runtime::host::{
-    AccountLoad, Host, SStoreResult, SStoreStatus, SelfDestructResult, StateLoad,
+    AccountLoad, Host, HostError, SStoreResult, SStoreStatus, SelfDestructResult, StateLoad,
 };
 use dora::runtime::result::VMError;
 use evmc_sys::{evmc_access_status, evmc_address, evmc_bytes32, evmc_storage_status};
@@ -88,7 +88,7 @@ impl Host for EvmcDelegateHost<'_> {
         &mut self.env
     }
 
-    fn sload(&mut self, addr: Address, key: U256) -> Option<StateLoad<U256>> {
+    fn sload(&mut self, addr: Address, key: U256) -> Result<StateLoad<U256>, HostError> {
         unsafe {
             let addr = transmute::<Address, evmc_address>(addr);
             let key = transmute::<[u8; 32], evmc_bytes32>(key.to_be_bytes());
@@ -97,11 +97,16 @@ impl Host for EvmcDelegateHost<'_> {
                 evmc_access_status::EVMC_ACCESS_COLD
             );
             let result = self.context.get_storage(&addr, &key);
-            Some(StateLoad::new(U256::from_be_bytes(result.bytes), is_cold))
+            Ok(StateLoad::new(U256::from_be_bytes(result.bytes), is_cold))
         }
     }
 
-    fn sstore(&mut self, addr: Address, key: U256, value: U256) -> Option<StateLoad<SStoreResult>> {
+    fn sstore(
+        &mut self,
+        addr: Address,
+        key: U256,
+        value: U256,
+    ) -> Result<StateLoad<SStoreResult>, HostError> {
         unsafe {
             let addr = transmute::<Address, evmc_address>(addr);
             let key = transmute::<[u8; 32], evmc_bytes32>(key.to_be_bytes());
@@ -111,7 +116,7 @@ impl Host for EvmcDelegateHost<'_> {
                 evmc_access_status::EVMC_ACCESS_COLD
             );
             let status = self.context.set_storage(&addr, &key, &value);
-            Some(StateLoad::new(
+            Ok(StateLoad::new(
                 SStoreResult::Status(transmute::<evmc_storage_status, SStoreStatus>(status)),
                 is_cold,
             ))
@@ -136,12 +141,14 @@ impl Host for EvmcDelegateHost<'_> {
         }
     }
 
-    fn load_account_delegated(&mut self, _addr: Address) -> Option<StateLoad<AccountLoad>> {
-        // Nothing to do
-        None
+    fn load_account_delegated(
+        &mut self,
+        _addr: Address,
+    ) -> Result<StateLoad<AccountLoad>, HostError> {
+        Err(HostError::Unsupported("load_account_delegated"))
     }
 
-    fn balance(&mut self, addr: Address) -> Option<StateLoad<U256>> {
+    fn balance(&mut self, addr: Address) -> Result<StateLoad<U256>, HostError> {
         unsafe {
             let addr = transmute::<Address, evmc_address>(addr);
             let is_cold = matches!(
@@ -149,11 +156,11 @@ impl Host for EvmcDelegateHost<'_> {
                 evmc_access_status::EVMC_ACCESS_COLD
             );
             let value = self.context.get_balance(&addr);
-            Some(StateLoad::new(U256::from_be_bytes(value.bytes), is_cold))
+            Ok(StateLoad::new(U256::from_be_bytes(value.bytes), is_cold))
         }
     }
 
-    fn code(&mut self, addr: Address) -> Option<StateLoad<Bytes>> {
+    fn code(&mut self, addr: Address) -> Result<StateLoad<Bytes>, HostError> {
         unsafe {
             let addr = transmute::<Address, evmc_address>(addr);
             let is_cold = matches!(
@@ -163,11 +170,11 @@ impl Host for EvmcDelegateHost<'_> {
             let size = self.context.get_code_size(&addr);
             let mut code = Vec::with_capacity(size);
             self.context.copy_code(&addr, 0, &mut code);
-            Some(StateLoad::new(code.into(), is_cold))
+            Ok(StateLoad::new(code.into(), is_cold))
         }
     }
 
-    fn code_hash(&mut self, addr: Address) -> Option<StateLoad<B256>> {
+    fn code_hash(&mut self, addr: Address) -> Result<StateLoad<B256>, HostError> {
         unsafe {
             let addr = transmute::<Address, evmc_address>(addr);
             let hash = self.context.get_code_hash(&addr);
@@ -175,7 +182,7 @@ impl Host for EvmcDelegateHost<'_> {
                 self.context.access_account(&addr),
                 evmc_access_status::EVMC_ACCESS_COLD
             );
-            Some(StateLoad::new(B256::from(hash.bytes), is_cold))
+            Ok(StateLoad::new(B256::from(hash.bytes), is_cold))
         }
     }
 
@@ -183,7 +190,7 @@ impl Host for EvmcDelegateHost<'_> {
         &mut self,
         addr: Address,
         target: Address,
-    ) -> Option<StateLoad<SelfDestructResult>> {
+    ) -> Result<StateLoad<SelfDestructResult>, HostError> {
         unsafe {
             let addr = transmute::<Address, evmc_address>(addr);
             let target = transmute::<Address, evmc_address>(target);
@@ -195,7 +202,7 @@ impl Host for EvmcDelegateHost<'_> {
             let had_value = !U256::from_be_bytes(balance.bytes).is_zero();
             let target_exists = self.context.account_exists(&target);
             let first_register = self.context.selfdestruct(&addr, &target);
-            Some(StateLoad::new(
+            Ok(StateLoad::new(
                 SelfDestructResult {
                     had_value,
                     target_exists,
@@ -207,8 +214,8 @@ impl Host for EvmcDelegateHost<'_> {
     }
 
     #[inline]
-    fn block_hash(&mut self, number: u64) -> Option<B256> {
-        Some(B256::from(self.context.get_block_hash(number as i64).bytes))
+    fn block_hash(&mut self, number: u64) -> Result<B256, HostError> {
+        Ok(B256::from(self.context.get_block_hash(number as i64).bytes))
     }
 
     fn log(&mut self, log: Log) {
@@ -3,8 +3,8 @@
 
 use dashmap::DashMap;
 use dora::{
-    build_artifact,
-    primitives::{Address, Bytecode, Bytes, Bytes32, SpecId},
+    build_artifact_with_codegen,
+    primitives::{Address, Bytecode, Bytes, Bytes32, CodegenOptions, OptimizationLevel, SpecId},
     runtime::{
         ExitStatusCode,
         artifact::{Artifact, SymbolArtifact},
@@ -25,15 +25,37 @@ mod host;
 mod tests;
 
 lazy_static! {
-    static ref ARTIFACTS: DashMap<Bytes, SymbolArtifact> = DashMap::default();
+    static ref ARTIFACTS: DashMap<(Bytes, CodegenOptions), SymbolArtifact> = DashMap::default();
 }
 
 #[evmc_declare_vm("dora", "evm, ewasm, precompiles", "12.0.0")]
-pub struct DoraVM {}
+pub struct DoraVM {
+    /// The LLVM code generation options set by `evmc_set_option`.
+    codegen: CodegenOptions,
+}
 
 impl EvmcVm for DoraVM {
     fn init() -> Self {
-        Self {}
+        Self {
+            codegen: CodegenOptions::default(),
+        }
+    }
+
+    /// Sets the LLVM code generation options, the supported options are `opt-level` ("0" to "3"),
+    /// `target-cpu` and `target-features` e.g., "+avx2,+bmi2", where "native" denotes the host CPU.
+    fn set_option(&mut self, key: &str, value: &str) -> Result<(), SetOptionError> {
+        let codegen = self.codegen.clone();
+        self.codegen = match key {
+            "opt-level" => codegen.opt_level(
+                value
+                    .parse::<OptimizationLevel>()
+                    .map_err(|_| SetOptionError::InvalidValue)?,
+            ),
+            "target-cpu" => codegen.target_cpu(value),
+            "target-features" => codegen.target_features(value),
+            _ => return Err(SetOptionError::InvalidKey),
+        };
+        Ok(())
     }
 
     fn execute<'a>(
@@ -66,21 +88,21 @@ impl EvmcVm for DoraVM {
             spec_id,
             message.gas() as u64,
         );
-        let artifact = if let Some(artifact) =
-            ARTIFACTS.get(runtime_context.contract.code.original_byte_slice().as_ref())
-        {
+        let key = (
+            runtime_context.contract.code.original_bytes(),
+            self.codegen.clone(),
+        );
+        let artifact = if let Some(artifact) = ARTIFACTS.get(&key) {
             artifact.clone()
         } else {
-            let Ok(artifact) = build_artifact::<MemoryDB>(
+            let Ok(artifact) = build_artifact_with_codegen::<MemoryDB>(
                 &runtime_context.contract.code,
                 runtime_context.inner.spec_id,
+                &self.codegen,
             ) else {
                 return ExecutionResult::failure();
             };
-            ARTIFACTS.insert(
-                runtime_context.contract.code.original_bytes(),
-                artifact.clone(),
-            );
+            ARTIFACTS.insert(key, artifact.clone());
             artifact
         };
         let Ok(result) = artifact.execute(runtime_context) else {
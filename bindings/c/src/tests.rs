@@ -5,3 +5,25 @@ fn test_doravm_lifecycle() {
     let doravm = evmc_create_doravm();
     __evmc_destroy(doravm as _);
 }
+
+#[test]
+fn test_doravm_set_option() {
+    let mut doravm = DoraVM::init();
+    assert!(doravm.set_option("opt-level", "3").is_ok());
+    assert!(doravm.set_option("target-cpu", "native").is_ok());
+    assert!(doravm.set_option("target-features", "+avx2,+bmi2").is_ok());
+    assert_eq!(doravm.codegen.opt_level, OptimizationLevel::Aggressive);
+    assert_eq!(doravm.codegen.target_cpu, None);
+    assert_eq!(
+        doravm.codegen.target_features.as_deref(),
+        Some("+avx2,+bmi2")
+    );
+    assert!(matches!(
+        doravm.set_option("opt-level", "4"),
+        Err(SetOptionError::InvalidValue)
+    ));
+    assert!(matches!(
+        doravm.set_option("unknown", "0"),
+        Err(SetOptionError::InvalidKey)
+    ));
+}
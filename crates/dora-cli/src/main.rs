@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 use clap::{Args, Parser, Subcommand};
+use dora::{ArtifactCache, VM, VMContext, compile_handler_with_codegen};
 use dora_primitives::spec::SpecId;
-use dora_primitives::{Address, Bytecode, Bytes, Env, TxKind, U256};
+use dora_primitives::{
+    Address, Bytecode, Bytes, CodegenOptions, Env, OptimizationLevel, TxKind, U256,
+};
 use dora_runtime::db::MemoryDB;
 use std::str::FromStr;
 use tracing::{error, info};
@@ -65,6 +68,18 @@ struct RunArgs {
     /// VM Spec id
     #[arg(long, default_value = "Cancun")]
     spec_id: String,
+
+    /// LLVM optimization level (0, 1, 2 or 3)
+    #[arg(long, default_value = "2")]
+    opt_level: OptimizationLevel,
+
+    /// Target CPU e.g., x86-64-v3 (Default is the host CPU)
+    #[arg(long)]
+    target_cpu: Option<String>,
+
+    /// Comma separated target features e.g., +avx2,+bmi2 (Default is the host CPU features)
+    #[arg(long)]
+    target_features: Option<String>,
 }
 
 fn main() -> Result<()> {
@@ -138,8 +153,17 @@ fn main() -> Result<()> {
                 .map_err(|_| anyhow::anyhow!("unknown spec id"))?;
             // Set DB
             let db = MemoryDB::new().with_contract(address, Bytecode::new_raw(bytecode.into()));
+            // Set the code generation options
+            let mut codegen = CodegenOptions::default().opt_level(run_args.opt_level);
+            if let Some(target_cpu) = &run_args.target_cpu {
+                codegen = codegen.target_cpu(target_cpu);
+            }
+            if let Some(target_features) = &run_args.target_features {
+                codegen = codegen.target_features(target_features);
+            }
+            let handler = compile_handler_with_codegen(ArtifactCache::global(), codegen);
             // Run the contract
-            match dora::run(env, db) {
+            match VM::new(VMContext::new(db, env, handler)).transact_commit() {
                 Ok(result) => {
                     info!("Execution result: {:#?}", result);
                 }
@@ -644,6 +644,12 @@ pub trait Builder: IRTypes + TypeMethods {
     /// - `value` - The value to shift.
     fn sar(&mut self, shift: Self::Value, value: Self::Value) -> Result<Self::Value>;
 
+    /// Counts the leading zero bits of `value`, which is the bit width if `value` is zero.
+    ///
+    /// # Parameters
+    /// - `value` - The value to count.
+    fn clz(&mut self, value: Self::Value) -> Result<Self::Value>;
+
     /// Performs an unsigned remainder operation (`lhs % rhs`).
     ///
     /// # Arguments
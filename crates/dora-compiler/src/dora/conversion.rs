@@ -1,6 +1,9 @@
 use crate::{conversion::walker::walk_operation, errors::Result, value::IntoContextOperation};
 use dora_primitives::SpecId;
-use dora_runtime::call::{CallType, ExtCallType};
+use dora_runtime::{
+    call::{CallType, ExtCallType},
+    gas::GasSchedule,
+};
 use melior::{
     Context, ContextRef,
     dialect::DialectHandle,
@@ -38,6 +41,8 @@ pub struct ConversionPass<'c> {
     pub code_size: u32,
     pub spec_id: SpecId,
     pub limit_contract_code_size: usize,
+    /// The gas schedule of the dynamic gas costs baked into the generated code.
+    pub gas_schedule: GasSchedule,
 }
 
 impl ConversionPass<'_> {
@@ -224,15 +229,15 @@ impl ConversionPass<'_> {
             } else if name == "dora.mcopy" {
                 Self::mcopy(context, op)?;
             } else if name == "dora.log0" {
-                Self::log(context, op, 0)?;
+                Self::log(context, op, 0, &self.gas_schedule)?;
             } else if name == "dora.log1" {
-                Self::log(context, op, 1)?;
+                Self::log(context, op, 1, &self.gas_schedule)?;
             } else if name == "dora.log2" {
-                Self::log(context, op, 2)?;
+                Self::log(context, op, 2, &self.gas_schedule)?;
             } else if name == "dora.log3" {
-                Self::log(context, op, 3)?;
+                Self::log(context, op, 3, &self.gas_schedule)?;
             } else if name == "dora.log4" {
-                Self::log(context, op, 4)?;
+                Self::log(context, op, 4, &self.gas_schedule)?;
             } else if name == "dora.dataload" || name == "dora.dataloadn" {
                 Self::dataload(context, op)?;
             } else if name == "dora.datasize" {
@@ -197,17 +197,18 @@ pub(crate) fn compute_create2_cost<'c>(
 
 /// Computes LOG opcode cost, which is given by the following equations:
 ///
-/// Computes `dynamic_gas = 8 * size`.
+/// Computes `dynamic_gas = log_data * size`, where `log_data` is 8 in the Ethereum gas schedule.
 ///
-/// Note: `375 * topic_count` is the static gas.
+/// Note: `375 + 375 * topic_count` is the static gas.
 pub(crate) fn compute_log_dynamic_cost<'c>(
     rewriter: &'c Rewriter,
     size: Value<'c, 'c>,
+    log_data: u64,
 ) -> Result<Value<'c, 'c>> {
     let location = rewriter.get_insert_location();
-    let constant_8 = rewriter.make(rewriter.iconst_64(8))?;
-    let size_x_8 = rewriter.make(arith::muli(size, constant_8, location))?;
-    Ok(size_x_8)
+    let log_data = rewriter.make(rewriter.iconst_64(log_data as i64))?;
+    let gas = rewriter.make(arith::muli(size, log_data, location))?;
+    Ok(gas)
 }
 
 /// Computes memory gas cost, which is given by the following equations:
@@ -10,6 +10,7 @@ use crate::{
 };
 use crate::{check_runtime_error, ensure_non_staticcall, gas_or_fail, if_here};
 use dora_runtime::ExitStatusCode;
+use dora_runtime::gas::GasSchedule;
 use dora_runtime::symbols;
 use melior::{
     Context,
@@ -324,6 +325,7 @@ impl ConversionPass<'_> {
         context: &Context,
         op: &OperationRef<'_, '_>,
         num_topics: usize,
+        gas_schedule: &GasSchedule,
     ) -> Result<()> {
         debug_assert!(num_topics <= 4, "invalid log topic count: {num_topics}");
         operands!(op, offset, size);
@@ -337,7 +339,7 @@ impl ConversionPass<'_> {
 
         // Check the log mem offset and size overflow error
         u256_as_usize_or_fail!(op, rewriter, size);
-        let gas = compute_log_dynamic_cost(&rewriter, size)?;
+        let gas = compute_log_dynamic_cost(&rewriter, size, gas_schedule.log_data)?;
         gas_or_fail!(op, rewriter, gas, gas_counter_ptr);
         rewrite_ctx!(context, op, rewriter, NoDefer);
 
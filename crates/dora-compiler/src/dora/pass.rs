@@ -1,7 +1,7 @@
 use super::{conversion, storage};
 use crate::errors::Result;
 use dora_primitives::SpecId;
-use dora_runtime::constants::gas_cost::MAX_CODE_SIZE;
+use dora_runtime::gas::{GasSchedule, max_code_size};
 use melior::{Context, ir::Module as MLIRModule};
 
 /// Options for configuring a pass, including program-related settings.
@@ -17,6 +17,7 @@ pub struct PassOptions {
     pub spec_id: SpecId,
     pub code_size: u32,
     pub limit_contract_code_size: Option<usize>,
+    pub gas_schedule: Option<GasSchedule>,
 }
 
 impl Default for PassOptions {
@@ -25,6 +26,7 @@ impl Default for PassOptions {
             spec_id: SpecId::CANCUN,
             code_size: Default::default(),
             limit_contract_code_size: Default::default(),
+            gas_schedule: Default::default(),
         }
     }
 }
@@ -57,7 +59,12 @@ pub fn run(ctx: &Context, module: &mut MLIRModule, opts: &PassOptions) -> Result
         ctx,
         code_size: opts.code_size,
         spec_id: opts.spec_id,
-        limit_contract_code_size: opts.limit_contract_code_size.unwrap_or(MAX_CODE_SIZE),
+        limit_contract_code_size: opts
+            .limit_contract_code_size
+            .unwrap_or(max_code_size(opts.spec_id)),
+        gas_schedule: opts
+            .gas_schedule
+            .unwrap_or_else(|| GasSchedule::new(opts.spec_id)),
     };
     conversion_pass.run(module.as_operation())
 }
@@ -673,6 +673,13 @@ impl crate::backend::Builder for EVMBuilder<'_, '_> {
         Ok(op.result(0)?.to_ctx_value())
     }
 
+    fn clz(&mut self, value: Self::Value) -> Result<Self::Value> {
+        let op = self.builder.create(
+            dora_ir::evm::clz(self.context(), self.uint256_ty(), value, self.location()).into(),
+        );
+        Ok(op.result(0)?.to_ctx_value())
+    }
+
     fn urem(&mut self, lhs: Self::Value, rhs: Self::Value) -> Result<Self::Value> {
         let op = arith::remui(lhs, rhs, self.location());
         Ok(op.result(0)?.to_ctx_value())
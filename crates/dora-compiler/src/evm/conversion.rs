@@ -369,6 +369,11 @@ impl ConversionPass<'_> {
                     )
                     .into(),
                 );
+            } else if name == "evm.clz" {
+                rewriter::replace_op(
+                    op,
+                    dora_ir::dora::clz(self.ctx, uint256, op.operand(0)?, op.location()).into(),
+                );
             } else if name == "evm.keccak256" {
                 rewriter::replace_op(
                     op,
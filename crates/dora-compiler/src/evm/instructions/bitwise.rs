@@ -171,4 +171,15 @@ impl<'c> EVMCompiler<'c> {
         builder.stack_push(value)?;
         Ok(start_block)
     }
+
+    pub(crate) fn clz<'r>(
+        ctx: &mut CtxType<'c>,
+        start_block: BlockRef<'r, 'c>,
+    ) -> Result<BlockRef<'r, 'c>> {
+        let mut builder = Self::make_builder(ctx, start_block);
+        let value = builder.stack_pop()?;
+        let value = builder.clz(value)?;
+        builder.stack_push(value)?;
+        Ok(start_block)
+    }
 }
@@ -1,8 +1,8 @@
-use dora_primitives::{IndexMap, IndexMapEntry, SpecId};
+use dora_primitives::{CodegenOptions, IndexMap, IndexMapEntry, OptimizationLevel, SpecId};
 use dora_runtime::ExitStatusCode;
-use dora_runtime::constants::env::DORA_TRACING;
 use dora_runtime::{
-    constants::{ENTRYPOINT, MAX_STACK_SIZE},
+    constants::{ENTRYPOINT, MAX_STACK_SIZE, gas_cost::CLZ_GAS},
+    gas::GasSchedule,
     symbols as runtime_symbols,
 };
 use melior::dialect::llvm::AllocaOptions;
@@ -231,10 +231,8 @@ impl<'c> EVMCompiler<'c> {
         op: &Operation,
         opts: &EVMCompileOptions,
     ) -> Result<(BlockRef<'c, 'c>, BlockRef<'c, 'c>)> {
-        let op_infos = op_info_map(unsafe {
-            std::mem::transmute::<dora_primitives::SpecId, revmc::primitives::SpecId>(opts.spec_id)
-        });
-        let op_info = op_infos[op.opcode()];
+        let op_info = Self::op_info(op, opts.spec_id);
+        let static_gas = Self::static_gas(op, &op_info, &opts.effective_gas_schedule());
         // Single operation function does not contains multiple operation blocks
         let start_block = if ctx.operation_blocks.is_empty() {
             region.append_block(Block::new(&[]))
@@ -258,10 +256,15 @@ impl<'c> EVMCompiler<'c> {
 
         let mut op_start_block = start_block;
 
+        // The tracing hook needs to be done before gas metering to observe the gas before the instruction.
+        if opts.tracing {
+            op_start_block =
+                Self::tracing_block(ctx, region, op_start_block, index, op, static_gas)?;
+        }
+
         // Static gas metering needs to be done before stack checking.
         if opts.gas_metering {
-            op_start_block =
-                Self::gas_metering_block(ctx, region, op_start_block, index, op, &op_info)?;
+            op_start_block = Self::gas_metering_block(ctx, region, op_start_block, static_gas)?;
         }
 
         // Stack overflow/underflow check.
@@ -298,6 +301,7 @@ impl<'c> EVMCompiler<'c> {
             Operation::Shl => EVMCompiler::shl(ctx, op_start_block),
             Operation::Shr => EVMCompiler::shr(ctx, op_start_block),
             Operation::Sar => EVMCompiler::sar(ctx, op_start_block),
+            Operation::Clz => EVMCompiler::clz(ctx, op_start_block),
             // System instructions
             Operation::Keccak256 => EVMCompiler::keccak256(ctx, op_start_block),
             Operation::Address => EVMCompiler::address(ctx, op_start_block),
@@ -389,6 +393,28 @@ impl<'c> EVMCompiler<'c> {
         Ok((start_block, op_end_block))
     }
 
+    /// Returns the static gas and the availability of the operation in the spec, the opcodes
+    /// introduced after the revmc opcode table, e.g., the Osaka `CLZ` opcode, are added here.
+    fn op_info(op: &Operation, spec_id: SpecId) -> OpcodeInfo {
+        // EIP-7939: Count leading zeros (CLZ) opcode
+        if matches!(op, Operation::Clz) && spec_id.is_enabled_in(SpecId::OSAKA) {
+            return OpcodeInfo::new(CLZ_GAS as u16);
+        }
+        let op_infos = op_info_map(unsafe {
+            std::mem::transmute::<dora_primitives::SpecId, revmc::primitives::SpecId>(spec_id)
+        });
+        op_infos[op.opcode()]
+    }
+
+    /// Returns the static gas of the operation, the static gas of the `LOG` opcodes follows the
+    /// gas schedule.
+    fn static_gas(op: &Operation, op_info: &OpcodeInfo, gas_schedule: &GasSchedule) -> u64 {
+        match op {
+            Operation::Log(n) => gas_schedule.log_cost(*n),
+            _ => op_info.base_gas() as u64,
+        }
+    }
+
     #[inline]
     fn is_always_inline(op: &Operation) -> bool {
         matches!(
@@ -495,53 +521,65 @@ impl<'c> EVMCompiler<'c> {
         Ok(end_block)
     }
 
-    fn gas_metering_block<'r>(
+    fn tracing_block<'r>(
         ctx: &mut CtxType<'c>,
         region: &'r Region<'c>,
-        gas_check_block: BlockRef<'r, 'c>,
+        tracing_block: BlockRef<'r, 'c>,
         index: usize,
         op: &Operation,
-        op_info: &OpcodeInfo,
+        static_gas: u64,
+    ) -> Result<BlockRef<'r, 'c>> {
+        let end_block = region.append_block(Block::new(&[]));
+        let builder = OpBuilder::new_with_block(ctx.context, tracing_block);
+        let uint8 = builder.i8_ty();
+        let uint64 = builder.i64_ty();
+        let location = builder.get_insert_location();
+        let gas_counter = builder.make(builder.load(ctx.values.gas_counter_ptr, uint64))?;
+        let gas_value = builder.make(builder.iconst_64(static_gas as i64))?;
+        let opcode = builder.make(builder.iconst(uint8, op.opcode() as i64))?;
+        let pc = ctx.program.index_to_pc(index).unwrap_or_default();
+        let pc = builder.make(builder.iconst(builder.isize_ty(), pc as i64))?;
+        builder.create(func::call(
+            builder.context(),
+            FlatSymbolRefAttribute::new(builder.context(), runtime_symbols::TRACING),
+            &[
+                ctx.values.syscall_ctx,
+                pc,
+                opcode,
+                gas_counter,
+                gas_value,
+                ctx.values.stack_ptr,
+                ctx.values.stack_size_ptr,
+            ],
+            &[],
+            location,
+        ));
+        builder.create(cf::br(&end_block, &[], location));
+        Ok(end_block)
+    }
+
+    fn gas_metering_block<'r>(
+        ctx: &mut CtxType<'c>,
+        region: &'r Region<'c>,
+        gas_check_block: BlockRef<'r, 'c>,
+        static_gas: u64,
     ) -> Result<BlockRef<'r, 'c>> {
-        let base_gas = op_info.base_gas();
         let end_block = region.append_block(Block::new(&[]));
         let update_gas_remaining_block = region.append_block(Block::new(&[]));
         let builder = OpBuilder::new_with_block(ctx.context, gas_check_block);
-        let uint8 = builder.i8_ty();
         let uint64 = builder.i64_ty();
         let location = builder.get_insert_location();
         // Get address of gas counter global
         let gas_counter = builder.make(builder.load(ctx.values.gas_counter_ptr, uint64))?;
-        let gas_value = builder.make(builder.iconst_64(base_gas as i64))?;
-        if std::env::var(DORA_TRACING).is_ok() {
-            let opcode = builder.make(builder.iconst(uint8, op.opcode() as i64))?;
-            let pc = ctx.program.index_to_pc(index).unwrap_or_default();
-            let pc = builder.make(builder.iconst(builder.isize_ty(), pc as i64))?;
-            builder.create(func::call(
-                builder.context(),
-                FlatSymbolRefAttribute::new(builder.context(), runtime_symbols::TRACING),
-                &[
-                    ctx.values.syscall_ctx,
-                    pc,
-                    opcode,
-                    gas_counter,
-                    gas_value,
-                    ctx.values.stack_ptr,
-                    ctx.values.stack_size_ptr,
-                ],
-                &[],
-                builder.get_insert_location(),
-            ));
-        } else {
-            // FIXME : Insert an empty FFI interface to prevent inline optimization of gas registers
-            builder.create(func::call(
-                builder.context(),
-                FlatSymbolRefAttribute::new(builder.context(), runtime_symbols::NOP),
-                &[],
-                &[],
-                builder.get_insert_location(),
-            ));
-        }
+        let gas_value = builder.make(builder.iconst_64(static_gas as i64))?;
+        // FIXME : Insert an empty FFI interface to prevent inline optimization of gas registers
+        builder.create(func::call(
+            builder.context(),
+            FlatSymbolRefAttribute::new(builder.context(), runtime_symbols::NOP),
+            &[],
+            &[],
+            builder.get_insert_location(),
+        ));
         let flag = builder.make(arith::cmpi(
             builder.context(),
             arith::CmpiPredicate::Uge,
@@ -614,13 +652,14 @@ impl<'c> EVMCompiler<'c> {
         let mut ctx =
             CtxType::new_main_func_ctx(self.ctx, module, &main_region, &setup_block, program)?;
         let pre_exec_block = main_region.append_block(Block::new(&[]));
-        setup_block.append_operation(cf::br(&pre_exec_block, &[], location));
         let mut last_block = pre_exec_block;
         let has_dynamic_or_invalid_jumps = ctx.program.has_dynamic_or_invalid_jumps();
         // Suspend execution when encountering call or create instructions.
         let suspend = self.opts.suspend && ctx.program.may_suspend();
+        let is_eof = ctx.program.is_eof();
         // Generate all opcode with the inline mode.
-        if self.opts.inline {
+        // Note the tracing mode is always inlined because the tracing hook records the pc of each operation.
+        if self.opts.inline || self.opts.tracing {
             // Generate code for the program
             for (i, op) in ctx.program.operations().iter().enumerate() {
                 let (start_block, end_block) =
@@ -631,6 +670,9 @@ impl<'c> EVMCompiler<'c> {
                 }
                 last_block.append_operation(cf::br(&start_block, &[], location));
                 last_block = end_block;
+                if suspend && op.may_suspend(is_eof) {
+                    last_block = EVMCompiler::suspend_after_op(&mut ctx, &main_region, last_block)?;
+                }
             }
             let return_block = main_region.append_block(Block::new(&[]));
             EVMCompiler::return_empty_result(&ctx, return_block, ExitStatusCode::Stop)?;
@@ -646,7 +688,9 @@ impl<'c> EVMCompiler<'c> {
                 ))
                 .result(0)?
                 .into();
-            let continue_code: Value<'_, '_> = last_block
+            // The resumed blocks are not dominated by the pre-execution block, thus the constant
+            // used by all the operations is defined in the setup block in the suspend mode.
+            let continue_code: Value<'_, '_> = if suspend { setup_block } else { last_block }
                 .append_operation(arith::constant(
                     context,
                     IntegerAttribute::new(uint8, ExitStatusCode::Continue.to_u8() as i64).into(),
@@ -732,6 +776,33 @@ impl<'c> EVMCompiler<'c> {
                     if let Operation::Jumpdest { pc } = op {
                         ctx.register_jump_destination(*pc, start_block);
                     }
+                    if suspend && op.may_suspend(is_eof) {
+                        let is_stop = builder.make(arith::cmpi(
+                            context,
+                            arith::CmpiPredicate::Ne,
+                            result,
+                            continue_code,
+                            location,
+                        ))?;
+                        let resume_block = main_region.append_block(Block::new(&[]));
+                        ctx.resume_blocks.push(resume_block);
+                        let resume_index =
+                            builder.make(builder.iconst_32(ctx.resume_blocks.len() as i32))?;
+                        builder.create(cf::cond_br(
+                            context,
+                            is_stop,
+                            &ctx.stop_block,
+                            &ctx.suspend_block,
+                            &[result],
+                            &[resume_index],
+                            location,
+                        ));
+                        let builder = OpBuilder::new_with_block(context, resume_block);
+                        result = builder
+                            .make(builder.iconst_8(ExitStatusCode::Continue.to_u8() as i8))?
+                            .to_ctx_value();
+                        last_block = resume_block;
+                    }
                 }
             }
             let return_block = main_region.append_block(Block::new(&[]));
@@ -761,9 +832,20 @@ impl<'c> EVMCompiler<'c> {
         }
         // Suspend execution when encountering call or create instructions.
         if suspend {
+            setup_block.append_operation(cf::br(&ctx.resume_block, &[], location));
             // Build resume block and start from here before entering the op loop.
             {
                 let builder = OpBuilder::new_with_block(ctx.context, ctx.resume_block);
+                // Restore the stack top pointer from the stack size saved on suspend.
+                let stack_size =
+                    builder.make(builder.load(ctx.values.stack_size_ptr, builder.i64_ty()))?;
+                let stack_top = builder.make(builder.gep_dynamic(
+                    ctx.values.stack_ptr,
+                    &[stack_size],
+                    builder.i256_ty(),
+                    builder.ptr_ty(),
+                ))?;
+                builder.create(builder.store(stack_top, ctx.values.stack_top_ptr));
                 let resume_index = builder.make(func::call(
                     builder.context(),
                     FlatSymbolRefAttribute::new(builder.context(), runtime_symbols::GET_RESUME),
@@ -801,6 +883,30 @@ impl<'c> EVMCompiler<'c> {
             {
                 let builder = OpBuilder::new_with_block(ctx.context, ctx.suspend_block);
                 let resume_index: Value = ctx.suspend_block.argument(0)?.into();
+                // Save the stack size, so that the runtime can write the call result onto the
+                // stack top and the stack top pointer can be restored on resume.
+                let uint64 = builder.i64_ty();
+                let stack_top =
+                    builder.make(builder.load(ctx.values.stack_top_ptr, builder.ptr_ty()))?;
+                let stack_top = builder.make(
+                    OperationBuilder::new("llvm.ptrtoint", location)
+                        .add_results(&[uint64])
+                        .add_operands(&[stack_top])
+                        .build()?,
+                )?;
+                let stack_bottom = builder.make(
+                    OperationBuilder::new("llvm.ptrtoint", location)
+                        .add_results(&[uint64])
+                        .add_operands(&[ctx.values.stack_ptr])
+                        .build()?,
+                )?;
+                let stack_bytes = builder.make(arith::subi(stack_top, stack_bottom, location))?;
+                let stack_size = builder.make(arith::divui(
+                    stack_bytes,
+                    builder.make(builder.iconst_64(32))?,
+                    location,
+                ))?;
+                builder.create(builder.store(stack_size, ctx.values.stack_size_ptr));
                 builder.create(func::call(
                     builder.context(),
                     FlatSymbolRefAttribute::new(builder.context(), runtime_symbols::SET_RESUME),
@@ -815,6 +921,7 @@ impl<'c> EVMCompiler<'c> {
             }
         } else {
             debug_assert!(ctx.resume_blocks.is_empty());
+            setup_block.append_operation(cf::br(&pre_exec_block, &[], location));
             let builder = OpBuilder::new_with_block(ctx.context, ctx.suspend_block);
             builder.create(builder.unreachable());
             let builder = OpBuilder::new_with_block(ctx.context, ctx.resume_block);
@@ -826,6 +933,26 @@ impl<'c> EVMCompiler<'c> {
         Ok(())
     }
 
+    /// Suspends the execution at the end of the call or create operation block, and returns the
+    /// block where the execution is resumed after the sub call is done by the runtime.
+    fn suspend_after_op(
+        ctx: &mut CtxType<'c>,
+        region: &'c Region<'c>,
+        block: BlockRef<'c, 'c>,
+    ) -> Result<BlockRef<'c, 'c>> {
+        let resume_block = region.append_block(Block::new(&[]));
+        ctx.resume_blocks.push(resume_block);
+        let builder = OpBuilder::new_with_block(ctx.context, block);
+        // Note the resume index `0` denotes the start of the program.
+        let resume_index = builder.make(builder.iconst_32(ctx.resume_blocks.len() as i32))?;
+        builder.create(cf::br(
+            &ctx.suspend_block,
+            &[resume_index],
+            builder.get_insert_location(),
+        ));
+        Ok(resume_block)
+    }
+
     fn return_empty_result(
         ctx: &CtxType,
         block: BlockRef<'_, '_>,
@@ -874,6 +1001,14 @@ pub struct EVMCompileOptions {
     pub suspend: bool,
     /// Use common op functions instead of inlining everything.
     pub inline: bool,
+    /// Insert the tracing hook before each instruction, which calls the attached inspector at runtime.
+    /// When disabled, no tracing code is generated at all.
+    pub tracing: bool,
+    /// The custom gas schedule baked into the generated code, the Ethereum gas schedule of the spec
+    /// is used when it is not set.
+    pub gas_schedule: Option<GasSchedule>,
+    /// The LLVM code generation options e.g., the optimization level and the target CPU.
+    pub codegen: CodegenOptions,
 }
 
 impl Default for EVMCompileOptions {
@@ -884,6 +1019,9 @@ impl Default for EVMCompileOptions {
             stack_bound_checks: true,
             suspend: false,
             inline: false,
+            tracing: false,
+            gas_schedule: None,
+            codegen: CodegenOptions::default(),
         }
     }
 }
@@ -912,6 +1050,67 @@ impl EVMCompileOptions {
         self.suspend = suspend;
         self
     }
+
+    /// Set whether to insert the tracing hook before each instruction.
+    pub fn tracing(mut self, tracing: bool) -> Self {
+        self.tracing = tracing;
+        self
+    }
+
+    /// Set the custom gas schedule baked into the generated code.
+    pub fn gas_schedule(mut self, gas_schedule: GasSchedule) -> Self {
+        self.gas_schedule = Some(gas_schedule);
+        self
+    }
+
+    /// Set the LLVM code generation options.
+    pub fn codegen(mut self, codegen: CodegenOptions) -> Self {
+        self.codegen = codegen;
+        self
+    }
+
+    /// Set the LLVM optimization level.
+    pub fn opt_level(mut self, opt_level: OptimizationLevel) -> Self {
+        self.codegen = self.codegen.opt_level(opt_level);
+        self
+    }
+
+    /// Set the target CPU, "native" denotes the host CPU.
+    pub fn target_cpu(mut self, target_cpu: impl Into<String>) -> Self {
+        self.codegen = self.codegen.target_cpu(target_cpu);
+        self
+    }
+
+    /// Set the comma separated target features, "native" denotes the host CPU features.
+    pub fn target_features(mut self, target_features: impl Into<String>) -> Self {
+        self.codegen = self.codegen.target_features(target_features);
+        self
+    }
+
+    /// Returns the gas schedule baked into the generated code, which is the custom gas schedule or
+    /// the Ethereum gas schedule of the spec.
+    pub fn effective_gas_schedule(&self) -> GasSchedule {
+        self.gas_schedule
+            .unwrap_or_else(|| GasSchedule::new(self.spec_id))
+    }
+
+    /// Returns the key which identifies the options affecting the generated code, it is used by the
+    /// artifact caches. The spec ID is not included because it is a part of the cache key itself.
+    pub fn cache_key(&self) -> String {
+        format!(
+            concat!(
+                "evm:gas_metering={},stack_bound_checks={},suspend={},inline={},tracing={},",
+                "gas_schedule={:?},codegen={:?}"
+            ),
+            self.gas_metering,
+            self.stack_bound_checks,
+            self.suspend,
+            self.inline,
+            self.tracing,
+            self.effective_gas_schedule(),
+            self.codegen
+        )
+    }
 }
 
 /// The [`CtxValues`] struct encapsulates values specific to the EVM context, such as those used for
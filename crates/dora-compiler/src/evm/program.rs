@@ -146,7 +146,8 @@ opcodes! {
     SHL = 0x1B,
     SHR = 0x1C,
     SAR = 0x1D,
-    // unused 0x1E-0x1F
+    CLZ = 0x1E,
+    // unused 0x1F
     KECCAK256 = 0x20,
     // unused 0x21-0x2F
     ADDRESS = 0x30,
@@ -861,6 +862,7 @@ operations!(
     (Shl, SHL),
     (Shr, SHR),
     (Sar, SAR),
+    (Clz, CLZ),
     (Keccak256, KECCAK256),
     (Address, ADDRESS),
     (Balance, BALANCE),
@@ -1241,6 +1243,7 @@ impl Operation {
             Operation::Shl => (2, 1),
             Operation::Shr => (2, 1),
             Operation::Sar => (2, 1),
+            Operation::Clz => (1, 1),
             Operation::Keccak256 => (2, 1),
             Operation::Address => (0, 1),
             Operation::Balance => (1, 1),
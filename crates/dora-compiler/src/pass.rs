@@ -1,8 +1,21 @@
+use std::ffi::{CStr, c_char};
+
+use dora_primitives::CodegenOptions;
 use melior::{
     Context, Error,
-    ir::Module,
+    ir::{Attribute, Module, OperationRef, attribute::StringAttribute},
     pass::{self, PassManager},
 };
+use mlir_sys::{mlirOperationGetNextInBlock, mlirOperationSetAttributeByName, mlirStringRefCreate};
+
+use crate::errors::CompileError;
+
+// The LLVM C API of the host target, which is linked along with the MLIR execution engine.
+unsafe extern "C" {
+    fn LLVMGetHostCPUName() -> *mut c_char;
+    fn LLVMGetHostCPUFeatures() -> *mut c_char;
+    fn LLVMDisposeMessage(message: *mut c_char);
+}
 
 /// Executes a series of optimization and conversion passes on the given [`MLIR`][melior] module.
 ///
@@ -57,3 +70,162 @@ pub fn run(context: &Context, module: &mut Module) -> Result<(), Error> {
     pass_manager.add_pass(pass::conversion::create_reconcile_unrealized_casts());
     pass_manager.run(module)
 }
+
+/// Applies the target CPU and target features of the code generation options to all the LLVM
+/// functions of the lowered [`MLIR`][melior] module. The module is left untouched when neither is
+/// set, and the JIT compiles the module for the host CPU.
+///
+/// Note that this function must be called after [`run`], when all functions have been lowered to
+/// the `llvm.func` operations, and the JIT code must be checked by [`check_host_target`].
+pub fn set_target(
+    context: &Context,
+    module: &mut Module,
+    codegen: &CodegenOptions,
+) -> crate::errors::Result<()> {
+    let features = codegen.target_feature_list();
+    let mut attributes: Vec<(&str, Attribute)> = vec![];
+    if let Some(target_cpu) = &codegen.target_cpu {
+        attributes.push((
+            "target_cpu",
+            StringAttribute::new(context, target_cpu).into(),
+        ));
+    }
+    if !features.is_empty() {
+        let features = features
+            .iter()
+            .map(|feature| format!("{feature:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let attribute = format!("#llvm.target_features<[{features}]>");
+        let attribute = Attribute::parse(context, &attribute)
+            .ok_or_else(|| anyhow::anyhow!("invalid target features: {attribute}"))?;
+        attributes.push(("target_features", attribute));
+    }
+    if attributes.is_empty() {
+        return Ok(());
+    }
+    let mut op = module.body().first_operation().map(|op| op.to_raw());
+    while let Some(raw_op) = op {
+        // SAFETY: The operations and the attributes are owned by the living module and context.
+        unsafe {
+            let func = OperationRef::from_raw(raw_op);
+            if func.name().as_string_ref().as_str() == Ok("llvm.func") {
+                for (name, attribute) in &attributes {
+                    mlirOperationSetAttributeByName(
+                        raw_op,
+                        mlirStringRefCreate(name.as_ptr().cast(), name.len()),
+                        attribute.to_raw(),
+                    );
+                }
+            }
+            let next_op = mlirOperationGetNextInBlock(raw_op);
+            op = (!next_op.ptr.is_null()).then_some(next_op);
+        }
+    }
+    Ok(())
+}
+
+/// The x86-64 psABI microarchitecture levels with the features they require on the host.
+const X86_64_V2: &[&str] = &[
+    "cx16", "sahf", "popcnt", "sse3", "sse4.1", "sse4.2", "ssse3",
+];
+const X86_64_V3: &[&str] = &[
+    "cx16", "sahf", "popcnt", "sse3", "sse4.1", "sse4.2", "ssse3", "avx", "avx2", "bmi", "bmi2",
+    "f16c", "fma", "lzcnt", "movbe", "xsave",
+];
+const X86_64_V4: &[&str] = &[
+    "cx16", "sahf", "popcnt", "sse3", "sse4.1", "sse4.2", "ssse3", "avx", "avx2", "bmi", "bmi2",
+    "f16c", "fma", "lzcnt", "movbe", "xsave", "avx512f", "avx512bw", "avx512cd", "avx512dq",
+    "avx512vl",
+];
+
+/// The portable CPUs of the host architecture with the features they require on the host.
+const PORTABLE_CPUS: &[(&str, &[&str])] = if cfg!(target_arch = "x86_64") {
+    &[
+        ("generic", &[]),
+        ("x86-64", &[]),
+        ("x86-64-v2", X86_64_V2),
+        ("x86-64-v3", X86_64_V3),
+        ("x86-64-v4", X86_64_V4),
+    ]
+} else {
+    &[("generic", &[])]
+};
+
+/// Returns the LLVM message and disposes it.
+///
+/// # Safety
+///
+/// The message must be allocated by LLVM.
+unsafe fn take_llvm_message(message: *mut c_char) -> String {
+    // SAFETY: The message is a NUL terminated string owned by the caller.
+    unsafe {
+        let string = CStr::from_ptr(message).to_string_lossy().into_owned();
+        LLVMDisposeMessage(message);
+        string
+    }
+}
+
+/// Returns the name of the host CPU, e.g., "znver4" and "apple-m1".
+pub fn host_cpu_name() -> String {
+    // SAFETY: The returned message is allocated by LLVM.
+    unsafe { take_llvm_message(LLVMGetHostCPUName()) }
+}
+
+/// Returns the features of the host CPU, e.g., `["+avx2", "-avx512f"]`.
+pub fn host_cpu_features() -> Vec<String> {
+    // SAFETY: The returned message is allocated by LLVM.
+    let features = unsafe { take_llvm_message(LLVMGetHostCPUFeatures()) };
+    features
+        .split(',')
+        .filter(|feature| !feature.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Checks that the code generated with the target CPU and target features of the code
+/// generation options can run on the host CPU, otherwise the JIT code may crash with an illegal
+/// instruction. The target CPU must be the host CPU or a portable CPU of the host architecture,
+/// e.g., "x86-64-v3", whose features the host CPU has, and only the features the host CPU has
+/// can be enabled, while any feature can be disabled.
+///
+/// The foreign targets are only valid for the object code which is not executed by this
+/// process.
+pub fn check_host_target(codegen: &CodegenOptions) -> crate::errors::Result<()> {
+    let mut required = codegen
+        .target_feature_list()
+        .into_iter()
+        .filter(|feature| !feature.starts_with('-'))
+        .map(|feature| feature.strip_prefix('+').unwrap_or(feature))
+        .collect::<Vec<_>>();
+    if let Some(target_cpu) = &codegen.target_cpu {
+        let host_cpu = host_cpu_name();
+        if *target_cpu != host_cpu {
+            let Some((_, features)) = PORTABLE_CPUS
+                .iter()
+                .find(|(cpu, _)| *cpu == target_cpu.as_str())
+            else {
+                return Err(CompileError::Codegen(format!(
+                    "target cpu {target_cpu} is not supported by the host cpu {host_cpu}"
+                ))
+                .into());
+            };
+            required.extend_from_slice(features);
+        }
+    }
+    if required.is_empty() {
+        return Ok(());
+    }
+    let host_features = host_cpu_features();
+    match required.into_iter().find(|feature| {
+        !host_features
+            .iter()
+            .any(|host| host.strip_prefix('+') == Some(*feature))
+    }) {
+        Some(feature) => Err(CompileError::Codegen(format!(
+            "target feature {feature} is not supported by the host cpu"
+        ))
+        .into()),
+        None => Ok(()),
+    }
+}
@@ -17,6 +17,7 @@ pub use conversion::ConversionPass;
 #[cfg(test)]
 mod tests;
 
+use dora_primitives::{CodegenOptions, OptimizationLevel};
 use dora_runtime::wasm::WASMInstance;
 use dora_runtime::wasm::{env::WASMEnv, host};
 use func::FuncTranslator;
@@ -473,6 +474,8 @@ pub struct WASMCompileOptions {
     pub gas_metering: bool,
     /// Whether to check static memory bound and offset guard.
     pub static_memory_bound_check: bool,
+    /// The LLVM code generation options e.g., the optimization level and the target CPU.
+    pub codegen: CodegenOptions,
 }
 
 impl WASMCompileOptions {
@@ -487,4 +490,39 @@ impl WASMCompileOptions {
         self.static_memory_bound_check = static_memory_bound_check;
         self
     }
+
+    /// Set the LLVM code generation options.
+    pub fn codegen(mut self, codegen: CodegenOptions) -> Self {
+        self.codegen = codegen;
+        self
+    }
+
+    /// Set the LLVM optimization level.
+    pub fn opt_level(mut self, opt_level: OptimizationLevel) -> Self {
+        self.codegen = self.codegen.opt_level(opt_level);
+        self
+    }
+
+    /// Set the target CPU, "native" denotes the host CPU.
+    pub fn target_cpu(mut self, target_cpu: impl Into<String>) -> Self {
+        self.codegen = self.codegen.target_cpu(target_cpu);
+        self
+    }
+
+    /// Set the comma separated target features, "native" denotes the host CPU features.
+    pub fn target_features(mut self, target_features: impl Into<String>) -> Self {
+        self.codegen = self.codegen.target_features(target_features);
+        self
+    }
+
+    /// Returns the key which identifies the options affecting the generated code, it is used by the
+    /// artifact caches. Returns `None` when there are middlewares, which can't be identified.
+    pub fn cache_key(&self) -> Option<String> {
+        self.middlewares.is_empty().then(|| {
+            format!(
+                "wasm:gas_metering={},static_memory_bound_check={},codegen={:?}",
+                self.gas_metering, self.static_memory_bound_check, self.codegen
+            )
+        })
+    }
 }
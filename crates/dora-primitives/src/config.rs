@@ -31,3 +31,54 @@ impl std::str::FromStr for OptimizationLevel {
         }
     }
 }
+
+/// Represents the LLVM code generation options of the compiled artifacts, which trade the compile
+/// latency for the code quality without changing the semantics of the generated code. The target
+/// CPU and features of the executed artifacts must be supported by the host CPU.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CodegenOptions {
+    /// The LLVM optimization level.
+    pub opt_level: OptimizationLevel,
+    /// The target CPU e.g., "x86-64-v3" and "apple-m1", the host CPU is used when it is not set.
+    pub target_cpu: Option<String>,
+    /// The comma separated target features e.g., "+avx2,+bmi2", the host CPU features are used
+    /// when it is not set.
+    pub target_features: Option<String>,
+}
+
+impl CodegenOptions {
+    /// Set the LLVM optimization level.
+    pub fn opt_level(mut self, opt_level: OptimizationLevel) -> Self {
+        self.opt_level = opt_level;
+        self
+    }
+
+    /// Set the target CPU, "native" denotes the host CPU.
+    pub fn target_cpu(mut self, target_cpu: impl Into<String>) -> Self {
+        let target_cpu = target_cpu.into();
+        self.target_cpu = (!target_cpu.is_empty() && target_cpu != "native").then_some(target_cpu);
+        self
+    }
+
+    /// Set the comma separated target features, "native" denotes the host CPU features.
+    pub fn target_features(mut self, target_features: impl Into<String>) -> Self {
+        let target_features = target_features.into();
+        self.target_features =
+            (!target_features.is_empty() && target_features != "native").then_some(target_features);
+        self
+    }
+
+    /// Returns the target features as a list e.g., `["+avx2", "+bmi2"]`.
+    pub fn target_feature_list(&self) -> Vec<&str> {
+        self.target_features
+            .as_deref()
+            .map(|features| {
+                features
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|feature| !feature.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
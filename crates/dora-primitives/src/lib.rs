@@ -30,10 +30,12 @@ pub use revm::context_interface::{
     },
 };
 pub use revm::database::{DBErrorMarker, Database, DatabaseCommit, DatabaseRef};
-pub use revm::precompile::{PrecompileError, PrecompileOutput, PrecompileSpecId, Precompiles};
+pub use revm::precompile::{
+    PrecompileError, PrecompileOutput, PrecompileResult, PrecompileSpecId, Precompiles,
+};
 pub use revm::primitives::{
-    Address, B256, BLOCK_HASH_HISTORY, Bytes, FixedBytes, I256, KECCAK_EMPTY, Log, LogData, TxKind,
-    U256, address, alloy_primitives, b256,
+    Address, B256, BLOCK_HASH_HISTORY, Bloom, Bytes, FixedBytes, I256, KECCAK_EMPTY, Log, LogData,
+    TxKind, U256, address, alloy_primitives, b256,
     constants::MAX_INITCODE_SIZE,
     eip4844::{self, GAS_PER_BLOB},
     eip7702::{self, PER_AUTH_BASE_COST, PER_EMPTY_ACCOUNT_COST},
@@ -48,7 +50,7 @@ pub use revm::state::{
 pub mod config;
 pub mod spec;
 
-pub use config::OptimizationLevel;
+pub use config::{CodegenOptions, OptimizationLevel};
 pub use spec::{SpecId, SpecName};
 
 /// Converts a [U256] value to a [u64], saturating to [MAX][u64] if the value is too large.
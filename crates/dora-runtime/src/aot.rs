@@ -0,0 +1,379 @@
+//! A persistent on-disk cache of the native object code compiled from the EVM/WASM bytecode.
+//!
+//! The compiled object code is saved into the cache directory when a contract is compiled the
+//! first time. When the same contract is compiled again, e.g., in another process, the object
+//! code is linked into a shared library with the addresses of the runtime symbols of the current
+//! process, and then mapped into memory with `dlopen` instead of being compiled again.
+//!
+//! Linking requires a system linker, i.e., `cc` on the `PATH` or the one set by the
+//! `DORA_AOT_LINKER` environment variable. Without the linker, the entries are still saved but
+//! every load is a miss, thus the contracts are compiled as if the cache is disabled.
+
+use crate::{
+    constants::env::{DORA_AOT_CACHE_DIR, DORA_AOT_CACHE_MAX_SIZE, DORA_AOT_LINKER},
+    context::{RuntimeContext, SymbolSignature},
+    executor::{ExecuteKind, Executor},
+};
+use dora_primitives::{B256, SpecId};
+use sha2::{Digest, Sha256};
+use std::{
+    ffi::{CString, c_void},
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::SystemTime,
+};
+
+/// The magic bytes of the cache entry file.
+const MAGIC: &[u8; 8] = b"DORAAOT\0";
+/// The dora version, the cache entries of the other versions are never used.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The default max size of the cache directory in bytes.
+pub const DEFAULT_MAX_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// The key of a cache entry, the object code can only be reused with exactly the same key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AotCacheKey {
+    /// The hash of the contract code.
+    pub code_hash: B256,
+    /// The spec id which the code is compiled with.
+    pub spec_id: SpecId,
+    /// The compile options, e.g., `EVMCompileOptions::cache_key`.
+    pub options: String,
+}
+
+impl AotCacheKey {
+    /// Creates a new cache key.
+    pub fn new(code_hash: B256, spec_id: SpecId, options: impl Into<String>) -> Self {
+        Self {
+            code_hash,
+            spec_id,
+            options: options.into(),
+        }
+    }
+
+    /// Returns the file name of the cache entry.
+    fn file_name(&self) -> String {
+        let options = Sha256::digest(self.options.as_bytes());
+        format!(
+            "{}-{:?}-{}.o",
+            hex::encode(self.code_hash),
+            self.spec_id,
+            hex::encode(&options[..8])
+        )
+    }
+}
+
+/// The statistics of the [`AotCache`] loads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AotCacheStats {
+    /// The number of loads which mapped the cached object code.
+    pub hits: u64,
+    /// The number of loads which found no usable entry or failed to link it.
+    pub misses: u64,
+}
+
+/// A persistent on-disk cache of the native object code.
+///
+/// Each entry is a file contains the magic bytes, the sha256 checksum of the object code and the
+/// object code itself. The entries which fail the integrity checks are removed on load. When the
+/// total size of the entries exceeds the max size, the least recently used entries are evicted.
+///
+/// Note that loading the object code requires a system linker (`cc` by default, which can be
+/// changed with the `DORA_AOT_LINKER` environment variable or [`AotCache::with_linker`]) and is
+/// only supported on Linux, a cache miss is returned otherwise and the caller falls back to
+/// compiling the code.
+#[derive(Debug, Clone)]
+pub struct AotCache {
+    dir: PathBuf,
+    max_size: u64,
+    linker: String,
+    /// The hits and misses of the loads, which are shared by the clones.
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+impl AotCache {
+    /// Creates a new cache in the directory, the entries are saved into the sub directory of the
+    /// current dora version.
+    pub fn new<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref().join(VERSION);
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            max_size: DEFAULT_MAX_SIZE,
+            linker: std::env::var(DORA_AOT_LINKER).unwrap_or_else(|_| "cc".to_string()),
+            hits: Default::default(),
+            misses: Default::default(),
+        })
+    }
+
+    /// Creates a new cache from the `DORA_AOT_CACHE_DIR` and the `DORA_AOT_CACHE_MAX_SIZE`
+    /// environment variables, returns `None` when the cache directory is not set or can't be created.
+    pub fn from_env() -> Option<Self> {
+        let dir = std::env::var(DORA_AOT_CACHE_DIR).ok()?;
+        let cache = Self::new(dir).ok()?;
+        match std::env::var(DORA_AOT_CACHE_MAX_SIZE)
+            .ok()
+            .and_then(|size| size.parse().ok())
+        {
+            Some(max_size) => Some(cache.with_max_size(max_size)),
+            None => Some(cache),
+        }
+    }
+
+    /// Sets the max size of the cache directory in bytes.
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets the linker of the object code, it is `cc` or the `DORA_AOT_LINKER` environment
+    /// variable by default.
+    pub fn with_linker(mut self, linker: impl Into<String>) -> Self {
+        self.linker = linker.into();
+        self
+    }
+
+    /// Returns the statistics of the loads.
+    pub fn stats(&self) -> AotCacheStats {
+        AotCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the directory of the cache entries.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns whether the cache contains the entry of the key.
+    pub fn contains(&self, key: &AotCacheKey) -> bool {
+        self.dir.join(key.file_name()).is_file()
+    }
+
+    /// Saves the object code of the executor into the cache, the executor must be created with
+    /// the object dump enabled e.g., [`Executor::new_with_object_dump`].
+    pub fn store(&self, key: &AotCacheKey, executor: &Executor) -> io::Result<()> {
+        let path = self.dir.join(key.file_name());
+        let object_path = temp_path(&self.dir, "o");
+        executor.dump_to_object_file(&object_path);
+        let object = fs::read(&object_path);
+        let _ = fs::remove_file(&object_path);
+        let object = object?;
+        if object.is_empty() {
+            return Err(io::Error::other("empty object code"));
+        }
+        // Write to a temporary file and then rename it, so that the other processes never see a
+        // partially written entry.
+        let entry_path = temp_path(&self.dir, "tmp");
+        let result = (|| {
+            let mut file = File::create(&entry_path)?;
+            file.write_all(MAGIC)?;
+            file.write_all(&Sha256::digest(&object))?;
+            file.write_all(&object)?;
+            file.sync_all()?;
+            fs::rename(&entry_path, &path)
+        })();
+        if result.is_err() {
+            let _ = fs::remove_file(&entry_path);
+        }
+        result?;
+        self.evict()
+    }
+
+    /// Loads the executor of the key from the cache, returns `None` when the entry is not found,
+    /// corrupted or can't be loaded.
+    pub fn load(&self, key: &AotCacheKey, kind: ExecuteKind) -> Option<Executor> {
+        let executor = self.load_entry(key, kind);
+        let counter = if executor.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        executor
+    }
+
+    fn load_entry(&self, key: &AotCacheKey, kind: ExecuteKind) -> Option<Executor> {
+        let path = self.dir.join(key.file_name());
+        let entry = fs::read(&path).ok()?;
+        let Some(object) = verify_entry(&entry) else {
+            let _ = fs::remove_file(&path);
+            return None;
+        };
+        let symbols = match kind {
+            ExecuteKind::EVM => RuntimeContext::evm_symbols(),
+            ExecuteKind::WASM(_) => RuntimeContext::wasm_symbols(),
+        };
+        let library = NativeLibrary::link(&self.dir, &self.linker, object, &symbols).ok()?;
+        // Touch the entry for the LRU eviction.
+        if let Ok(file) = File::options().append(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(Executor::new_with_library(library, kind))
+    }
+
+    /// Removes the entry of the key from the cache.
+    pub fn remove(&self, key: &AotCacheKey) -> io::Result<()> {
+        fs::remove_file(self.dir.join(key.file_name()))
+    }
+
+    /// Removes all the entries from the cache.
+    pub fn clear(&self) -> io::Result<()> {
+        for (path, _, _) in self.entries()? {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the total size of the entries in bytes.
+    pub fn size(&self) -> io::Result<u64> {
+        Ok(self.entries()?.iter().map(|(_, size, _)| size).sum())
+    }
+
+    /// Evicts the least recently used entries until the total size is within the max size.
+    pub fn evict(&self) -> io::Result<()> {
+        let mut entries = self.entries()?;
+        let mut size: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if size <= self.max_size {
+            return Ok(());
+        }
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        for (path, entry_size, _) in entries {
+            if size <= self.max_size {
+                break;
+            }
+            // The entry may be removed by another process.
+            if fs::remove_file(path).is_ok() {
+                size -= entry_size;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path, size and modified time of the entries.
+    fn entries(&self) -> io::Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            // Skip the temporary files which are prefixed with a dot.
+            if entry.file_name().to_string_lossy().starts_with('.')
+                || path.extension().is_none_or(|ext| ext != "o")
+            {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            entries.push((
+                path,
+                metadata.len(),
+                metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            ));
+        }
+        Ok(entries)
+    }
+}
+
+/// Verifies the magic bytes and the checksum of the entry and returns the object code.
+fn verify_entry(entry: &[u8]) -> Option<&[u8]> {
+    let entry = entry.strip_prefix(MAGIC)?;
+    let (checksum, object) = entry.split_at_checked(32)?;
+    (!object.is_empty() && Sha256::digest(object).as_slice() == checksum).then_some(object)
+}
+
+/// Returns a unique temporary path in the directory.
+fn temp_path(dir: &Path, extension: &str) -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+    dir.join(format!(".{}-{id}.{extension}", std::process::id()))
+}
+
+/// A shared library loaded with `dlopen`, which is closed when dropped.
+#[derive(Debug)]
+pub(crate) struct NativeLibrary {
+    handle: *mut c_void,
+}
+
+unsafe impl Send for NativeLibrary {}
+unsafe impl Sync for NativeLibrary {}
+
+impl NativeLibrary {
+    /// Links the object code into a shared library with the runtime symbols defined as absolute
+    /// addresses, and loads it.
+    #[cfg(target_os = "linux")]
+    fn link(
+        dir: &Path,
+        linker: &str,
+        object: &[u8],
+        symbols: &[SymbolSignature],
+    ) -> io::Result<Self> {
+        let object_path = temp_path(dir, "o.tmp");
+        let library_path = temp_path(dir, "so");
+        let result = (|| {
+            fs::write(&object_path, object)?;
+            let mut command = std::process::Command::new(linker);
+            command
+                .arg("-shared")
+                .arg("-o")
+                .arg(&library_path)
+                .arg(&object_path);
+            for (symbol, address) in symbols {
+                command.arg(format!("-Wl,--defsym={symbol}={:#x}", *address as usize));
+            }
+            let output = command.output()?;
+            if !output.status.success() {
+                return Err(io::Error::other(
+                    String::from_utf8_lossy(&output.stderr).into_owned(),
+                ));
+            }
+            Self::open(&library_path)
+        })();
+        // The library is already mapped into memory, the files can be removed.
+        let _ = fs::remove_file(&object_path);
+        let _ = fs::remove_file(&library_path);
+        result
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn link(
+        _dir: &Path,
+        _linker: &str,
+        _object: &[u8],
+        _symbols: &[SymbolSignature],
+    ) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "loading the AOT object code is only supported on Linux",
+        ))
+    }
+
+    /// Loads the shared library.
+    fn open(path: &Path) -> io::Result<Self> {
+        let path = CString::new(path.to_string_lossy().as_bytes()).map_err(io::Error::other)?;
+        let handle = unsafe { libc::dlopen(path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+        if handle.is_null() {
+            return Err(io::Error::other("failed to load the AOT library"));
+        }
+        Ok(Self { handle })
+    }
+
+    /// Searches a symbol in the library and returns a pointer to it, or null if not found.
+    pub(crate) fn lookup(&self, name: &str) -> *mut () {
+        let Ok(name) = CString::new(name) else {
+            return std::ptr::null_mut();
+        };
+        unsafe { libc::dlsym(self.handle, name.as_ptr()) as *mut () }
+    }
+}
+
+impl Drop for NativeLibrary {
+    fn drop(&mut self) {
+        unsafe { libc::dlclose(self.handle) };
+    }
+}
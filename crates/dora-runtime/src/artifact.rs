@@ -97,8 +97,13 @@ impl Artifact for SymbolArtifact {
         match &self.executor.kind {
             ExecuteKind::EVM => {
                 let mut initial_gas = context.gas_limit();
+                let mut stack = Stack::new();
+                let mut stack_size = 0;
                 let func: EVMEntryFunc = unsafe { std::mem::transmute(ptr) };
-                func(&mut context, &mut initial_gas, &mut Stack::new(), &mut 0);
+                func(&mut context, &mut initial_gas, &mut stack, &mut stack_size);
+                // Flush the step end hook of the last instruction for the traced code.
+                context
+                    .trace_step_end(context.gas_remaining(), stack.as_slice(stack_size as usize));
                 Ok(CallResult {
                     status: context.status(),
                     gas_limit: context.gas_limit(),
@@ -119,6 +124,28 @@ impl Artifact for SymbolArtifact {
 }
 
 impl SymbolArtifact {
+    /// Executes the EVM code compiled with the suspend mode from the resume index of the context,
+    /// the gas counter and the stack are kept by the caller across the executions. Returns the
+    /// exit status code, which is [`ExitStatusCode::Suspend`](crate::ExitStatusCode::Suspend)
+    /// when the execution is suspended on a sub call.
+    pub(crate) fn execute_suspended(
+        &self,
+        context: &mut RuntimeContext,
+        gas: &mut u64,
+        stack: &mut Stack,
+        stack_size: &mut u64,
+    ) -> Result<u8> {
+        let ptr = self.executor.get_entrypoint_ptr();
+        if ptr.is_null() {
+            return Err(anyhow::anyhow!("function main not found"));
+        }
+        if !matches!(self.executor.kind, ExecuteKind::EVM) {
+            return Err(anyhow::anyhow!("only the EVM code can be suspended"));
+        }
+        let func: EVMEntryFunc = unsafe { std::mem::transmute(ptr) };
+        Ok(func(context, gas, stack, stack_size))
+    }
+
     /// Executes a WASM function by name with the given arguments.
     ///
     /// # Arguments
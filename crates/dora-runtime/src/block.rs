@@ -0,0 +1,416 @@
+//! The block executor which runs the transactions of a block in order and builds the receipts.
+
+use core::fmt;
+
+use dora_primitives::{
+    Address, B256, BlockEnv, Bloom, Bytes, DatabaseCommit, Entry, EvmState, JournalOutput,
+    JournalTr, Log, SpecId, TxEnv, U256,
+};
+
+use crate::{
+    context::VMContext,
+    db::Database,
+    result::{ExecutionResult, ResultAndState, VMError},
+    system::{self, MAINNET_DEPOSIT_CONTRACT_ADDRESS, Requests},
+    vm::VM,
+};
+
+/// A system call executed by the [`BlockExecutor`] before the transactions of the block, the
+/// calls of the Ethereum system contracts are executed by the executor, see [`system`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemCall {
+    /// The system contract address.
+    pub address: Address,
+    /// The call data.
+    pub data: Bytes,
+}
+
+impl SystemCall {
+    /// Creates a new system call.
+    #[inline]
+    pub fn new(address: Address, data: Bytes) -> Self {
+        Self { address, data }
+    }
+}
+
+/// An EIP-4895 withdrawal of a validator, which is applied after the transactions of the block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Withdrawal {
+    /// The monotonically increasing index of the withdrawal.
+    pub index: u64,
+    /// The index of the validator.
+    pub validator_index: u64,
+    /// The recipient of the withdrawal.
+    pub address: Address,
+    /// The withdrawal amount in gwei.
+    pub amount: u64,
+}
+
+impl Withdrawal {
+    /// Returns the withdrawal amount in wei.
+    #[inline]
+    pub fn amount_wei(&self) -> U256 {
+        U256::from(self.amount) * U256::from(GWEI_TO_WEI)
+    }
+}
+
+/// An ommer header of the block, whose miner is rewarded before the Merge.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ommer {
+    /// The number of the ommer block.
+    pub number: u64,
+    /// The miner of the ommer block.
+    pub beneficiary: Address,
+}
+
+/// The number of wei in a gwei.
+const GWEI_TO_WEI: u64 = 1_000_000_000;
+/// The number of wei in an ether.
+const ETH_TO_WEI: u128 = 1_000_000_000_000_000_000;
+
+/// The input of a block execution.
+#[derive(Debug, Clone, Default)]
+pub struct Block {
+    /// The block environment.
+    pub env: BlockEnv,
+    /// The transactions in the execution order, different transaction types can be mixed.
+    pub transactions: Vec<TxEnv>,
+    /// The system calls executed before the transactions.
+    pub system_calls: Vec<SystemCall>,
+    /// The parent block hash stored by the EIP-2935 system call since Prague.
+    pub parent_hash: Option<B256>,
+    /// The parent beacon block root stored by the EIP-4788 system call since Cancun.
+    pub parent_beacon_block_root: Option<B256>,
+    /// The ommer headers, which are rewarded along with the beneficiary before the Merge.
+    pub ommers: Vec<Ommer>,
+    /// The withdrawals applied after the transactions since Shanghai.
+    pub withdrawals: Option<Vec<Withdrawal>>,
+}
+
+/// The receipt of a transaction in the block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    /// The transaction type.
+    pub tx_type: u8,
+    /// Whether the transaction is executed successfully.
+    pub success: bool,
+    /// The gas used by the block up to and including the transaction.
+    pub cumulative_gas_used: u64,
+    /// The logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// The bloom filter of the logs.
+    pub logs_bloom: Bloom,
+}
+
+/// The output of a block execution.
+#[derive(Debug, Clone, Default)]
+pub struct BlockOutput {
+    /// The receipts of the transactions.
+    pub receipts: Vec<Receipt>,
+    /// The execution results of the transactions.
+    pub results: Vec<ExecutionResult>,
+    /// The total gas used by the transactions.
+    pub gas_used: u64,
+    /// The bloom filter of all the logs in the block.
+    pub logs_bloom: Bloom,
+    /// The accounts changed by the block, the original values of the storage slots are the ones
+    /// before the block.
+    pub state: EvmState,
+    /// The EIP-7685 requests of the block, which is `None` before Prague.
+    pub requests: Option<Requests>,
+}
+
+/// Errors that make the block invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockError {
+    /// The system call to the address fails.
+    SystemCall { address: Address, error: String },
+    /// The transaction at the index is invalid.
+    Transaction { index: usize, error: VMError },
+    /// The gas limit of the transaction at the index exceeds the gas left in the block.
+    GasLimitExceeded {
+        index: usize,
+        gas_limit: u64,
+        available: u64,
+    },
+    /// The deposit contract emits a malformed EIP-6110 deposit event.
+    InvalidDepositEvent(String),
+    /// The account can't be loaded to apply the block reward or the withdrawal.
+    BalanceIncrement { address: Address, error: String },
+}
+
+impl fmt::Display for BlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SystemCall { address, error } => {
+                write!(f, "system call to {} failed: {}", address, error)
+            }
+            Self::Transaction { index, error } => {
+                write!(f, "transaction {} error: {}", index, error)
+            }
+            Self::GasLimitExceeded {
+                index,
+                gas_limit,
+                available,
+            } => write!(
+                f,
+                "transaction {} gas limit {} exceeds the block available gas {}",
+                index, gas_limit, available
+            ),
+            Self::InvalidDepositEvent(error) => write!(f, "invalid deposit event: {}", error),
+            Self::BalanceIncrement { address, error } => {
+                write!(f, "balance increment of {} failed: {}", address, error)
+            }
+        }
+    }
+}
+
+/// Executes the blocks and commits their state changes into the database.
+///
+/// # Example
+///
+/// ```no_check
+/// let mut executor = BlockExecutor::new(VMContext::new(db, env, compile_handler()));
+/// let output = executor.execute_block(Block {
+///     env: block_env,
+///     transactions,
+///     ..Default::default()
+/// })?;
+/// assert_eq!(output.receipts.len(), output.results.len());
+/// assert!(output.results.iter().all(|result| result.is_success()));
+/// assert_eq!(output.receipts.last().unwrap().cumulative_gas_used, output.gas_used);
+/// ```
+pub struct BlockExecutor<DB: Database + DatabaseCommit> {
+    vm: VM<DB>,
+    /// The deposit contract whose logs are parsed as the EIP-6110 deposit requests.
+    deposit_contract: Address,
+}
+
+impl<DB: Database + DatabaseCommit> BlockExecutor<DB> {
+    /// Creates a new block executor with the context, the config of the context is used for all
+    /// the blocks.
+    #[inline]
+    pub fn new(context: VMContext<DB>) -> Self {
+        Self {
+            vm: VM::new(context),
+            deposit_contract: MAINNET_DEPOSIT_CONTRACT_ADDRESS,
+        }
+    }
+
+    /// Sets the deposit contract of the chain, it is the mainnet deposit contract by default.
+    #[inline]
+    pub fn with_deposit_contract(mut self, deposit_contract: Address) -> Self {
+        self.deposit_contract = deposit_contract;
+        self
+    }
+
+    /// Returns the VM context.
+    #[inline]
+    pub fn context(&self) -> &VMContext<DB> {
+        &self.vm.context
+    }
+
+    /// Returns the mutable VM context.
+    #[inline]
+    pub fn context_mut(&mut self) -> &mut VMContext<DB> {
+        &mut self.vm.context
+    }
+
+    /// Returns the VM context.
+    #[inline]
+    pub fn into_context(self) -> VMContext<DB> {
+        self.vm.into_context()
+    }
+
+    /// Executes the block, the state changes of the system calls and the transactions are
+    /// committed into the database one by one, thus the database is partially updated when an
+    /// error is returned.
+    pub fn execute_block(&mut self, block: Block) -> Result<BlockOutput, BlockError> {
+        let block_gas_limit = block.env.gas_limit;
+        let spec_id = self.vm.spec_id();
+        let system_calls = system::pre_block_calls(&mut self.vm.journal.database, spec_id, &block)?;
+        let increments = balance_increments(spec_id, &block);
+        self.vm.context.env.block = block.env;
+        let mut output = BlockOutput::default();
+        for SystemCall { address, data } in system_calls.into_iter().chain(block.system_calls) {
+            let state = system_call_state(address, self.vm.system_call(address, data))?;
+            self.commit(&mut output.state, state);
+        }
+        output.receipts.reserve(block.transactions.len());
+        output.results.reserve(block.transactions.len());
+        for (index, tx) in block.transactions.into_iter().enumerate() {
+            let available = block_gas_limit - output.gas_used;
+            if tx.gas_limit > available {
+                return Err(BlockError::GasLimitExceeded {
+                    index,
+                    gas_limit: tx.gas_limit,
+                    available,
+                });
+            }
+            let tx_type = tx.tx_type;
+            self.vm.context.env.tx = tx;
+            let ResultAndState { result, state } = self
+                .vm
+                .transact()
+                .map_err(|error| BlockError::Transaction { index, error })?;
+            self.commit(&mut output.state, state);
+            output.push_result(tx_type, result);
+        }
+        let state = apply_balance_increments(&mut self.vm, &increments)?;
+        self.commit(&mut output.state, state);
+        if let Some((requests, states)) =
+            system::post_block_requests(&mut self.vm, self.deposit_contract, &output.receipts)?
+        {
+            for state in states {
+                self.commit(&mut output.state, state);
+            }
+            output.requests = Some(requests);
+        }
+        Ok(output)
+    }
+
+    /// Commits the state changes into the database and merges them into the block state.
+    fn commit(&mut self, block_state: &mut EvmState, state: EvmState) {
+        self.vm.journal.database.commit(state.clone());
+        merge_state(block_state, state);
+    }
+}
+
+impl BlockOutput {
+    /// Appends the result of the next transaction and builds its receipt.
+    pub(crate) fn push_result(&mut self, tx_type: u8, result: ExecutionResult) {
+        self.gas_used += result.gas_used();
+        let logs = result.logs().to_vec();
+        let mut logs_bloom = Bloom::ZERO;
+        for log in &logs {
+            logs_bloom.accrue_log(log);
+        }
+        self.logs_bloom.accrue_bloom(&logs_bloom);
+        self.receipts.push(Receipt {
+            tx_type,
+            success: result.is_success(),
+            cumulative_gas_used: self.gas_used,
+            logs,
+            logs_bloom,
+        });
+        self.results.push(result);
+    }
+}
+
+/// Returns the static reward of the block miner, which is zero since the Merge.
+pub fn block_reward(spec_id: SpecId) -> U256 {
+    let reward = if spec_id.is_enabled_in(SpecId::MERGE) {
+        0
+    } else if spec_id.is_enabled_in(SpecId::PETERSBURG) {
+        2 * ETH_TO_WEI
+    } else if spec_id.is_enabled_in(SpecId::BYZANTIUM) {
+        3 * ETH_TO_WEI
+    } else {
+        5 * ETH_TO_WEI
+    };
+    U256::from(reward)
+}
+
+/// Returns the balance increments applied after the transactions of the block, i.e. the block
+/// and ommer rewards before the Merge and the withdrawals since Shanghai.
+///
+/// The miner gets an extra 1/32 of the block reward per ommer, and the ommer miner gets
+/// `(8 + ommer number - block number) / 8` of the block reward.
+pub fn balance_increments(spec_id: SpecId, block: &Block) -> Vec<(Address, U256)> {
+    let mut increments = Vec::new();
+    let reward = block_reward(spec_id);
+    if !reward.is_zero() {
+        let ommers = U256::from(block.ommers.len());
+        increments.push((
+            block.env.beneficiary,
+            reward + reward / U256::from(32) * ommers,
+        ));
+        for ommer in &block.ommers {
+            let distance = U256::from((8 + ommer.number).saturating_sub(block.env.number));
+            increments.push((ommer.beneficiary, reward * distance / U256::from(8)));
+        }
+    }
+    if spec_id.is_enabled_in(SpecId::SHANGHAI) {
+        for withdrawal in block.withdrawals.iter().flatten() {
+            increments.push((withdrawal.address, withdrawal.amount_wei()));
+        }
+    }
+    increments
+}
+
+/// Increases the balances with the increments and returns the state changes.
+///
+/// The zero increments are skipped, thus the empty recipients are not created.
+pub(crate) fn apply_balance_increments<DB: Database>(
+    vm: &mut VM<DB>,
+    increments: &[(Address, U256)],
+) -> Result<EvmState, BlockError> {
+    for (address, amount) in increments {
+        if amount.is_zero() {
+            continue;
+        }
+        let account =
+            vm.journal
+                .load_account(*address)
+                .map_err(|err| BlockError::BalanceIncrement {
+                    address: *address,
+                    error: err.to_string(),
+                })?;
+        account.data.mark_touch();
+        account.data.info.balance = account.data.info.balance.saturating_add(*amount);
+    }
+    let JournalOutput { state, .. } = vm.journal.finalize();
+    Ok(state)
+}
+
+/// Returns the state changes of the system call, a failed system call makes the block invalid.
+pub(crate) fn system_call_state(
+    address: Address,
+    output: Result<ResultAndState, VMError>,
+) -> Result<EvmState, BlockError> {
+    let ResultAndState { result, state } = output.map_err(|err| BlockError::SystemCall {
+        address,
+        error: err.to_string(),
+    })?;
+    if !result.is_success() {
+        return Err(BlockError::SystemCall {
+            address,
+            error: format!("{:?}", result),
+        });
+    }
+    Ok(state)
+}
+
+/// Merges the state changes of a transaction into the block state.
+pub(crate) fn merge_state(block_state: &mut EvmState, state: EvmState) {
+    for (address, account) in state {
+        if !account.is_touched() {
+            continue;
+        }
+        match block_state.entry(address) {
+            Entry::Vacant(entry) => {
+                entry.insert(account);
+            }
+            Entry::Occupied(mut entry) => {
+                let merged = entry.get_mut();
+                merged.info = account.info;
+                merged.status |= account.status;
+                if account.is_selfdestructed() || account.is_created() {
+                    // The storage before the account is destroyed or created is discarded.
+                    merged.storage = account.storage;
+                } else {
+                    for (key, slot) in account.storage {
+                        match merged.storage.entry(key) {
+                            Entry::Vacant(entry) => {
+                                entry.insert(slot);
+                            }
+                            Entry::Occupied(mut entry) => {
+                                entry.get_mut().present_value = slot.present_value;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
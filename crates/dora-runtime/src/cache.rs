@@ -0,0 +1,514 @@
+//! A process-wide, thread-safe in-memory cache of the compiled artifacts.
+//!
+//! The cache is shared by the VM instances through the [`Handler`](crate::handler::Handler), so a
+//! contract is compiled only once no matter how many VMs or threads execute it. The entries are
+//! evicted in the least recently used order when the estimated memory size exceeds the capacity.
+
+use crate::{SymbolArtifact, constants::env::DORA_ARTIFACT_CACHE_CAPACITY, gas::GasSchedule};
+use dora_primitives::{B256, Bytecode, CodegenOptions, HashMap, SpecId};
+use parking_lot::{Condvar, Mutex};
+use std::{
+    collections::BTreeMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, OnceLock},
+};
+
+/// The default capacity of the cache in bytes.
+pub const DEFAULT_CAPACITY: usize = 512 * 1024 * 1024;
+/// The estimated memory size of an artifact besides its native code, e.g., the execution engine.
+const ARTIFACT_BASE_SIZE: usize = 16 * 1024;
+/// The estimated native code size in bytes per byte of the bytecode.
+const NATIVE_CODE_SIZE_RATIO: usize = 32;
+
+/// The key of a cached artifact, an artifact compiled under one spec, gas schedule or code
+/// generation options is never reused under another one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArtifactKey {
+    /// The hash of the contract code.
+    pub code_hash: B256,
+    /// The spec id which the code is compiled with.
+    pub spec_id: SpecId,
+    /// The gas schedule which the code is compiled with.
+    pub gas_schedule: GasSchedule,
+    /// Whether the code is compiled with the suspend mode for the
+    /// [`FrameScheduler`](crate::scheduler::FrameScheduler).
+    pub suspend: bool,
+    /// The hash of the LLVM code generation options which the code is compiled with.
+    pub codegen: u64,
+    /// Whether the code is compiled with the tracing hooks for the inspector.
+    pub tracing: bool,
+}
+
+impl ArtifactKey {
+    /// Creates a new artifact key with the Ethereum gas schedule of the spec and the default code
+    /// generation options.
+    #[inline]
+    pub fn new(code_hash: B256, spec_id: SpecId) -> Self {
+        Self {
+            code_hash,
+            spec_id,
+            gas_schedule: GasSchedule::new(spec_id),
+            suspend: false,
+            codegen: codegen_hash(&CodegenOptions::default()),
+            tracing: false,
+        }
+    }
+
+    /// Set the gas schedule which the code is compiled with.
+    #[inline]
+    pub fn with_gas_schedule(mut self, gas_schedule: GasSchedule) -> Self {
+        self.gas_schedule = gas_schedule;
+        self
+    }
+
+    /// Set whether the code is compiled with the suspend mode.
+    #[inline]
+    pub fn with_suspend(mut self, suspend: bool) -> Self {
+        self.suspend = suspend;
+        self
+    }
+
+    /// Set the LLVM code generation options which the code is compiled with.
+    #[inline]
+    pub fn with_codegen(mut self, codegen: &CodegenOptions) -> Self {
+        self.codegen = codegen_hash(codegen);
+        self
+    }
+
+    /// Set whether the code is compiled with the tracing hooks.
+    #[inline]
+    pub fn with_tracing(mut self, tracing: bool) -> Self {
+        self.tracing = tracing;
+        self
+    }
+}
+
+/// Returns the hash of the code generation options, which is stable in the process.
+fn codegen_hash(codegen: &CodegenOptions) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    codegen.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The statistics of the [`ArtifactCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// The number of lookups which found the artifact, including the ones waiting for a
+    /// concurrent compilation of the same code.
+    pub hits: u64,
+    /// The number of lookups which did not find the artifact.
+    pub misses: u64,
+    /// The number of evicted artifacts.
+    pub evictions: u64,
+    /// The number of cached artifacts.
+    pub entries: usize,
+    /// The estimated memory size of the cached artifacts in bytes.
+    pub size: usize,
+}
+
+impl CacheStats {
+    /// Returns the ratio of the hits to all the lookups.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// A thread-safe LRU cache of the compiled artifacts with a memory budget.
+///
+/// The cache replaces the v0.5.0 per-VM `artifacts` map of the
+/// [`VMContext`](crate::context::VMContext), pass the same cache to the handlers of multiple VMs
+/// with [`Handler::with_artifact_cache`](crate::handler::Handler::with_artifact_cache) to share
+/// the artifacts between them.
+///
+/// # Example
+///
+/// ```no_check
+/// let cache = Arc::new(ArtifactCache::new(64 * 1024 * 1024));
+/// let vm1 = VM::new(VMContext::new(db1, env1, compile_handler_with_cache(cache.clone())));
+/// let vm2 = VM::new(VMContext::new(db2, env2, compile_handler_with_cache(cache.clone())));
+/// ```
+#[derive(Debug)]
+pub struct ArtifactCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<ArtifactKey, CacheEntry>,
+    /// The keys ordered by the last used tick, the first one is the least recently used.
+    lru: BTreeMap<u64, ArtifactKey>,
+    /// The compilations in progress.
+    pending: HashMap<ArtifactKey, Arc<PendingCompile>>,
+    tick: u64,
+    size: usize,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    artifact: SymbolArtifact,
+    size: usize,
+    tick: u64,
+}
+
+/// A compilation in progress, the other threads compiling the same code wait for its result.
+#[derive(Debug, Default)]
+struct PendingCompile {
+    /// `None` when the compilation is in progress, `Some(None)` when it is failed.
+    result: Mutex<Option<Option<SymbolArtifact>>>,
+    cond: Condvar,
+}
+
+impl PendingCompile {
+    fn wait(&self) -> Option<SymbolArtifact> {
+        let mut result = self.result.lock();
+        loop {
+            if let Some(artifact) = result.as_ref() {
+                return artifact.clone();
+            }
+            self.cond.wait(&mut result);
+        }
+    }
+
+    fn finish(&self, artifact: Option<SymbolArtifact>) {
+        *self.result.lock() = Some(artifact);
+        self.cond.notify_all();
+    }
+}
+
+/// Finishes the pending compilation on drop, which also wakes up the waiting threads when the
+/// compilation panics.
+struct PendingGuard<'a> {
+    cache: &'a ArtifactCache,
+    key: ArtifactKey,
+    pending: Arc<PendingCompile>,
+    artifact: Option<(SymbolArtifact, usize)>,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        let artifact = self.artifact.take();
+        {
+            let mut state = self.cache.state.lock();
+            state.pending.remove(&self.key);
+            if let Some((artifact, size)) = &artifact {
+                state.insert(self.key, artifact.clone(), *size, self.cache.capacity);
+            }
+        }
+        self.pending.finish(artifact.map(|(artifact, _)| artifact));
+    }
+}
+
+impl CacheState {
+    fn get(&mut self, key: &ArtifactKey) -> Option<SymbolArtifact> {
+        self.tick += 1;
+        let tick = self.tick;
+        let entry = self.entries.get_mut(key)?;
+        self.lru.remove(&entry.tick);
+        self.lru.insert(tick, *key);
+        entry.tick = tick;
+        Some(entry.artifact.clone())
+    }
+
+    fn insert(&mut self, key: ArtifactKey, artifact: SymbolArtifact, size: usize, capacity: usize) {
+        self.remove(&key);
+        self.tick += 1;
+        self.lru.insert(self.tick, key);
+        self.entries.insert(
+            key,
+            CacheEntry {
+                artifact,
+                size,
+                tick: self.tick,
+            },
+        );
+        self.size += size;
+        while self.size > capacity {
+            let Some((_, key)) = self.lru.pop_first() else {
+                break;
+            };
+            if let Some(entry) = self.entries.remove(&key) {
+                self.size -= entry.size;
+                self.evictions += 1;
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &ArtifactKey) -> Option<SymbolArtifact> {
+        let entry = self.entries.remove(key)?;
+        self.lru.remove(&entry.tick);
+        self.size -= entry.size;
+        Some(entry.artifact)
+    }
+}
+
+impl Default for ArtifactCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl ArtifactCache {
+    /// Creates a new cache with the capacity in bytes.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Returns the process-wide cache shared by the VMs created with the default compile handler,
+    /// its capacity can be set with the `DORA_ARTIFACT_CACHE_CAPACITY` environment variable.
+    pub fn global() -> Arc<ArtifactCache> {
+        static GLOBAL: OnceLock<Arc<ArtifactCache>> = OnceLock::new();
+        GLOBAL
+            .get_or_init(|| {
+                let capacity = std::env::var(DORA_ARTIFACT_CACHE_CAPACITY)
+                    .ok()
+                    .and_then(|capacity| capacity.parse().ok())
+                    .unwrap_or(DEFAULT_CAPACITY);
+                Arc::new(ArtifactCache::new(capacity))
+            })
+            .clone()
+    }
+
+    /// Returns the estimated memory size of the artifact compiled from the code.
+    #[inline]
+    pub fn estimated_size(code: &Bytecode) -> usize {
+        ARTIFACT_BASE_SIZE + code.len() * NATIVE_CODE_SIZE_RATIO
+    }
+
+    /// Returns the capacity of the cache in bytes.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the artifact of the key and marks it as the most recently used one.
+    pub fn get(&self, key: &ArtifactKey) -> Option<SymbolArtifact> {
+        let mut state = self.state.lock();
+        let artifact = state.get(key);
+        if artifact.is_some() {
+            state.hits += 1;
+        } else {
+            state.misses += 1;
+        }
+        artifact
+    }
+
+    /// Returns the artifact of the key and marks it as the most recently used one, but unlike
+    /// [`get`](Self::get), the lookup is not counted in the statistics.
+    pub fn peek(&self, key: &ArtifactKey) -> Option<SymbolArtifact> {
+        self.state.lock().get(key)
+    }
+
+    /// Inserts the artifact with its estimated memory size, the least recently used artifacts are
+    /// evicted when the cache is full.
+    pub fn insert(&self, key: ArtifactKey, artifact: SymbolArtifact, size: usize) {
+        self.state.lock().insert(key, artifact, size, self.capacity);
+    }
+
+    /// Returns the artifact of the key, or compiles it with `compile` and inserts it.
+    ///
+    /// When multiple threads request the same missing key at the same time, only one of them
+    /// compiles the code and the others wait for its result. If that compilation fails, the
+    /// waiting threads try to compile the code by themselves.
+    pub fn get_or_try_insert_with<E>(
+        &self,
+        key: ArtifactKey,
+        size: usize,
+        compile: impl FnOnce() -> Result<SymbolArtifact, E>,
+    ) -> Result<SymbolArtifact, E> {
+        loop {
+            let pending = {
+                let mut state = self.state.lock();
+                if let Some(artifact) = state.get(&key) {
+                    state.hits += 1;
+                    return Ok(artifact);
+                }
+                match state.pending.get(&key) {
+                    Some(pending) => pending.clone(),
+                    None => {
+                        state.misses += 1;
+                        let pending = Arc::new(PendingCompile::default());
+                        state.pending.insert(key, pending.clone());
+                        drop(state);
+                        let mut guard = PendingGuard {
+                            cache: self,
+                            key,
+                            pending,
+                            artifact: None,
+                        };
+                        let artifact = compile()?;
+                        guard.artifact = Some((artifact.clone(), size));
+                        return Ok(artifact);
+                    }
+                }
+            };
+            if let Some(artifact) = pending.wait() {
+                self.state.lock().hits += 1;
+                return Ok(artifact);
+            }
+        }
+    }
+
+    /// Returns whether the cache contains the artifact of the key.
+    pub fn contains(&self, key: &ArtifactKey) -> bool {
+        self.state.lock().entries.contains_key(key)
+    }
+
+    /// Removes the artifact of the key from the cache.
+    pub fn remove(&self, key: &ArtifactKey) -> Option<SymbolArtifact> {
+        self.state.lock().remove(key)
+    }
+
+    /// Removes all the artifacts from the cache, the statistics are kept.
+    pub fn clear(&self) {
+        let mut state = self.state.lock();
+        state.entries.clear();
+        state.lru.clear();
+        state.size = 0;
+    }
+
+    /// Returns the number of the cached artifacts.
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the statistics of the cache.
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state.lock();
+        CacheStats {
+            hits: state.hits,
+            misses: state.misses,
+            evictions: state.evictions,
+            entries: state.entries.len(),
+            size: state.size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArtifactCache, ArtifactKey};
+    use crate::SymbolArtifact;
+    use dora_primitives::{B256, SpecId};
+    use std::{
+        convert::Infallible,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+        time::Duration,
+    };
+
+    fn key(byte: u8) -> ArtifactKey {
+        ArtifactKey::new(B256::repeat_byte(byte), SpecId::CANCUN)
+    }
+
+    #[test]
+    fn test_artifact_cache_lru_eviction() {
+        let cache = ArtifactCache::new(300);
+        cache.insert(key(1), SymbolArtifact::default(), 100);
+        cache.insert(key(2), SymbolArtifact::default(), 100);
+        cache.insert(key(3), SymbolArtifact::default(), 100);
+        // Use the first one so the second one becomes the least recently used.
+        assert!(cache.get(&key(1)).is_some());
+        cache.insert(key(4), SymbolArtifact::default(), 100);
+        assert!(cache.contains(&key(1)));
+        assert!(!cache.contains(&key(2)));
+        assert!(cache.contains(&key(3)));
+        assert!(cache.contains(&key(4)));
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 3);
+        assert_eq!(stats.size, 300);
+        assert_eq!(stats.evictions, 1);
+        // The artifact larger than the capacity is never cached.
+        cache.insert(key(5), SymbolArtifact::default(), 400);
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().size, 0);
+    }
+
+    #[test]
+    fn test_artifact_cache_spec_in_key() {
+        let cache = ArtifactCache::default();
+        let code_hash = B256::repeat_byte(1);
+        cache.insert(
+            ArtifactKey::new(code_hash, SpecId::SHANGHAI),
+            SymbolArtifact::default(),
+            1,
+        );
+        assert!(
+            cache
+                .get(&ArtifactKey::new(code_hash, SpecId::CANCUN))
+                .is_none()
+        );
+        assert!(
+            cache
+                .get(&ArtifactKey::new(code_hash, SpecId::SHANGHAI))
+                .is_some()
+        );
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        // Peeking the artifact does not update the statistics.
+        assert!(
+            cache
+                .peek(&ArtifactKey::new(code_hash, SpecId::SHANGHAI))
+                .is_some()
+        );
+        assert_eq!(cache.stats(), stats);
+    }
+
+    #[test]
+    fn test_artifact_cache_dedup_concurrent_compiles() {
+        let cache = Arc::new(ArtifactCache::default());
+        let compiles = Arc::new(AtomicUsize::new(0));
+        let handles = (0..8)
+            .map(|_| {
+                let cache = cache.clone();
+                let compiles = compiles.clone();
+                thread::spawn(move || {
+                    cache
+                        .get_or_try_insert_with(key(1), 1, || {
+                            compiles.fetch_add(1, Ordering::SeqCst);
+                            thread::sleep(Duration::from_millis(50));
+                            Ok::<_, Infallible>(SymbolArtifact::default())
+                        })
+                        .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(compiles.load(Ordering::SeqCst), 1);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (7, 1));
+    }
+
+    #[test]
+    fn test_artifact_cache_failed_compile() {
+        let cache = ArtifactCache::default();
+        let result = cache.get_or_try_insert_with(key(1), 1, || Err("compile error"));
+        assert!(result.is_err());
+        assert!(!cache.contains(&key(1)));
+        let result =
+            cache.get_or_try_insert_with(key(1), 1, || Ok::<_, &str>(SymbolArtifact::default()));
+        assert!(result.is_ok());
+        assert!(cache.contains(&key(1)));
+    }
+}
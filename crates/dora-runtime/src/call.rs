@@ -76,6 +76,17 @@ pub enum CallKind {
     ReturnContract = 10,
 }
 
+impl CallKind {
+    /// Returns whether the kind is one of the create kinds.
+    #[inline]
+    pub fn is_create(&self) -> bool {
+        matches!(
+            self,
+            CallKind::Create | CallKind::Create2 | CallKind::EofCreate
+        )
+    }
+}
+
 impl From<CallType> for CallKind {
     fn from(value: CallType) -> Self {
         match value {
@@ -1,3 +1,5 @@
+use dora_primitives::{Address, address};
+
 /// Max stack size
 pub const MAX_STACK_SIZE: usize = 1024;
 /// Max EOF function stack size
@@ -6,10 +8,21 @@ pub const MAX_FUNCTION_STACK_SIZE: usize = 1024;
 pub const CALL_STACK_LIMIT: usize = 1024;
 /// MLIR call entry point name.
 pub const ENTRYPOINT: &str = "call";
+/// The caller address of the system calls, e.g., the EIP-4788 beacon roots call.
+pub const SYSTEM_ADDRESS: Address = address!("0xfffffffffffffffffffffffffffffffffffffffe");
+/// The gas limit of the system calls.
+pub const SYSTEM_CALL_GAS_LIMIT: u64 = 30_000_000;
+/// EIP-7825: Transaction gas limit cap
+/// The gas limit of a transaction is capped to `2^24` since Osaka.
+pub const TX_GAS_LIMIT_CAP: u64 = 1 << 24;
 
 pub mod env {
     pub const DORA_TRACING: &str = "DORA_TRACING";
     pub const DORA_DISABLE_CONSOLE: &str = "DORA_DISABLE_CONSOLE";
+    pub const DORA_AOT_CACHE_DIR: &str = "DORA_AOT_CACHE_DIR";
+    pub const DORA_AOT_CACHE_MAX_SIZE: &str = "DORA_AOT_CACHE_MAX_SIZE";
+    pub const DORA_AOT_LINKER: &str = "DORA_AOT_LINKER";
+    pub const DORA_ARTIFACT_CACHE_CAPACITY: &str = "DORA_ARTIFACT_CACHE_CAPACITY";
 }
 
 pub mod gas_cost {
@@ -27,11 +40,21 @@ pub mod gas_cost {
     /// By default the limit is `0x6000` (~25kb)
     pub const MAX_CODE_SIZE: usize = 0x6000;
     pub const MAX_INITCODE_SIZE: usize = 2 * MAX_CODE_SIZE;
+    /// EIP-7907: Meter contract code size and increase limit
+    /// The limit is raised to `0xc000` (~49kb) since Osaka.
+    pub const OSAKA_MAX_CODE_SIZE: usize = 0xC000;
+    /// The cost per word of loading the code of a cold contract beyond [`MAX_CODE_SIZE`].
+    pub const LARGE_CONTRACT_WORD_COST: u64 = 2;
+    /// EIP-7939: Count leading zeros (CLZ) opcode
+    pub const CLZ_GAS: u64 = 5;
     /// EIP-1884: Repricing for trie-size-dependent opcodes
     pub const INSTANBUL_SLOAD_GAS: u64 = 800;
     pub const SSTORE_SET: u64 = 20000;
     pub const SSTORE_RESET: u64 = 5000;
     pub const REFUND_SSTORE_CLEARS: i64 = 15000;
+    pub const LOG: u64 = 375;
+    pub const LOGTOPIC: u64 = 375;
+    pub const LOGDATA: u64 = 8;
     /// The standard cost of calldata token.
     pub const STANDARD_TOKEN_COST: u64 = 4;
     /// The cost of a non-zero byte in calldata.
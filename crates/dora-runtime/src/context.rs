@@ -3,24 +3,29 @@ use std::convert::Infallible;
 use std::sync::Arc;
 
 use crate::SymbolArtifact;
+use crate::cache::ArtifactKey;
 use crate::call::{CallKind, CallMessage, CallResult, CallType, ExtCallType};
+use crate::constants::env::DORA_TRACING;
 use crate::constants::gas_cost::MIN_CALLEE_GAS;
-use crate::constants::{CALL_STACK_LIMIT, MAX_FUNCTION_STACK_SIZE, MAX_STACK_SIZE, gas_cost};
-use crate::db::{Database, DatabaseError};
+use crate::constants::{CALL_STACK_LIMIT, MAX_FUNCTION_STACK_SIZE, gas_cost};
+use crate::db::Database;
 use crate::executor::ExecutionEngine;
-use crate::handler::{Frame, Handler};
-use crate::host::{AccountLoad, Host, SStoreResult, SelfDestructResult, StateLoad};
+use crate::gas::GasSchedule;
+use crate::handler::{CallStart, Frame, FrameReturn, FrameReturnKind, Handler};
+use crate::host::{AccountLoad, Host, HostError, SStoreResult, SelfDestructResult, StateLoad};
+use crate::inspector::{Inspector, StepState, TracerEip3155};
+use crate::precompile::{ContextPrecompile, ContextPrecompiles, PrecompileInput, StaticHost};
 use crate::result::VMError;
 use crate::stack::Stack;
 use crate::wasm::host::gas_limit;
 use crate::wasm::trap::wasm_raise_trap;
 use crate::{ExitStatusCode, gas, symbols};
 use dora_primitives::{
-    Account, Address, AuthorizationTr, B256, BLOCK_HASH_HISTORY, Bytecode, Bytes, Bytes32, Cfg,
-    CfgEnv, EOF_MAGIC_BYTES, EVMBytecode, EmptyBytecode, Env, Eof, HashMap, InvalidTransaction,
-    Journal, JournalCheckpoint, JournalEntry, JournalTr, KECCAK_EMPTY, Log, LogData, OpCode,
-    PER_AUTH_BASE_COST, PER_EMPTY_ACCOUNT_COST, PrecompileError, PrecompileSpecId, Precompiles,
-    SpecId, TransactionType, U256, as_u64_saturated, as_usize_saturated, keccak256,
+    Account, Address, AuthorizationTr, B256, BLOCK_HASH_HISTORY, Bytecode, Bytes, Bytes32, CfgEnv,
+    EOF_MAGIC_BYTES, EVMBytecode, Eip7702Bytecode, EmptyBytecode, Env, Eof, InvalidTransaction,
+    Journal, JournalCheckpoint, JournalEntry, JournalTr, KECCAK_EMPTY, Log, LogData,
+    PER_AUTH_BASE_COST, PER_EMPTY_ACCOUNT_COST, PrecompileError, SpecId, TransactionType, U256,
+    as_u64_saturated, as_usize_saturated, keccak256,
 };
 
 /// Function type for the EVM main entrypoint of the generated code.
@@ -42,10 +47,23 @@ pub struct VMContext<DB: Database> {
     pub handler: Handler<DB>,
     /// State with journaling support.
     pub journal: Journal<DB>,
-    /// Precompiles that are available for evm.
-    pub precompiles: &'static Precompiles,
-    /// The compiled artifacts by the compiler.
-    pub artifacts: HashMap<B256, SymbolArtifact>,
+    /// Precompiles that are available for evm, including the custom ones registered on the context.
+    pub precompiles: ContextPrecompiles,
+    /// The optional inspector to trace the execution.
+    pub inspector: Option<Box<dyn Inspector<DB>>>,
+    /// The custom gas schedule of the chain, the Ethereum gas schedule of the spec is used when
+    /// it is not set.
+    pub gas_schedule: Option<GasSchedule>,
+    /// The beneficiary rewards accumulated instead of being paid, the parallel executor pays
+    /// them in the transaction order to avoid that all the transactions conflict on the
+    /// beneficiary balance.
+    pub(crate) deferred_reward: Option<U256>,
+    /// The first host error of the transaction, it is returned by the VM even if the failed
+    /// frame is a sub call whose failure is handled by its caller.
+    pub(crate) host_error: Option<HostError>,
+    /// The OP-stack hardfork and transaction fields of the OP-stack execution mode.
+    #[cfg(feature = "optimism")]
+    pub optimism: crate::optimism::OpEnv,
 }
 
 impl<DB: Database> VMContext<DB> {
@@ -59,11 +77,97 @@ impl<DB: Database> VMContext<DB> {
             env,
             handler,
             journal,
-            precompiles: Precompiles::new(PrecompileSpecId::from_spec_id(spec_id)),
-            artifacts: Default::default(),
+            precompiles: ContextPrecompiles::from_spec_id(spec_id),
+            // Keep the `DORA_TRACING` environment variable as a shortcut of the EIP-3155 stdout tracer.
+            inspector: if std::env::var(DORA_TRACING).is_ok() {
+                Some(Box::new(TracerEip3155::stdout()))
+            } else {
+                None
+            },
+            gas_schedule: None,
+            deferred_reward: None,
+            host_error: None,
+            #[cfg(feature = "optimism")]
+            optimism: Default::default(),
         }
     }
 
+    /// Registers the custom precompile at the address, it replaces the builtin precompile at the
+    /// same address.
+    #[inline]
+    pub fn with_precompile(mut self, address: Address, precompile: ContextPrecompile) -> Self {
+        self.set_precompile(address, precompile);
+        self
+    }
+
+    /// Registers the custom precompile at the address, returns the previous custom one.
+    #[inline]
+    pub fn set_precompile(
+        &mut self,
+        address: Address,
+        precompile: ContextPrecompile,
+    ) -> Option<ContextPrecompile> {
+        self.precompiles.insert(address, precompile)
+    }
+
+    /// Sets the custom gas schedule of the context.
+    #[inline]
+    pub fn with_gas_schedule(mut self, gas_schedule: GasSchedule) -> Self {
+        self.gas_schedule = Some(gas_schedule);
+        self
+    }
+
+    /// Returns the gas schedule, which is the custom gas schedule or the Ethereum gas schedule of
+    /// the spec.
+    #[inline]
+    pub fn gas_schedule(&self) -> GasSchedule {
+        self.gas_schedule
+            .unwrap_or_else(|| GasSchedule::new(self.spec_id()))
+    }
+
+    /// Returns the key of the artifact compiled from the code under the current spec and gas
+    /// schedule.
+    #[inline]
+    pub fn artifact_key(&self, code_hash: B256) -> ArtifactKey {
+        ArtifactKey::new(code_hash, self.spec_id()).with_gas_schedule(self.gas_schedule())
+    }
+
+    /// Attaches the inspector to the context.
+    #[inline]
+    pub fn with_inspector<I: Inspector<DB> + 'static>(mut self, inspector: I) -> Self {
+        self.set_inspector(inspector);
+        self
+    }
+
+    /// Sets the inspector of the context.
+    #[inline]
+    pub fn set_inspector<I: Inspector<DB> + 'static>(&mut self, inspector: I) {
+        self.inspector = Some(Box::new(inspector));
+    }
+
+    /// Detaches and returns the inspector of the context.
+    #[inline]
+    pub fn take_inspector(&mut self) -> Option<Box<dyn Inspector<DB>>> {
+        self.inspector.take()
+    }
+
+    /// Returns `true` if an inspector is attached and the code should be compiled with tracing.
+    #[inline]
+    pub fn is_inspecting(&self) -> bool {
+        self.inspector.is_some()
+    }
+
+    /// Runs the closure with the attached inspector, if any.
+    ///
+    /// The inspector is detached during the closure so that it can access the context mutably.
+    #[inline]
+    fn inspect<R>(&mut self, f: impl FnOnce(&mut dyn Inspector<DB>, &mut Self) -> R) -> Option<R> {
+        let mut inspector = self.inspector.take()?;
+        let result = f(inspector.as_mut(), self);
+        self.inspector = Some(inspector);
+        Some(result)
+    }
+
     /// Returns the configured EVM spec ID.
     #[inline]
     pub fn spec_id(&self) -> SpecId {
@@ -104,8 +208,7 @@ impl<DB: Database> VMContext<DB> {
         }
 
         // Load access list
-        self.load_access_list()
-            .map_err(|_| VMError::Database(DatabaseError))?;
+        self.load_access_list().map_err(VMError::database)?;
         Ok(())
     }
 
@@ -115,7 +218,7 @@ impl<DB: Database> VMContext<DB> {
         // Set warm loaded addresses.
         self.journal
             .warm_preloaded_addresses
-            .extend(self.precompiles.addresses());
+            .extend(self.precompiles.addresses().copied());
     }
 
     /// Deducts the caller balance to the transaction limit.
@@ -125,7 +228,7 @@ impl<DB: Database> VMContext<DB> {
         let mut caller_account = self
             .journal
             .load_account(caller)
-            .map_err(|_| VMError::Database(DatabaseError))?;
+            .map_err(VMError::database)?;
 
         let is_call = self.env.tx.kind.is_call();
 
@@ -212,7 +315,7 @@ impl<DB: Database> VMContext<DB> {
             let mut authority_acc = self
                 .journal
                 .load_account_code(authority)
-                .map_err(|_| VMError::Database(DatabaseError))?;
+                .map_err(VMError::database)?;
 
             // 5. Verify the code of `authority` is either empty or already delegated.
             if let Some(bytecode) = &authority_acc.info.code {
@@ -267,7 +370,7 @@ impl<DB: Database> VMContext<DB> {
         let caller_account = self
             .journal
             .load_account(caller)
-            .map_err(|_| VMError::Database(DatabaseError))?;
+            .map_err(VMError::database)?;
 
         let reimbursed =
             effective_gas_price.saturating_mul((gas_remaining + gas_refunded as u64) as u128);
@@ -294,20 +397,20 @@ impl<DB: Database> VMContext<DB> {
             effective_gas_price
         };
 
+        let reward = U256::from(coinbase_gas_price * (gas_used - gas_refunded as u64) as u128);
+        if let Some(deferred_reward) = &mut self.deferred_reward {
+            *deferred_reward += reward;
+            return Ok(());
+        }
+
         let coinbase_account = self
             .journal
             .load_account(beneficiary)
-            .map_err(|_| VMError::Database(DatabaseError))?;
+            .map_err(VMError::database)?;
 
         coinbase_account.data.mark_touch();
         coinbase_account.data.info.balance =
-            coinbase_account
-                .data
-                .info
-                .balance
-                .saturating_add(U256::from(
-                    coinbase_gas_price * (gas_used - gas_refunded as u64) as u128,
-                ));
+            coinbase_account.data.info.balance.saturating_add(reward);
 
         Ok(())
     }
@@ -339,6 +442,16 @@ impl<DB: Database> VMContext<DB> {
         &self.env.cfg
     }
 
+    /// Returns the contract code size limit, which is the configured limit or the limit of the
+    /// spec.
+    #[inline]
+    pub fn max_code_size(&self) -> usize {
+        self.env
+            .cfg
+            .limit_contract_code_size
+            .unwrap_or_else(|| gas::max_code_size(self.spec_id()))
+    }
+
     /// Fetch block hash from database.
     #[inline]
     pub fn block_hash(&mut self, number: u64) -> Result<B256, DB::Error> {
@@ -439,28 +552,51 @@ impl<DB: Database> VMContext<DB> {
         self.journal.selfdestruct(address, target)
     }
 
-    fn invoke_call_handler(&mut self, frame: Frame) -> Result<CallResult, VMError> {
+    pub(crate) fn invoke_call_handler(&mut self, frame: Frame) -> Result<CallResult, VMError> {
         let call_handler = self.handler.call_handler.clone();
         call_handler(frame, self)
     }
 
     #[inline]
     fn is_precompile_address(&self, address: &Address) -> bool {
-        self.precompiles.get(address).is_some()
+        self.precompiles.contains(address)
     }
 
     /// Call precompile contract
-    pub fn call_precompile(
-        &mut self,
-        address: Address,
-        calldata: &Bytes,
-        gas_limit: u64,
-    ) -> Result<Option<CallResult>, VMError> {
-        let result = match self.precompiles.get(&address) {
-            Some(precompile) => (*precompile)(calldata, gas_limit),
-            None => return Ok(None),
+    pub fn call_precompile(&mut self, msg: &CallMessage) -> Result<Option<CallResult>, VMError> {
+        let address = msg.code_address;
+        let result = match self.precompiles.get_custom(&address).cloned() {
+            Some(ContextPrecompile::Stateless(precompile)) => precompile(&msg.input, msg.gas_limit),
+            Some(ContextPrecompile::Stateful(precompile)) => {
+                let input = PrecompileInput {
+                    data: &msg.input,
+                    gas_limit: msg.gas_limit,
+                    caller: msg.caller,
+                    target_address: msg.recipient,
+                    value: msg.value,
+                    is_static: msg.is_static,
+                };
+                if msg.is_static {
+                    let mut host = StaticHost::new(self);
+                    let result = precompile(&input, &mut host);
+                    // The static call fails on the first state change, the same as the opcodes.
+                    if host.state_changed() {
+                        return Ok(Some(CallResult::new_with_gas_limit_and_status(
+                            msg.gas_limit,
+                            ExitStatusCode::StateChangeDuringStaticCall,
+                        )));
+                    }
+                    result
+                } else {
+                    precompile(&input, self)
+                }
+            }
+            None => match self.precompiles.builtin().get(&address) {
+                Some(precompile) => (*precompile)(&msg.input, msg.gas_limit),
+                None => return Ok(None),
+            },
         };
-        let mut call_result = CallResult::new_with_gas_limit(gas_limit);
+        let mut call_result = CallResult::new_with_gas_limit(msg.gas_limit);
         match result {
             Ok(output) => {
                 call_result.output = output.bytes;
@@ -484,12 +620,81 @@ impl<DB: Database> VMContext<DB> {
 
     /// Handle frame sub call.
     pub fn call(&mut self, msg: CallMessage) -> Result<CallResult, VMError> {
+        match self.call_start(msg)? {
+            CallStart::Done(result) => Ok(result),
+            CallStart::Frame(frame, frame_return) => {
+                let result = self.invoke_call_handler(frame)?;
+                Ok(self.call_end(frame_return, result))
+            }
+        }
+    }
+
+    /// Starts the call frame of the message, the returned frame is executed by the caller and
+    /// then finished with [`VMContext::call_end`].
+    pub fn call_start(&mut self, mut msg: CallMessage) -> Result<CallStart, VMError> {
+        if self.inspector.is_none() || matches!(msg.kind, CallKind::ReturnContract) {
+            return self.frame_start(msg);
+        }
+        let result = self
+            .inspect(|inspector, ctx| {
+                if msg.kind.is_create() {
+                    inspector.create(&mut msg, ctx)
+                } else {
+                    inspector.call(&mut msg, ctx)
+                }
+            })
+            .flatten();
+        let start = match result {
+            Some(result) => CallStart::Done(result),
+            None => self.frame_start(msg.clone())?,
+        };
+        Ok(match start {
+            CallStart::Done(mut result) => {
+                self.inspect_call_end(&msg, &mut result);
+                CallStart::Done(result)
+            }
+            CallStart::Frame(frame, mut frame_return) => {
+                frame_return.msg = Some(msg);
+                CallStart::Frame(frame, frame_return)
+            }
+        })
+    }
+
+    /// Finishes the call frame started by [`VMContext::call_start`] with its execution result.
+    pub fn call_end(&mut self, frame_return: FrameReturn, mut result: CallResult) -> CallResult {
+        match frame_return.kind {
+            FrameReturnKind::Call(checkpoint) => self.call_return(&result.status, checkpoint),
+            FrameReturnKind::Create(address, checkpoint) => {
+                self.create_return(&mut result, address, checkpoint)
+            }
+            FrameReturnKind::EofCreate(address, checkpoint) => {
+                self.eofcreate_return(&mut result, address, checkpoint)
+            }
+        }
+        if let Some(msg) = frame_return.msg {
+            self.inspect_call_end(&msg, &mut result);
+        }
+        result
+    }
+
+    fn inspect_call_end(&mut self, msg: &CallMessage, result: &mut CallResult) {
+        self.inspect(|inspector, ctx| {
+            if msg.kind.is_create() {
+                inspector.create_end(msg, result, ctx)
+            } else {
+                inspector.call_end(msg, result, ctx)
+            }
+        });
+    }
+
+    /// Starts the call frame of the message without the inspector hooks.
+    fn frame_start(&mut self, msg: CallMessage) -> Result<CallStart, VMError> {
         // Check depth
         if self.journal.depth() > CALL_STACK_LIMIT {
-            return Ok(CallResult::new_with_gas_limit_and_status(
+            return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                 msg.gas_limit,
                 ExitStatusCode::CallTooDeep,
-            ));
+            )));
         }
         match msg.kind {
             CallKind::Call
@@ -500,7 +705,9 @@ impl<DB: Database> VMContext<DB> {
             | CallKind::ExtStaticcall
             | CallKind::ExtDelegatecall => {
                 // Make account warm and loaded
-                let _ = self.journal.load_account_delegated(msg.code_address);
+                self.journal
+                    .load_account_delegated(msg.code_address)
+                    .map_err(VMError::database)?;
                 // Create subroutine checkpoint
                 let checkpoint = self.journal.checkpoint();
                 // Touch address. For "EIP-158 State Clear", this will erase empty accounts.
@@ -508,7 +715,7 @@ impl<DB: Database> VMContext<DB> {
                     // If transfer value is zero, load account and force the touch.
                     if msg.value.is_zero() {
                         self.load_account(msg.recipient)
-                            .map_err(|_| VMError::Database(DatabaseError))?;
+                            .map_err(VMError::database)?;
                         self.journal.touch(msg.recipient);
                     } else {
                         // Transfer value from caller to called account. As value get transferred
@@ -516,56 +723,62 @@ impl<DB: Database> VMContext<DB> {
                         if let Some(err) = self
                             .journal
                             .transfer(msg.caller, msg.recipient, msg.value)
-                            .map_err(|_| VMError::Database(DatabaseError))?
+                            .map_err(VMError::database)?
                         {
                             self.journal.checkpoint_revert(checkpoint);
-                            return Ok(CallResult::new_with_gas_limit_and_status(
+                            return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                                 msg.gas_limit,
                                 err.into(),
-                            ));
+                            )));
                         }
                     }
                 }
                 let is_ext_delegate = matches!(msg.kind, CallKind::ExtDelegatecall);
                 if !is_ext_delegate {
-                    if let Some(call_result) =
-                        self.call_precompile(msg.code_address, &msg.input.clone(), msg.gas_limit)?
-                    {
-                        if call_result.status.is_ok() {
-                            self.journal.checkpoint_commit();
-                        } else {
+                    match self.call_precompile(&msg) {
+                        Ok(Some(call_result)) => {
+                            if call_result.status.is_ok() {
+                                self.journal.checkpoint_commit();
+                            } else {
+                                self.journal.checkpoint_revert(checkpoint);
+                            }
+                            return Ok(CallStart::Done(call_result));
+                        }
+                        Ok(None) => {}
+                        // Revert the value transfer and the state changes of the precompile.
+                        Err(err) => {
                             self.journal.checkpoint_revert(checkpoint);
+                            return Err(err);
                         }
-                        return Ok(call_result);
                     }
                 }
                 // Load account and bytecode
                 let account = self
                     .journal
                     .load_account_code(msg.code_address)
-                    .map_err(|_| VMError::Database(DatabaseError))?;
+                    .map_err(VMError::database)?;
                 let code_hash = account.info.code_hash;
                 let mut bytecode = account.info.code.clone().unwrap_or_default();
                 // ExtDelegateCall is not allowed to call non-EOF contracts.
                 if is_ext_delegate && !bytecode.original_byte_slice().starts_with(&EOF_MAGIC_BYTES)
                 {
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::InvalidExtDelegatecallTarget,
-                    ));
+                    )));
                 }
                 if bytecode.is_empty() {
                     self.journal.checkpoint_commit();
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::Stop,
-                    ));
+                    )));
                 }
                 if let EVMBytecode::Eip7702(eip7702_bytecode) = bytecode {
                     bytecode = self
                         .journal
                         .load_account_code(eip7702_bytecode.delegated_address)
-                        .map_err(|_| VMError::Database(DatabaseError))?
+                        .map_err(VMError::database)?
                         .info
                         .code
                         .clone()
@@ -578,49 +791,48 @@ impl<DB: Database> VMContext<DB> {
                     bytecode,
                     Some(code_hash),
                 );
-                let call_result = self.invoke_call_handler(Frame {
-                    contract,
-                    gas_limit: msg.gas_limit,
-                    is_static: msg.is_static,
-                    is_eof_init: msg.is_eof_init,
-                    validate_eof: msg.validate_eof,
-                    depth: self.journal.depth(),
-                })?;
-                self.call_return(&call_result.status, checkpoint);
-                Ok(call_result)
+                Ok(CallStart::Frame(
+                    Frame {
+                        contract,
+                        gas_limit: msg.gas_limit,
+                        is_static: msg.is_static,
+                        is_eof_init: msg.is_eof_init,
+                        validate_eof: msg.validate_eof,
+                        depth: self.journal.depth(),
+                    },
+                    FrameReturn::new(FrameReturnKind::Call(checkpoint)),
+                ))
             }
             CallKind::EofCreate => {
                 let (input, init_code, created_address) =
                     (msg.input, msg.init_code, msg.code_address);
                 // Fetch balance of caller.
-                let caller_balance = self
-                    .balance(msg.caller)
-                    .map_err(|_| VMError::Database(DatabaseError))?;
+                let caller_balance = self.balance(msg.caller).map_err(VMError::database)?;
                 // Check if caller has enough balance to send to the created contract.
                 if caller_balance.data < msg.value {
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::OutOfFunds,
-                    ));
+                    )));
                 }
                 // Increase nonce of caller and check if it overflows
                 if self.journal.inc_nonce(msg.caller).is_none() {
                     // Note returns a normal result instead of a nonce overflow error here.
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::Return,
-                    ));
+                    )));
                 }
                 // Created address is not allowed to be a precompile.
                 if self.is_precompile_address(&created_address) {
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::CreateCollision,
-                    ));
+                    )));
                 }
                 // Warm load account.
                 self.load_account(created_address)
-                    .map_err(|_| VMError::Database(DatabaseError))?;
+                    .map_err(VMError::database)?;
                 // Create account, transfer funds and make the journal checkpoint.
                 let checkpoint = match self.journal.create_account_checkpoint(
                     msg.caller,
@@ -630,10 +842,10 @@ impl<DB: Database> VMContext<DB> {
                 ) {
                     Ok(checkpoint) => checkpoint,
                     Err(err) => {
-                        return Ok(CallResult::new_with_gas_limit_and_status(
+                        return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                             msg.gas_limit,
                             err.into(),
-                        ));
+                        )));
                     }
                 };
 
@@ -646,16 +858,17 @@ impl<DB: Database> VMContext<DB> {
                     caller: msg.caller,
                     call_value: msg.value,
                 };
-                let mut call_result = self.invoke_call_handler(Frame {
-                    contract,
-                    gas_limit: msg.gas_limit,
-                    is_static: msg.is_static,
-                    is_eof_init: msg.is_eof_init,
-                    validate_eof: msg.validate_eof,
-                    depth: self.journal.depth(),
-                })?;
-                self.eofcreate_return(&mut call_result, created_address, checkpoint);
-                Ok(call_result)
+                Ok(CallStart::Frame(
+                    Frame {
+                        contract,
+                        gas_limit: msg.gas_limit,
+                        is_static: msg.is_static,
+                        is_eof_init: msg.is_eof_init,
+                        validate_eof: msg.validate_eof,
+                        depth: self.journal.depth(),
+                    },
+                    FrameReturn::new(FrameReturnKind::EofCreate(created_address, checkpoint)),
+                ))
             }
             CallKind::ReturnContract => {
                 self.journal.checkpoint_commit();
@@ -663,22 +876,20 @@ impl<DB: Database> VMContext<DB> {
                 // Eof bytecode is going to be hashed.
                 self.journal
                     .set_code(msg.recipient, Bytecode::new_raw(msg.input));
-                Ok(CallResult::new_with_gas_limit_and_status(
+                Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                     msg.gas_limit,
                     ExitStatusCode::Return,
-                ))
+                )))
             }
             CallKind::Create | CallKind::Create2 => {
                 // Fetch balance of caller.
-                let caller_balance = self
-                    .balance(msg.caller)
-                    .map_err(|_| VMError::Database(DatabaseError))?;
+                let caller_balance = self.balance(msg.caller).map_err(VMError::database)?;
                 // Check if caller has enough balance to send to the created contract.
                 if caller_balance.data < msg.value {
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::OutOfFunds,
-                    ));
+                    )));
                 }
                 // Increase nonce of caller and check if it overflows
                 let old_nonce;
@@ -686,10 +897,10 @@ impl<DB: Database> VMContext<DB> {
                     old_nonce = nonce - 1;
                 } else {
                     // Note returns a normal result instead of a nonce overflow error here.
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::Return,
-                    ));
+                    )));
                 }
                 // Created address
                 let mut init_code_hash = B256::ZERO;
@@ -702,14 +913,14 @@ impl<DB: Database> VMContext<DB> {
                 };
                 // Created address is not allowed to be a precompile.
                 if self.is_precompile_address(&created_address) {
-                    return Ok(CallResult::new_with_gas_limit_and_status(
+                    return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                         msg.gas_limit,
                         ExitStatusCode::CreateCollision,
-                    ));
+                    )));
                 }
                 // Warm load account.
                 self.load_account(created_address)
-                    .map_err(|_| VMError::Database(DatabaseError))?;
+                    .map_err(VMError::database)?;
                 // Create account, transfer funds and make the journal checkpoint.
                 let checkpoint = match self.journal.create_account_checkpoint(
                     msg.caller,
@@ -719,10 +930,10 @@ impl<DB: Database> VMContext<DB> {
                 ) {
                     Ok(checkpoint) => checkpoint,
                     Err(err) => {
-                        return Ok(CallResult::new_with_gas_limit_and_status(
+                        return Ok(CallStart::Done(CallResult::new_with_gas_limit_and_status(
                             msg.gas_limit,
                             err.into(),
-                        ));
+                        )));
                     }
                 };
 
@@ -735,16 +946,17 @@ impl<DB: Database> VMContext<DB> {
                     caller: msg.caller,
                     call_value: msg.value,
                 };
-                let mut call_result = self.invoke_call_handler(Frame {
-                    contract,
-                    gas_limit: msg.gas_limit,
-                    is_static: msg.is_static,
-                    is_eof_init: msg.is_eof_init,
-                    validate_eof: msg.validate_eof,
-                    depth: self.journal.depth(),
-                })?;
-                self.create_return(&mut call_result, created_address, checkpoint);
-                Ok(call_result)
+                Ok(CallStart::Frame(
+                    Frame {
+                        contract,
+                        gas_limit: msg.gas_limit,
+                        is_static: msg.is_static,
+                        is_eof_init: msg.is_eof_init,
+                        validate_eof: msg.validate_eof,
+                        depth: self.journal.depth(),
+                    },
+                    FrameReturn::new(FrameReturnKind::Create(created_address, checkpoint)),
+                ))
             }
         }
     }
@@ -788,8 +1000,8 @@ impl<DB: Database> VMContext<DB> {
         }
 
         // EIP-170: Contract code size limit
-        // By default limit is 0x6000 (~25kb)
-        if result.output.len() > self.cfg().max_code_size() {
+        // By default limit is 0x6000 (~25kb), and 0xc000 (~49kb) since Osaka (EIP-7907)
+        if result.output.len() > self.max_code_size() {
             self.journal.checkpoint_revert(journal_checkpoint);
             result.status = ExitStatusCode::CreateContractSizeLimit;
             return;
@@ -846,9 +1058,9 @@ impl<DB: Database> VMContext<DB> {
         }
 
         // EIP-170: Contract code size limit
-        // By default limit is 0x6000 (~25kb)
+        // By default limit is 0x6000 (~25kb), and 0xc000 (~49kb) since Osaka (EIP-7907)
         if spec_id.is_enabled_in(SpecId::SPURIOUS_DRAGON)
-            && result.output.len() > self.cfg().max_code_size()
+            && result.output.len() > self.max_code_size()
         {
             self.journal.checkpoint_revert(journal_checkpoint);
             result.status = ExitStatusCode::CreateContractSizeLimit;
@@ -878,14 +1090,47 @@ impl<DB: Database> VMContext<DB> {
         result.status = ExitStatusCode::Return;
     }
 
+    /// Returns the artifact compiled from the code under the current spec and gas schedule from
+    /// the handler cache.
     #[inline]
     pub fn get_artifact(&self, code_hash: B256) -> Result<Option<SymbolArtifact>, Infallible> {
-        Ok(self.artifacts.get(&code_hash).cloned())
+        Ok(self
+            .handler
+            .artifact_cache
+            .get(&self.artifact_key(code_hash)))
+    }
+
+    /// Records the database error of the host as the first host error of the transaction.
+    fn record_host_error(&mut self, error: DB::Error) -> HostError {
+        let error = HostError::database(error);
+        self.host_error.get_or_insert_with(|| error.clone());
+        error
     }
 
+    /// Takes the first host error of the transaction.
     #[inline]
-    pub fn set_artifact(&mut self, code_hash: B256, artifact: SymbolArtifact) {
-        self.artifacts.insert(code_hash, artifact);
+    pub(crate) fn take_host_error(&mut self) -> Option<HostError> {
+        self.host_error.take()
+    }
+
+    /// Saves the artifact compiled from the code under the current spec and gas schedule into the
+    /// handler cache, the size is the estimated memory size of the artifact, see
+    /// [`ArtifactCache::estimated_size`](crate::cache::ArtifactCache::estimated_size).
+    ///
+    /// It replaces the v0.5.0 `set_artifact` method and `artifacts` map of the context, which kept
+    /// the artifacts per VM without a memory budget. Use
+    /// `ctx.set_artifact_with_size(code_hash, artifact, ArtifactCache::estimated_size(&code))`
+    /// instead of `ctx.set_artifact(code_hash, artifact)`, and [`VMContext::get_artifact`] instead
+    /// of reading `ctx.artifacts`.
+    #[inline]
+    pub fn set_artifact_with_size(
+        &mut self,
+        code_hash: B256,
+        artifact: SymbolArtifact,
+        size: usize,
+    ) {
+        let key = self.artifact_key(code_hash);
+        self.handler.artifact_cache.insert(key, artifact, size);
     }
 }
 
@@ -901,13 +1146,25 @@ impl<DB: Database> Host for VMContext<DB> {
     }
 
     #[inline]
-    fn sload(&mut self, addr: Address, key: U256) -> Option<StateLoad<U256>> {
-        self.sload(addr, key).ok()
+    fn gas_schedule(&self) -> GasSchedule {
+        self.gas_schedule()
     }
 
     #[inline]
-    fn sstore(&mut self, addr: Address, key: U256, value: U256) -> Option<StateLoad<SStoreResult>> {
-        self.sstore(addr, key, value).ok()
+    fn sload(&mut self, addr: Address, key: U256) -> Result<StateLoad<U256>, HostError> {
+        self.sload(addr, key)
+            .map_err(|err| self.record_host_error(err))
+    }
+
+    #[inline]
+    fn sstore(
+        &mut self,
+        addr: Address,
+        key: U256,
+        value: U256,
+    ) -> Result<StateLoad<SStoreResult>, HostError> {
+        self.sstore(addr, key, value)
+            .map_err(|err| self.record_host_error(err))
     }
 
     #[inline]
@@ -921,26 +1178,29 @@ impl<DB: Database> Host for VMContext<DB> {
     }
 
     #[inline]
-    fn load_account_delegated(&mut self, addr: Address) -> Option<StateLoad<AccountLoad>> {
-        self.load_account_delegated(addr).ok()
+    fn load_account_delegated(
+        &mut self,
+        addr: Address,
+    ) -> Result<StateLoad<AccountLoad>, HostError> {
+        self.load_account_delegated(addr)
+            .map_err(|err| self.record_host_error(err))
     }
 
     #[inline]
-    fn balance(&mut self, addr: Address) -> Option<StateLoad<U256>> {
-        self.journal
-            .load_account(addr)
-            .map(|acc| acc.map(|a| a.info.balance))
-            .ok()
+    fn balance(&mut self, addr: Address) -> Result<StateLoad<U256>, HostError> {
+        self.balance(addr)
+            .map_err(|err| self.record_host_error(err))
     }
 
     #[inline]
-    fn code(&mut self, addr: Address) -> Option<StateLoad<Bytes>> {
-        self.code(addr).ok()
+    fn code(&mut self, addr: Address) -> Result<StateLoad<Bytes>, HostError> {
+        self.code(addr).map_err(|err| self.record_host_error(err))
     }
 
     #[inline]
-    fn code_hash(&mut self, addr: Address) -> Option<StateLoad<B256>> {
-        self.code_hash(addr).ok()
+    fn code_hash(&mut self, addr: Address) -> Result<StateLoad<B256>, HostError> {
+        self.code_hash(addr)
+            .map_err(|err| self.record_host_error(err))
     }
 
     #[inline]
@@ -948,32 +1208,65 @@ impl<DB: Database> Host for VMContext<DB> {
         &mut self,
         addr: Address,
         target: Address,
-    ) -> Option<StateLoad<SelfDestructResult>> {
-        self.journal.selfdestruct(addr, target).ok()
+    ) -> Result<StateLoad<SelfDestructResult>, HostError> {
+        if self.inspector.is_none() {
+            return self
+                .journal
+                .selfdestruct(addr, target)
+                .map_err(|err| self.record_host_error(err));
+        }
+        let value = match self.journal.load_account(addr) {
+            Ok(account) => account.data.info.balance,
+            Err(err) => return Err(self.record_host_error(err)),
+        };
+        let result = self
+            .journal
+            .selfdestruct(addr, target)
+            .map_err(|err| self.record_host_error(err))?;
+        self.inspect(|inspector, _| inspector.selfdestruct(addr, target, value));
+        Ok(result)
     }
 
-    fn block_hash(&mut self, number: u64) -> Option<B256> {
+    fn block_hash(&mut self, number: u64) -> Result<B256, HostError> {
         let block_number = self.env.block.number;
         let Some(diff) = block_number.checked_sub(number) else {
-            return Some(B256::ZERO);
+            return Ok(B256::ZERO);
         };
         if diff == 0 {
-            return Some(B256::ZERO);
+            return Ok(B256::ZERO);
         }
         if diff <= BLOCK_HASH_HISTORY {
-            return self.block_hash(number).ok();
+            return self
+                .block_hash(number)
+                .map_err(|err| self.record_host_error(err));
         }
-        Some(B256::ZERO)
+        Ok(B256::ZERO)
     }
 
     #[inline]
     fn log(&mut self, log: Log) {
+        self.inspect(|inspector, ctx| inspector.log(&log, ctx));
         self.journal.log(log);
     }
 
     #[inline]
     fn call(&mut self, msg: CallMessage) -> Result<CallResult, VMError> {
-        self.call(msg)
+        self.call(msg).inspect_err(|err| {
+            if let VMError::Database(error) = err {
+                self.host_error
+                    .get_or_insert_with(|| HostError::Database(error.clone()));
+            }
+        })
+    }
+
+    #[inline]
+    fn step(&mut self, step: &StepState<'_>) {
+        self.inspect(|inspector, ctx| inspector.step(step, ctx));
+    }
+
+    #[inline]
+    fn step_end(&mut self, step: &StepState<'_>) {
+        self.inspect(|inspector, ctx| inspector.step_end(step, ctx));
     }
 }
 
@@ -991,12 +1284,13 @@ impl<DB: Database> Host for VMContext<DB> {
 /// - `is_static`: A boolean flag indicating whether the context is static.
 /// - `is_eof_init`: A boolean flag indicating whether the context is EOF init.
 /// - `spec_id`: The EVM spec ID from [SpecId].
+/// - `gas_schedule`: The gas schedule of the dynamic gas costs from [GasSchedule].
 /// ```
 #[derive(Debug, Clone)]
 pub struct InnerContext {
     /// Represents the mutable, byte-addressable memory used during contract execution.
     /// This memory is accessible by smart contracts for reading and writing data.
-    memory: Vec<u8>,
+    pub(crate) memory: Vec<u8>,
     /// The return data buffer for internal calls.
     /// It has multi usage:
     ///
@@ -1026,6 +1320,34 @@ pub struct InnerContext {
     pub resume_at: u32,
     /// VM spec id
     pub spec_id: SpecId,
+    /// The gas schedule of the dynamic gas costs.
+    pub gas_schedule: GasSchedule,
+    /// The traced instruction (pc, opcode, gas remaining) waiting for the step end hook.
+    traced_step: Option<(usize, u8, u64)>,
+    /// Whether the sub calls are suspended and driven by the frame scheduler instead of being
+    /// called on the host, which is only set for the code compiled with the suspend mode.
+    pub suspend: bool,
+    /// The sub call suspended by the compiled code waiting for the frame scheduler.
+    pending_call: Option<PendingCall>,
+}
+
+/// The sub call suspended by the compiled code, which is started by the frame scheduler and
+/// its result is written back to the caller frame on resume.
+#[derive(Debug, Clone)]
+pub(crate) struct PendingCall {
+    /// The call message of the sub call.
+    pub(crate) msg: CallMessage,
+    /// How the result is written back to the caller frame.
+    pub(crate) kind: PendingCallKind,
+}
+
+/// The kind of the [`PendingCall`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PendingCallKind {
+    /// The call operations, the output is copied into the memory range.
+    Call { ret_offset: usize, ret_size: usize },
+    /// The create operations, the created address is pushed onto the stack.
+    Create,
 }
 
 impl Default for InnerContext {
@@ -1044,6 +1366,10 @@ impl Default for InnerContext {
             is_eof_init: Default::default(),
             resume_at: Default::default(),
             spec_id: Default::default(),
+            gas_schedule: GasSchedule::new(Default::default()),
+            traced_step: Default::default(),
+            suspend: Default::default(),
+            pending_call: Default::default(),
         }
     }
 }
@@ -1266,6 +1592,7 @@ impl<'a> RuntimeContext<'a> {
         Self {
             inner: InnerContext {
                 spec_id,
+                gas_schedule: host.gas_schedule(),
                 depth,
                 memory: Vec::with_capacity(4 * 1024),
                 is_static,
@@ -1368,15 +1695,101 @@ impl<'a> RuntimeContext<'a> {
     pub fn set_exit_status(&mut self, code: ExitStatusCode) {
         self.inner.exit_status = Some(code);
     }
+
+    /// Takes the sub call suspended by the compiled code.
+    #[inline]
+    pub(crate) fn take_pending_call(&mut self) -> Option<PendingCall> {
+        self.inner.pending_call.take()
+    }
+
+    /// Writes the result of the suspended sub call back to the frame, returns the value to be
+    /// pushed onto the stack and the unused gas returned to the frame.
+    pub(crate) fn resume_call(
+        &mut self,
+        kind: PendingCallKind,
+        call_result: CallResult,
+    ) -> (Bytes32, u64) {
+        match kind {
+            PendingCallKind::Call {
+                ret_offset,
+                ret_size,
+            } => {
+                let (value, gas_returned) =
+                    self.handle_call_result(call_result, ret_offset, ret_size);
+                (Bytes32::from_u256(U256::from(value)), gas_returned)
+            }
+            PendingCallKind::Create => {
+                let mut value = Bytes32::ZERO;
+                let gas_returned = self.handle_create_result(call_result, &mut value);
+                (value, gas_returned)
+            }
+        }
+    }
+
+    /// Handles the result of the call operations, returns the call status value and the unused
+    /// gas returned to the caller.
+    fn handle_call_result(
+        &mut self,
+        call_result: CallResult,
+        ret_offset: usize,
+        ret_size: usize,
+    ) -> (u64, u64) {
+        self.inner.returndata = call_result.output.to_vec();
+        let target_len = min(ret_size, self.inner.returndata.len());
+        let is_eof = self.contract.code.is_eof();
+        // Check the error message.
+        if call_result.status.is_ok() {
+            self.inner.gas_refunded += call_result.gas_refunded;
+            // Copy call output to the memory.
+            if target_len != 0 {
+                self.inner.memory[ret_offset..ret_offset + target_len]
+                    .copy_from_slice(&self.inner.returndata[..target_len]);
+            }
+            (if is_eof { 0 } else { 1 }, call_result.gas_remaining)
+        } else if call_result.status.is_revert() {
+            // Copy call output to the memory.
+            if target_len != 0 {
+                self.inner.memory[ret_offset..ret_offset + target_len]
+                    .copy_from_slice(&self.inner.returndata[..target_len]);
+            }
+            (if is_eof { 1 } else { 0 }, call_result.gas_remaining)
+        } else {
+            (if is_eof { 2 } else { 0 }, 0)
+        }
+    }
+
+    /// Handles the result of the create operations, sets the created address to the value and
+    /// returns the unused gas returned to the caller.
+    fn handle_create_result(&mut self, call_result: CallResult, value: &mut Bytes32) -> u64 {
+        self.inner.returndata = if call_result.status.is_revert() {
+            call_result.output.to_vec()
+        } else {
+            Vec::new()
+        };
+        // Check the error message.
+        if call_result.status.is_ok() {
+            // Set created address to the value.
+            value.copy_from(&call_result.create_address.unwrap_or_default());
+            self.inner.gas_refunded += call_result.gas_refunded;
+            call_result.gas_remaining
+        } else if call_result.status.is_revert() {
+            *value = Bytes32::ZERO;
+            call_result.gas_remaining
+        } else {
+            *value = Bytes32::ZERO;
+            0
+        }
+    }
 }
 
 // System call functions
 impl RuntimeContext<'_> {
     extern "C" fn nop() {}
 
-    /// EIP-3155: EVM trace specification - A JSON format for EVM traces: https://eips.ethereum.org/EIPS/eip-3155
-    /// For example:
-    /// {"pc":21,"op":0,"gas":"0x4c3fe","gasCost":"0x0","memSize":32,"stack":[],"depth":1,"refund":0,"opName":"STOP"}
+    /// Instruction tracing hook, which is only emitted into the code compiled with tracing enabled.
+    ///
+    /// The step end hook of the previous instruction is deferred until the next instruction
+    /// or the end of the frame, where the state after the instruction is observable.
     extern "C" fn tracing(
         &mut self,
         pc: usize,
@@ -1387,36 +1800,39 @@ impl RuntimeContext<'_> {
         stack_size_ptr: *mut u64,
     ) {
         let stack_size = unsafe { *stack_size_ptr } as usize;
-        let stack =
-            unsafe { Vec::<Bytes32>::from_raw_parts(stack_ptr, stack_size, MAX_STACK_SIZE) };
-        let op_name = OpCode::new(op).map(|i| i.as_str()).unwrap();
-        println!(
-            "{{\"pc\":{},\"op\":{},\"gas\":\"0x{:x}\",\"gasCost\":\"0x{:x}\",\"memSize\":{},\"stack\":{},\"depth\":{},\"refund\":{},\"opName\":{:?}}}",
+        let stack = unsafe { std::slice::from_raw_parts(stack_ptr, stack_size) };
+        self.trace_step_end(gas, stack);
+        self.host.step(&StepState {
             pc,
-            op,
-            gas,
+            opcode: op,
+            gas_remaining: gas,
             gas_cost,
-            self.memory().len(),
-            serde_json::to_string(
-                &stack
-                    .iter()
-                    .map(|v| hex::encode(v.to_be_bytes())
-                        .trim_start_matches('0')
-                        .to_string())
-                    .map(|v| if v.is_empty() {
-                        "0x0".to_string()
-                    } else {
-                        format!("0x{v}")
-                    })
-                    .collect::<Vec<String>>()
-            )
-            .unwrap(),
-            self.inner.depth,
-            self.inner.gas_refunded,
-            op_name,
-        );
-        // DO NOT free the stack pointer.
-        stack.leak();
+            gas_refunded: self.inner.gas_refunded,
+            depth: self.inner.depth,
+            stack,
+            memory: &self.inner.memory,
+            return_data: &self.inner.returndata,
+            contract: &self.contract,
+        });
+        self.inner.traced_step = Some((pc, op, gas));
+    }
+
+    /// Invokes the deferred step end hook of the last traced instruction.
+    pub(crate) fn trace_step_end(&mut self, gas: u64, stack: &[Bytes32]) {
+        if let Some((pc, opcode, gas_before)) = self.inner.traced_step.take() {
+            self.host.step_end(&StepState {
+                pc,
+                opcode,
+                gas_remaining: gas,
+                gas_cost: gas_before.saturating_sub(gas),
+                gas_refunded: self.inner.gas_refunded,
+                depth: self.inner.depth,
+                stack,
+                memory: &self.inner.memory,
+                return_data: &self.inner.returndata,
+                contract: &self.contract,
+            });
+        }
     }
 
     extern "C" fn write_result(
@@ -1469,6 +1885,35 @@ impl RuntimeContext<'_> {
         unsafe { &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>) }
     }
 
+    /// EIP-7907: Returns the extra cost of loading the large code of a cold contract for all the
+    /// call kinds, the code of a delegated account is loaded from the delegated address.
+    fn large_contract_call_cost(
+        &mut self,
+        to: Address,
+        account_load: &StateLoad<AccountLoad>,
+    ) -> Result<u64, HostError> {
+        let is_cold = account_load
+            .data
+            .is_delegate_account_cold
+            .unwrap_or(account_load.is_cold);
+        if !is_cold || !self.inner.spec_id.is_enabled_in(SpecId::OSAKA) {
+            return Ok(0);
+        }
+        let code = self.host.code(to)?.data;
+        let code = match account_load.data.is_delegate_account_cold {
+            Some(_) => match Eip7702Bytecode::new_raw(code.clone()) {
+                Ok(bytecode) => self.host.code(bytecode.delegated_address)?.data,
+                Err(_) => code,
+            },
+            None => code,
+        };
+        Ok(gas::large_contract_cost(
+            self.inner.spec_id,
+            code.len(),
+            is_cold,
+        ))
+    }
+
     extern "C" fn call(
         &mut self,
         local_gas_limit: &Bytes32,
@@ -1488,8 +1933,8 @@ impl RuntimeContext<'_> {
         let to = Address::from(call_to_address);
         // Load account and calculate gas cost.
         let mut account_load = match self.host.load_account_delegated(to) {
-            Some(account_load) => account_load,
-            None => {
+            Ok(account_load) => account_load,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 self.inner.result.value = 0;
                 return &self.inner.result as _;
@@ -1499,7 +1944,16 @@ impl RuntimeContext<'_> {
             account_load.is_empty = false;
         }
         let transfers_value = !value_to_transfer.as_u256().is_zero();
-        let gas_cost = gas::call_cost(self.inner.spec_id, transfers_value, account_load);
+        let large_contract_cost = match self.large_contract_call_cost(to, &account_load) {
+            Ok(cost) => cost,
+            Err(_) => {
+                self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
+                self.inner.result.value = 0;
+                return &self.inner.result as _;
+            }
+        };
+        let gas_cost =
+            gas::call_cost(self.inner.spec_id, transfers_value, account_load) + large_contract_cost;
         // original_gas - gas_cost
         let (gas_remaining, overflow) = original_remaining_gas.overflowing_sub(gas_cost);
         if overflow {
@@ -1559,39 +2013,47 @@ impl RuntimeContext<'_> {
             is_eof_init: false,
             validate_eof: true,
         };
+        self.sub_call(
+            call_msg,
+            ret_offset as usize,
+            ret_size as usize,
+            original_remaining_gas,
+            gas_remaining,
+        )
+    }
+
+    /// Calls the sub call message on the host and writes the result back to the frame, or suspends
+    /// the sub call for the frame scheduler in the suspend mode.
+    fn sub_call(
+        &mut self,
+        call_msg: CallMessage,
+        ret_offset: usize,
+        ret_size: usize,
+        original_remaining_gas: u64,
+        gas_remaining: u64,
+    ) -> *const RuntimeResult<u64> {
+        if self.inner.suspend {
+            // The whole gas limit of the sub call is charged here, and the unused gas is
+            // returned by the frame scheduler on resume.
+            self.inner.pending_call = Some(PendingCall {
+                msg: call_msg,
+                kind: PendingCallKind::Call {
+                    ret_offset,
+                    ret_size,
+                },
+            });
+            self.inner.result.value = 0;
+            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
+            return &self.inner.result as _;
+        }
+        let gas_limit = call_msg.gas_limit;
         let call_result = self
             .host
             .call(call_msg)
             .unwrap_or_else(|_| CallResult::new_with_gas_limit(gas_limit));
-        self.inner.returndata = call_result.output.to_vec();
-        let ret_offset = ret_offset as usize;
-        let ret_size = ret_size as usize;
-        let target_len = min(ret_size, self.inner.returndata.len());
-        let is_eof = self.contract.code.is_eof();
-        // Check the error message.
-        if call_result.status.is_ok() {
-            let gas_remaining = gas_remaining + call_result.gas_remaining;
-            self.inner.gas_refunded += call_result.gas_refunded;
-            // Copy call output to the memory.
-            if target_len != 0 {
-                self.inner.memory[ret_offset..ret_offset + target_len]
-                    .copy_from_slice(&self.inner.returndata[..target_len]);
-            }
-            self.inner.result.value = if is_eof { 0 } else { 1 };
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        } else if call_result.status.is_revert() {
-            let gas_remaining = gas_remaining + call_result.gas_remaining;
-            // Copy call output to the memory.
-            if target_len != 0 {
-                self.inner.memory[ret_offset..ret_offset + target_len]
-                    .copy_from_slice(&self.inner.returndata[..target_len]);
-            }
-            self.inner.result.value = if is_eof { 1 } else { 0 };
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        } else {
-            self.inner.result.value = if is_eof { 2 } else { 0 };
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        }
+        let (value, gas_returned) = self.handle_call_result(call_result, ret_offset, ret_size);
+        self.inner.result.value = value;
+        self.inner.result.gas_used = original_remaining_gas - (gas_remaining + gas_returned);
         &self.inner.result as _
     }
 
@@ -1621,8 +2083,8 @@ impl RuntimeContext<'_> {
         let to = Address::from(call_to_address);
         // Load account and calculate gas cost.
         let mut account_load = match self.host.load_account_delegated(to) {
-            Some(account_load) => account_load,
-            None => {
+            Ok(account_load) => account_load,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 self.inner.result.value = 0;
                 return &self.inner.result as _;
@@ -1632,7 +2094,16 @@ impl RuntimeContext<'_> {
             account_load.is_empty = false;
         }
         let transfers_value = !value_to_transfer.as_u256().is_zero();
-        let gas_cost = gas::call_cost(self.inner.spec_id, transfers_value, account_load);
+        let large_contract_cost = match self.large_contract_call_cost(to, &account_load) {
+            Ok(cost) => cost,
+            Err(_) => {
+                self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
+                self.inner.result.value = 0;
+                return &self.inner.result as _;
+            }
+        };
+        let gas_cost =
+            gas::call_cost(self.inner.spec_id, transfers_value, account_load) + large_contract_cost;
         // original_gas - gas_cost
         let (gas_remaining, overflow) = original_remaining_gas.overflowing_sub(gas_cost);
         if overflow {
@@ -1698,26 +2169,8 @@ impl RuntimeContext<'_> {
             is_eof_init: true,
             validate_eof: true,
         };
-        let call_result = self
-            .host
-            .call(call_msg)
-            .unwrap_or_else(|_| CallResult::new_with_gas_limit(gas_limit));
-        self.inner.returndata = call_result.output.to_vec();
-        // Check the error message.
-        if call_result.status.is_ok() {
-            let gas_remaining = gas_remaining + call_result.gas_remaining;
-            self.inner.gas_refunded += call_result.gas_refunded;
-            self.inner.result.value = 0;
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        } else if call_result.status.is_revert() {
-            let gas_remaining = gas_remaining + call_result.gas_remaining;
-            self.inner.result.value = 1;
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        } else {
-            self.inner.result.value = 2;
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        }
-        &self.inner.result as _
+        // Note the EXT*CALL output is not copied into the memory.
+        self.sub_call(call_msg, 0, 0, original_remaining_gas, gas_remaining)
     }
 
     fn memory_set_data(
@@ -1752,13 +2205,13 @@ impl RuntimeContext<'_> {
         balance: &mut Bytes32,
     ) -> *const RuntimeResult<()> {
         match self.host.balance(self.contract.target_address) {
-            Some(state) => {
+            Ok(state) => {
                 *balance = state.data.into();
                 unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
                 }
             }
-            None => {
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
@@ -1941,8 +2394,8 @@ impl RuntimeContext<'_> {
             .host
             .sload(self.contract.target_address, stg_key.to_u256())
         {
-            Some(result) => result,
-            None => {
+            Ok(result) => result,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 return unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
@@ -1974,8 +2427,8 @@ impl RuntimeContext<'_> {
             stg_key.to_u256(),
             stg_value.to_u256(),
         ) {
-            Some(result) => result,
-            None => {
+            Ok(result) => result,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 return unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
@@ -1988,6 +2441,7 @@ impl RuntimeContext<'_> {
 
         match gas::sstore_cost(
             self.inner.spec_id,
+            &self.inner.gas_schedule,
             &result.data,
             gas_remaining,
             result.is_cold,
@@ -1999,7 +2453,8 @@ impl RuntimeContext<'_> {
                 self.inner.result.error = ExitStatusCode::OutOfGas.to_u8();
             }
         }
-        self.inner.gas_refunded += gas::sstore_refund(self.inner.spec_id, &result.data);
+        self.inner.gas_refunded +=
+            gas::sstore_refund(self.inner.spec_id, &self.inner.gas_schedule, &result.data);
         unsafe { &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>) }
     }
 
@@ -2063,10 +2518,10 @@ impl RuntimeContext<'_> {
 
     extern "C" fn block_hash(&mut self, number: &mut Bytes32) -> *const RuntimeResult<()> {
         match self.host.block_hash(as_u64_saturated!(number.as_u256())) {
-            Some(hash) => {
+            Ok(hash) => {
                 *number = hash.into();
             }
-            None => {
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
             }
         };
@@ -2088,7 +2543,7 @@ impl RuntimeContext<'_> {
     }
 
     extern "C" fn extcodesize(&mut self, address: &Bytes32) -> *const RuntimeResult<u64> {
-        let Some(code) = self.host.code(address.to_address()) else {
+        let Ok(code) = self.host.code(address.to_address()) else {
             self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
             return &self.inner.result as _;
         };
@@ -2130,8 +2585,8 @@ impl RuntimeContext<'_> {
     extern "C" fn store_in_balance(&mut self, address: &mut Bytes32) -> *const RuntimeResult<()> {
         let addr = address.to_address();
         let result = match self.host.balance(addr) {
-            Some(result) => result,
-            None => {
+            Ok(result) => result,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 return unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
@@ -2162,8 +2617,8 @@ impl RuntimeContext<'_> {
     ) -> *const RuntimeResult<()> {
         let addr = address_value.to_address();
         let code = match self.host.code(addr) {
-            Some(code) => code,
-            None => {
+            Ok(code) => code,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 return unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
@@ -2171,7 +2626,8 @@ impl RuntimeContext<'_> {
             }
         };
         let code_offset = as_usize_saturated!(code_offset.to_u256());
-        let gas_cost = gas::extcodecopy_gas_cost(self.inner.spec_id, code.is_cold);
+        let gas_cost = gas::extcodecopy_gas_cost(self.inner.spec_id, code.is_cold)
+            + gas::large_contract_cost(self.inner.spec_id, code.len(), code.is_cold);
         let size = size as usize;
         let memory_offset = memory_offset as usize;
         if size != 0 {
@@ -2185,8 +2641,8 @@ impl RuntimeContext<'_> {
     extern "C" fn extcodehash(&mut self, address: &mut Bytes32) -> *const RuntimeResult<()> {
         let addr = Address::from(address as &Bytes32);
         let code_hash = match self.host.code_hash(addr) {
-            Some(code_hash) => code_hash,
-            None => {
+            Ok(code_hash) => code_hash,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 return unsafe {
                     &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
@@ -2250,6 +2706,18 @@ impl RuntimeContext<'_> {
             is_eof_init: false,
             validate_eof: true,
         };
+        if self.inner.suspend {
+            // The created address is written onto the stack by the frame scheduler on resume.
+            self.inner.pending_call = Some(PendingCall {
+                msg: call_msg,
+                kind: PendingCallKind::Create,
+            });
+            *value = Bytes32::ZERO;
+            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
+            return unsafe {
+                &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>)
+            };
+        }
         let call_result = match self.host.call(call_msg) {
             Ok(result) => result,
             Err(_) => {
@@ -2259,27 +2727,8 @@ impl RuntimeContext<'_> {
                 };
             }
         };
-        self.inner.returndata = if call_result.status.is_revert() {
-            call_result.output.to_vec()
-        } else {
-            Vec::new()
-        };
-
-        // Check the error message.
-        if call_result.status.is_ok() {
-            // Set created address to the value.
-            value.copy_from(&call_result.create_address.unwrap_or_default());
-            let gas_remaining = gas_remaining + call_result.gas_remaining;
-            self.inner.gas_refunded += call_result.gas_refunded;
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        } else if call_result.status.is_revert() {
-            *value = Bytes32::ZERO;
-            let gas_remaining = gas_remaining + call_result.gas_remaining;
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        } else {
-            *value = Bytes32::ZERO;
-            self.inner.result.gas_used = original_remaining_gas - gas_remaining;
-        }
+        let gas_returned = self.handle_create_result(call_result, value);
+        self.inner.result.gas_used = original_remaining_gas - (gas_remaining + gas_returned);
         unsafe { &*(&self.inner.result as *const RuntimeResult<u64> as *const RuntimeResult<()>) }
     }
 
@@ -2538,8 +2987,8 @@ impl RuntimeContext<'_> {
             .host
             .selfdestruct(self.contract.target_address, receiver_address)
         {
-            Some(result) => result,
-            None => {
+            Ok(result) => result,
+            Err(_) => {
                 self.inner.result.error = ExitStatusCode::FatalExternalError.to_u8();
                 return &self.inner.result as _;
             }
@@ -2598,312 +3047,314 @@ impl RuntimeContext<'_> {
     }
 }
 
-type SymbolSignature = (&'static str, *const fn() -> ());
+pub(crate) type SymbolSignature = (&'static str, *const fn() -> ());
 
 impl RuntimeContext<'_> {
     /// Registers all the syscalls as symbols in the execution engine.
     pub fn register_evm_symbols(engine: &ExecutionEngine) {
-        unsafe {
-            // Global variables and syscalls with corresponding function signatures
-            let symbols_and_signatures: &[SymbolSignature] = &[
-                // Debug functions
-                (symbols::NOP, RuntimeContext::nop as *const _),
-                (symbols::TRACING, RuntimeContext::tracing as *const _),
-                // Syscalls
-                (
-                    symbols::WRITE_RESULT,
-                    RuntimeContext::write_result as *const _,
-                ),
-                (
-                    symbols::CTX_IS_STATIC,
-                    RuntimeContext::ctx_is_static as *const _,
-                ),
-                (symbols::EXP, RuntimeContext::exp as *const _),
-                (
-                    symbols::KECCAK256_HASHER,
-                    RuntimeContext::keccak256_hasher as *const _,
-                ),
-                (
-                    symbols::EXTEND_MEMORY,
-                    RuntimeContext::extend_memory as *const _,
-                ),
-                (symbols::MEMORY_PTR, RuntimeContext::memory_ptr as *const _),
-                (
-                    symbols::MEMORY_SIZE,
-                    RuntimeContext::memory_size as *const _,
-                ),
-                (symbols::SLOAD, RuntimeContext::sload as *const _),
-                (symbols::SSTORE, RuntimeContext::sstore as *const _),
-                (symbols::APPEND_LOG, RuntimeContext::append_log as *const _),
-                (
-                    symbols::APPEND_LOG_ONE_TOPIC,
-                    RuntimeContext::append_log_with_one_topic as *const _,
-                ),
-                (
-                    symbols::APPEND_LOG_TWO_TOPICS,
-                    RuntimeContext::append_log_with_two_topics as *const _,
-                ),
-                (
-                    symbols::APPEND_LOG_THREE_TOPICS,
-                    RuntimeContext::append_log_with_three_topics as *const _,
-                ),
-                (
-                    symbols::APPEND_LOG_FOUR_TOPICS,
-                    RuntimeContext::append_log_with_four_topics as *const _,
-                ),
-                (symbols::CALLDATA, RuntimeContext::calldata as *const _),
-                (
-                    symbols::CALLDATA_SIZE,
-                    RuntimeContext::calldata_size as *const _,
-                ),
-                (
-                    symbols::CALLDATA_COPY,
-                    RuntimeContext::calldata_copy as *const _,
-                ),
-                (symbols::DATA_LOAD, RuntimeContext::data_load as *const _),
-                (
-                    symbols::DATA_SECTION,
-                    RuntimeContext::data_section as *const _,
-                ),
-                (
-                    symbols::DATA_SECTION_SIZE,
-                    RuntimeContext::data_section_size as *const _,
-                ),
-                (
-                    symbols::DATA_SECTION_COPY,
-                    RuntimeContext::data_section_copy as *const _,
-                ),
-                (symbols::CODE_COPY, RuntimeContext::code_copy as *const _),
-                (symbols::ORIGIN, RuntimeContext::origin as *const _),
-                (symbols::ADDRESS, RuntimeContext::address as *const _),
-                (symbols::CALLVALUE, RuntimeContext::callvalue as *const _),
-                (
-                    symbols::STORE_IN_BLOBBASEFEE_PTR,
-                    RuntimeContext::store_in_blobbasefee_ptr as *const _,
-                ),
-                (
-                    symbols::EXT_CODE_SIZE,
-                    RuntimeContext::extcodesize as *const _,
-                ),
-                (symbols::COINBASE, RuntimeContext::coinbase as *const _),
-                (
-                    symbols::STORE_IN_TIMESTAMP_PTR,
-                    RuntimeContext::store_in_timestamp_ptr as *const _,
-                ),
-                (
-                    symbols::STORE_IN_BASEFEE_PTR,
-                    RuntimeContext::store_in_basefee_ptr as *const _,
-                ),
-                (symbols::CALLER, RuntimeContext::caller as *const _),
-                (
-                    symbols::STORE_IN_GASLIMIT_PTR,
-                    RuntimeContext::store_in_gaslimit_ptr as *const _,
-                ),
-                (
-                    symbols::STORE_IN_GASPRICE_PTR,
-                    RuntimeContext::store_in_gasprice_ptr as *const _,
-                ),
-                (
-                    symbols::BLOCK_NUMBER,
-                    RuntimeContext::block_number as *const _,
-                ),
-                (symbols::PREVRANDAO, RuntimeContext::prevrandao as *const _),
-                (symbols::BLOB_HASH, RuntimeContext::blob_hash as *const _),
-                (symbols::CHAINID, RuntimeContext::chainid as *const _),
-                (
-                    symbols::STORE_IN_BALANCE,
-                    RuntimeContext::store_in_balance as *const _,
-                ),
-                (
-                    symbols::STORE_IN_SELFBALANCE_PTR,
-                    RuntimeContext::store_in_selfbalance_ptr as *const _,
-                ),
-                (
-                    symbols::EXT_CODE_COPY,
-                    RuntimeContext::extcodecopy as *const _,
-                ),
-                (symbols::BLOCK_HASH, RuntimeContext::block_hash as *const _),
-                (
-                    symbols::EXT_CODE_HASH,
-                    RuntimeContext::extcodehash as *const _,
-                ),
-                (symbols::EOFCREATE, RuntimeContext::eofcreate as *const _),
-                (
-                    symbols::RETURNCONTRACT,
-                    RuntimeContext::returncontract as *const _,
-                ),
-                (symbols::CREATE, RuntimeContext::create as *const _),
-                (symbols::CREATE2, RuntimeContext::create2 as *const _),
-                (symbols::CALL, RuntimeContext::call as *const _),
-                (
-                    symbols::EXTCALL_ADDR_VALIDATE,
-                    RuntimeContext::extcall_addr_validate as *const _,
-                ),
-                (symbols::EXTCALL, RuntimeContext::extcall as *const _),
-                (symbols::RETURNDATA, RuntimeContext::returndata as *const _),
-                (
-                    symbols::RETURNDATA_SIZE,
-                    RuntimeContext::returndata_size as *const _,
-                ),
-                (
-                    symbols::RETURNDATA_COPY,
-                    RuntimeContext::returndata_copy as *const _,
-                ),
-                (
-                    symbols::SELFDESTRUCT,
-                    RuntimeContext::selfdestruct as *const _,
-                ),
-                (symbols::TLOAD, RuntimeContext::tload as *const _),
-                (symbols::TSTORE, RuntimeContext::tstore as *const _),
-                (
-                    symbols::FUNC_STACK_PUSH,
-                    RuntimeContext::func_stack_push as *const _,
-                ),
-                (
-                    symbols::FUNC_STACK_POP,
-                    RuntimeContext::func_stack_pop as *const _,
-                ),
-                (
-                    symbols::FUNC_STACK_GROW,
-                    RuntimeContext::func_stack_grow as *const _,
-                ),
-                (symbols::SET_RESUME, RuntimeContext::set_resume as *const _),
-                (symbols::GET_RESUME, RuntimeContext::get_resume as *const _),
-            ];
-
-            for (symbol, signature) in symbols_and_signatures {
-                engine.register_symbol(symbol, *signature as *mut ());
-            }
+        for (symbol, signature) in Self::evm_symbols() {
+            unsafe { engine.register_symbol(symbol, signature as *mut ()) };
         }
     }
 
     /// Registers all WASM libcalls as symbols in the execution engine.
     pub fn register_wasm_symbols(engine: &ExecutionEngine) {
-        unsafe {
-            let symbols_and_signatures: &[SymbolSignature] = &[
-                (
-                    symbols::wasm::TABLE_INIT,
-                    wasmer_vm::libcalls::wasmer_vm_table_init as *const _,
-                ),
-                (
-                    symbols::wasm::TABLE_COPY,
-                    wasmer_vm::libcalls::wasmer_vm_table_copy as *const _,
-                ),
-                (
-                    symbols::wasm::TABLE_FILL,
-                    wasmer_vm::libcalls::wasmer_vm_table_fill as *const _,
-                ),
-                (
-                    symbols::wasm::TABLE_SIZE,
-                    wasmer_vm::libcalls::wasmer_vm_table_size as *const _,
-                ),
-                (
-                    symbols::wasm::TABLE_GET,
-                    wasmer_vm::libcalls::wasmer_vm_table_get as *const _,
-                ),
-                (
-                    symbols::wasm::TABLE_SET,
-                    wasmer_vm::libcalls::wasmer_vm_table_set as *const _,
-                ),
-                (
-                    symbols::wasm::TABLE_GROW,
-                    wasmer_vm::libcalls::wasmer_vm_table_grow as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_TABLE_SIZE,
-                    wasmer_vm::libcalls::wasmer_vm_imported_table_size as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_TABLE_GET,
-                    wasmer_vm::libcalls::wasmer_vm_imported_table_get as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_TABLE_SET,
-                    wasmer_vm::libcalls::wasmer_vm_imported_table_set as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_TABLE_GROW,
-                    wasmer_vm::libcalls::wasmer_vm_imported_table_grow as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_INIT,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_init as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_SIZE,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_size as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_GROW,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_grow as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_COPY,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_copy as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_FILL,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_fill as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_NOTIFY,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_atomic_notify as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_WAIT32,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_atomic_wait32 as *const _,
-                ),
-                (
-                    symbols::wasm::MEMORY_WAIT64,
-                    wasmer_vm::libcalls::wasmer_vm_memory32_atomic_wait64 as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_SIZE,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_size as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_GROW,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_grow as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_COPY,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_copy as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_FILL,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_fill as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_NOTIFY,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_atomic_notify as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_WAIT32,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_atomic_wait32 as *const _,
-                ),
-                (
-                    symbols::wasm::IMPORTED_MEMORY_WAIT64,
-                    wasmer_vm::libcalls::wasmer_vm_imported_memory32_atomic_wait64 as *const _,
-                ),
-                (
-                    symbols::wasm::FUNC_REF,
-                    wasmer_vm::libcalls::wasmer_vm_func_ref as *const _,
-                ),
-                (
-                    symbols::wasm::DATA_DROP,
-                    wasmer_vm::libcalls::wasmer_vm_data_drop as *const _,
-                ),
-                (
-                    symbols::wasm::ELEM_DROP,
-                    wasmer_vm::libcalls::wasmer_vm_elem_drop as *const _,
-                ),
-                (symbols::wasm::RAISE_TRAP, wasm_raise_trap as *const _),
-                (symbols::wasm::GAS_LIMIT, gas_limit as *const _),
-            ];
-
-            for (symbol, signature) in symbols_and_signatures {
-                engine.register_symbol(symbol, *signature as *mut ());
-            }
-        }
+        for (symbol, signature) in Self::wasm_symbols() {
+            unsafe { engine.register_symbol(symbol, signature as *mut ()) };
+        }
+    }
+
+    /// Returns all the syscalls with their addresses, which are resolved by the compiled EVM code.
+    pub(crate) fn evm_symbols() -> Vec<SymbolSignature> {
+        // Global variables and syscalls with corresponding function signatures
+        vec![
+            // Debug functions
+            (symbols::NOP, RuntimeContext::nop as *const _),
+            (symbols::TRACING, RuntimeContext::tracing as *const _),
+            // Syscalls
+            (
+                symbols::WRITE_RESULT,
+                RuntimeContext::write_result as *const _,
+            ),
+            (
+                symbols::CTX_IS_STATIC,
+                RuntimeContext::ctx_is_static as *const _,
+            ),
+            (symbols::EXP, RuntimeContext::exp as *const _),
+            (
+                symbols::KECCAK256_HASHER,
+                RuntimeContext::keccak256_hasher as *const _,
+            ),
+            (
+                symbols::EXTEND_MEMORY,
+                RuntimeContext::extend_memory as *const _,
+            ),
+            (symbols::MEMORY_PTR, RuntimeContext::memory_ptr as *const _),
+            (
+                symbols::MEMORY_SIZE,
+                RuntimeContext::memory_size as *const _,
+            ),
+            (symbols::SLOAD, RuntimeContext::sload as *const _),
+            (symbols::SSTORE, RuntimeContext::sstore as *const _),
+            (symbols::APPEND_LOG, RuntimeContext::append_log as *const _),
+            (
+                symbols::APPEND_LOG_ONE_TOPIC,
+                RuntimeContext::append_log_with_one_topic as *const _,
+            ),
+            (
+                symbols::APPEND_LOG_TWO_TOPICS,
+                RuntimeContext::append_log_with_two_topics as *const _,
+            ),
+            (
+                symbols::APPEND_LOG_THREE_TOPICS,
+                RuntimeContext::append_log_with_three_topics as *const _,
+            ),
+            (
+                symbols::APPEND_LOG_FOUR_TOPICS,
+                RuntimeContext::append_log_with_four_topics as *const _,
+            ),
+            (symbols::CALLDATA, RuntimeContext::calldata as *const _),
+            (
+                symbols::CALLDATA_SIZE,
+                RuntimeContext::calldata_size as *const _,
+            ),
+            (
+                symbols::CALLDATA_COPY,
+                RuntimeContext::calldata_copy as *const _,
+            ),
+            (symbols::DATA_LOAD, RuntimeContext::data_load as *const _),
+            (
+                symbols::DATA_SECTION,
+                RuntimeContext::data_section as *const _,
+            ),
+            (
+                symbols::DATA_SECTION_SIZE,
+                RuntimeContext::data_section_size as *const _,
+            ),
+            (
+                symbols::DATA_SECTION_COPY,
+                RuntimeContext::data_section_copy as *const _,
+            ),
+            (symbols::CODE_COPY, RuntimeContext::code_copy as *const _),
+            (symbols::ORIGIN, RuntimeContext::origin as *const _),
+            (symbols::ADDRESS, RuntimeContext::address as *const _),
+            (symbols::CALLVALUE, RuntimeContext::callvalue as *const _),
+            (
+                symbols::STORE_IN_BLOBBASEFEE_PTR,
+                RuntimeContext::store_in_blobbasefee_ptr as *const _,
+            ),
+            (
+                symbols::EXT_CODE_SIZE,
+                RuntimeContext::extcodesize as *const _,
+            ),
+            (symbols::COINBASE, RuntimeContext::coinbase as *const _),
+            (
+                symbols::STORE_IN_TIMESTAMP_PTR,
+                RuntimeContext::store_in_timestamp_ptr as *const _,
+            ),
+            (
+                symbols::STORE_IN_BASEFEE_PTR,
+                RuntimeContext::store_in_basefee_ptr as *const _,
+            ),
+            (symbols::CALLER, RuntimeContext::caller as *const _),
+            (
+                symbols::STORE_IN_GASLIMIT_PTR,
+                RuntimeContext::store_in_gaslimit_ptr as *const _,
+            ),
+            (
+                symbols::STORE_IN_GASPRICE_PTR,
+                RuntimeContext::store_in_gasprice_ptr as *const _,
+            ),
+            (
+                symbols::BLOCK_NUMBER,
+                RuntimeContext::block_number as *const _,
+            ),
+            (symbols::PREVRANDAO, RuntimeContext::prevrandao as *const _),
+            (symbols::BLOB_HASH, RuntimeContext::blob_hash as *const _),
+            (symbols::CHAINID, RuntimeContext::chainid as *const _),
+            (
+                symbols::STORE_IN_BALANCE,
+                RuntimeContext::store_in_balance as *const _,
+            ),
+            (
+                symbols::STORE_IN_SELFBALANCE_PTR,
+                RuntimeContext::store_in_selfbalance_ptr as *const _,
+            ),
+            (
+                symbols::EXT_CODE_COPY,
+                RuntimeContext::extcodecopy as *const _,
+            ),
+            (symbols::BLOCK_HASH, RuntimeContext::block_hash as *const _),
+            (
+                symbols::EXT_CODE_HASH,
+                RuntimeContext::extcodehash as *const _,
+            ),
+            (symbols::EOFCREATE, RuntimeContext::eofcreate as *const _),
+            (
+                symbols::RETURNCONTRACT,
+                RuntimeContext::returncontract as *const _,
+            ),
+            (symbols::CREATE, RuntimeContext::create as *const _),
+            (symbols::CREATE2, RuntimeContext::create2 as *const _),
+            (symbols::CALL, RuntimeContext::call as *const _),
+            (
+                symbols::EXTCALL_ADDR_VALIDATE,
+                RuntimeContext::extcall_addr_validate as *const _,
+            ),
+            (symbols::EXTCALL, RuntimeContext::extcall as *const _),
+            (symbols::RETURNDATA, RuntimeContext::returndata as *const _),
+            (
+                symbols::RETURNDATA_SIZE,
+                RuntimeContext::returndata_size as *const _,
+            ),
+            (
+                symbols::RETURNDATA_COPY,
+                RuntimeContext::returndata_copy as *const _,
+            ),
+            (
+                symbols::SELFDESTRUCT,
+                RuntimeContext::selfdestruct as *const _,
+            ),
+            (symbols::TLOAD, RuntimeContext::tload as *const _),
+            (symbols::TSTORE, RuntimeContext::tstore as *const _),
+            (
+                symbols::FUNC_STACK_PUSH,
+                RuntimeContext::func_stack_push as *const _,
+            ),
+            (
+                symbols::FUNC_STACK_POP,
+                RuntimeContext::func_stack_pop as *const _,
+            ),
+            (
+                symbols::FUNC_STACK_GROW,
+                RuntimeContext::func_stack_grow as *const _,
+            ),
+            (symbols::SET_RESUME, RuntimeContext::set_resume as *const _),
+            (symbols::GET_RESUME, RuntimeContext::get_resume as *const _),
+        ]
+    }
+
+    /// Returns all the WASM libcalls with their addresses, which are resolved by the compiled WASM code.
+    pub(crate) fn wasm_symbols() -> Vec<SymbolSignature> {
+        vec![
+            (
+                symbols::wasm::TABLE_INIT,
+                wasmer_vm::libcalls::wasmer_vm_table_init as *const _,
+            ),
+            (
+                symbols::wasm::TABLE_COPY,
+                wasmer_vm::libcalls::wasmer_vm_table_copy as *const _,
+            ),
+            (
+                symbols::wasm::TABLE_FILL,
+                wasmer_vm::libcalls::wasmer_vm_table_fill as *const _,
+            ),
+            (
+                symbols::wasm::TABLE_SIZE,
+                wasmer_vm::libcalls::wasmer_vm_table_size as *const _,
+            ),
+            (
+                symbols::wasm::TABLE_GET,
+                wasmer_vm::libcalls::wasmer_vm_table_get as *const _,
+            ),
+            (
+                symbols::wasm::TABLE_SET,
+                wasmer_vm::libcalls::wasmer_vm_table_set as *const _,
+            ),
+            (
+                symbols::wasm::TABLE_GROW,
+                wasmer_vm::libcalls::wasmer_vm_table_grow as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_TABLE_SIZE,
+                wasmer_vm::libcalls::wasmer_vm_imported_table_size as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_TABLE_GET,
+                wasmer_vm::libcalls::wasmer_vm_imported_table_get as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_TABLE_SET,
+                wasmer_vm::libcalls::wasmer_vm_imported_table_set as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_TABLE_GROW,
+                wasmer_vm::libcalls::wasmer_vm_imported_table_grow as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_INIT,
+                wasmer_vm::libcalls::wasmer_vm_memory32_init as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_SIZE,
+                wasmer_vm::libcalls::wasmer_vm_memory32_size as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_GROW,
+                wasmer_vm::libcalls::wasmer_vm_memory32_grow as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_COPY,
+                wasmer_vm::libcalls::wasmer_vm_memory32_copy as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_FILL,
+                wasmer_vm::libcalls::wasmer_vm_memory32_fill as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_NOTIFY,
+                wasmer_vm::libcalls::wasmer_vm_memory32_atomic_notify as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_WAIT32,
+                wasmer_vm::libcalls::wasmer_vm_memory32_atomic_wait32 as *const _,
+            ),
+            (
+                symbols::wasm::MEMORY_WAIT64,
+                wasmer_vm::libcalls::wasmer_vm_memory32_atomic_wait64 as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_SIZE,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_size as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_GROW,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_grow as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_COPY,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_copy as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_FILL,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_fill as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_NOTIFY,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_atomic_notify as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_WAIT32,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_atomic_wait32 as *const _,
+            ),
+            (
+                symbols::wasm::IMPORTED_MEMORY_WAIT64,
+                wasmer_vm::libcalls::wasmer_vm_imported_memory32_atomic_wait64 as *const _,
+            ),
+            (
+                symbols::wasm::FUNC_REF,
+                wasmer_vm::libcalls::wasmer_vm_func_ref as *const _,
+            ),
+            (
+                symbols::wasm::DATA_DROP,
+                wasmer_vm::libcalls::wasmer_vm_data_drop as *const _,
+            ),
+            (
+                symbols::wasm::ELEM_DROP,
+                wasmer_vm::libcalls::wasmer_vm_elem_drop as *const _,
+            ),
+            (symbols::wasm::RAISE_TRAP, wasm_raise_trap as *const _),
+            (symbols::wasm::GAS_LIMIT, gas_limit as *const _),
+        ]
     }
 }
@@ -1,11 +1,23 @@
+use crate::genesis::{Genesis, GenesisAccount, GenesisAlloc};
 pub use dora_primitives::StorageSlot;
 use dora_primitives::{
     Account, AccountInfo, AccountStatus, Address, B256, Bytecode, KECCAK_EMPTY, U256, keccak256,
 };
 pub use dora_primitives::{DBErrorMarker, Database, DatabaseCommit, DatabaseRef, HashMap};
-use std::{convert::Infallible, fmt::Debug};
+use std::{
+    convert::Infallible,
+    fmt::{Debug, Display},
+};
 use thiserror::Error;
 
+mod file;
+#[cfg(feature = "rpc")]
+mod rpc;
+
+pub use file::{DEFAULT_CHECKPOINT_THRESHOLD, FileDB, FileDBError};
+#[cfg(feature = "rpc")]
+pub use rpc::{RpcDB, RpcDBError};
+
 /// An error that occurs during database access operations.
 ///
 /// This error is typically encountered when there is a failure or inconsistency
@@ -16,12 +28,20 @@ use thiserror::Error;
 /// # Example:
 /// ```
 /// use dora_runtime::db::DatabaseError;
-/// let error = DatabaseError;
-/// assert_eq!("Error during database access", error.to_string());
+/// let error = DatabaseError::new("missing trie node");
+/// assert_eq!("Error during database access: missing trie node", error.to_string());
 /// ```
 #[derive(Error, Debug, Clone, Hash, PartialEq, Eq)]
-#[error("Error during database access")]
-pub struct DatabaseError;
+#[error("Error during database access: {0}")]
+pub struct DatabaseError(pub String);
+
+impl DatabaseError {
+    /// Creates a database error with the message of the original error.
+    #[inline]
+    pub fn new(error: impl Display) -> Self {
+        Self(error.to_string())
+    }
+}
 
 impl DBErrorMarker for DatabaseError {}
 
@@ -272,6 +292,124 @@ impl MemoryDB {
         account.storage = storage;
     }
 
+    /// Inserts the account, the account at the same address is replaced.
+    #[inline]
+    pub fn insert_account(&mut self, address: Address, account: DbAccount) {
+        self.accounts.insert(address, account);
+    }
+
+    /// Returns the contract code with the code hash.
+    #[inline]
+    pub fn contract(&self, code_hash: &B256) -> Option<&Bytecode> {
+        self.contracts.get(code_hash)
+    }
+
+    /// Inserts the contract code with the code hash.
+    #[inline]
+    pub fn insert_code(&mut self, code_hash: B256, bytecode: Bytecode) {
+        self.contracts.insert(code_hash, bytecode);
+    }
+
+    /// Returns the block hashes in the database.
+    #[inline]
+    pub fn block_hashes(&self) -> &HashMap<u64, B256> {
+        &self.block_hashes
+    }
+
+    /// Returns the accounts in the database.
+    #[inline]
+    pub fn accounts(&self) -> &HashMap<Address, DbAccount> {
+        &self.accounts
+    }
+
+    /// Creates a database from a geth-style genesis JSON, only the alloc of the genesis is
+    /// loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_check
+    /// let db = MemoryDB::from_genesis(&std::fs::read_to_string("genesis.json")?)?;
+    /// ```
+    pub fn from_genesis(json: &str) -> Result<Self, serde_json::Error> {
+        let genesis: Genesis = serde_json::from_str(json)?;
+        let mut db = Self::new();
+        db.insert_alloc(genesis.alloc);
+        Ok(db)
+    }
+
+    /// Creates a database from a geth-style alloc JSON, e.g., the output of [`MemoryDB::dump`].
+    pub fn from_alloc(json: &str) -> Result<Self, serde_json::Error> {
+        let alloc: GenesisAlloc = serde_json::from_str(json)?;
+        let mut db = Self::new();
+        db.insert_alloc(alloc);
+        Ok(db)
+    }
+
+    /// Inserts the genesis accounts, the accounts at the same addresses are replaced.
+    ///
+    /// The accounts without a status are inserted with the `Created` status like the accounts
+    /// inserted by [`MemoryDB::insert_contract`].
+    pub fn insert_alloc(&mut self, alloc: GenesisAlloc) {
+        for (address, account) in alloc {
+            let bytecode_hash = if account.code.is_empty() {
+                KECCAK_EMPTY
+            } else {
+                let bytecode = Bytecode::new_raw(account.code);
+                let hash = keccak256(bytecode.original_byte_slice());
+                self.contracts.insert(hash, bytecode);
+                hash
+            };
+            self.accounts.insert(
+                address,
+                DbAccount {
+                    nonce: account.nonce,
+                    balance: account.balance,
+                    storage: account.storage.into_iter().collect(),
+                    bytecode_hash,
+                    status: account.status.unwrap_or(AccountStatus::Created),
+                    account_state: AccountState::None,
+                },
+            );
+        }
+    }
+
+    /// Dumps the accounts in the geth-style alloc format with their status, which can be loaded
+    /// by [`MemoryDB::from_alloc`]. The block hashes are not dumped.
+    ///
+    /// # Example
+    ///
+    /// ```no_check
+    /// let json = serde_json::to_string_pretty(&db.dump())?;
+    /// ```
+    pub fn dump(&self) -> GenesisAlloc {
+        self.accounts
+            .iter()
+            .map(|(address, account)| {
+                let code = if account.bytecode_hash == KECCAK_EMPTY {
+                    Default::default()
+                } else {
+                    self.contracts
+                        .get(&account.bytecode_hash)
+                        .map(|bytecode| bytecode.original_bytes())
+                        .unwrap_or_default()
+                };
+                let account = GenesisAccount {
+                    balance: account.balance,
+                    nonce: account.nonce,
+                    code,
+                    storage: account
+                        .storage
+                        .iter()
+                        .filter(|(_, value)| !value.is_zero())
+                        .map(|(key, value)| (*key, *value))
+                        .collect(),
+                    status: Some(account.status),
+                };
+                (*address, account)
+            })
+            .collect()
+    }
+
     #[inline]
     pub fn into_state(self) -> HashMap<Address, Account> {
         self.accounts
@@ -314,6 +452,26 @@ impl Database for MemoryDB {
     }
 }
 
+impl DatabaseRef for MemoryDB {
+    type Error = Infallible;
+
+    fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        Ok(self.accounts.get(&address).cloned().map(AccountInfo::from))
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        Ok(self.contracts.get(&code_hash).cloned().unwrap_or_default())
+    }
+
+    fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        Ok(self.sload(address, index))
+    }
+
+    fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
+        Ok(self.block_hashes.get(&number).cloned().unwrap_or_default())
+    }
+}
+
 impl DatabaseCommit for MemoryDB {
     /// Commits a set of changes to the in-memory database.
     ///
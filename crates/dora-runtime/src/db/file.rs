@@ -0,0 +1,458 @@
+//! An embedded on-disk database which persists the state with atomic commits per block.
+//!
+//! The database directory contains:
+//!
+//! - `state.json`: the snapshot of the accounts, the storage and the block hashes.
+//! - `wal.log`: the write-ahead log of the blocks flushed after the snapshot.
+//! - `code/`: the contract codes named by their hashes.
+//!
+//! The accounts and the storage are kept in memory, thus the whole state must fit in memory, and
+//! the codes are read from the files on demand. The changes committed by the transactions are
+//! pending until [`FileDB::flush_block`] appends them as one checksummed log record, a record
+//! which is partially written by a crash is discarded on [`FileDB::open`], thus a block is
+//! either completely replayed or not at all. Once the log passes the checkpoint threshold, the
+//! state is written into a new snapshot and the log is truncated, which bounds the replay.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use dora_primitives::{
+    Account, AccountInfo, AccountStatus, Address, B256, Bytecode, DBErrorMarker, Database,
+    DatabaseCommit, HashMap, HashSet, KECCAK_EMPTY, U256, keccak256,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{AccountState, DbAccount, MemoryDB};
+use crate::genesis::GenesisAlloc;
+
+const SNAPSHOT_FILE: &str = "state.json";
+const WAL_FILE: &str = "wal.log";
+const CODE_DIR: &str = "code";
+/// The length prefix and the checksum of a log record.
+const RECORD_HEADER_LEN: usize = 4 + 32;
+/// The default size of the write-ahead log in bytes which triggers a checkpoint.
+pub const DEFAULT_CHECKPOINT_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+/// An error that occurs when the [`FileDB`] reads or writes its files.
+#[derive(Error, Debug)]
+pub enum FileDBError {
+    #[error("file database I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("file database encoding error: {0}")]
+    Encoding(#[from] serde_json::Error),
+    #[error("the code {0} is missing in the file database")]
+    MissingCode(B256),
+    #[error("the file database has pending changes which are not flushed")]
+    PendingChanges,
+}
+
+impl DBErrorMarker for FileDBError {}
+
+/// The persisted state of an account, the storage slots of a log record are only the changed
+/// ones unless the storage is cleared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountRecord {
+    nonce: u64,
+    balance: U256,
+    code_hash: B256,
+    status: u8,
+    #[serde(default)]
+    storage_cleared: bool,
+    storage: BTreeMap<U256, U256>,
+}
+
+/// A block flushed to the write-ahead log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockRecord {
+    sequence: u64,
+    block_hashes: BTreeMap<u64, B256>,
+    accounts: BTreeMap<Address, AccountRecord>,
+}
+
+/// The snapshot of the state after the log record with the sequence.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Snapshot {
+    sequence: u64,
+    block_hashes: BTreeMap<u64, B256>,
+    accounts: BTreeMap<Address, AccountRecord>,
+}
+
+/// The accounts changed since the last flush.
+#[derive(Debug, Default)]
+struct PendingAccount {
+    storage_cleared: bool,
+    keys: HashSet<U256>,
+}
+
+/// A file-backed database for long-running local devnets and replay jobs.
+///
+/// The transactions commit into the database through [`DatabaseCommit`] like the
+/// [`MemoryDB`], and the block is persisted by [`FileDB::flush_block`] once it is fully
+/// executed. The changes which are not flushed are lost on a crash and can be dropped by
+/// [`FileDB::rollback`], e.g., when the block is invalid.
+///
+/// The whole state is kept in memory and only the codes are loaded lazily, thus the database is
+/// not suitable for the states larger than the available memory, e.g., the mainnet state.
+///
+/// # Example
+///
+/// ```no_run
+/// use dora_primitives::B256;
+/// use dora_runtime::db::FileDB;
+/// let mut db = FileDB::open("devnet").unwrap();
+/// // Execute the block with the database and commit the transactions.
+/// db.flush_block(1, B256::ZERO).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct FileDB {
+    path: PathBuf,
+    /// The state in memory, the contracts of it are the cache of the code files.
+    state: MemoryDB,
+    sequence: u64,
+    pending: HashMap<Address, PendingAccount>,
+    pending_block_hashes: BTreeMap<u64, B256>,
+    wal: File,
+    /// The size of the write-ahead log in bytes which triggers a checkpoint.
+    checkpoint_threshold: u64,
+}
+
+impl FileDB {
+    /// Opens the database at the directory or creates an empty one if it does not exist, the
+    /// snapshot is loaded and the log records after it are replayed.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, FileDBError> {
+        let path = path.as_ref().to_path_buf();
+        fs::create_dir_all(path.join(CODE_DIR))?;
+        let snapshot = match fs::read(path.join(SNAPSHOT_FILE)) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Snapshot::default(),
+            Err(err) => return Err(err.into()),
+        };
+        let mut db = Self {
+            state: MemoryDB::new(),
+            sequence: snapshot.sequence,
+            pending: Default::default(),
+            pending_block_hashes: Default::default(),
+            wal: OpenOptions::new()
+                .create(true)
+                .read(true)
+                .append(true)
+                .open(path.join(WAL_FILE))?,
+            path,
+            checkpoint_threshold: DEFAULT_CHECKPOINT_THRESHOLD,
+        };
+        for (number, hash) in snapshot.block_hashes {
+            db.state.insert_block_hash(number, hash);
+        }
+        for (address, account) in snapshot.accounts {
+            db.apply_account(address, account);
+        }
+        db.replay()?;
+        Ok(db)
+    }
+
+    /// Sets the size of the write-ahead log in bytes after which [`FileDB::flush_block`] writes
+    /// a checkpoint, `u64::MAX` disables the automatic checkpoints.
+    pub fn with_checkpoint_threshold(mut self, checkpoint_threshold: u64) -> Self {
+        self.checkpoint_threshold = checkpoint_threshold;
+        self
+    }
+
+    /// Returns the state in memory including the pending changes.
+    #[inline]
+    pub fn state(&self) -> &MemoryDB {
+        &self.state
+    }
+
+    /// Returns `true` if there are changes which are not flushed.
+    #[inline]
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty() || !self.pending_block_hashes.is_empty()
+    }
+
+    /// Inserts the genesis accounts as pending changes, which are persisted by the next flush.
+    pub fn insert_alloc(&mut self, alloc: GenesisAlloc) {
+        for address in alloc.keys() {
+            self.pending.entry(*address).or_default().storage_cleared = true;
+        }
+        self.state.insert_alloc(alloc);
+    }
+
+    /// Inserts a block hash as a pending change, which is persisted by the next flush.
+    pub fn insert_block_hash(&mut self, number: u64, hash: B256) {
+        self.state.insert_block_hash(number, hash);
+        self.pending_block_hashes.insert(number, hash);
+    }
+
+    /// Persists the pending changes and the hash of the executed block atomically.
+    ///
+    /// The new codes are written before the log record, thus the record never refers to a
+    /// missing code. When the log passes the checkpoint threshold, a checkpoint is written after
+    /// the record, and the block is persisted even if the checkpoint fails.
+    pub fn flush_block(&mut self, number: u64, hash: B256) -> Result<(), FileDBError> {
+        self.insert_block_hash(number, hash);
+        let mut accounts = BTreeMap::new();
+        for (address, pending) in &self.pending {
+            let Some(account) = self.state.accounts().get(address) else {
+                continue;
+            };
+            self.write_code(account.bytecode_hash)?;
+            let storage = if pending.storage_cleared {
+                account.storage.iter().map(|(k, v)| (*k, *v)).collect()
+            } else {
+                pending
+                    .keys
+                    .iter()
+                    .map(|key| (*key, account.storage.get(key).copied().unwrap_or_default()))
+                    .collect()
+            };
+            accounts.insert(
+                *address,
+                AccountRecord {
+                    nonce: account.nonce,
+                    balance: account.balance,
+                    code_hash: account.bytecode_hash,
+                    status: account.status.bits(),
+                    storage_cleared: pending.storage_cleared,
+                    storage,
+                },
+            );
+        }
+        let record = BlockRecord {
+            sequence: self.sequence + 1,
+            block_hashes: self.pending_block_hashes.clone(),
+            accounts,
+        };
+        let body = serde_json::to_vec(&record)?;
+        let mut bytes = Vec::with_capacity(RECORD_HEADER_LEN + body.len());
+        bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(keccak256(&body).as_slice());
+        bytes.extend_from_slice(&body);
+        let len = self.wal.metadata()?.len();
+        if let Err(err) = self
+            .wal
+            .write_all(&bytes)
+            .and_then(|_| self.wal.sync_data())
+        {
+            // Truncate the partially written record, otherwise the later records are appended
+            // after it and discarded by the replay.
+            self.wal.set_len(len)?;
+            return Err(err.into());
+        }
+        self.sequence = record.sequence;
+        self.pending.clear();
+        self.pending_block_hashes.clear();
+        if len + bytes.len() as u64 >= self.checkpoint_threshold {
+            self.checkpoint()?;
+        }
+        Ok(())
+    }
+
+    /// Drops the pending changes by reloading the persisted state, the log records after the
+    /// last checkpoint are replayed.
+    pub fn rollback(&mut self) -> Result<(), FileDBError> {
+        *self = Self::open(&self.path)?.with_checkpoint_threshold(self.checkpoint_threshold);
+        Ok(())
+    }
+
+    /// Writes the state into a new snapshot and truncates the write-ahead log, the pending
+    /// changes must be flushed before.
+    ///
+    /// The snapshot is written into a temporary file and renamed, and the log records which
+    /// are already in the snapshot are skipped on the replay, thus a crash at any point keeps
+    /// the database consistent.
+    pub fn checkpoint(&mut self) -> Result<(), FileDBError> {
+        if self.has_pending() {
+            return Err(FileDBError::PendingChanges);
+        }
+        let snapshot = Snapshot {
+            sequence: self.sequence,
+            block_hashes: self
+                .state
+                .block_hashes()
+                .iter()
+                .map(|(k, v)| (*k, *v))
+                .collect(),
+            accounts: self
+                .state
+                .accounts()
+                .iter()
+                .map(|(address, account)| {
+                    let record = AccountRecord {
+                        nonce: account.nonce,
+                        balance: account.balance,
+                        code_hash: account.bytecode_hash,
+                        status: account.status.bits(),
+                        storage_cleared: true,
+                        storage: account.storage.iter().map(|(k, v)| (*k, *v)).collect(),
+                    };
+                    (*address, record)
+                })
+                .collect(),
+        };
+        write_atomic(&self.path, SNAPSHOT_FILE, &serde_json::to_vec(&snapshot)?)?;
+        self.wal.set_len(0)?;
+        self.wal.sync_all()?;
+        Ok(())
+    }
+
+    /// Replays the log records after the snapshot, the incomplete or corrupted tail written by
+    /// a crash is truncated.
+    fn replay(&mut self) -> Result<(), FileDBError> {
+        let mut bytes = Vec::new();
+        (&self.wal).read_to_end(&mut bytes)?;
+        let mut offset = 0;
+        while let Some(record) = decode_record(&bytes[offset..]) {
+            let (len, record) = record?;
+            offset += len;
+            if record.sequence <= self.sequence {
+                continue;
+            }
+            for (number, hash) in record.block_hashes {
+                self.state.insert_block_hash(number, hash);
+            }
+            for (address, account) in record.accounts {
+                self.apply_account(address, account);
+            }
+            self.sequence = record.sequence;
+        }
+        if offset < bytes.len() {
+            self.wal.set_len(offset as u64)?;
+            self.wal.sync_all()?;
+        }
+        Ok(())
+    }
+
+    fn apply_account(&mut self, address: Address, record: AccountRecord) {
+        let mut account = DbAccount {
+            nonce: record.nonce,
+            balance: record.balance,
+            bytecode_hash: record.code_hash,
+            status: AccountStatus::from_bits_truncate(record.status),
+            account_state: AccountState::Touched,
+            ..Default::default()
+        };
+        if record.storage_cleared {
+            account.account_state = AccountState::StorageCleared;
+        } else if let Some(current) = self.state.accounts().get(&address) {
+            account.storage = current.storage.clone();
+        }
+        account.storage.extend(record.storage);
+        self.state.insert_account(address, account);
+    }
+
+    /// Writes the code file if it does not exist, the code is in the cache since it is
+    /// committed after the last flush.
+    fn write_code(&self, code_hash: B256) -> Result<(), FileDBError> {
+        let name = hex::encode(code_hash);
+        if code_hash == KECCAK_EMPTY || self.path.join(CODE_DIR).join(&name).exists() {
+            return Ok(());
+        }
+        let code = self
+            .state
+            .contract(&code_hash)
+            .ok_or(FileDBError::MissingCode(code_hash))?;
+        write_atomic(&self.path.join(CODE_DIR), &name, code.original_byte_slice())?;
+        Ok(())
+    }
+}
+
+impl Database for FileDB {
+    type Error = FileDBError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        Ok(self
+            .state
+            .accounts()
+            .get(&address)
+            .cloned()
+            .map(AccountInfo::from))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        if code_hash == KECCAK_EMPTY {
+            return Ok(Bytecode::default());
+        }
+        if let Some(code) = self.state.contract(&code_hash) {
+            return Ok(code.clone());
+        }
+        let path = self.path.join(CODE_DIR).join(hex::encode(code_hash));
+        let code = match fs::read(path) {
+            Ok(bytes) => Bytecode::new_raw(bytes.into()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(FileDBError::MissingCode(code_hash));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        self.state.insert_code(code_hash, code.clone());
+        Ok(code)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        Ok(self.state.sload(address, index))
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        Ok(self
+            .state
+            .block_hashes()
+            .get(&number)
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+impl DatabaseCommit for FileDB {
+    /// Commits the changes into the state in memory, they are persisted by the next
+    /// [`FileDB::flush_block`].
+    fn commit(&mut self, changes: HashMap<Address, Account>) {
+        for (address, account) in &changes {
+            if !account.is_touched() {
+                continue;
+            }
+            let pending = self.pending.entry(*address).or_default();
+            if account.is_selfdestructed() || account.is_created() {
+                pending.storage_cleared = true;
+                pending.keys.clear();
+            }
+            pending.keys.extend(account.storage.keys().copied());
+        }
+        self.state.commit(changes);
+    }
+}
+
+/// Decodes the log record at the start of the bytes and returns its length, `None` is returned
+/// if the record is incomplete or its checksum does not match.
+fn decode_record(bytes: &[u8]) -> Option<Result<(usize, BlockRecord), FileDBError>> {
+    let len = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?) as usize;
+    let checksum = bytes.get(4..RECORD_HEADER_LEN)?;
+    let body = bytes.get(RECORD_HEADER_LEN..RECORD_HEADER_LEN + len)?;
+    if keccak256(body).as_slice() != checksum {
+        return None;
+    }
+    Some(
+        serde_json::from_slice(body)
+            .map(|record| (RECORD_HEADER_LEN + len, record))
+            .map_err(Into::into),
+    )
+}
+
+/// Writes the file through a temporary file and a rename, thus the file is either the old or
+/// the new one after a crash.
+fn write_atomic(dir: &Path, name: &str, bytes: &[u8]) -> io::Result<()> {
+    let tmp = dir.join(format!("{name}.tmp"));
+    let mut file = File::create(&tmp)?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    fs::rename(&tmp, dir.join(name))?;
+    #[cfg(unix)]
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
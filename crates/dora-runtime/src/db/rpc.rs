@@ -0,0 +1,391 @@
+//! A lazy database which fetches the state at a pinned block from an Ethereum JSON-RPC endpoint,
+//! which is used to execute the transactions on top of a remote chain, e.g., to replay the
+//! historical blocks without the pre-dumped state.
+//!
+//! The fetched accounts, codes, storage slots and block hashes are kept in memory and optionally
+//! in a cache file `<cache dir>/<block number>.json`, thus the reruns against the same block are
+//! served from the cache without the endpoint. The changes committed by the transactions are
+//! kept in memory on top of the remote state and never written into the cache.
+//!
+//! The database is only available with the `rpc` feature, which pulls in the HTTP client.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use dora_primitives::{
+    Account, AccountInfo, AccountStatus, Address, B256, Bytecode, Bytes, DBErrorMarker, Database,
+    DatabaseCommit, HashMap, KECCAK_EMPTY, U256, keccak256,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use thiserror::Error;
+
+use super::{DbAccount, MemoryDB};
+
+/// An error that occurs when the [`RpcDB`] fetches the state or accesses its cache.
+#[derive(Error, Debug)]
+pub enum RpcDBError {
+    #[error("rpc database transport error: {0}")]
+    Transport(String),
+    #[error("rpc error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("rpc database encoding error: {0}")]
+    Encoding(#[from] serde_json::Error),
+    #[error("rpc database I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("the code {0} is missing in the rpc database")]
+    MissingCode(B256),
+    #[error("the block {0} is not found by the rpc endpoint")]
+    MissingBlock(u64),
+}
+
+impl DBErrorMarker for RpcDBError {}
+
+/// A remote account at the pinned block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedAccount {
+    nonce: u64,
+    balance: U256,
+    code_hash: B256,
+}
+
+/// The remote state fetched at the pinned block.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcCache {
+    accounts: BTreeMap<Address, CachedAccount>,
+    codes: BTreeMap<B256, Bytes>,
+    storage: BTreeMap<Address, BTreeMap<U256, U256>>,
+    block_hashes: BTreeMap<u64, B256>,
+}
+
+/// A JSON-RPC response, the result is absent when the request fails.
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    id: u64,
+    #[serde(default)]
+    result: Value,
+    error: Option<RpcErrorObject>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcErrorObject {
+    code: i64,
+    message: String,
+}
+
+/// A database which lazily fetches the state at the block from a JSON-RPC endpoint.
+///
+/// # Example Usage:
+/// ```no_run
+/// use dora_runtime::db::RpcDB;
+/// let db = RpcDB::new("http://localhost:8545", 20_000_000)
+///     .with_cache_dir("rpc-cache")
+///     .unwrap();
+/// ```
+pub struct RpcDB {
+    url: String,
+    agent: ureq::Agent,
+    block: u64,
+    cache_path: Option<PathBuf>,
+    cache: RpcCache,
+    /// Whether the cache contains the state which is not written into the cache file.
+    dirty: bool,
+    /// The loaded remote state with the committed changes on top.
+    state: MemoryDB,
+    next_id: u64,
+}
+
+impl RpcDB {
+    /// Creates a database which reads the state after the block from the endpoint.
+    pub fn new(url: impl Into<String>, block: u64) -> Self {
+        Self {
+            url: url.into(),
+            agent: ureq::Agent::new_with_defaults(),
+            block,
+            cache_path: None,
+            cache: RpcCache::default(),
+            dirty: false,
+            state: MemoryDB::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Caches the fetched state in the directory, the state cached by the previous runs against
+    /// the same block is loaded. The directory should not be shared by different chains.
+    pub fn with_cache_dir(mut self, dir: impl AsRef<Path>) -> Result<Self, RpcDBError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.json", self.block));
+        if path.exists() {
+            self.cache = serde_json::from_slice(&fs::read(&path)?)?;
+        }
+        self.cache_path = Some(path);
+        Ok(self)
+    }
+
+    /// Returns the block number which the remote state is read at.
+    #[inline]
+    pub fn block_number(&self) -> u64 {
+        self.block
+    }
+
+    /// Returns the loaded state with the committed changes.
+    #[inline]
+    pub fn state(&self) -> &MemoryDB {
+        &self.state
+    }
+
+    /// Writes the fetched state into the cache file, the file is replaced atomically.
+    pub fn flush_cache(&mut self) -> Result<(), RpcDBError> {
+        let Some(path) = &self.cache_path else {
+            return Ok(());
+        };
+        if !self.dirty {
+            return Ok(());
+        }
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec(&self.cache)?)?;
+        fs::rename(&tmp, path)?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Sends a JSON-RPC request to the endpoint and decodes the result.
+    pub fn request<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Value,
+    ) -> Result<T, RpcDBError> {
+        let result = self.batch(vec![(method, params)])?.remove(0);
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Sends the JSON-RPC requests as one batch and returns the results in the same order.
+    fn batch(&mut self, calls: Vec<(&str, Value)>) -> Result<Vec<Value>, RpcDBError> {
+        let first_id = self.next_id;
+        let requests: Vec<Value> = calls
+            .into_iter()
+            .map(|(method, params)| {
+                let id = self.next_id;
+                self.next_id += 1;
+                json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+            })
+            .collect();
+        let len = requests.len();
+        let body = self
+            .agent
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .send(serde_json::to_string(&requests)?)
+            .map_err(|err| RpcDBError::Transport(err.to_string()))?
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| RpcDBError::Transport(err.to_string()))?;
+        let mut responses: Vec<RpcResponse> = serde_json::from_str(&body)?;
+        if responses.len() != len {
+            return Err(RpcDBError::Transport(format!(
+                "expected {len} responses, got {}",
+                responses.len()
+            )));
+        }
+        // The responses of a batch may be in any order.
+        responses.sort_by_key(|response| response.id);
+        responses
+            .into_iter()
+            .enumerate()
+            .map(|(i, response)| {
+                if let Some(error) = response.error {
+                    return Err(RpcDBError::Rpc {
+                        code: error.code,
+                        message: error.message,
+                    });
+                }
+                if response.id != first_id + i as u64 {
+                    return Err(RpcDBError::Transport(format!(
+                        "unexpected response id {}",
+                        response.id
+                    )));
+                }
+                Ok(response.result)
+            })
+            .collect()
+    }
+
+    /// Returns the block tag of the pinned block.
+    #[inline]
+    fn block_tag(&self) -> String {
+        format!("{:#x}", self.block)
+    }
+
+    /// Returns the remote account and stores its code, the account is fetched unless it is
+    /// cached.
+    fn remote_account(&mut self, address: Address) -> Result<CachedAccount, RpcDBError> {
+        if let Some(account) = self.cache.accounts.get(&address) {
+            return Ok(account.clone());
+        }
+        let tag = self.block_tag();
+        let results = self.batch(vec![
+            ("eth_getBalance", json!([address, tag])),
+            ("eth_getTransactionCount", json!([address, tag])),
+            ("eth_getCode", json!([address, tag])),
+        ])?;
+        let mut results = results.into_iter();
+        let balance: U256 = serde_json::from_value(results.next().unwrap_or_default())?;
+        let nonce: U256 = serde_json::from_value(results.next().unwrap_or_default())?;
+        let code: Bytes = serde_json::from_value(results.next().unwrap_or_default())?;
+        let code_hash = if code.is_empty() {
+            KECCAK_EMPTY
+        } else {
+            let code_hash = keccak256(&code);
+            self.cache.codes.insert(code_hash, code);
+            code_hash
+        };
+        let account = CachedAccount {
+            nonce: nonce.saturating_to(),
+            balance,
+            code_hash,
+        };
+        self.cache.accounts.insert(address, account.clone());
+        self.dirty = true;
+        Ok(account)
+    }
+
+    /// Returns the remote storage slot, the slot is fetched unless it is cached.
+    fn remote_storage(&mut self, address: Address, index: U256) -> Result<U256, RpcDBError> {
+        if let Some(value) = self
+            .cache
+            .storage
+            .get(&address)
+            .and_then(|storage| storage.get(&index))
+        {
+            return Ok(*value);
+        }
+        let tag = self.block_tag();
+        let value: U256 = self.request("eth_getStorageAt", json!([address, index, tag]))?;
+        self.cache
+            .storage
+            .entry(address)
+            .or_default()
+            .insert(index, value);
+        self.dirty = true;
+        Ok(value)
+    }
+
+    /// Returns the remote block hash, the hash is fetched unless it is cached.
+    fn remote_block_hash(&mut self, number: u64) -> Result<B256, RpcDBError> {
+        if let Some(hash) = self.cache.block_hashes.get(&number) {
+            return Ok(*hash);
+        }
+        #[derive(Deserialize)]
+        struct BlockHeader {
+            hash: B256,
+        }
+        let header: Option<BlockHeader> = self.request(
+            "eth_getBlockByNumber",
+            json!([format!("{number:#x}"), false]),
+        )?;
+        let hash = header.ok_or(RpcDBError::MissingBlock(number))?.hash;
+        self.cache.block_hashes.insert(number, hash);
+        self.dirty = true;
+        Ok(hash)
+    }
+}
+
+impl Database for RpcDB {
+    type Error = RpcDBError;
+
+    /// Returns `None` for the empty remote accounts, since the endpoint can't tell the missing
+    /// accounts from the empty ones, which are the same after EIP-161.
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        if let Some(account) = self.state.accounts().get(&address) {
+            // The missing accounts are kept as not existing to skip the refetch.
+            if account.status.contains(AccountStatus::LoadedAsNotExisting)
+                && account.nonce == 0
+                && account.balance.is_zero()
+                && account.bytecode_hash == KECCAK_EMPTY
+            {
+                return Ok(None);
+            }
+            return Ok(Some(account.clone().into()));
+        }
+        let account = self.remote_account(address)?;
+        if account.nonce == 0 && account.balance.is_zero() && account.code_hash == KECCAK_EMPTY {
+            let account = DbAccount {
+                bytecode_hash: KECCAK_EMPTY,
+                status: AccountStatus::LoadedAsNotExisting,
+                ..Default::default()
+            };
+            self.state.insert_account(address, account);
+            return Ok(None);
+        }
+        if let Some(code) = self.cache.codes.get(&account.code_hash) {
+            self.state
+                .insert_code(account.code_hash, Bytecode::new_raw(code.clone()));
+        }
+        let account = DbAccount {
+            nonce: account.nonce,
+            balance: account.balance,
+            bytecode_hash: account.code_hash,
+            ..Default::default()
+        };
+        self.state.insert_account(address, account.clone());
+        Ok(Some(account.into()))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        if code_hash == KECCAK_EMPTY {
+            return Ok(Bytecode::default());
+        }
+        // The codes are fetched along with the accounts.
+        self.state
+            .contract(&code_hash)
+            .cloned()
+            .ok_or(RpcDBError::MissingCode(code_hash))
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        if !self.state.accounts().contains_key(&address) {
+            self.basic(address)?;
+        }
+        let account = &self.state.accounts()[&address];
+        if let Some(value) = account.storage.get(&index) {
+            return Ok(*value);
+        }
+        // The storage of the created and destroyed accounts is not on the remote chain.
+        if account.account_state.is_storage_cleared()
+            || account.status.contains(AccountStatus::LoadedAsNotExisting)
+        {
+            return Ok(U256::ZERO);
+        }
+        let value = self.remote_storage(address, index)?;
+        self.state.sstore(address, index, value);
+        Ok(value)
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        if let Some(hash) = self.state.block_hashes().get(&number) {
+            return Ok(*hash);
+        }
+        let hash = self.remote_block_hash(number)?;
+        self.state.insert_block_hash(number, hash);
+        Ok(hash)
+    }
+}
+
+impl DatabaseCommit for RpcDB {
+    fn commit(&mut self, changes: HashMap<Address, Account>) {
+        self.state.commit(changes);
+    }
+}
+
+impl Drop for RpcDB {
+    fn drop(&mut self) {
+        // The cache is only an optimization, thus the failure is ignored.
+        let _ = self.flush_cache();
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::arc_with_non_send_sync)]
 
+use crate::aot::NativeLibrary;
 use crate::constants::ENTRYPOINT;
 use crate::context::{EVMEntryFunc, RuntimeContext, WASMEntryFunc};
 use crate::wasm::WASMInstance;
@@ -8,10 +9,12 @@ use melior::StringRef;
 use melior::ir::Module;
 use mlir_sys::{
     MlirExecutionEngine, mlirExecutionEngineCreate, mlirExecutionEngineDestroy,
-    mlirExecutionEngineLookup, mlirExecutionEngineRegisterSymbol,
+    mlirExecutionEngineDumpToObjectFile, mlirExecutionEngineLookup,
+    mlirExecutionEngineRegisterSymbol,
 };
 use parking_lot::RwLock;
 use std::fmt::Debug;
+use std::path::Path;
 use std::sync::Arc;
 
 /// The stack size at runtime, used for recursive program execution to prevent stack overflow
@@ -25,6 +28,8 @@ pub const RUNTIME_STACK_SIZE: usize = 128 * 1024 * 1024;
 #[derive(Default, Debug, Clone)]
 pub struct Executor {
     engine: ExecutionEngine,
+    /// The native library loaded from the AOT cache, which is used instead of the engine when present.
+    library: Option<Arc<NativeLibrary>>,
     pub(crate) kind: ExecuteKind,
 }
 
@@ -65,12 +70,44 @@ impl Executor {
     /// let executor = Executor::new(&module, &runtime_ctx, OptimizationLevel::Aggressive);
     /// ```
     pub fn new(module: &Module, opt_level: OptimizationLevel, kind: ExecuteKind) -> Self {
-        let engine = ExecutionEngine::new(module, opt_level as usize, &[], false);
+        Self::new_with_object_dump(module, opt_level, kind, false)
+    }
+
+    /// Creates a new `Executor` instance like [`Executor::new`], the native object code can be dumped
+    /// with [`Executor::dump_to_object_file`] when `enable_object_dump` is set.
+    pub fn new_with_object_dump(
+        module: &Module,
+        opt_level: OptimizationLevel,
+        kind: ExecuteKind,
+        enable_object_dump: bool,
+    ) -> Self {
+        let engine = ExecutionEngine::new(module, opt_level as usize, &[], enable_object_dump);
         match kind {
             ExecuteKind::EVM => RuntimeContext::register_evm_symbols(&engine),
             ExecuteKind::WASM(_) => RuntimeContext::register_wasm_symbols(&engine),
         }
-        Self { engine, kind }
+        Self {
+            engine,
+            library: None,
+            kind,
+        }
+    }
+
+    /// Creates a new `Executor` instance from a native library loaded from the AOT cache.
+    pub(crate) fn new_with_library(library: NativeLibrary, kind: ExecuteKind) -> Self {
+        Self {
+            engine: ExecutionEngine::default(),
+            library: Some(Arc::new(library)),
+            kind,
+        }
+    }
+
+    /// Dumps the native object code of the compiled module to the file, the executor must be
+    /// created with the object dump enabled.
+    pub fn dump_to_object_file(&self, path: &Path) {
+        // The compilation of the engine is lazy, look up the entrypoint to force it.
+        self.get_entrypoint_ptr();
+        self.engine.dump_to_object_file(&path.to_string_lossy());
     }
     /// Retrieves the EVM main entry point function from the execution engine.
     ///
@@ -120,7 +157,10 @@ impl Executor {
     /// Searches a symbol in a module and returns a pointer to it.
     #[inline]
     pub fn lookup(&self, name: &str) -> *mut () {
-        self.engine.lookup(name)
+        match &self.library {
+            Some(library) => library.lookup(name),
+            None => self.engine.lookup(name),
+        }
     }
 }
 
@@ -188,6 +228,13 @@ impl ExecutionEngine {
         unsafe { mlirExecutionEngineLookup(*self.raw, StringRef::new(name).to_raw()) as *mut () }
     }
 
+    /// Dumps the object code to the file, which requires the object dump to be enabled when
+    /// the engine is created.
+    #[inline]
+    pub fn dump_to_object_file(&self, path: &str) {
+        unsafe { mlirExecutionEngineDumpToObjectFile(*self.raw, StringRef::new(path).to_raw()) }
+    }
+
     /// Register a symbol. This symbol will be accessible to the JIT'd codes.
     ///
     /// # Safety
@@ -205,7 +252,7 @@ impl ExecutionEngine {
 
 impl Drop for ExecutionEngine {
     fn drop(&mut self) {
-        if Arc::strong_count(&self.raw) == 1 {
+        if Arc::strong_count(&self.raw) == 1 && !self.raw.ptr.is_null() {
             unsafe { mlirExecutionEngineDestroy(*self.raw) }
         }
     }
@@ -2,58 +2,114 @@
 
 use crate::constants::gas_cost::{
     ACCESS_LIST_ADDRESS, ACCESS_LIST_STORAGE_KEY, CALL_STIPEND, CALLVALUE,
-    COLD_ACCOUNT_ACCESS_COST, COLD_SLOAD_COST, INITCODE_WORD_COST, INSTANBUL_SLOAD_GAS, NEWACCOUNT,
-    NON_ZERO_BYTE_MULTIPLIER, NON_ZERO_BYTE_MULTIPLIER_ISTANBUL, REFUND_SSTORE_CLEARS,
-    SSTORE_RESET, SSTORE_SET, STANDARD_TOKEN_COST, TOTAL_COST_FLOOR_PER_TOKEN, WARM_SLOAD_COST,
-    WARM_SSTORE_RESET,
+    COLD_ACCOUNT_ACCESS_COST, COLD_SLOAD_COST, INITCODE_WORD_COST, INSTANBUL_SLOAD_GAS,
+    LARGE_CONTRACT_WORD_COST, LOG, LOGDATA, LOGTOPIC, MAX_CODE_SIZE, NEWACCOUNT,
+    NON_ZERO_BYTE_DATA_COST, NON_ZERO_BYTE_DATA_COST_ISTANBUL, NON_ZERO_BYTE_MULTIPLIER,
+    NON_ZERO_BYTE_MULTIPLIER_ISTANBUL, OSAKA_MAX_CODE_SIZE, REFUND_SSTORE_CLEARS, SSTORE_RESET,
+    SSTORE_SET, TOTAL_COST_FLOOR_PER_TOKEN, TRANSACTION_ZERO_DATA, WARM_SLOAD_COST,
 };
 use crate::host::{AccountLoad, SStoreResult, SStoreStatus, SelfDestructResult, StateLoad};
 use dora_primitives::eip7702::PER_EMPTY_ACCOUNT_COST;
 use dora_primitives::spec::SpecId;
 use dora_primitives::{AccessListItem, U256};
 
-#[inline]
-pub fn sstore_cost(spec_id: SpecId, result: &SStoreResult, gas: u64, is_cold: bool) -> Option<u64> {
-    // EIP-1706: Disable `SSTORE` if `gasleft` is less than call stipend.
-    if spec_id.is_enabled_in(SpecId::ISTANBUL) && gas <= CALL_STIPEND {
-        return None;
+/// The repriceable gas costs of a chain, the static costs are baked into the generated code by the
+/// compiler and the dynamic costs are charged by the runtime. The costs not in the schedule always
+/// follow the Ethereum rules of the spec.
+///
+/// # Example
+///
+/// ```no_check
+/// let schedule = GasSchedule {
+///     sstore_set: 40_000,
+///     ..GasSchedule::new(SpecId::CANCUN)
+/// };
+/// let ctx = VMContext::new(db, env, compile_handler()).with_gas_schedule(schedule);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasSchedule {
+    /// The cost of `SSTORE` setting a clean zero slot to a nonzero value.
+    pub sstore_set: u64,
+    /// The cost of `SSTORE` changing a clean nonzero slot, the cold slot access cost is
+    /// deducted from it since Berlin (EIP-2929).
+    pub sstore_reset: u64,
+    /// The refund of `SSTORE` clearing a slot before London, it is derived from
+    /// `sstore_reset` since London (EIP-3529).
+    pub sstore_clears_refund: i64,
+    /// The static cost of the `LOG` opcodes.
+    pub log: u64,
+    /// The static cost of each topic of the `LOG` opcodes.
+    pub log_topic: u64,
+    /// The cost of each byte of the `LOG` data.
+    pub log_data: u64,
+    /// The cost of each zero byte of the transaction data.
+    pub tx_data_zero: u64,
+    /// The cost of each nonzero byte of the transaction data.
+    pub tx_data_non_zero: u64,
+}
+
+impl GasSchedule {
+    /// Returns the Ethereum gas schedule of the spec.
+    pub const fn new(spec_id: SpecId) -> Self {
+        Self {
+            sstore_set: SSTORE_SET,
+            sstore_reset: SSTORE_RESET,
+            sstore_clears_refund: REFUND_SSTORE_CLEARS,
+            log: LOG,
+            log_topic: LOGTOPIC,
+            log_data: LOGDATA,
+            tx_data_zero: TRANSACTION_ZERO_DATA,
+            // EIP-2028: Transaction data gas cost reduction
+            tx_data_non_zero: if spec_id.is_enabled_in(SpecId::ISTANBUL) {
+                NON_ZERO_BYTE_DATA_COST_ISTANBUL
+            } else {
+                NON_ZERO_BYTE_DATA_COST
+            },
+        }
     }
-    if spec_id.is_enabled_in(SpecId::BERLIN) {
-        // Berlin specification logic
-        let base_cost = match result {
-            SStoreResult::Slot(slot) => calculate_sstore_slot_cost::<
-                WARM_SLOAD_COST,
-                WARM_SSTORE_RESET,
-            >(
-                slot.original_value, slot.present_value, slot.new_value
-            ),
-            SStoreResult::Status(status) => {
-                calculate_sstore_status_cost::<WARM_SLOAD_COST, WARM_SSTORE_RESET>(status)
-            }
-        };
-        let additional_cost = if is_cold { COLD_SLOAD_COST } else { 0 };
-        Some(base_cost + additional_cost)
-    } else if spec_id.is_enabled_in(SpecId::ISTANBUL) {
-        // Istanbul specification logic
-        Some(match result {
-            SStoreResult::Slot(slot) => calculate_sstore_slot_cost::<
-                INSTANBUL_SLOAD_GAS,
-                SSTORE_RESET,
-            >(
-                slot.original_value, slot.present_value, slot.new_value
-            ),
-            SStoreResult::Status(status) => {
-                calculate_sstore_status_cost::<INSTANBUL_SLOAD_GAS, SSTORE_RESET>(status)
-            }
-        })
-    } else {
-        // Frontier specification logic
-        Some(match result {
-            SStoreResult::Slot(slot) => {
-                frontier_sstore_slot_cost(slot.present_value, slot.new_value)
+
+    /// Returns the static cost of the `LOG` opcode with the number of topics.
+    #[inline]
+    pub const fn log_cost(&self, num_topics: u8) -> u64 {
+        self.log + num_topics as u64 * self.log_topic
+    }
+}
+
+#[inline]
+pub fn sstore_cost(
+    spec_id: SpecId,
+    schedule: &GasSchedule,
+    result: &SStoreResult,
+    gas: u64,
+    is_cold: bool,
+) -> Option<u64> {
+    match result {
+        SStoreResult::Slot(slot) => sstore_slot_cost(
+            spec_id,
+            schedule,
+            slot.original_value,
+            slot.present_value,
+            slot.new_value,
+            gas,
+            is_cold,
+        ),
+        SStoreResult::Status(status) => {
+            // EIP-1706: Disable `SSTORE` if `gasleft` is less than call stipend.
+            if spec_id.is_enabled_in(SpecId::ISTANBUL) && gas <= CALL_STIPEND {
+                return None;
             }
-            SStoreResult::Status(status) => frontier_sstore_status_cost(status),
-        })
+            let (sload_gas, sstore_reset_gas) = sstore_costs(spec_id, schedule);
+            let cost = match status {
+                // New value is set from zero.
+                SStoreStatus::Added => schedule.sstore_set,
+                // Value is reset to a non-zero value.
+                SStoreStatus::Modified | SStoreStatus::Deleted => sstore_reset_gas,
+                // Before Istanbul, the unchanged slots are also charged the reset cost.
+                _ if !spec_id.is_enabled_in(SpecId::ISTANBUL) => sstore_reset_gas,
+                _ => sload_gas,
+            };
+            Some(cost + sstore_cold_cost(spec_id, is_cold))
+        }
     }
 }
 
@@ -61,6 +117,7 @@ pub fn sstore_cost(spec_id: SpecId, result: &SStoreResult, gas: u64, is_cold: bo
 ///
 /// # Parameters
 /// - `spec_id`: The current VM specification identifier.
+/// - `schedule`: The gas schedule of the chain.
 /// - `original`: The original value of the storage slot.
 /// - `current`: The current value of the storage slot.
 /// - `new`: The new value being written to the storage slot.
@@ -73,6 +130,7 @@ pub fn sstore_cost(spec_id: SpecId, result: &SStoreResult, gas: u64, is_cold: bo
 #[inline]
 pub fn sstore_slot_cost(
     spec_id: SpecId,
+    schedule: &GasSchedule,
     original: U256,
     current: U256,
     new: U256,
@@ -83,79 +141,70 @@ pub fn sstore_slot_cost(
     if spec_id.is_enabled_in(SpecId::ISTANBUL) && gas <= CALL_STIPEND {
         return None;
     }
-    if spec_id.is_enabled_in(SpecId::BERLIN) {
-        // Berlin specification logic
-        let base_cost = calculate_sstore_slot_cost::<WARM_SLOAD_COST, WARM_SSTORE_RESET>(
-            original, current, new,
-        );
-        let additional_cost = if is_cold { COLD_SLOAD_COST } else { 0 };
-        Some(base_cost + additional_cost)
-    } else if spec_id.is_enabled_in(SpecId::ISTANBUL) {
-        // Istanbul specification logic
-        Some(calculate_sstore_slot_cost::<
-            INSTANBUL_SLOAD_GAS,
-            SSTORE_RESET,
-        >(original, current, new))
-    } else {
+    let (sload_gas, sstore_reset_gas) = sstore_costs(spec_id, schedule);
+    let cost = if spec_id.is_enabled_in(SpecId::ISTANBUL) {
+        match (original == current, original.is_zero(), current == new) {
+            (_, _, true) => sload_gas,                  // No change in value.
+            (true, true, false) => schedule.sstore_set, // New value is set from zero.
+            (true, false, false) => sstore_reset_gas,   // Value is reset to a non-zero value.
+            _ => sload_gas,                             // Default case.
+        }
+    } else if current.is_zero() && !new.is_zero() {
         // Frontier specification logic
-        Some(frontier_sstore_slot_cost(current, new))
-    }
-}
-
-#[inline]
-fn calculate_sstore_slot_cost<const SLOAD_GAS: u64, const SSTORE_RESET_GAS: u64>(
-    original: U256,
-    current: U256,
-    new: U256,
-) -> u64 {
-    match (original == current, original.is_zero(), current == new) {
-        (_, _, true) => SLOAD_GAS,                // No change in value.
-        (true, true, false) => SSTORE_SET,        // New value is set from zero.
-        (true, false, false) => SSTORE_RESET_GAS, // Value is reset to a non-zero value.
-        _ => SLOAD_GAS,                           // Default case.
-    }
+        schedule.sstore_set
+    } else {
+        sstore_reset_gas
+    };
+    Some(cost + sstore_cold_cost(spec_id, is_cold))
 }
 
+/// Returns the `SLOAD` and the reset costs charged by `SSTORE`, the cold slot access cost is
+/// deducted from the reset cost and charged separately since Berlin.
 #[inline]
-fn calculate_sstore_status_cost<const SLOAD_GAS: u64, const SSTORE_RESET_GAS: u64>(
-    status: &SStoreStatus,
-) -> u64 {
-    match status {
-        SStoreStatus::Added => SSTORE_SET, // New value is set from zero.
-        SStoreStatus::Modified | SStoreStatus::Deleted => SSTORE_RESET_GAS, // Value is reset to a non-zero value.
-        _ => SLOAD_GAS,                                                     // Default case.
+fn sstore_costs(spec_id: SpecId, schedule: &GasSchedule) -> (u64, u64) {
+    if spec_id.is_enabled_in(SpecId::BERLIN) {
+        (
+            WARM_SLOAD_COST,
+            schedule.sstore_reset.saturating_sub(COLD_SLOAD_COST),
+        )
+    } else {
+        (sload_cost(spec_id, false), schedule.sstore_reset)
     }
 }
 
+/// Returns the cold slot access cost of `SSTORE` since Berlin.
 #[inline]
-fn frontier_sstore_slot_cost(current: U256, new: U256) -> u64 {
-    if current.is_zero() && !new.is_zero() {
-        SSTORE_SET
+const fn sstore_cold_cost(spec_id: SpecId, is_cold: bool) -> u64 {
+    if is_cold && spec_id.is_enabled_in(SpecId::BERLIN) {
+        COLD_SLOAD_COST
     } else {
-        SSTORE_RESET
+        0
     }
 }
 
+/// Returns the refund of `SSTORE` clearing a slot.
 #[inline]
-fn frontier_sstore_status_cost(status: &SStoreStatus) -> u64 {
-    if matches!(status, SStoreStatus::Added) {
-        SSTORE_SET
+fn sstore_clears_schedule(spec_id: SpecId, schedule: &GasSchedule) -> i64 {
+    if spec_id.is_enabled_in(SpecId::LONDON) {
+        // EIP-3529: Reduction in refunds
+        (schedule.sstore_reset.saturating_sub(COLD_SLOAD_COST) + ACCESS_LIST_STORAGE_KEY) as i64
     } else {
-        SSTORE_RESET
+        schedule.sstore_clears_refund
     }
 }
 
 /// Calculates the refund amount for the `SSTORE` opcode based on the VM specification.
 #[inline]
-pub fn sstore_refund(spec_id: SpecId, result: &SStoreResult) -> i64 {
+pub fn sstore_refund(spec_id: SpecId, schedule: &GasSchedule, result: &SStoreResult) -> i64 {
     match result {
         SStoreResult::Slot(slot) => sstore_slot_refund(
             spec_id,
+            schedule,
             slot.original_value,
             slot.present_value,
             slot.new_value,
         ),
-        SStoreResult::Status(status) => sstore_status_refund(spec_id, status),
+        SStoreResult::Status(status) => sstore_status_refund(spec_id, schedule, status),
     }
 }
 
@@ -163,27 +212,29 @@ pub fn sstore_refund(spec_id: SpecId, result: &SStoreResult) -> i64 {
 ///
 /// # Parameters
 /// - `spec_id`: The current VM specification identifier.
+/// - `schedule`: The gas schedule of the chain.
 /// - `original`: The original value of the storage slot.
 /// - `current`: The current value of the storage slot.
 /// - `new`: The new value being written.
 ///
 /// # Returns
 /// The refund amount as `i64`.
-pub fn sstore_slot_refund(spec_id: SpecId, original: U256, current: U256, new: U256) -> i64 {
+pub fn sstore_slot_refund(
+    spec_id: SpecId,
+    schedule: &GasSchedule,
+    original: U256,
+    current: U256,
+    new: U256,
+) -> i64 {
+    let sstore_clears_schedule = sstore_clears_schedule(spec_id, schedule);
     if !spec_id.is_enabled_in(SpecId::ISTANBUL) {
         return if !current.is_zero() && new.is_zero() {
-            REFUND_SSTORE_CLEARS
+            sstore_clears_schedule
         } else {
             0
         };
     }
 
-    let sstore_clears_schedule = if spec_id.is_enabled_in(SpecId::LONDON) {
-        (SSTORE_RESET - COLD_SLOAD_COST + ACCESS_LIST_STORAGE_KEY) as i64
-    } else {
-        REFUND_SSTORE_CLEARS
-    };
-
     if current == new {
         return 0;
     }
@@ -200,16 +251,11 @@ pub fn sstore_slot_refund(spec_id: SpecId, original: U256, current: U256, new: U
     }
 
     if original == new {
-        let (sstore_reset_cost, sload_cost) = if spec_id.is_enabled_in(SpecId::BERLIN) {
-            (SSTORE_RESET - COLD_SLOAD_COST, WARM_SLOAD_COST)
-        } else {
-            (SSTORE_RESET, sload_cost(spec_id, false))
-        };
-
+        let (sload_cost, sstore_reset_cost) = sstore_costs(spec_id, schedule);
         refund += if original.is_zero() {
-            (SSTORE_SET - sload_cost) as i64
+            schedule.sstore_set as i64 - sload_cost as i64
         } else {
-            (sstore_reset_cost - sload_cost) as i64
+            sstore_reset_cost as i64 - sload_cost as i64
         };
     }
 
@@ -220,26 +266,23 @@ pub fn sstore_slot_refund(spec_id: SpecId, original: U256, current: U256, new: U
 ///
 /// # Parameters
 /// - `spec_id`: The current VM specification identifier.
+/// - `schedule`: The gas schedule of the chain.
 /// - `status`: The status of the `SSTORE` opcode.
 ///
 /// # Returns
 /// The refund amount as `i64`.
-pub fn sstore_status_refund(spec_id: SpecId, status: &SStoreStatus) -> i64 {
+pub fn sstore_status_refund(spec_id: SpecId, schedule: &GasSchedule, status: &SStoreStatus) -> i64 {
+    let sstore_clears_schedule = sstore_clears_schedule(spec_id, schedule);
     if !spec_id.is_enabled_in(SpecId::ISTANBUL) {
         return if matches!(
             status,
             SStoreStatus::Deleted | SStoreStatus::AddedDeleted | SStoreStatus::ModifiedDeleted
         ) {
-            REFUND_SSTORE_CLEARS
+            sstore_clears_schedule
         } else {
             0
         };
     }
-    let sstore_clears_schedule = if spec_id.is_enabled_in(SpecId::LONDON) {
-        (SSTORE_RESET - COLD_SLOAD_COST + ACCESS_LIST_STORAGE_KEY) as i64
-    } else {
-        REFUND_SSTORE_CLEARS
-    };
     match status {
         SStoreStatus::Assigned | SStoreStatus::Added | SStoreStatus::Modified => 0,
         SStoreStatus::Deleted | SStoreStatus::ModifiedDeleted => sstore_clears_schedule,
@@ -247,15 +290,11 @@ pub fn sstore_status_refund(spec_id: SpecId, status: &SStoreStatus) -> i64 {
         SStoreStatus::DeletedRestored
         | SStoreStatus::ModifiedRestored
         | SStoreStatus::AddedDeleted => {
-            let (sstore_reset_cost, sload_cost) = if spec_id.is_enabled_in(SpecId::BERLIN) {
-                (SSTORE_RESET - COLD_SLOAD_COST, WARM_SLOAD_COST)
-            } else {
-                (SSTORE_RESET, sload_cost(spec_id, false))
-            };
+            let (sload_cost, sstore_reset_cost) = sstore_costs(spec_id, schedule);
             if matches!(status, SStoreStatus::AddedDeleted) {
-                (SSTORE_SET - sload_cost) as i64
+                schedule.sstore_set as i64 - sload_cost as i64
             } else {
-                (sstore_reset_cost - sload_cost) as i64
+                sstore_reset_cost as i64 - sload_cost as i64
             }
         }
     }
@@ -284,6 +323,28 @@ pub const fn initcode_cost(len: u64) -> Option<u64> {
     cost_per_word(len, INITCODE_WORD_COST)
 }
 
+/// Returns the contract code size limit of the spec, which is raised by EIP-7907 since Osaka.
+#[inline]
+pub const fn max_code_size(spec_id: SpecId) -> usize {
+    if spec_id.is_enabled_in(SpecId::OSAKA) {
+        OSAKA_MAX_CODE_SIZE
+    } else {
+        MAX_CODE_SIZE
+    }
+}
+
+/// EIP-7907: Meter contract code size and increase limit
+///
+/// Apply extra gas cost of 2 for every 32-byte chunk of the code beyond [`MAX_CODE_SIZE`] when
+/// the code of a cold contract is loaded since Osaka.
+#[inline]
+pub const fn large_contract_cost(spec_id: SpecId, code_len: usize, is_cold: bool) -> u64 {
+    if !spec_id.is_enabled_in(SpecId::OSAKA) || !is_cold || code_len <= MAX_CODE_SIZE {
+        return 0;
+    }
+    num_words((code_len - MAX_CODE_SIZE) as u64) * LARGE_CONTRACT_WORD_COST
+}
+
 /// Calculates the `SLOAD` cost based on the VM specification.
 ///
 /// # Parameters
@@ -519,6 +580,7 @@ pub struct InitialGas {
 /// - Number of tokens in calldata
 pub fn calculate_initial_tx_gas(
     spec_id: SpecId,
+    schedule: &GasSchedule,
     input: &[u8],
     is_create: bool,
     access_list: &[AccessListItem],
@@ -526,8 +588,10 @@ pub fn calculate_initial_tx_gas(
 ) -> InitialGas {
     let mut gas = InitialGas::default();
 
-    let tokens_in_calldata = get_tokens_in_calldata(input, spec_id.is_enabled_in(SpecId::ISTANBUL));
-    gas.initial_gas += tokens_in_calldata * STANDARD_TOKEN_COST;
+    let zero_data_len = input.iter().filter(|v| **v == 0).count() as u64;
+    let non_zero_data_len = input.len() as u64 - zero_data_len;
+    gas.initial_gas += zero_data_len * schedule.tx_data_zero;
+    gas.initial_gas += non_zero_data_len * schedule.tx_data_non_zero;
 
     // Get number of access list account and storages.
     if spec_id.is_enabled_in(SpecId::BERLIN) {
@@ -558,7 +622,7 @@ pub fn calculate_initial_tx_gas(
     if spec_id.is_enabled_in(SpecId::PRAGUE) {
         gas.initial_gas += authorization_list_num * PER_EMPTY_ACCOUNT_COST;
         // Calculate gas floor for EIP-7623
-        gas.floor_gas = calc_tx_floor_cost(tokens_in_calldata);
+        gas.floor_gas = calc_tx_floor_cost(get_tokens_in_calldata(input, true));
     }
 
     gas
@@ -590,11 +654,12 @@ mod test {
 
     #[test]
     fn calculate_initial_tx_gas() {
-        use crate::gas::{InitialGas, calculate_initial_tx_gas};
+        use crate::gas::{GasSchedule, InitialGas, calculate_initial_tx_gas};
 
         assert_eq!(
             calculate_initial_tx_gas(
                 SpecId::CANCUN,
+                &GasSchedule::new(SpecId::CANCUN),
                 &hex_literal::hex!(
                     "8f111f3c000000000000000000000000000000000000000000000000000000000007381d00000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000139f6a000000000000000000000000e64a54e2533fd126c2e452c5fab544d80e2e4eb5000000000000000000000000000000000000000000000000000000000886627c00000000000000000000000000000000000000000000000000000000088663120000000000000000000000000000000000000000000000000000000000013d7d005b38a43238ce236c2202c08cecdf3bad85ef33b303356c9c3118fca13ba2a2552ee29f09369dba076d08039e830d535dd2960e4311286e7f435c4ccf8fd0d827b94734e7ffecde462f814042050d1547ac622478ddd34bd23a784507a988a72a94f73ea19abad27aa82a7241fe1b80732e41782d900f7aa2ee8a8b224c0eecc6230c8e20c2089b275ac07f700cb559c39fff5aaad79db55796f31cba1531408793c7ddb1e47437a8b01c42a9b8cadbec782367add573d656181c561476f3baf91a7fd1660cf4aab595440100c9e600ae149264289023cd068405b64ba0af194f4861c5abd99161b52ddb958a3bc1d54d2fa702de945b5e2fb7be1e2fede126b77cb8011eee70eda9a52a2e04a0c5417ac2180428f542d878e9a8deaa2a9958ca81d31896cf0940a1e258b33a4f449e5426a2b24e494b611b7e84c64b1e92ac0420622cab6a9cccee7fdeeb254611914f1ab84273c1c22d92563b22c1c341887568bbc0b2001c84b7c734f07747e470889703402e92a8707a35f66eac8912c0302d11e2f9cb49250b073639240ac46e46e402001806221309ec33014c24f2e9aa3ae823f9fef373fafe522d5391f059eb149809bc7756e2f601fa3399ae74ab8601ef6c6241e0eb50ea1a4af85b8309655bda469807be7ea4e6015081a09bc0415da099a1a54d20c1e1904a1a8c61e87e95e96a0361bc06f60a9bd14936c3f4d1934f9aa598e851205e32cc34cd165da0e48503c8df00a891025083827f02ec5a7bee8fc7eaf33fe55adae19e461f64bd5a0a7702000ebd9f0afcc4217d02f17f2b0696e88980c39b29ec6a980016e9b295d25b50c8768abb5bc0110ce4142fd92a8e93f3dff41ab3f5f653e20f3a3548ff2d54b3ec2b3fd3b59718fe0fc4404141cc50eeed6f349f18b80f7ea5cbca83c2cd3a2b91570b019d0a88b251650f240877aa5bd52a53821424e1201465b04226c0b6337c2e7e8ecbee8c5f3dd529accdd09fc95b9e7b6c584bca12c6f8d32945920585765c0e546a894df966c3e2fcc313aac0a927b6ad21fd4df575700da99bce931ec99ee0d2d9026054ac0f2d6da2080e8786fb39180669429efe3f0bd60428875f24cd5e2a6470ac26d8b7a53f11dc290e6358d1022056569ca6651bf192fc0f66b79b558c36001b974d8cdf8b90963a9d7a301888ea6799bf0f7988be341cceb64fafe1a08d554732caf8a555ef5c13d23af66206a40c541190f40cc8dbdb669676f09d7ffcffae51f1a78149eade32518c094d23b0b3a74191e56f551faaabf1bab58213134c67f9e42ee726fc4324e53ccedb240b7f83415d6e18dc29ee3beda555c95fcaebafb6cff8c22c04b7d7d1990ef2a3fa218ba04915454135e01961b0294318884c04104b90fcfe9b92c623d0eb7ff225d77d9e7f0d42598e6672ae3035a0080eef480e486d59d83a1c509bff8cfac192b05c5f3fe06cc68052d29632e0d274a71aa1be1d0a0652040adb22160be1ff6f3487a2aebf62d829b50284a867330d9ff6700744002b4015ba5fb2baef3342fe855131f4226b022248470c103859aa0e39546875ea8146b27d76c9d0763f367ce9dc20ad3a6cf2c9cbcd03734ff231c16fb13434d2cbe74642c4c915da53804eefe3fb8eb096aec68a5252b204cc6b97014a189ac1095e0b01bfda10b20f20fb91b269d36d4a52212fcd5d26421681585ca8f355c5bbfdeae043385263479257c2384fd595621fa9f3b07e9cf110e3183a7163d405029353da140a5254ed0e39f7422dafa597cc7aa3a47264f8f3474a32eb64747f657117ee47ea4cca4bea2aa22acac0ca4c4a4feaf45f3677b071744fba6181feffaf91d6ec51d7b833caeccd6361696e6262cac569cecacec2cccdc1c6cec9ce63c2c2c966ca62c695b767b5e46033ab17c0cccacec1c5cd6366c666c663cef95b3ad8982d385839b83948e9494d1cdc497949591ccb169a8041024c2e4d93cc6862b21a79945d3941283c6f01639c5cfe5e174adb80c7c4e6df6a1176a0d2d9841e5cde047ecbdd9106d8aaf8ae9e1c4e2f08314cbccedd11cfe274736717a8667d309156b983b1b621299b5605546ac047dda76fa65aa62d27ef9b2a7f91bfaa6cdc0485ce100505778e7886dfea00821bdc7756185bdbaa8f8de754b50306619890fc17e11c826f253497123fa333673747809ee8bff5c96b4bd35a51b1a347ed950761a67f91d093e782029ddf08299fb1160004a0a10c40d3413cb5b0892e3fcc8002634d5a831e04010ac6e69e4ab02179c443821f8169393654ec412339fa38255f095611634f0c480406907169592c4e50192b7a7c83e7d44df5dc99cefdc3019f84efa242b5131527cd41b91a838e08a9a85bb51f9a50024eefde971cc6feceddc68b725db8e8ad864ff9d6f5efe8b53004516cff5152ce6d3108f9c84988e39d3a438761172f54d6ea4061f29f5c4405d66ce10c28f86c14e53b6a2f96222097c05b3baac4081df1685731d9d83f722b9ed5259fbfe923f633895878bfc88b5d205a00e6025185ac496504e473827455d31c93377c7c0af3d16a522efaa6aecf826ec148714e79bd4680fdb8f858220db0aba107fc4b79e194377dc0f71cf3aaaaf44c0bf8e75be861841c5d16d2e3b9df7bf360f837f32f6b904617249e57d812bdb1e44d6101f5b287b2695f67edf0eff5e6ac4d084af5111b21378e2a7a62286576dc7be4d50fc73d8ad2ef99dd1af9889e8526afc512803fee781ee2274b0db705c0065bb73e4cbbae6ff8987fa729dc14315258253be08ce6d0e65480e01800c87ed32d4487a0c9a3b40d64edde1274cf5128a4543c573b28ee6f540e87c4767509c1d005aadbbc5f509ab7fee5410e13de2532ada0816238de90fe37b78fc0d2c7d47865dc0576df842301977c91105db723362151b10e2ad551a0b98e29da6b33cf5db17aa7c92b3ab90ee73b94b43289ad654efc85caf11378978b86723c196a0ee46e1222c66064aa3f71c5c75529afc4f7d986689dd78c676470e7d24387355ca3719eb414fc03f43e0c39213d1c8a0a1190ab87af90bee2a85843361df2cc62fdf1ac8a7205ea973b3e13dc051ade24062e57b87492b7c025734a37b7ba0ebabf3b9c319637f3f7cde8e12045de8748b8ad25e993643acc1410300521e4398b619e90a1f419751d3e8cb139eebadb16f48ada20874efc6f2c8a5f3a573ff9ea3d10afc2ebe7ff4e4c0789fe2d3c679701993bd192113cb11cd7392f19adc904ebbf5e72081fa3a07e262e2c3149bd6916516531fc6a50c058e883b3467941a547b8add0c0541f1867e14e908807357ee912e7c7b14ec312a4ee298a7f4ad65f9b9b73c6a52377a359c7e6441910508167ae561923e5d74428571441a483bca44e3eb38cd586d6ca5f7419bf4fee961d1f007214213e8a5fb41738c7792113e3f858a7ca3c18370932d0adc9a5e210a0a8f2ce2e4d0e9067b905918fc462ff628f41d336cd417e2fd780be5f41d82369d71bc39556258eef90d0c93a4e7564c6e4da0870a6e8f87b6156fbede182ecef529afa032af9faa03252b03321cb64b8fb6046a2994fa69274d5ab0c87eef60fe8f7e1dc2035b9fda952df48abe661755bd6c151013dd465b42a44ed7c7ffdb836709b1e0451b6a849348a15106f693d7410945aaffd6f063cbf9f2488e90be6bd1b0008e5d389a09df2c27376dc5463ec8d30a482d9ae0beb685c9a2c17c59115fa240ea49d844c1366efc1507a7afe6f3f4d40230903d34135bd2b6339d3b73cca03a5325b9048c4a1f7fe0e484b119774fd28a056fd1c8331c71ad21589995ab3bd5195032efacfe74658ede7409e62d4c47212b7d46ee224b87561de69a09271870cd28a895bc3193ab5cd7f76e954f68ec6aa3ad87aaebc7ff3eaa458375d90018317791b8cddc8f2c37ab84bb31ec7af473e674d26123e7205ae07e0786bf50a6ff4d24f21c05fec87e121bac4a76a41b95dbd36a10ff61540df1355037bd1a1c4d6e95c3900d51bf555db65ee1331b4edbb8955d065d255c9e36b86a5bbc0d00d26f1a33a0c07602283ec9999e7929197f89440242080cd05c60d8c5eace18f91686568f7121520a8b469f6e70e0001e64857ffbfb8633ba20d47d637d872ebcae244b7a08d178fc58ec65f82b7daa805da6ca558cba4c232f13ac5531385e7a2b3b18b84ef9b59fbc4ba4f5d84f3d52f0209b7faa43576a59747d1baad9ab6234016a5f8797409dadfd630826e12772178783fb2c1474411dff8eac07c6534cb0fa6faa7aef18e0f0d88395b4b3a51d80a62daddadb1e4fe03613ce7931b89bbcd2f738a3db19626055f78f2c76d1646e48abe5207252d337da4f92355c6cc730c35d0ed81045d9995511bd23ab80bc47c5904aaf43b6d0ea143a01b502f4a2da6fe09fb39a7142e4115772b14c03b043d5a2178ddfc5fb4c57f1d751e685b429b6209e7b410e7bf0b96e55385446603ef9376bd1455c358590db4ddf83f7d698299fc88ab63efa2c1f6c2e9aeee34dfeeb9b397b8fae9457b9d67fddabbcee3cec7043b190401c6483f17fbd6753439906ed2a02d8f69f8e7665a6f576cd1f42d6bc7be229b9d02c1217c843875dd765791c4aa5a54a7c1e41e5cb6e7ef1f5bff8a13664e29819dc0449fcbbdb055235df16618891735fdea3aa7a9fc6ffe5710a1de70cb873bc1b0dcd16314f89fc120667d805688b92560133101931ac48608d55b4ac84938b0aa28b9c848f8a8bb59737268a9711e06ad96a926bba34fa919f1c2717ff3d43787971ee7f3ecf09de9507648e947b8064467246089aa4a7280f0015dd9c7dac6c1c4c88899951380cf86339ec042ce6e78db6e4e11f9a19363daa845dc511b83f03955eb63e5f8673a4d9f6060c8474497d3d3828cd2e0bb993cbdff8121d54578419f0049e7b483dba1cf8b0f8041250e6e258cee1e166eb95991400a1c2724a130836d198c5876e2bd09f115c5035640ca2cc7bdfa35d276b404005dde82b11a5a3b6a326feb6f37e2f3fe2c740e18c8d9215779625d752fba44e675818cf5c9c6e3822c05649aa1c49cd2b3dc3522d8c19c1e0f39d930246df0143b20d29b38a10e7b9ab4017b6121a1fe528320c04a527f7411aa3da961842110794498248c0d4909903bf26094ddae2a34cd0b2090a605f984c309d05518c4f0308d14de3fe9cb9245eba5d6901a8a9e836968ae1a8d92b9e0ef6161206ec070284e65e1300118c0eaf08795ac074c18ec8d6bead11a38c6361eb98eb9e01c10345000d40303651a10887906a5845b8b3fb5cf6487c03cb9d4fb925ceb15c4c4234530fdbd3c02431d9a95d2dd77ef1d8ea57de5ca2e06d0c5b89acefdadd2d21a6f39355f7b8ff0438bb4a4dc76fd990c4d4b45d8d4a50ebe90b9b6e86c6b298477bafb85e030708aae743ce1c76d66b1d9d5e6076648856e230a579f96dd28e46620a4cb20000741802dce1a8d7cec120e9214a85098e6ac2d31209a3cdd4454681cba13c27c6961ae40e8d6e3171a66bd2f869089a7223487c3f99f4279d86ba94f4ee885e910ec5199ce780013a3098754aa346b07c2b86915396fd151fd0484b8078d00f001fa782694f68846a01ff5864ffef832ca8cb0a4135b9f207f5f070b2be9975bba00a5a783265e8d3dd2afa8b0171ef75e57731be573ab9ae06aec1f78bad8f0b08364744a11200151abb7b5c96669262d8d1786231a6baf18679800fbafe5a4a5d62ac63d0c5cc5e44688df92330fe07e5e2089a949b9961cad52126303b9189bb19743ba887301f37778cf73a8f7feef08f9b2af2976162c2eaef661c3fd906fc4326490ad64416593cdce74d9f3d9de7fce54a170952cd17b6bfc53f355d49091e8778877bdd65d20e4a836d4235f689cd9b81381b12cd06d90c5e29b6d568cc324caaefed09c54f8fa7ebbc392fe4a8580736a83d532677624c8c627c34a3e37684579b5425bdde30581773fac8133f0465ed38607ee9ca3e715e2bcabbc795acb7928bc6f39dca4a804e978c63378d74127e52a00018b33902051753712a1e0a04560229fb0b4860eef2c506e3ac261100c17e9caa0827f6f858b5c97dd104177d233f7f7063b3a0787cb0eb8750e060397096a90792d162164ff7764a6de0e5829317a5c6f1a287885416246b69017e4025dca7375835dacc18998108f4898e96847359c5f0dafd6481e49fe683b7b2f2d9d1bcb32b888bc14755906a7974a0f1e7367b570d04197bffb303dff7c4fb5c8e97d8fc2507edbae2093e258e7937d47ceb7caa01953a3b1ed7eecb537b5fa5ed6c7af933a226c173e016f056904742e0ef92543f9ae6cbd62177a6b6422b19e661b1bb26d0d4a2dd63bc0ebab6814e52a826b3f34f9126ce149eb33d2b7345a944896bb33c54222bf40e1f68f6df891c718d2723f5af738b4df83f8f02f0b123820b2ccdf4ebb8ef2e2c09ab10075a2d58fa1befdc80f1fd7c895fd20003ac9afa70558606d70999acf9e96e53126b515f2d6305d30cf72acedba47eefab61848dcb38fc9956c404d681f9e08bd7ec50481a65b8643f8ca0febb858935d5cca81947fa51ad16354206881745682faf1a1e1e9357066164005a9cfa16aec65fbcb4666488f1c6e4a2aaad233aa15c6bdd9271e95dd8d89ca4e504136ec10531de9b0d1bdfaacede1cb56adfc901bfb3105bf0d3a7b910b4cf0f2614c9d8285a22f0452811c93f5455efd2af4d91572792925f97091857447337bb2cc0029b8e13a671f32d40142fcd6726d780de1a596f699ce1c854088fda7707dc32a5bf84f81252d2f10b8f32b78aea39130a57f23fd5cdb75bf37ba403b015c85aa493adf707a7c4a66cd18720b09e38b4f92fdfe68463d83f165e1fa5bbd4d1cd067d595b30e2698378133623d6753b2799211d86adbe6dbc1321a74d816a580389e939ad3b3e6f2fc2d051a6d812f528306b37f9fa3ef262e9b13097f2d8a87f55cb228be0e1c4abda583e2a34ed6837f80a4a385434267bf862182f6ea01b4e574a0320bf8834af7c5a3f3512f13a9656ad278c6bdf52813bca5e4ad1868f4b0df0195ee26a7823223c9dbaa331e100952245428fea8a93d2999493e927872afe1513bef41ea0a0a8f3d59f35f918e4b32f020cc008d7443237090517315216c14ce730ec2fedc1f8d0c8526da2c2fbca1ede6aeff76c1a47fdc2d7b080eea029f3b8cc475f7361b81c142faee83cbaff41c0846d33a5cf476628d0e96d3ad567017b8b9fad1139ee4a3588e46cf5b9cf9c70f93a5518ddeb494b252266ab31cc2ca1e034a1057d49da037bcedae3a0ad7cd48bf6468932fabfbc21d77a0c7b611c75421fb765c42acf992a487c81638490daa5787439cb358a6a7add37aabc76df8997504595b6c95149e043980dda30418d07baca453532023546107855f8c677a2141ced023dae8e99d057a11c358e89615027d51b63883e87563cfee06e8ddf1fd1cc38c3825c0959031f4d5ca695611e8aba33d0793c05680fd38211a0cca25e848d7c09c0eacced1cf863ecb41e1ac861027d9f136610d8aec003fcc7b147a8c34483e3c222593e243e3a710f0501b12747ca192ef3dccb2f238c5d9213fed60b7057d0ae29f202b4920461746384f316f01d6e0c0a5789bf0276156e0a9fb2081a71008e16246f6302a90682bfc4398181890fabaff03e09aeb76989592cc0adb85af51c8966ecd2a1fcd9627338a895f97914b686cb35f9307925a6697e92b7aa600e8888633215d04f3ccd1e6243ebdfdb4a47a04b9f7cd6f2d3d7a973755cf239ea3e1baff97014fc746e995920eb29f91ea8c8a806ba27def6cf180c1ce30161a38b17ff0b5eee86818b5a98edb6d2588dbae59b29e11721c6c56d0b86513e595882ae6210364f42e32193d05842af80f30f469088aa8bfc5220ddb1db58447355664a95c99bf09d4ca42d0c9c633ddae0a8139a431b5ee4389b2d3c88d662e8c5e25e0598ef6c5cdcda897d5a5c38f63a9f857f395718361f6ca7ef978b1aadcbbd03e6dcfd601de947fb56931983a37d9af656d65d0b5bb10db6467e7f25ba070d35166cf336544516c7ffd73597ae44383f1e00a4602a5072aeda2b622687d020effd77da441ca2c204f0cbabb186a4feb2eb27aa38c01c594a162c2370ad167fd9e85ba6221a093017fecf8bafedc2cb57a26afe26d3de8537c88bd7b71139fb726550b86d1132027d3f05ba916331159fbd033c5d2c135d7eacd2935bde34be92e381ad351e637163823f6482b34703aaf414c9abdb086d21c2abf330cf7fd1a9e963d6e0a0f3e08833b07a869da9a5729c86938e0138f473b9038bdfae54734340002e0b24c3d52c0e4b38ba9de895110a74b004120e200b889bcbbd6cde3d726488d4d83137289a977349ed3c4d193b0df012904728a14b8d040ca579cd009178f47d3a6bb381fd0be2008de9c87f6ae9f3e82015fbddeaa7894503010fbb3f406099ee2d3425312cc47a6e5b7144c30bf7810e34ce4f64b7c745c6c629f4cda806a46cbe16de3ca5665394f4e93fa9852d1ff74d2b6d0329b0329eb326c88011a89327d7903c98241134b94085a8185cef6bf0138954cf7e462e746826edbc400de4b3b9cc19c1b8da33863a1bd18c51c856204b0fb48ed9558207cf10196767fe91e53e350f29ebe8f948e07c8a475925f0bf4d4ba737736e118338d13801864acf22a9895cfb0f41abb41179436d2a6ca3de902d94d7954d213fc03445eef79ab8bf8a199eed1ef65ac7cd8b7c2c440cbf820ef244415d1dd19dc791cf464db06082b40d1a3be12e01f01acd6056b5e54893ac9c5231d86c5af766a95595c85669c757a3365b828ed371674b640bb4433ba5c52a5fc37e859fb98161cd954a9a101e9991838cd24dbfe0430f848e25e5256ca296424b005208517d84ac6f0d76f8ad6af753c0e5c49c240a679394eb5ef29c17550ad35eed96de1c1109871bf2e2559db51caaed692ed74603330ef36a0821ef2d777af30f00d98f4d00619f18058a8a3be8c3e04cd27551155a1eea628087581379a4140defc1142e3200870572e27c639a55e8407fcec58bc34238e271b6c2a1107b47a8ca7643cb468c61c284f50e5e1b74387ce78b2f4fb5cd30a176841aba1a40097903ac6ddfdf7e3c6b80bec5fd5962ddb283ee4e179f3825b074ff79135e148b096d0514adf2668a4a5a0d39346af0b2e4dc489c9e2b8fe0bed00bafe890f15932e0568fe0f7527704cc86581072c252412749fad4f0662ee9003871e4609200d68cc7ee33cd2ce5136a37dda90022c58bccbdaba21af49cbf1f8b7f6deee4ec547427f34480b7ee4b288256563758a14792f8acbeb5cfac17c54a3e2ec15e14f8e4ad7716040cd916a63d706ca59f27a8b7cfee54347c6a13a907affa5500ac22c54f4d23e8a1dc959d01655dc923d6a79ba14f31db46fa7ec2fc675d202f00fd08650889c70c844afe0b0eccce0f40cae8fba39c5cae62cae937925ab731a02ac66d5e02e50f18c35e83aed160da60910354af160137ea109c992383ae2a445c858a389be9142cdc776d4159cf5c31781fb29066a7186c3a731aa264b4ace634a4d0280302f9a00aa1b006d79bba8f6f6458126ea69689ee8e918798760b1fc4d353d070b49526407013bfba5fa92f8204a0528fbdb384e41c57f15af5603aa3b92b042dc0f16864d39118d8d146e8af8e5ecd1d9c5d1b2a3bbaac50b87bb1a366ba11bd1e4767eca49325ef617b963a18d4286e6916b2435fc23fec93cce665eca01be9e7c7c4e247ec2803c05f21c2a878c26908a42f02ca0d4a40c21e1171da7026dd3b62835c0ce998756a47524d38133774efadb19d67214a2324ca996ff3f287a1414b1830f11faf0dfd2828eedc3a04422d73898f3e78ae190a93fa9f911694d3220a0e73dc6d9a8c5995e710751b7264487e21ab67d806ac4ad87f6fcdad35a4d5081ebf7ef9cb74b2f469dd52e235ffcffc4f0bc4483023e94bb09f801bc775adef9c53d5c6ceeaae56e378bd5622c52630e0a4204f024f6b6693db37d168a38e82f4ace75942899c57f1943da2694dee57da10f741fa0b09b7a4c6c7acce4999cd6df9ffb450fedffc80b4e40bebdb2ee331ef5dd400dea780a38a8e81e5ada146c143c03ca41c93b07da2a713a0cf7180b4c30e13139afb46fa7c2ffe29e52ed8268844d5bb27be470000e0603d35a4402accd3bcd7efd5875aa2e33bb975a840c423e2695f878c9708ff503fa0b195d4508c9c890b268da3af216e97b299a099588fb69d65cc146c73cb2e8e20b18536e0499ddfcacfdc8fcb14259c1e585c95ba032d60e158d4bc252b97975d5bc94b23910559ee87fecbe509a9ee5754172adf0398de5ab1a4d0de4bd8afd250de03e37f8c260605099563a2d3fa6f844dae3a7de3107e2fc08d421b2fff049de57af66e5cb3b54abf480bcd63c1a67cec5b87fd6fe3b2c94c18482208344806caf2a037a9d611aca3f105e36f9b1cdf635e3227ecab70f0834778f063176d8dbe8047cb6bf7e154a068a9a3a51667a599e6f352249f2437fdf06f868b454d6855c24508f0c48698f62d56ee3d7526edd8cf7d489bc4864869f2d96d04f4655ce5d0b224ba64befd5ffc041c5e6d0d2a630120ccf380085222715dc295e0591dfb3b172b354bdefee12f4e53d7b0a481d4086d8753cd2000a569dd5e28ec160e0cb76d51b76f1cea8f8265601fd05878b5a0fba2a2cae9a78cc70525e540ac12b506108eb1a16a805d770bd21d9803897e671dd49b9ac1786afb9170d51674391de05a602e0d0612a38a8e8918af9310af60c53a7edd55259d7b6a60e3f03ff41d6520f87cd8151b400b4ef4057e360368a8ff811c44afef1fe9f69355f8e5cb8229afa676847b536514fa62957489267e515a0413dd314d196f58063b64820fc85a8b256ce0c54abcf7f0783cceb3c878822f3d10a12f895cb8be10cef4365d37d8fbf7fe8e38ca36c20203e4ebcc3deecb91a174c8a7ba5eed96c1c36c2caf4edb5b8c7ba61db62539c18ba81cad6521182ff2e03ff214793737f3b5ac0b51e637bd01192184bff41a9a1cb643a371249da0f76fc7849eff47214212b2cff1a09f10539213ed25996906891ccef83de40b69919a204c5392515533386b2442df8e95bc851d881562a88eebae3ffa1ea7315b0abd69e6b2ece48e9f221fd6e981bdda2a3ecac6101897a6b871ff694fda9dcf2102fc42ff2f803a7ef2130cfe4086b77311d6435a1ab13a7cffd2fc49c4377c3bb2cb8f7edf58fcf654cf00250f3b97929c668f058dade5f552810ffd986eb8cb9b156291816fdef116173003d4c64c87c16d3dc38146ce555cb11f28f3a8a2975ce53ffff3f47bd0e4e5c9d3fe934e3354e6119c5011e40630280c82db7b744acf60fd9e3b0ba56a357719395eecb68ecb4a38602de33ea6cc846fae895ddaa88b0cd15a5cac67d1c556c5b6aa2bfac3e5634bcc5b69fcd6b86cccc92474864890c00d5acaace6e84e8bc5272ed57ad1b2a72d995b4bd334c2c1c2040916232a3b38274ed2996edf5cdfa90d4479c7f11a18492ba88757a00aef6d518508eb1b8e8b8cc9d32f9b0a9b89844bd60c670810e8a365e20fa1d0ebab65488446707a03dc07901e74b206265c4812b40ad70421882ba1ac360dd88ced1a9cbc4e3896ebabc50682d53e059b8f9e6e9c8366f12904fde5892b042887b3dd067d1d3ce1bb27f3100ad5d19da57056d6ce351529236e64b1c0f0f2cd42061c374454ad901318c1c0a5de06bd037a924e17a8705c33f55df4f4053a9c7e57ef21e4892b334fac41825a40e8e1a3641cc376ee9de5796192264e29ded4a83a8f5667dd6973bdadeb118baf47d412d639b03e1d6470c758e30a07e40749fa2defd088e47213c331ad59e36d64112576cd9cd3004ee21f807d87c58b8aabcb0d1669328f774ac00cead432a5c852dda75668550d3c42e92b5648205cc9c82c90f046767f52886508feaa3fd61618542ebd7d9fdf0b6fe961d94855838b73e43a7da66337cedf650ab42409f9a67d0a3bb0135c9106b06f45f6af637a4bd43201acfb540332f57eb5d1754255f715f90551f8932c2ed8bff32a82ab825b2147980bd41bd5fcff19a7c75a0f661e6f8f235b8922c2d1b909c6c9802583ad5bb52a0116ea3f23ec1a0c45b4a05b435be4d79a6a5db9006b903a04dc6647ff8ea4e599452564530209c8af39fcf451cc3a35827a76e657db708adf265275db2a1a17689cbdb40aae4fd5abad5d8ad5807631ca8cfb0c44767eac7af89fbbca8416339611e1c3d2e67a5dc952521e7bb1e942dcc2ee3163fd0674c35dd1ba6d83d735158362050962f57b8b801e10915e9c8e225bb2701cda9e3434242619c0e31e146998e7b13b62810be56338fa214d93fbe3eefca0f3fbdff5a07a206f1f1369e73d2af712ec0f1c5c069c29597180e020cca5b53ec8a91e1eac45242edfb203335aaf16ab0059ec23bd973354075dcf5cc8fba0e766b9a68b9bb6186df0a148460203dcda5b280fcf7e435d0a33296a1fbd8c22bb8edbf63dbe4437af058fdfd8727d099dd4fe5bf409ed7f5b2c31ad19b1c62f7ffd42d6bdd7bba5f804378bf3bc58c5e710538a898105ada846080766b03a9329a9e7b7b6ce164052607b9919a0ec9745504a2ce5c175c5a9efecd0b08bb5b5fb1d1c922dfe1d93f293ed5a381fef75196153a38114f171c7b89ff8ac13131d657c7b487d6667a0848932f0d1d86f4fe2b37ecdbc0173f051d1b241dfc62a8a7b2ad70673acc2e615d21ef49ad00ff004d800d87d6e603e452be9aa617a08bf2bf35663eb1fb6750a62b3460255315160477819233a95ed91e9c5187dc356c4b0eee890b7e06086ea7fe9c5821caa8dd2244e7e6a846afbfdba574b4c5bd8dfc0a13f3928cbf5573f8c6640537b7b966ee42528890cb171db4b8b9014dd069bdd911c20ca3fd33ce258bf64be91f6ec93e892a94cf3f739dc2b8e35821f405c4ac0085c77b62f1ea7492a7aaaf5d1f037230054d7936bba8e125eaabaef05781ebb63be50cfe24e7fdfe4370e8f0951c0b1119f826dac218ba90f96a6a3ab4122abbd82b7666e123b686a7a83d3c81d08d4c4484debe6a983c5aed339920f863f5b471399ee3bbd5618fb54b929df1f88046c9ba61e075642438a32101123ffbb3035936f8cae7c4c663ff91f43c6b962b4637da89ce59ef3e6c6cc9f4c94b7ade6450dbe31d04e7ca6b217c1e9f68a0c746ceb78249f3b2e12f77d51153add727c44be389959fe94782fea5720819572422177ad5403a228e7f00d11cc1d4fe2ffa94f5c9cd362cef3385fc2be8cd6d34b567b16d1a76ca0579f21e5d736cca5888bcb6de80577d8e048832d64d756e6b92cccade8c07b753f6b3c6e9698a6770d0ed40ce9a2c88153cfcfb4a4b9388a6e2dedd0c051d46ec21ac4d87bce3ff47f5fb8145fdf61acc1d5aa6f8e1ad04a05e6648deb991ed3f173e83caa02e9d40a170b3c9eea3bdf6a65258e40c5b0517390e2c3ca3d954b68d22cf783a5a452ad492d83e245be3e160e901b249f19f7848fdf508688274ac0b9b928d098681f1ae5e59772bac089c335541e902f398dde0fa8627258154613265dfa3337c9cdd6eca890e6e79f1391ac48538689f06e2c3fd97b28f4edef28219cee050a9ccc481968162fabd348f80e6b170f03396250328cde4fb9da07eb628b894c70ff17f49cc8fd1df556a0a2a6dd36a6938f4652603480c0bda42a91e2d013c7e424d1f22a28d99821873217cd0c9101b9bcd6898d8f0365c485fdbf889d617ef06916c0fe7a664f868e0104140c3e422711a65a7e71d21e883fdd358922bc2b86cfd804cbdbeb4adddf096f0e9d64e11902c4c5661ba0811341265e5b3078697711029fdcf9d72b7d87f0f2879b2d85bf178d5f12772e661594a1a43bee30c59f005aa9d3587bb49527c1cccdd6a1c138bbf9d84de25eb8178a73528dbc442cbae48509fb23def70cc937d876223970ace2b1585967a996b292a2904ff006901c9b6719468eee4e996e9ec4883c4a3a75d1656e102c57b20cdb384ed91680546c2336d5d20e28c1864f17656cad8fad3b30d5b29de18c280a086133096a48bf2fe4174f374e6a8a4dcb52b10d83036c9097102c0927aa3b63e255da117041689204b4b895930b73cd197f435e45cd48b5fa9953fb65d4b3cc0af0707d17f4e36408d01b682f5c5f67d99b8030254baaa3674ce7fa03f2d6b123d336e55a054cfc65d909bacb7633af8bb135fbfa09d873731bd573f6650cf80cbb5a5d2818c60427057de5eb2231a6ee12e31e3e44858edc67c45ca2c9b2c7d18edd40174ca1f05fccc5fed16cb12df4ef4d73210c0b4ac897b20934789db1eed48f4488b9262cddb3eda6351f6e26033eda9eaac5d4dee8cccc66690aca16a00e19b604b46c2e6e383c174298f86a43b69985fe40188c8003ab058393d1249050b84b98da7cce46d1f3dad7de021afb8235fea7f6d53cd4eaa97db17daed9546820425dd7f22a1304b149a3970e811663804e7e065052fca42692b77e18d366945597a7f48ba4c6398a61987c19ab4da472d6c8ac4f7638738a558057ab14e23b6ab587e3c405fc59d58c94f3079cf587cb7b25dfe39c2042f0cef18c0998ecb180e3417090bf3465b6ecbd81e9bd5a8480c67d86d8e9f85bb806c80b8ff88b21b0c64dd9a965e0a36cf4930dfc293691260b6e3b5d44d03089f0569e4b053271c9bf1ae9e82811f5996820a1ff3e372558e952de4aa5be1360bd70468a4b4b2237c430ea19c068743ecc7e66511d700086cedd3f2fb7f4c3e67d7de871f05c1f13b7b3a72f72803fca09c1ec4b98a3d117cc022330cb247744f574842a688920178d609e0e3fa713d8001ece8ce48beb0c4a447372c88854e6fd340258c062b80179865b46f957117117e157efb3f68bfd04fc9dcf4f2110a7db8958eaaa0b76e23bc496e31928b9bfe494d6971e9264b0729c74286acdab8399ef8d8de906e9c65a5ebff630554b8fecc04968a263867a131c485a374caca43c136fa117a8e6246eae5fdb6c64d0402d455bad05fa0066b77131b62fb4d9d0b884beeff8fbeb07daf6f2f16a4acb8300224f68fbb442da2a769ae98d83b833075f334811214205ae24fe9c2a58a8d1f64e80265b365414220c5796a0beb4565cea43faf8bc399e288f7ae6a58677fce1268339178ccb6401c9de29956a42745f76c64a0db01ccf257bc9a607a0a2590a77cd83a35fec06c52488d32164174b1fccbda5939890a014cdbfa5bdf1ac20d6c3ae6751161700699e15e1ce9803ca9c878c04a932c4dafcf5550f98395a58cc159c92b676a8d6ff3d64741e21b42b02103c0fc5c4674534b5fffe43670cf866dac97c02610d0db7d3035f2020b06310131720968899685a9be870d5904558ef45b1b801fd6c5471a759e6815cf342655f31dec005ff8c724363ea6859aea2b5015954eaa38a5ca6def3fec87ea748d3c3c2c51fc67469cb804d2d5a3ee81bb8320c0d2f83508d10a693c1fa9c07b4a43286423a45f9b10a481b0f847583ba8c95317202c9067461c564bdbaa3b3c3dc8f6f9a7e2f9e45f1a98c65a7c8c438c183f5f43d1a1b4a36b8a58ccfa83367161174f62c1a43e1f526f8394fd930605a9134442db1f5328d1734fff1b87cc23f273edfef1f59a3d0d2f77affb003dc16474683db9e9536cbef1a9701c4e5b1bfc6e46cfb2dfa8af1d62b3c25fc524105d4250b92aa0097d69dd5cb887558c6ea8c1d6cc4ba7738db9f77e6740e74cbf73d752248cc6a89a6b88729d2eddec0c605f0106023928f02689450e3a5db3b1e2b9ab61d38ef0dcc8264ef7bc47466ffa515f8e74c844ab162563af0aa8cca26031a45fc72105fdf29e19047d36a6d31c59e9b573801d47a1fcf5e1da0f66250ce748d394c701705d38a1750b789234743970a2a0cf6998230a3ac829facfebb5c2489e1611c53d594004e26d6ea8c55680c6c8efdcf1748e7b8037878177aa80fcfe2ff2597402958df3b908bd90a3b99e4addc4d1f2e9ad7c86e68be54bbe8b789e731e4be652ea51520522fcc58341e5612d0d5bb5c1f16822382657757c147d839ec47cf460d0a6e24d5f3c27a5103c2fed9060c7427d8a4c66a1bd1513b53163cf0a0776fa9fd4c44097375db4b9fadba07007983f8381d691e98a7bd3deded3f3ee0a5d3cf7ab747ba7bbb5260bc5b8e645e6835636f140a30e3b5ceb06ab7ec1805f77f66a3f6ecf47de7299484decd2638be633c1dc06209186e0a6b2894d42c946ec76673b77e3b89249a7da5e8e7accefd5aebc0dfdfb8bb29a891de69aa6d56d952cf7af62643d6bada990180b31a0b2279d0e12cc726bc12664d1fea222e44442f39ddeea4602965276c3e30c57a8e4c3136a727eff2f131e8f8f526b2a76189e6db8b6a6dcf2dce98b0464bdc79ec8cb3c2ebbc3dbf5127d3f80861f94de021a4dfede0d037d109bb4ee7b27e99c6af340f8f54ce589eea98a9afc357fa4811d812af1c332bef5caca37a51a3e4436298ba154d5ec696186a87673bd3b72521f0a71c41b43b120423a9aea236c8db62718e3c670bc8c915107a536aa7f1daf8a69a6dc423152c248e8bcf8f9d9af4200fb9bc18db5a801a6a280a285c5a653095c778a86f0dd70bf8e84dac4fbba1ad52110b7477cf0391d0ef3b8b2c6f61227ad887a839d02f15a00da1121cc4e656889f2009a9947d794e4f9c2c43e93fd5ad0d77532b020dbd6d77e18084a7fb86046777004f85d813a20173c0b37bd99434aae0329a201e3b8998bcc7050fe80f364350a2c21b0742c183c986bbdcdb797c6c334d4cd43667982ce16d326f4af6f815ea33ec2283bb7ad591e8302508880ebdc6f2a8f359911e64c65cbfe0f4400976898a715150c39a7177378e5e0a3831d1c546c6d153b077d737fd85720591fb4d9ac6c5c5a62d1acf7371d4caf3ecc5106ab377b896a4181a1ff27411c0401fe8b47b13fd64e525e7221e8d7c5d81fa062d051c8515a3a67f6a44ca42bc6740652f9b1c5a1b650f20ebccc91a655bec636e7493850b61bb03d80680cd787864cd24e3fc1dfaf0dad72653fd130bb5e46f82f688a262bd588716a0aff8f66f25ea2f07e84f53285ec28bb478afb45f196c7ebffed90cdb5b8e674b9c6fd504061ceacf3d1de566f5a2a739876eaaaf9cf110a455b8f8dad1619901e213f4fe091d89908e2b771bccb36ec6673ca8396555ba30948a96ea00aeb04fa5e6f3b1c3810d97da55440d876c590f7699f099bcea82bc4d205dfdd7c0e4d15090eea82670a2d6d225217d9cc7fe4936ef4c0b5dcf8f34f66bb04deff25502b16d4812aa6060bdc08515d7a3cce502f33126d4f0c859d2b71087c5958152ee34a1eef5f62035ba2356deab597afbeb5260162946a04c6cad6d1e54def3b9fbf2284f0ac8495c7a4732b77718260757d4e77d29511c410702902163999035027b38b0048085eaa7ca039ab112ff564d96d7f712f789db5112bf6285a3a937652ac1f3357e141c2384ace22c90c5c1f075e3768840e10bb4718392cfacad8577f04fc63d1cc773cb293bc20e5e6f5f4e56531b01298ce887e6c1998900c01b47b50a69b58591839b13fd00161c968c6eccd5cc58bb1b5873eb1a1aadf36ab7bb527751a6ed0bcf16642841e02ec29e1d5c15a12544dce46e0fd04d9772c03ca180d7d3522e04f08ccbd954dfdf9a81e8ee735e18ea65f5bc26de9791e2991cf60c298c78c0a08c5447400709fdaf1f9cc7855d78d533be83f1ffb2c15b37182342b5f8a92984998ca7083d2afdfd0602c659c5bc4d1233b656a191affa895720318f92547ca9f6feba72596fcd1da3c6bbe6a9e4990c1354a8f091a7fe91d4b3ba9649c8473597cdcba9968f6c8032189c1638ba6865a594d76993aef5f8b60e766f0003ff444ce242ee110b562ad3bf2fbb8956a11f864789cd655b7c6299a013c9056fdbfd968115274d17cdc4d904e37748fa9965e4bb17b38dcacc97c85c46bbe1a57b60c4c40187e4af9984f39e78c5997ceb7741df0af3ac112d0e4da159913f4bbbfa04de480de2b1724cc48e75cb40b823006f9f2c758e264edfb9f4666fa92599e87bc4159f1703321e1d5accab37f775f768d943ec614f17e1f164238c27e36486108b5f3a4ff6007568630c53930fe8f0f2194d9e8d0ab2f37f19f336a7e81ba87942f8ff39723a4bef020e0aa6598c9a4b9e3b187f28d4cb01ff478968026ccea19ffb958e247a94b9a0a46c45739e09ffa77e605089f0634aefbba37b0f95c1c402006a50556ae2ff217cf2d56579a9ebb5abce8e7c4a1aa8820c514eab9561edd67181e0e3af80567bca67029e00ebd871008cfaf5db3d1e0a958846f9d13081be671f6d7d6a8c3b344c9535e9ff6f61e68d2a1d2d932ab713610e107643a6241d81a664df09f3fc5cacf952ca70515b6992a03cef64499ae6cec397cfb9c8b29efc42a431225d8545c32ab5780b2d729bef17f02870ee6b45130b634ec154064dc44c14038b89d9125051087fca7c4ee7d4f951f92bdf0a1b0fe48436a19aadd5bff2b2380dea4348ec125e12bd10b602570f488a3b9559cd686e263667d50356844b3f5595da03bea1524d0d931dd06b07cf9ae656701c3b631bbcf374688254fcc9bf0b8985f9e0eb0fee44099c5179cdaad3e2958263b9cdcb2480ed3bfe0b7a002d91274058a02e6a561a0775020de9b236b5a808f2aa088d9d80f66ae56eed8dc93890e9685631a0acd320a06b2413ae63feea63645df0166ff669b4086349273b195ea486c511a718bba2e90e3889bf5fa074d2b54cfcb844fcb65c8bcf23485b10ce0f004d75907dd98eb6eb67dc28ce27af766017645eafc74567c7c4b905f2eaadf59ca897d62a0f46afa179c815f2870f6fe33dc1aa60de6aacde714cfd59e49c21a6ad62d841c23e0f86f7f15ca322c634834e5820474bf0f9dfc373a75c94eb27a00328123d92be7cb3d83901e7c791a8ed68475408f61bc9b1aad87f5ed5b9d7165520970161afc8138bcac4979c3da0ddcbf463ac4624cae578505722a5abd558ed0155e99c51b038bf412145bcb91c0782d17e116af7c666faaf7145c2da0ce52e77303f3d86ebc3af45b67241fdd745985815bc242eec2cfebc1b2ae5bb9cdb99887a78a4017590ab788ed6c4ea85aa222bf9e0ce851a6cee757672a36e7cef76112892964ec9bf0cc05029503e22256ad260bc89c1e6f0e54ffea3de5e7d33a21adbd4356db2fd5313ca03b59291be5e13e132626358e82e0dd2280112768e1e3d184a44bc64385783e79b5658031a26d57652ccb0b3f43a663d6494067fd545e8799f9e63381bfa5ed92af2e9a1f025625355c5a553cdc875fd85d9b8c2f2fc5ee14f8e9c6f2384e6725b588e60ae5fc81f71756cb298cae45d4aff512361f16dd63906dd51591280f36a8a68890332469d0a98d2d24f0a60d9311280fdaa5a51020a70eb2b84cb7acba0d83a657dd414add73c56f5937dd6d52aa83821b8eb738196139ff2472ca1112e59ef5ef65ad2bbb7bfd3d58b2e0357cc76f7f4293c4cf3af2c84787f5e43d4e7a5669cae6d026338e1c74ff15acfb012bbd5a19b96ca018fc04233ae62f84cb1a0aa11e672eb2221281d6dd030377037d1b1d35d62b7d17e8c982e730224bb912072f9d9e0eb831e727c015ae28de19ddebeeb7e3f1fd7ca2a1cbe796d4e94e97f327a4895932e38202c44a3f3b5226edff816ccd9ccfe05588bc80b041b5382a1b3c78bdff03bfd8825939dfebe9d86b0c29691e4fe3400a98860198c753e2be649e4fff8b16319e0ab160d42d84a1941108043d1c87aeda4e52c5172e4a334a273f93d796dbb00c03005fc1c022cc75a68a239530e72d63f7959e37dcdefd4f127e5c8b1c98d31c5896ee3a341ff1e5a930d8291ab2a5f63cab63aecbe405a283ef17a04ff4d2f00d87643c9ae3512429d46229f4f16f50ce9a8f234c7b446434e4cc63c64c4b0e51fbe1b9f8e16c7140f23f8cfab35975e94ce223faa47b3693291f60648d704fa245bf1d85b5885166ba853498e0056e5bffb15f5820bb3edd70be2a61790edc27abf4ff5b596f3520bb94792e50542dde18698fd96c12d97b27a91c0ea63f13b1243aa7cb139b495db2837f557f17245abf8bc4a9541b12ed020b9e34233392e3c66e237962b72aec6f1f154c8c595d0a4b05d87d918916e5a1ba8d414c1f3ab5801ccac220619dc70404936fa9df84fd9cbf2c6c88b59172335a76aab18e5b8ef0132a34d765a546a626434d2783bd47848c3cb38c868d964e964df457d1e676efb02f17b6701a0ae71766eb3b1a14fd9c3d5e096065d132fc477cffe1ac64d29f09a65ff74fc4a09e60636923626ee2ec6ee1aac93afac4d93829f91d17fbe0987538661b01d70acefbbce7a39ae310a8525f287446a21ac14075586fabd25564fbdbb9c5a339a5ff94953e27db0c669fb5cc612bcbf2f9ae21d9006efa384cbc1351d7ea496c7f831ac910f7e08590014b18543f2ed9d98531f2a457e19e6c98ad7c5f77bebc0f4faa5036fd89cec4aa79f46bffedcda39e0a8b30cf46cd35caa0f0b2f147b8eab8b4dba5ca23e9b21f469d280a20d39d6edcc0109d6bf4eda2e82282145a68e3fdda5c70772541d9908aead9cb35edbf061c54fc082d6dc26c98ae2c9d713a2fd98718363af4e508398aed5b870f7cfa745699367303803d5a7711b8c3369f4afa83cd88538a8facbfddebf0d4a9bc177a4529d01e2a9a7bb71e445a178c5b83c140f2eca192b56f3e5921721130d5e8cf43ac45f61648ed640ca42454ba0d094c3c208532c147d349c539e2c9b6bd10f3082db6c71b53fc87b4b25813d5beabaff9080e9a0afeeed00a21c1ad7bff27391f215d61e2ec480af59a7859aa97014ee759599f03055ad020fcbf90b107531181842566070481d06fcd857bfcca40c666218afbc4369c6494ab6b8f740ce4f224bf9dfa44414c4f88cc788c29ea52ab627729a55013976b0e8676ff66a19843b98e64d9886d3f76c528489284df8c3fed4cb4bc44933bb94b35720b38e5b2c934ff6e1a881b7cc51e5e3449cb599688e68f51c1700c9f25f5e2303588280022918189aadc4ad5692d880bbd9e9d2fffd135b9f43baca5f02880a365a477dcea020ff097e2dd1295988263318bc65f4c45cd46f73c81fc63a4b761327a7b3a968f93a5d2b1cd0666bacf03d9f32387f1953c00340c47196b35653b3ee40eca44e5ac6c55629dc956d4c9db5bddbb7a170af759f3d8580d7c5d83a403400b1f8905053a0014ba4a9e26b9d6e03903f8b0f62cbd09d61486a2527137b3149181af2389a21749c24b24750dae0e52bce0fb0d23cb374fcb67eed651561c5956fd3097ab90ecd793065437d8832bed9e8cd287ff155ac8c494f97d543ccf3b6e6d610a75828e19fe68f49da7d0aae5f04e98b8dee76bd420f22cff094036f864ebb2f786f9bb261a2584a9c4119a2aefb0ba8596c26cc7dda52f3cffd7be90045c1ce559c7364f7d67a4d5a8d674e04787ca7996483cbf4fb50ba5a08a5249cd77c78ae2c72643e24fdb69428933eb06d352e6a51be7bb64c1e127442a2f104c043308a26836428ba54df61f261721866abbe2bcc07e820a404e670b19b37bdc476dbd927a43199cd5ef2f07aabfc11a742df2b40ec3d2e46e94177987fdded40a1c21755cdc183def6d37e3e4a1fc21362703237d12a51b73282afd5ecf1785c25f6e0bad05f1057257d34818422bf16a408b29b5614b61855f464c950f36b72b20c84fef70b215a815dafd99482d12bdfad8eab1dc08086a6800fe25c00dca81c4552acdc3fc07e546a69f2a2302fd0c7c9000d019aee4aaf81a1dad0f2906a45bd795ac6dae31ef4cda5a5e4b7a45228e055f54e3a90e6d3ae6e1cb327e63a87f5b23e420cfb945557ad97fd47815daf47e8e752c47f3a6b83d8cd92b1e0c88c1319dfaa576f011226d8c14d7ed23d559e0592215bcd89268bbcc5d057140a65eb44d9783662af8bd2d7f41502153bc44c329774693d0556f13cb3c9768e66b5a20e106d44ab573f175c21c9fbe4c1c9ce7d6c7d87683f052e8c0fc71733d2d5f04b694c8a5e8a98a46b9fe4eb8fe5636ecb5c080834118681e26379eb49d9e9a3889b1d70ad5931d4be89ab20bc3699ce1f10f4ed126831c3e202afc98bd086054c2db145a7af4454c5521fce894f77759771e0b2fe54ee4eb74d39303f382d5ea614891205b00b7d054294360c13dbc72b18fd9aea860017d53dd5ee5270473adbbf82e1bf5fbd92b10def9595268b13fc6d79cef0fcf439b3582057106ef76f9809e34975eba0f6d4317fc721541a28a30e48ce2cf9f9b28faccf55d4902ccea88fe0a888f6341d96e8f44a2e09dd19ee2f5ecbbac41a1b21165121f1dc866504dde42fd1e20099d891c5df593e842e527410c7c49c4ad6e3f926a446ea6c2a50cf67d0ae1eab991408532cf861431ecea55276850640ca41ffbcb713762b17fd39b90919b2479a294dcb39a64e9aa264b61344269f92e4da2dc0536ee296c11508d1fd53521d3a2e0efed3ebf4097ee58bc84f311dcd4ada22ef8b9993236c5f0533e03f38a08c9a5b21ac2bfd6f4fc23713fed95c448d00e6633ccb2ff203ea90b31512010de7e3c5cf75d20e05d9783fb7cb54737940d140bac0105a27159e9dfc0a40cd17f8269906915d7448d6bca270e36a3ce721065e91a4e004c7865973f7d9ffab7ea78052c9e6c6b26dd599a7a8e87175ff78ffdcb41c1e2990c4cc2e90eff42dc43e51f747a34147104b88eb8822f58d733dc15e04ae56e2318b6cf69741e0718d788d74776c265c28147fc011bd1a98deef6893b0baba278234ecc06e7d0ae0d14d823965eab81223806fe276518a908609065fb8f51569c661fdb8f9e0cbbf7842128fb827fc02a8785ab2a888bad79a06476c4a22ecc312e85d50f689fb972df1f9759b0f3c654e4c9eeac89afcaa08fa4dec1ee9029b93b86b8d1a9f083a88e1c09b05e095cbb0af32a006f20e8f3ae028c79900e2fedf81705671c61c3f6812876d2d14c7c7a63d4bf8d46bc74cd45db161cde084041349ed0f10d528713826f51c47afb0f79fb79f132fdcb91cdfbd4c8f4fa809160743a238cff2af903042518e86f3af342cd43da1e1efe574896afbc459f3a111816a84ced630d4efda056c602649b9f293dbfb65ed51a697e419142f8c7052727531758789b9e84c6172af1290d3cb4afa11c5ed2be422235fe4280ccb992d78069e344d962678e3a2b8d0c1b3f0366267411d1730d649ba988bd1bc1b8666664a11c73c5556c6a1cc9d9766659ca013b43d4d6a0f300c1d75916fab80d97e6a5cae5e111252a26d555dd9f1589746f40eef5a8f258e12f76d18a2b2b87d75e56fc8aa0eba01cac2bcea037c23f0b8886c0673750f9282eeac404b7a222cc6e9eaa0703fd77d95808af492aef9bb4f9350a60318a296db42f770f73ee78ea68715a334c460b24e4e816ed53e421686fdf73f83c2c8992b31f10bdc45d109da01f10ad2061fce1475d4cdb200e93778dc947dd324dd328475f8370037d82e4f53a1cff379e5aa101109956ccd60747e8917f2f9043c828f96a408df287794b2967ed35c1113582fc9ed06f0d0de5db055ef59e9f5e3c0cb676e77d131c8250292c9cfc41b9eabf9efa1e0edc7954adc33b9cc8b3196eb4fee64aa8cea0ab78301b29c2a47ed15578216bb91ba8146bc54db31492d27ffaff81b5797b94619e8017926dc700cf9370e0266ea3410282dfb5d343ce524faa00ed872606346c8048eced061e8d5807c03d92118c8ec4450dfac92c5499235a08dede53685fd8fdafa7b784362c59f6b4773fa79ffc4722b4ebde37d2d72968f3edd7c631d61723423c3e7a81d067c26317bbd64124a461e1ff1a8644b4f13a3d7c953a573ed14734b6646f4081ba2d6a2f15b9077a008c3ab68335c9e9759364c576b529021bd442bf85dd3c694e6276e5d17055e2ba4b5cca74134e590d05b8407c336e5ce445df8d11792966a212dfb322a0697c0e1f29976d5dbcf6f84e954bacfaf465681788f0b436ac9492d5a80352aa533701c9dd2accd22282f1ea6a3e7c834c3d0f985177893fcc5a6a1d74fa4bfd5c29e5776f4dfd796af0ed96e06b3f01811a062ebc1e42d181a24a5b692d4ce6e632102e5ea48302814aec19e840a705fd48c26d36d7ab14e650037957a6783da2b166a77109c3cd0d984304226091248ee410bf48fc4ff7befadf0841677864f497bb249fcdf25528c83056f854d6e646a0a6f71f07d00c8f6002f6f1c8b587cd3145d40bb67c5ec70ad3a934c8e7e8f1e209b16468b30bc439b61d9682838ab1eb5c060caa16137e6b470d9803420bf1c83bababf37efa9bf3181f9679d5c4fd67aab238461c4c846e5043700eaebad924343891841b73ebc8210db27efac2007820903308029ce95e35fe078ea1e3aaa2e57996ef79212ee887bc3dadbf6c4f3a4b37f88df10d644bd1f92d1d118e0d5ace7538df0e3be311aaec58ff3f3c790347faf681632217b17bd3ee82bfff0bc90c877839590a830114a4cb66c73c8a6a825246f1a741ae2fd421e159f01006779a7b294fdecf3738210365de78ae7a47dd2d64676a80279bba542734d29aba5d58dd74ce2540b97c9b735f09af0ac4c63108c8769be7d09f31d2e00dd1e6cedba22d46251802d27c36e6dc1c11e8d0a8fcfd965963c506065bfb3546cf34b3e31916dfc4008f3b5d013339247aec86e66ad7f6437d3caff7e47184cafbaa39f1a79039ff1fee4a5c138acc6e8eeb6528d5e5ae9e6347e83b66fb176b2bf9ee07e8c9a41990455adbb73d90627d8040062cbb641783465e9dc932857b0936477d9077d4c3c0ed9a4fb689749ad161e2312116135d6d4437faf4729b33e072cbf1a77403c3b9ddf23f17ca7e45341dc0f673617d5c1f8ae064ff31f7c9f51cbc476ebae500ba4c1f4066a30510241077ab03ba94685f0cd36c527e0a550100c819d51ebd4b1ec54352738327c59de6c5f852203f0080304225202584f2402907c011f26963ffff80fd6e33c84e850ce86b6dc45cf95b9c27336e8c704c12a833a8b14bbe746067b40d9ff2aedb5c1b864dbd613950a2d4f2761102fad8ec7cd0632b44a359b27b5ec14455f7f62fd867b9b9e1a8cbbb9f321cb946edfb70d6b4f1c8fc0f7fa71f27508864d8e316c8373e2ecb7c2146576c0ab258bac857f88d529bfe65731329bf4156557d1b2392a7723f03b6487f3a28f13bea0473fac69a2c0db3ffd877dc33f9ad28d64359d4d7c476344df9b0eb8c89197d67298d2b070a20f0ca7737cd5ec70413b98d9e45f732cf2577158053e7bd461930100cb4eecf685cecb7bda78acffb70e888675173c2c77fec631d0788bd8107848de339acf0cdfda744472812aacb4f8936b026a0d275cbcf171bce3909b9c9325f23dc60b501728f276c9abd62b330e02ef5369b3f89e723dc96e5f14ae6252f21ba72498f96f9fea2eeabfbf9e1e6310bb2df880b0208b0e44c2ad9bd69f7c01bd0d3d28122bfbc9cd261134adf596758ab2de01f52882c901a92f4d726a88aa8781e2d3432266492df4ba58626dfb7cbee1f5111cf9b6f2f7558070e2aa695d33d2904fe701d6b26409e85da032017527028fdf93e7733a6ab750f4783d2bb04d40d5b73e58823b8b4d29778b685af6c6e62da3bac0ab9301a0c068290bf46ff3f904050a00e3c5e8f3256eb34c2d03c20a31b13d2d89edfb8cbd500c2413ed04f25dc60399d467d48883cc2ce861dd5b1cd26e774366a3307c40fca7781a45ce851523fcaa1f441526a94d71bdacdf9bad5f121d29e70c62d419d4fc67e0d5d91092fa36dd2dd85da7e7c737ce54b1bcd669b940f717a5a596587bdd057cc8e8850acf16d69c42ec9696fb888c2011a1a247fb909388fbf9171ef5cd23a52e1f17b2c62dd49ffcc9685f6faec125ccbbec240ae5f31a290a2b6e973dc80e5fa5d7bd8653aff774e220678732368cea2a888df145cb41acd6b7c815972e6ac5ca838dc42b492a2a62fb6df2cfac088171ec2da2261e5e747c6089498811a95964756989b1f1d52b98aa80eaa96fe314ba93a6cc4cf3963615b4b8d2ee4bd31051ce10b0fe5db30b873afa9fc981c07502bc8114ea221097c00d0630ccde49b9374c9fb5dd851b40a9690a30325e18a7e22faaa900590e8f732d6d1405e5b671384a0ef272c2793d3f5e2a673332f100044ddeb1008c7c03401f88e779b5cf88163eb60856f69bcb4c5899d54ff0f5e98a0acf169155245c93405fa3936cd2f80afa59941f5cd29a8e9143f3803cca7615ca271688c2003026e8ed8e0dd8ddcfab4c869e48598b93863f8f340add421949dbfb8a477fa15fd9487eb167d8f771bf74fdca54cf4482b79e68c5e978345a1d25597f104fc4f7097e112161dd7119e4c0522ec5feb5d2e68d916b7ea9681d670d821e1ea7ecdcd3627515c8b5c218e6013087d01c44a6b413a69301e146da1ff100a93fbf7c537659b213208dbf6f535a7a1bbbb5daa724f0ca1b750309de6a07a709bd6360338966cb79fa94a356759e216127b7165cc3beb1ba3e8f9af64c61ad3b47e9f5dc919be709bf0eb1dbfc91462c9fa974a5b7b8620b7a3b9c94b5e70b8fb0792cfe60cc6fc2a2219df4099feb2821dc8ebef6d163ad384bee0f9eee4f3fe43cc32488ca329d1dd47f1c1867a4b286349e7fd751006ce26989e221e1e5fa07addcb95a611ca53a5c109b602b61e8d922cd1c5452e2543e203a6170cf9fd8d69f3d801636605754984835ae141c0508eaa2703a67ace1f3f17e8419f57867290f8e9aa4b34717b02cb34ecec8655a8c1bf82f3e69640397957502d90d02b8749df68aab487beba4b61b4bd5c5396ae0f94738b4fbb4abe39b167fd09b1d4941eb3441d9fd9f8ad6bf8523d3b7d3be66ec3e0b506a0ecf349d1cb2309cdb8ab3748234dbd257131a2a2b17d6d9126ef4aa172b36cdb35b190a88c8a57820f53b3a6f672d5f880c9445ae4227082f8f8a80a1fc834524c414b22edcd8de9feb00b9f6ef57898df8d9c38abfdf05f530612d8a7e5a274bfd4d9b4e71050be365e9ef187c441533b69b851541ad1dce4d7018952ad4c5a9f3d94fd440737c362d788a9a7e67b3566c87e75abb5ff4b40543c1c79d3f74f720f3e2aa08bcd595737c41eb578af4c22f323d0d4dacb1cdcc427aebecfcd947c2913de054a4f40b1a6c06358400a6b32ccef6958383de8bafc1fac219c52c89fe86150fa2d2cf557c15d20e294925271abebc85b5d82a61d389816a11872e395d9e9a8273e0d920ee0b6801bc519bf44010f8f92e654dd8c014d17e781fcff4f6e9574f48f3af46892ab9111a133992d352225b644023cb8127e712decc6e30f4c79d64dcd41b12bffc3e9b27cbe12b69a8e8a15ae194cf5b350a020a9364b1052e716dad7a60c9a9a9af3640eec365c6fe4156fa1dae6b2ffa077836602a96ecbaa13393d198b3002c61e4d887e016c126230e72c60761207b0fe5a9b98ea58a63581deb2ec5625a39ea3a5a3c82cf799b47807a264cc707d29d537c81a3877884a8abe53d242d6742ccf60a0eacf261b87642f2d33e02002dc6cd870e3ce906d06fe0ddf685c977038f0e33424e81f5f23f1c2747da97fcc928a9499ae053233ea8474bc9407e13b8cda6d0998005a6357afefe858fc1920e094d3234c821611ea0ca97f163256037affc9864063381062e9570af6bc160cbca3d90d80854994d7cbe31b8c2eab5af49deff78a7e9a42c92308a543a06ed4bdb2fcf26853e232408a336e86b32f68726d5c728b4b41e24933322774186904f29777d815823235b5b2fdfc68d73010524683eaffb41b50129141ea4c9f18632c5b080415e5c3497a771d1ea3ddc86cdb34536d37f03a285fd40a2003427d71101b74c5b66b1b34d7461becbf9b33a711e28f197d3b48b508b0c5e2315c697c9484effcd990ab1cc87b19f204cabc2fa9ca467788dd508e7cbb42fe6466bec73231483772eace9692d4396e0b4196eccf0e2e1394a4aff9107fca3ce8d1372f2ecaa9921f107d83a3c8221f9d86282278c0d86cc2e9acded77c8f111c461d6e76b64fc899027bab03c33375333859163674a0096c3ddc93bdab20862485860085efb88dff852e5756e843a8669c07a318c7571768b733262cf259cc61e1aa4ad67050e8b72cea828ce046017d3b53f2654ce9be8d281d02b165511f1eda4e72e601a31a57402fb86f8c141e4f437e1d450a1f335956e900261b86e425d53456938a061d83c2359bd54f3c0a2d8f3d28ee95518c852c035cbea4fa6262bc410bdff3f9101a4c5e85cd8b887e72abe017f7696a9836dd71d74ce2c12bb63a7cb33c1016b4c5d59086f152d91e2f98158d38d12d05ac0fd64df9ab259eb5b444d9b7f88252422033857fabd87024862c14918a30858570011ec637f9c2077036a4d648d3a26e9257522d790e2194590c1440d3eb5a86f885217f03d4c4a9cb1c75bb179c7e5d28f2d7952c45d55fa504cbfe2e8878ac4dbdeb34a0189d83395d29f426639f954da70f0c352702fb840a8a0e634523818d040dc2d80eee0229fd9273e9216e5853e0ebbb615f08fff802a5d8fdc5c27e38aa2702085eb80d000df752ae0faffc770f384249f4c1b3c7336525752837bb66771040c7950e618fd5b575717c0239c4fdac8a3e85eab915e3e6a71e551043b01acce22f81c4cf8525ab7cee132054a9fe1aada0ea60ec094a579120c9fd180c91f143ca51fd7c07c9d3c415249d574e1f0802f254ee1e8af99fcc8a27fecd16d56804989c96ff3147de1e9cbd98061da5b202122ea0e55d964052ddaf4fd74a9065e60f5ad6ffc500419b1dadf5403522cff1b23d956850821126b27e8c8da5c5641002dd7a2314cca51738bebde9ae27286ec9e7504cca79e6eb21988604778190332af42701c2a299e707805de7ea944699d9744960541a4bff06e56c76dbe506007098dc443afa53c0b4ecabf81a4d0759ff7ef8f70a04df81f5a2c2ce7bb7fdfe52835f3804828df689b28a7df873bc4e700a2b6d39938a41afabb921fd1120fd851f8e085f234d090b2b7a91d35a262f3e6474c0c0c179b6f84b9c19d050c3998c9b3d1e078789beb9ac3330c8c9d58f0f941ed84b1ea573ae4128fc6baab82bb2783f947281be38f763c35aefb872aebd3bc847a2b7c396696a3a8f179067692889019e03f9cab60de12d5f4e4697af3219697550a8a8393ca437e448ea1808bcdd4816c3d558a6fcd403da2deb1fe309efed46da33c775dfcd85f4592c4c56c3cb64c9afe4b3bd4f2da2badff505915e9dc092fc0687419aba73cb964dc7a70d9cc9dee888154d989c12737096f3d3ff214f388281818df7874d2853f79c523a225a750bf23dcf67e117c2d3d2be796272195f8c3340ad2ec74a7c12f032d308998d1b2910d9ee38da088faf8430f962710b0c3b1fc7f5cf3e6ca197b2314adfd48ecdd08aaae9a98baa0b8091b24dc5bf3dc35b73c36c3d3013d30a447b9b11c55c699b42dcbd77230c61e6750218c4437dac8e5ccb74b709a1be327ef5085af4e36247a8a224b34a7bb9eb818a6b2f4f12645df19da674f06d918af23ba283d287073576276b3bdb4b09b188f0e5b43b8221e540a4d95aa719f79e271d70e5099a9aed2cb3ace5b84fe7d59ce959e11f463d614a6b3395d795bef06dbad4210622701c76c1cbccf47f4bd0ac56a5ff902732c2cec1c770e03bf448e9a70caead6562ffc712e804b216537202c2f196f7ede46d914456efed33e6c3c0fd34b1b6ee8c95f83443395e634088005cddf2fd60a42830d2af34283b7f7304d0716e209a696f9d84e88fe4848f39d14b79bb33783b4479d77f21eef53f78a973c076011cbd31def0527405ce5fa097737e0e379b49c02008d4240b226715b0b5f80f890a15684376b87c2d4778f65bdb09c32752489bb58ecb7dbdf754bbe42226a1aea8cfa2cfe8de37e96810a1fe889b490060a27858c82bab08f704e3be506ebad725abc7491fad5acfc2c56a1a60dd0978a957019455aa42742f71e46b45d29bb55aca40f5af1d85c27a0ea3bae45d855dbc8822e802a56938a2bc278641bc967db099f16c3d9d21298e0f3c3c8f70bfe19e928f5021c827988396430320e5a94e95da0c8f8f369cf74ace1dd1a3c138213b68a08ba464210468317f60d69c3b9afecc64f1922e3bd00f322324028498b96cdac5b2dab6a739f2162e793cc4327e198c02bd8985505bd3dd5e5c14a622b1d55c062bce87d3915df6261227edde4ce9ffc701e58fd07f4702526f950fdeaf92d518820991c643090cbbf7aba07f769ffebcfc1a1f48ad6d57a3f1a32fa9a028ba2d51bfec95cb0a79e97f8aed7a5e41be40201f4d9129f301b3cc4c57f6292563438ca659ae9d759a31701bd6c2119406aa97cd6e186b28dc4f091faee1a7c57b5a83240fffbaf054e2036b25c5978960d72e266977cc22dfd11302d0091adf9db39fab5c876bc4d7ceac873b07d7ea50e30c70f9369716d682875a6ed8df347a9612190894f0b984715ca0338ef0e0b9fb4e765ddda2ec64fb9cb982019824c237ec1645c414624441f2817c39d989ec7adb306a9a34a7c8dee11046431743022db65769fc7ee04e8eea9c570d229195d83103f420fec09eaee916d475dce85f75c1902f6abfa58187434e070787413176a5835c636d202829cacfe2193348e22728a85e3849ba2c6595b18ecf4813648ca6e0c9c473f6506b7d8079916fe8888ec1785af1884ee633e661787f3e7221942bc980757850abb4731fe275277b66b8f51bfc9e541db972ca4013a603c2017f70c34b593143f7679dfa853fbf3a23c772165127c6f39b786934585bd6ca358662dd3a0cac9c78c58fcd94ca56e9eaeb918ad11a15ed8ec774498cd5488c907de6b8d4b99023d67d8cbe1dc94269c4af76d339d6415072ef2d68735a6fd7430879d0267681e9f8cb3c13950bd8b133eb74d4e7c4ea2bdcfe7a244aa2723edd0b41977e56aa6c65ad682329a5cdb20d9b68edfa1683fd8090e9ec077def0995fed5d4dd65781e88bdb9c70ca369a86667fae8963741b064aaa61130a83363872e68967ce23e86c949b6ad7370337bf42e26ad2887d61db7beb585b71f0c37171bb38999190fb76520756d2ef5772e1fcca2d05552f3a6b9f4f8197a36db06a34bf8c2042d4cabce426e71913d507951baa70906a4bf75ffa3833f5adeb6a532c4822b4a5fbd60244dcc3e4ad546e720e87b5fa8f46f2868512278ade1e354d3cb6757c6f6bfcca43ae41336ae88252e3c9795c38c8bb9280403510b82b4410453ea4092f60cec4e9392c13656ca63c1363d07d8f18b20c61f19811847489d02034c82f58d70de444a8d94cb5550ca35b01890187c0a0ee1dc923c68024e335350d45fc2f952775edf47b2c77ecd5300a2e46a064fbcf4888924acb48998acc570ddbff888fdbf4c24b488888208450511b5027b57d8d4568875e326fd87e6d80c2e019458570eb03f3a23aaa0c43326576baa636b9ec238c4eae095a63b6a6b8b30a0c4b4e0f13309cc4f9389b8e795fe762a23f2b34e8c9695e7270a19ba65aa79974538097616136653d68a74ed3bcce5b8fd77c1d97401e34413164a60a75a29a95c809aa4e85748ac16c7bbd807a2e5fc9043457f13f78bd7146bd79b07499863bcbdc13b7d18210d5f11466c3a33824263261623a0b871005e29c8089df77acafaa749c8e56141f7e89ef367501827cec9c1cac9c669fed5f198ee12ad25e7251f109bedfce457874956ecc727aa51c15decd34a59c026c2bd810ab323916d6e09fca669f61b38c44da0388a4e1944a7d879a2987517665e5f5a14f76621f072c10e500aaaf02e21117fddf62884b44c8193c67cea9c9385ae0fa4906f1539d1b43111d0df1d575051a82de17e38ef06e3acdb8a7fc3f7132e89802cb7c58a2540cadcbca50535230140ade270a29fa3190b053d2dde6b162895f2ce01b0deae8754a78197073523d35727f65209d674e5099252311a6a7a9fa27356d32fe22713f5bbb357d5c49147377bb6d467dd7eda6e1eb2213fc8bc45904acdf5708113a8e754b2f07cffdcb7cbd14f279cfc3fa060d1c8680fdf3adb5adcdce866ed0ccd9f4df9f76abd67e156e9f661d0e66cf72cd5ef5ffaad105c27ecda917f9e3f59a48a440006eacffd43515ad17f883293bcc16cab2dd453af4032ad9c65fbb9cb4c73a9be1d24fa9b524c57d9bc0775a96615ad300cae42bf6865f6118b421dd41dbe3f318c047ab233e5801d29247f2fcf2b418215528b87b7f80efa1da2019dac158c83fe0900b1cc27fe9dc0627196912c8b3e6f530c7ff457840919194eace652ca620ea07cca987ce89baff65c48cf32a447d0a809b79006da1272ca3411eeb91d7d39c1e8dbf966c3e5c9c6c3607ad87d518921f77d3990f209e6a5b902434cf80334fcacd872c0127cd732d2b732006946ca8df77755b20b3a5f558984713d65fa10627bf7cb4a0f7522eaf0328d1b7072f05afe2447f0e1fb576bae5dba414ffb28376075dbf123da870258c08bafae53e1f9cb81d65bdeff90b13359d249bea3da821720aa9c685cfab963de66ad7f75e86f0936311f88c389742183ceb69bcdef33ca48406068acfeb0ccac625f40e511b55b1a3093f7e3c67cef5240c96f638f9c70d4ddeea2a9d012c4557fc9487ab1e3c5ff9b77f00c0d052048c1e68d494d5641481a58a1b879511145e2da3d375330691579ed2fb18f1037da72adb5a10b8c4dc4a2904956c5333f5d76924ac15da063465a1a3466f4f09c0227e5b3371c85c5165a27478b3f69de4e9a1c9216167f45009dcc4a9f56a7536338493c2379d5a68f9166093a2f5d85b04f7b92c3725efea5157ec7b4ab532141bd1c664062d3f17f11248ed36448c9aca377921497a8932870353229bf95833b83c14014654cb818db07e5a87ba1d8bce9169890d2f8df15f48aea317521dcc9006d382076e27d4303f6f77a69a0d717be3cad62a055bc0f3c6f94a85843972453dcbf55db10a9b5c3e9f1858477ee7816a231f5fa103b0e1e340d5cf93ecd8d93cde5f4b87fe64a07cc1660deaa88d366cfecd5755c43d00a6d535b7553ce5de26d84b048e1aa5add563756550c811eddbffcb5d9bb7e23e72c47f6af8f874186a40e301265bd171b31ec6f20a9c26422533207738b0926d44f33daf2583a9616c1bf2c268bdb31178ca464fa82531749c63e8e13385bf90bd7bcc06c1e2c59453492437a8164fc5b446da60b91349c71333e28ffb9b95d98e77ff59d4e86dee2d63f89456f5cf5e328d39690efb45c372c93821f81e4fa9ee170e719999a477f48947bec34f2c7a0fd793e90c0b3de187821555e1ee1efe306e9acd045650b3fa8882179c4155b46e60f9726fea25e074c24093fc52e885df0de2fa6ab417a78c943043fee3f81f177429333f902b7afeba8027ba074696d5d2fc26ef31d05cf74cb3b94003758db98e53abb289e8420a4b72c6ee35d521ba344e2d5c265db665462ae6bed6d00db3ff75831acaf5a7ed7b833d2ab01fca21de55df051abf77e16e1926d08dcb39f598fca65dc829fea921c21206372d149318a1fea584ab120f28c51894316e9140b44a10d1e3f0099aa3d0fc18fabb550f2b93b16d50f1aec374fcc76364a8d5412d616741794e6200509682ab56af53421e6412de55ec2d48cf277f4480a550bf35e3db46b4570ab2276b8bba454080ea71a6d2c65d1ac3debb0fab2aaf3a13a8f40ca4acd7ffaccefce3dd998a3f3ac84aac4f76f908e37702b335d01e5b9b0557d3091cc82c19cdaa305db3da4192ef27f3a44082c372caccb46a65404a4a8b319af88d86a9e15022ee29f69d26253089c86f23ec045167ea93a446ebd01009d0ec32cdf8c9703b8020bf431e8dd2ec3f17251ebeb643d0af7aecbff5e601f78cd7a6e5ddd6b40c2a1f85947b17ed3c4e9d12296703902db834c4525e2dc55a7fc9b743fd0f9f8c420a2fcdf7c85301f14ee147cb19597ae8cb81386274286203a0562d5bcf726a1509163c1c07ea532709bc3fee49d56047c2dae883770bc665dec87bf6fe9742d7a5ea0c075066ea7908fab6b90615dd664db2ea347cd9403c48210099cd072bc46a6fd411ad6dcc83a151ea4a9b7e5621b6099ecebdc5d798e76541d06dac12b8ea2e0cc3852107942cfa8c2235e1e005b42617d1baaf02326b02d3abf24b90a839212cc6289bd0bdebcad3ec5a0b37b6c5db1f56d3d2d67ea6d21d5dc6153ffdd4ce949d62b8bb6bef0ff939c67e377e197d2fe648d68362637c240f1be1592bb21b67f8f9b2d8d09a9a59974a8de9eec600384ba65f4ef24fc77200ff68fe538021b454249f0387f1e3b723c6d72dd01667d6d09494d22d5c00570aefc4b3f09952dd31c77b0c0142cf30d70a4b75808d1902f1cf5d32ae60377fe1e9431cc3bb3f949d1abc9f0deb041d86520694f6a4150156ce0bbbdf998daeeb067af28455007cacd2c14c5bb7a4a277939214156fe9d467b7078999683b0a0e93911f91661e84363baba31689291b705b1194dd92d5d1ec311793591dedcfaf69ec5637285739dfc8b8c600125e7134b5351c6905a920e42197b9431d7d0d72ffe5fb1bed2b1e775d5fe2c07d87a86b8dc9b0d339cebfc3a85233cffa9aa341e060f6268dc376f646237210aa7617e15200bff210bcb6fc99c870a66bbb8d871410727106bc61bcaf7e44c07da89e9752bd92d2b5b0be4533d6bf4f3c7fa3569d5eaa5b5393ee62214b6df90e6cffc1133233516792a7002f977d283eb4d32c992bf0df6283af999b62e4cceb653c0fec367d6a521239fe33700d21cf90975c8e060072168f899bec5f6375bc7ff20fe110571e33f447665560756d531c871b34d592b4ead501968c55a7dd8f644e395826886844c2eec88e04460c568ae29def41fe151367a89b73ad2977fa0f23c88ceed52043f2dd07b8495608c67ab43d86b07d43ba1d2ffa6b5acd0174ad5f99dc68e98c33c1fed5644c795e5b03734206c3d711adf52a69112f7cb4511707c409777d4e8ba86a718993cb397c42f81055d9a4701756de4766744c9243df6ab28cb9fbd7c8d5e8d6022a372e3c4074a2c1192821031da5f7729ab5799e579b18add02d07f88c4c47908f0487a90280b064f54f4ce3c32d82e9b212bba66a063d956ca7314d65280487860046e8cbeb4c94b161f525085ee486cde7533eb3f76f44a0103500aab527f403b33c72005a9e5a14c57a107838175bc1d92aef2748e765b99921a86dd780fd08041095a5207fab08ae5077a7952407359c79d2d0cd53e5bc50a5b028f60c9549caf1551e66a7ca4e32f8c2f28f8715d608f9f9010b4dd5490b1f1e01ba5e92de0f3987829b82c03ef5b9defb16f8b35a4c944739e02c84781e2ffd8217714e9c1e720fe1a0495d0c0949120a096e747dd6ba1782f4d788da45bb17e331dd5380672f2559b51946751bb2bcab3c73def868dc2148b0babb6efefe9d4bc8abbc0bfb5cb38244a6a5dd33b8f00686a7e3b5a4700aa1dfaa8f112ad06460f99a58a2594f39891b6b1f45e9c9dc3b3dde969b534e6107129249e23b1ff158357e35bb643b078f09d3cd2858d11cdd46e6fcd36fea03db36600e03d249fbbc4522ea418346f3fbdadc5e741e69c32ef815e7c0c41291efcea86fe2e69cb09df08a4603b0ce594513a0aa24f2dd9620e2b9ab30151c89dd62b625fe77a9973e657d15b3addccedb4d1759b756ad60a5674e86b784a16098e15e9f7bb19b0fd52be1e73c61cdc1d6c42702ba18ef0e61f077f3ff4b68582718d30ecb948b41f2057e9e8741a1bd303330f59d01b9d804a4edec661dae24c81c5c8d28ffbab72f07cdea357c59b27feb09517c427ed12364b38a61a9b820c8f5643c50b30e045bbb39cc2f953ec4d6142bbd8d1385d3fb3cac57c6fdfd8f67ad6e41f2c17a6d32bee74285204b6fe037de875609bea073ae583d6424159455e28def41d3e0a090890164fe3e88dc6e2ef9aaf47ce030f988a794bc27e01abed95ba6f0ebd16aa1e389076e42f339b42b57db4c31fcf502a44f292cad2e5487a9647f0fff1fd9653f7bb5d88467c1f991f000eea8253edaedbff83d03066200fd45417b01a1367d7ce8ef886c4577b1e89ab6bd9dfd3ec77ca070becf1bf8566031af1da326a486c16c98d5b139d048bee47b6c4a2a2e3c2f26e2498ffcf648e4c9e64932e0b671412fec2455f71387e84141afac2e742a8bafa2cf5e53c2873d324282f31248fbd8a9c86122d413456128e65160f72a86a1038d6871b46802c9f1f163b3520a821030f769eac7a7396d0a72016ba700b471c186d4d5849c73b1a298a1b5a08f85ce4f205516192ee3ffd5f546d8504a5405165392451123903cb1c503a4235d832bf53fbed8192132f3bfcc2b310160eb0833a780003a491210deadb7ecba4165296a947d99f5f1be252cd532be785f9e1f1dfeb39c868aaaf464ba34ed2e13a0895df418012b02088675b957e7abb2267905937c6d4ef007f1538b95a9cca9b8eb6654e73d270194dbc8b406955a682aabad3279365dafe0c70c9162185d25ef8d44d783018d6cf4243f56fee967fa3f472c1254521719df92c8acf22d7f268a545d7f48ada47a2d5222e2b4c18a3200c1164c00b2b09cc5f9da946b12ccacaedb5fda49f087ac768834264ec7bcb8f47c609dbe0d9bf792aae07bb5919f46908f222475e33e460b8bf912843f3f209571a4aa925dff33a1fdac9aaf19ffb1a3d9620a1c69db948d6edf26229df807c3fe5b473f41a547fb985bbba2c0762178f3e216470fe350866c1674ff0190f32a0bda668d00202551c5b9931d6c72aa150f69ce260537f26da939678e4c2aaffec82a998c7dabdbdf0abc26adb6eb5ca1ff8e3d3e3fc6dbea4bc0c8bba59c8fd25012c512c2a16e7d7f38b1ff6876a154675c43d72c0f996700ab8fbb03738978b95f7f136f4933092741472c2c5194b67b0c829081eab4b55267091c1d86e3f19bfb90b2638cbff9b140e8d89431a33731c20219be7dcd51e456c015d38ce6612d521fd9e8b06c634e6346e588dc8b88b76cf953c141e850c98b405b8fefb514ceb7f983f14a5f4a791744e40e6c1bfef18e42736090bfc38da4aa93daf62957143d7803872d6b5ee636071cc2ee5d006952962f6412f2264b7dda531d95b17bd477422f814ed877ccf1721eb2f16a3ddb9792e6c8321cadbf0bde006bca207a11b2dcd85c39163f18679f3ff29afae0aaac124b423e33fe754845a568e0a7554278c13b3f504c6ee10a354c96837bfe3f2fa349edf61b8f3aaeea0fb90cd2b8064bcffde0675da3677ebb8f4a2eafa7ff2207bf9d8f285eaa9faf7fc3b18fe007879754c260ce46cf16bdc479df136ff17fe1db81b62c727053a39e48aec54e76089f04bc43cf4b42a81f9a286884b25e40eea0efb9a4e00e4ffec7703baf4b08d95b70d55c83df83021318803085ef35eb7780db090e710f029482906e1d5275e7ed44eea04392f7653f75d3c361aa4ac2e882bf4bcdcef7f7196b522d55ce18c37de0197ca2f049ba32d124e0e2f423ffed071c59166fcc1b0a2ab0296940a91a6a316ab81c509bd8b8d26c0186aa36bedcf2f0635d3e13066448e9214c64133fd4c9e777fe531f58734d31b90ba1f501ceb039932de80ef1142ff271b0b45332dd4a58e35e748feb56be2f9c683b2a96e6936fd68279eff2f7776cbe7afebc6461f29d3fdadf56b850b07bee10c9c126d887b7fc6a91a43f4a73184903e7eb355907d2b9ca4501f0c9124b828bcfcb738065c7af793261506fb7aac21655638278a2b3e096f3948cab43b81dbdfc7d0a4975e4433336443fef18ce64e196826b6252de765f9d4bf5733616f455efc1256adcb1d83644b807cc14515bb37dbee693ab2c36b8d6022838b47e6e8968e9a4d8555346b4523974489c0f98dbb63207d9925fdac1bf6654e2e12809ed205659d76de069d966dedde7a2c7f6213a798763c010e2ab6dfb71d37d057a0104ed4069095b36d19e98a1219fd9e510d853b52066d695cbd3a783cffd4355cf8aa504487dd19824687956edb5077b885cf29a86afc1b5e5b408b65296284e88140716310fd3fcd3acda804609bfbbe7c2b0baec25f9edfc45002638c7391f526cac531b42afd84fcebb087a1a1cea79b5b7e6592e19193552112b580359da9b268d10946831f7a59cc2c3827e0b47f819a2c9eb4d33b5aa0032b8a52885a5e74ff6e7c38afdbbf29a7e4c702d4870320de20c4f347c1c54bccd09790a6a74c4e1f5e4190d4922faffb1d2e47c417b9b1dd341580170b1081837809a9126dd70a10bfb3d7c99c377df10eb4a897e0a37f1266aba40551fd177ef990e74b5e48c6af66f31a7e1ad888be0142cec9c11cf636e67c10cea47a81aab625d356e751b037422ee000d3e8efaf131f062e22065ac7a58e8c0b567c69ae604a73e46c85138e7e7bf6ffb478153f8b6aa56803bf2b09d014052345233f58e8961de310dbb4c11c0abecb1eda2c08565f3c27d0295f2fb9ae2be751ea4f5909a54044dac07de8e5e1600ee2ca1803dffac9d56617586d81423509248cf3039f6d7a79cb837886015332241916c3c8693385641371b59522df26f00b26c7d8c8ae22bc1e1005875139e6896438fdf006f841bdf928c138a341efef6a46378e61751a0eaf0a24d49a60f6bbb6022598a047a00bfbce7cfde4ec4b198188c964cff5b5d81e83cfb7e30b29d52a2510eae75fe9da886902c93078f947c1ee88bcb23c63819d7af2a2978fa57acb6e60481e0ebb17afadbce371251784bbf34a9484419b77d79ccfd9f58c17c509fabb0847deb2571325c687bbf1125382d6e793ad59a54dc96c33505be37c05680af65f13726921fc850da5aeb76707e3656681508bc7420a2b706aa74aaae4465be9031b20ff110cc6438d400234bae37c4601500b3ee9c51de229964de3a7a5c6f45655b2b882269360a0c06d6ad72e7fe4a8257dddc775984056a72401973e0f497a75d63b67d65dd2199061bd2b5bce089f0d2d42d41d1e0b43e6410d6288fc8bc4313d8de07635965ccfb5df100c5b580c736ad1dde92ac8c0ca6b47af5f731cde1e22f05c2b1da955cffc6ee55dd99cc8df451f130536c30e4a6d89109d8b03282d62ccd9ab59866575b8b025ae448609692ed6cc7f403d2ecd485c08b013a0fccf798c02187f289ffa40683e25b553dc8eb205f4fb6742c140b40c0b67b69225edf32c7d1b4fc82c5d11e981a07b05ed57c4e31bdc729671d559a1bccb2c64add5e350d9445c7c61f23a0163759a93fdd41ada564591811b14e12f1561496c4d505d8538982f16a690c643e5a52ed69bc52eb0149ee30f16e7ff0bd4fd8c2732b4ffd4390e780a4dfa69c9eaf6df8c87a5e1867f7d49fda68e41942ec9108930a18f2c245b517bbba15817dd430c220b259b95606b17b15059627f3f328163a2d4bb8f66ede9e6cadc81981e0dfa8f31871afcda2f010890f9b0e9f3cec16bccfeb696c8cd8509a071ff9ec1be805c4e0a63282f9ed4dde8428a0070819c38da1adb3d8647bb51f14c57ca32dc72aaaec99029a1b3c20d1199ce65c1a7943a4014f23759137dee4dd443a1e3f195a559b0840a215c7364b7dc397b9451e550a01d95973f670f9c8ec343f88c1c233c04b7792095a26f700d0adb13cfb8467baea153a50f9f5992b70ff89d814cfb7fcca365b05111a9663c895089eeaa5065d97440fab62c51ebbbc70bf3dd6b8c72ff2f0b8f27a3d4a255ddf50e6f1699637eefa55a26f1183449891169f4859d308a0487d00e921b40e929a0c9e4cf6b698d2f5a641b35151a0ad04e90e09fce14062bc7a895d20a078f3db7ac7a62c134b0bead5e31a12a4314d49e65a52a3cf695b8256da8b576d5cf6dc09e3750bcc6b7fb900785c62369fbf025ba0a83aab69cc00adc3eb0b5a7fffff16225793c208b7ac2ee76335ec9f0a87edb1c26c8e7356ab8b573adcc262c31df9f7c1d95fc100164810a9a7a89d8ebcd8c4edf531eef105caef8e1267fc37ea44849244038141b035a74d51e8ec13a2a69bd8d759e4e2a7e3c92d6b40800f39556f39148fd69fd9af1656b377081a79ca7a705d74c1cb39bbc1a47dfeac15de0e4f53722721c784e09aaaa9c81960ee72b800fa16259417f92228e838f0a7e2aa1f31baa063c2eb09096f8fe9ba32dc797b5c3ac1b20c05d4da5fe09b40edf69a9c2d37a11a6dd5bf1e54cfeb335845ac55423ef02073b35dbed767b3cb39fe396a9d7c208f240e575b3f0bfed6159c3ee6e698674a0857b73414e8e3a4daa2767b221305d609580a6a0af50fe74c642c4d589f30c540babfb8e2af707f5758cb7b9b5817a5762f805f1eb37815b60100428d0b525f6a91bae3009e80d6355abc6d07894e58cd1d24d463212bbca9be36502247872073dd7abc56399d7ad7884ff094a710073cfb48a2aa7390017c5e8f5e88d9746c28bcfcad6c790101ba44e07ab7741d7a7eecf033dcc6380560d056143d06120bfb7982086d35773988b00f6f1358bd2448a144f247f0049960bb52f36a27da6aac2ffa4c3b1b4caea8a9d5720492ba5722361cd224ee7d1b1a407f807845c2808dbcb274b3cf09e5e7394fe32b80b7c9f57c88db5961004f4a517d3e36a6b29a6953abe81fd84db0aaaee92846843051979e705755a5879834aadd6d281884181f067569a749f010a0c8d73cda131bbcac7940890ce9d579554e0dc37e44c5715384dde1c0871bbd630a9a6ab708cc15da7d3752c5cdb4dfaaba7daa57c3bfa335e41437d357ee2e1dcfbfee79cd70f95d3826c040df872a338e9aab302dd23f87f2602c37417e1f766b7aeff2e86f58aac7ba36f4bff1d9df22dd4a78cdf9ecfc565153fa107bc222b2fffc75e3a8cf9288b39f7f337a90f3e43e5a8e973442fed014f4681cebd9b5bf475068ad2150ee4485f5c7cca92b57ec8ea860f58d43cf0b65c0de47a1ca0904d5ace1e3feb73584b3938e28938b799d032476e58afdeea8ad2008a8976ffdd813e88f9a027d5e4c345c1f32769ec844e05bdd600d2c3b559f4e0dd8b6aac1ef78e767de7d041b74cfefa6d28fac56382019add3f74e686591832fcb8d382b99689e0908474ce6d2effcafdb32b82e83c4d9c72389d5eb297f6485ad024ecbfcca577773c45fb2e4f388281c9aed95b37bb9b7e8533d8d20481ede8ac21aac175e821f722de279341ca724a40780eb8a0bb5ec760bdc4f09b86a4d78efe07b6dc9fde2e72a5bfb2213605d828e5dbc46b15082a251bd6c31c35182e97cbebaa2b96f5641d961444a415aa8bdf29d36c8f777dbf8fae27624dc77b0932639ed9fe36e840c42b9d6ab2f68df2c2ac78c0bfe14dc235d0db6db9e9499aa7e9119f0b94548bfe9140a7d4b40cc76d1529657c2a2b58a3ebc67dbf5f9ba74bef83fc74c144fb258935286f9869d1ee6d46ecc0486f65318c12e8fd70fa81fd97582a64fd279ef88bb90609c8716e3b86dda9ab5b16631023d6daafa7b7f1bda133b1e3487e384eb8179fd9fecf39db25a6cb10b43920fb34a4a7087044d7ec5dd80a31519825a3abb025b0c07733017a6cdd0183c140399194a4ec550836ddb7386fad0311f8a764dc09a25493ed9f2ff469fd898def42fcea12a3c3070fdece0ec80ef90950ffebf28e06ccad0ac2472daca241881b2709899abf23c35591e113c823ac19e014292b43d010bdbd98e58e54b9b2f65011b5a978bf56225ad94d8c483421f47c8c3056219b0564dc0a130948f7b7ca830e4c6d079e212fd9196c3d0ef3b0dd803b4e80b0f9eb17dad5d4a53acb0d39ac8014b52bd9f079a8d05dd0a3824961fe152f9f346dde14331b78467db93cbb3c1b4c1926c2d6a2e37c6b82a57c53b20744ec078d974df7a6c5feaf35d38aee872c95945d7afe56d7f81194679601478b160e3abce0e15003a5c161603f9fe94c6358176c655468e4fe875accaf363ac42dfdbfda65adf97e730591da3cd77c5202fa74e71119bb47eaa5a2d40b289d1dc959287f6e142694ae18d0f261d19d6accea8c3573459a4317e84f28808e979985f00ed0daa8e8b56dfb7481f018f3cacec70abea5455bfa3e95a492806fbfa2ab8847cb7b9f9e3666e697d480c0e80c60e6e75796cb92161786b226966c9aec5b5e35bfc716dc263e28fca472371a83d542ecf64b1aa982f8b04f7d8c508d5aae7c392fab49bcee8581a085b24e811a8504b55da7cd764a198b9160f7d3e2c11118bd6fb41540502c069008f81eb0b546537522a2b25cb8ac45034b348fa9b42b9502359223945673d3097e8815fd65886df36797399904c0c6ef5d6169079b45722b20b7416447b4f70560ae968c5cf606d9241b1ef37e9179f6149b606736b7a6ac4a17c88f36b11e82418547ef7ab18707433359d10b63634161c64842bef558d2e8c49d7709a35fa9cd0dd92ef1227d9d758fb14c3192a0f2be8ebd867860a0c2d3425718a23e4560b7220865644348925aaf29d73d7b8d6109afd931526cb95da369b285867eab6d1a16fcbc25ab3c5df967805aa57550427d10107fdb1db2cc4b9e66b3d01cfd18d39cfe0c425b3e583e7b40257d35bfce7a8b381e2d02ee434a1c24ed553a73e80c996662b930a93eb99585401762aa723550382dd04e4eaeeae90cc7d53a724916cb3d9c2aeebbbef0bbdc0002640c1b9315f8ec88ca6ebd4a8e4dc2c648ca96b1539285fcffc52b0df971b55407a48b378cd8e853b23b5b3759b056d16f1d4315929449bb29c7b3ac11bb8abd55a7eba052d5cf968607e5ff251fce625eb6a1c2a41015b1b92333d0ef5b49f5f6647e7672f3cf0bd487691990a328c073e5d37910116e296680282b7c32762a231dbc736a0f53326a827dc192de09612dc1ebb51f5ac058d201e9d583da9ba5d9e0cb6a9ab0d60f26b1dc3fb7783889ccdd0838f3d306c308921708cd88497c18f6dc409ebcf399d85e207fadf6e2a871799a001bb9692b9d68e98a2aaecb66a1b18048fb9fdd388907911eff3d6b901b36592c0f520b626e85ee651074d913431ddfdc13241cbaacd39721f09e57dbc4567a5ef6e5735996ac3dc2b962a7e6ff6677e9911535f28a6064c973012266775f8357d881b7a2b47254941814900dc4957ac7acadd98b90a5cbec59f462fe03e876eda02df56865c0c7cef2cddcd90c50afb9090858e230b611baae748a23207a257def7ec8213ad86576702a1209281e21f1fe4639dce5a8c418fcf8e0b54a246198172639f7ab5280d3ac5c984093de8ead26fd8b9900e6ca9f75e5b59078ce8081f0da6c2072473be825faac491728ab1b288f32301fc5d3827ea719361a351d8e1c145daec5b29c75412eec517c71ff106f29ff3ac29054a31aefdad04740e67ab3c64179adbe4f7fb44bcccff5126649c0dfaffdf3f6abaaa3f35a86d7e171af0a228fa102ccedde034f5f8ecfd8d83390b6a5e5c2595983696c55695af3a925f0dcbd40e5f9306574ba51eb06543070b1f20fc72067eea3e9d0ec25da441eb64830db60a8e09a283c2eaf6c68ae5073532023f3b26ce49425464f088ce33ecd24ed5cfd274ccc1a0d0639735d05e687f8409325931e1686d9809988334b080ede33088a587e9331e3f1fcde636e171856067a9eb87e6a325e1541c54b60fc47f409f8b3e1caf3c9500d984d8b4e79a3f23361d79110995cef9ce460e92de7ce20625163a748b77c73c125d62514feb341140308b9c32e302e1258e3a2e698e3f3f96f940dfd6e2d639ddb0a1453f0c0ca356c54ddd15e5900118775134cde67438c4adfeb76f00adc524f42e902c570358db879e91287f802afa40c28b21ab86434642020bd772f64dd932f8983dd89e48503feb36c98286e273e774a1a6fe961ac3dfd31aeaa14e9bd1debcd51c0029ac02749c352eb0cbfc4676f9279375536ae865e37dd47b9ea27fcc09b91fce68af1853506315a2626a877e9569e303c383d16c6688ef7eff7bc1fb21590fe3b08c1e5266529b874f4eaf25a3650773d52244bf0458a93b9d54b29ff8358cd78b2cee0475ae537041f08bbe899479859fb6f5b80de59196ea545a8ba67e7843224668939c67c79efa754f53aad69d421e141aef145c9ca22480f65e2b06adaf5030719561e2b09e13c29f4e7e4f0a69759750019d508be9841724dd2346aff2008f0915308aa518da8e3cf65daf37a5213195fe98c831b5660c2195856cd4eeb270f90504b2d4c61ed5fcd3dc25f8ec68c6e9b05c6388c556a7fb8f3598710310c7804e91264baa03f89e02bea223ebe7b47c036eab7615f9ad961457ba06d94f1615c3402e8e86b61826d5b7c73bc801a9136f5533d4a3cf05cbf0a184628d3c56042ace01432cc906c2a7a970c1d438714125b2856eab6cc9b385a6a9e7c922b98721b7872d1947fb974143aff90f774d0d54ff48a348a67a9c216de8f20a988cb8809d72ce3157920c7de836dc67d16270d45ed79690898fb81f5026405577e589e04cbcfb27bda4fd66a7fb7fee93273a6a87a39e446e4eb77575cfedf8283ff72a780fac24ac1b9f4136f2d6063d7baa74b151bd358b0609c28fe5708dcfb9bd3be3a0778f12104143a60e0376050f08508ca34237d4bde89a97f2f57a4da83e815e295bb275dcce902f3013017987b20bd1528d7ca55cffd395ddb7153289770a70b0c1c22d7b598e349ec25cceabc79a6b30a52fa3d5115aba6db7fd1d7114b2c15ad005805f832b5d3a141dbcecdb949c40c0b43fcbb58b67730927614c0db63e7c029372789924b2380d733f42b73b91afeeb7bcb777caa3254b8db7dde25d99828630371e74085feb16ec56ce88b0326db5774a1f4676b03a12a9c703751610d3ed83e7c15f22e1056c7340cd27fd157e03ed2cf04dce203ba19df389f7595a055e4d1191f102795e3010998c9497cdcb2315f704a437a8bf074cf06bf8c8e31f23a64dc0be9945d51b580389969c500b557aec7eb350c94ae48d58530fcce621ba8f6d2cd5a5a34dec303d57c840ee140a25d5aa5864122ffb36adab2fd35b87dcbbc468f924a8169174d8b911bd0c96f4936025f6d543fed1d75995c9bb9e21e7a919934609e056847df77c992cae1f37a700ad25738e6c773e34d1c94d8c44d17e41c9fdb1d1ad5054695260c07366f71aacd9b1a590271f3aa46b6c24be319d314f1cec510bbd05578b827d4cca2a77f728ef216201dc494d8bcc69c20b71f7311f89e13abb0dc59a4ed7957ad387b9c3163d34edf6be498feaaa8ab5cc112c7a78d5661ab4e3b9a54b6f5add11f9c2aa89481f8291f782a21966d45bd0decd1064a686c872cc45775712b1c1da3068a8fc46788925b5d79895086e82832d546485b903632fe46b20ce2112188b4084caac0bb13582247b8ffc3d058f89b621905a6944ef857d2888fe63e18b5c0e14a3a0e8119253cb30d08d0cf6fdbb8c7fcc6557f6714b9998966800e31362ce51aa920c73f7611f21095f7cad924335a9fd96c0eca367a58b8bc893613894474ceacd04e143132f069837bd66906020eb7511cff8127cccfb63fb0988d36697e39d8bbe2471835cb727e33a076534decc8332d14c8d292ee93815d1d13a1e3afd66bdbb18c8a31bc3fe6587d6aa7f72507055e2c50d4a09fe3e4051f20bbb804ba3abd72ab6768751bc344281486409ca715faec4a37dffb37ccfce7271097cc5c322c26bd781a5bdbb786661658d0f89afcae741036dd46414bc0c7c8ec5deb0c090a089192264f63701e3e1246885a1ae1acd476b4b556bd3cd3c1ca4dbbd2b73aaadb3936a23f6794916aaf31e59675468bd41f0e698d6fb6ea76ca18103d926c297055525b54a22223d8e4db1027e475a07ed19eb1f8cf7a7e7e8e1eb1342b5601d25679dc54a273be624fc5b2d060ed3d948174e57d6f9769f2cadbc17689feaf371946645cba6456fcf2e45d99a0b1d8834c5035411c37ddbaeac15d79e1b2cb09f385df2d11e6166f25e29f6fa79a7bc384b953b865cfd6303f7283e8015a730b44230250d26615401677ed5ed5e784736ea396b20783efd22b43fd4925de330bc655229e939dea94d7f8feeecfb304d51b6d2f14313721a237cfcd0b02e6e6873f057de6f75354e106c67a93ffef7d1a267ec1c122430bae860b7829f81c16085c0831b41069b8409c42c26181d4850c430bb9860b1429941c16a85ca831b4d068b8409b42c76181de8501430ba3860b4c29cc1c16585c5831b4b069b8c09ec2c16181d3850b430bb7860b3c29bc1c16f85cf831b40868b8209822c46141d84504438ba8860b6229e21c16245c2431b44869b8209d22c36141d6450e438bbc860b0a298a1c16945c9431b4a868b8a09aa2c66141dd4503438ba6860b5a29da1c16745c7431b4e869b8a09f62c061c1d0c508438bb1860b2629a61c16cc5ccc31b45868b8609962c561c1dac506438bad860b7629f61c161c5c1c31b43869b8e09ce2c261c1d5c50d438bbb860b1e299e1c16bc5cbc31b4f868b8e09be2c761c1df2500434ba0860b4129c11c16425c4231b48469b8109e12c16121d2250a434bb4860b3129b11c16e25ce231b42468b8909892c46121d92505434baa860b6929e91c16327cc300a6ef6bff97fefd27000897b9e76ad6f23970bcbf546c16d77471ebcfb41d638c9e32a5ea57c97190da623cc7a7c696311b607188fd6db8cf32d269f61c1d3ca820f1916f7bb07b8d2fd613f0a20e33d3bbc5e3c0c0dae04bdf1bfd95a737bd44459f5c32cb02f0600ea0d9357ebea9ec716a081d005b396f7e7d74c2f3e3d10973623b23bb10e2b67899aa4abd723e387f86f8461bf725370b083aef98c849c592a960f79a49a732f99af228117ecd49125a59c3162472fcf731e6eac43416f3ff4a7c4e4a33ef0dce386bada39f0254f29c45b96d36b11a7cc6e007038b6da703b8762d64c935bd523e315c9523f5c55d1e36977400485178ff2db02cbee8f6887c8d3f25192927e622110d9fbc4dc213fe1f1f6aede6cdf5e47d7394624686fd9f425f6183be6bc9d0c1624182b54d78e12cc6bbb999649e423e8a7108314ad7006c9a03601699b7659f3ad7fea7dbea2f2fca9b189a4ecf0173964d688535d6a26822c4de47bc0b32bce77d6cc8cc63e9c79d45fc06d2fa5eb15e4ad3d4dc1f2a34462b7a24f80b5a2a81b6e547204985ef588f036836cf1a2ccea2455b7f14516fd4edc960d8d063e261e1d4530360167e30a78447caa74573e0077721bd371e8e11899597001a65743e291b5993eee9fd4c151cd2b48fc7b6c2173f972dab76bc02b1db2322b9cdf8052f8d2ce384302affbe33a9547c15446e7d292b23ac8de277b17ce07567aad5f9cbf3df2dacc82cc1ccb550f21045c06ff80315e3d09313b179415c125b3455c8f45cb57a3511c43d234f9b154c3a7279b0bb4ffc58dda79650982e2bb0b9b5e0da9e2be92516f383898609eed4d80f113fa3e3b6189c3ba2bf1b11e2dcbf46c0589e847220b72ae10e76d8470b0d9c665dd26ff840e35f212fb6116c5524c816136abc6dc3b26f9595b7b587f37c5169a80f79e69cd24126f3768e50ace5c69d29eb859612c96b7773fafc4e2e921200f0123e37e611ad546d03c5038eaca0dd68f90de6739d6649f91bfa87c239670465e58f0f16d9bcfc3ec973b62fd8faf1a6e3b39c19767b9a052c1b3d8c4f5df465a5357d4e62c8bd76f6ea75b2d122214dfaa8f3535aaa5f7c5d88f547f2edb83cbe67f155b8a02c3d0ec58d32fcb282bbbf62d0de3fc68749b2656930350893aa7df8a3d6d99127d3a13ca4c4681d7dc2c83041b92cf04faf198a06f2eb521ba2909899bde7d8fd7d1401e5e7ed9a3d96f0c4311d12fc52f5a34879a4a64db6aa4aedd8aac32af27a9660f557de1746c793f1dac5fc48d33c2fe378d1db52a7add694fa20bea95ff1f268729de67964b804c218b603c1473ab564238262437ab2eac8584e64a6c00d676d92f433dca6477013486e50ff4f8d4e71be7f39a518d3c0e4c0c80f9574d10afa6962bb8005952a4ec2a90172690767b6b16f6dbaeca6613e61e28fa6197db7b95a84f13ca6626183f412f732eed456bfe9a2ea134f3dc73cffdc23d4d612baf368d8691f0eab78215821538190fb5255337690911ad08e620d403ab32cb534885b78e50ef580b9aa71f8edfd188d209bbf5aa29e27e0101099363bfb48bb5e025ddb44557d693c577f4bfb8362b467d5a81fea18dd7e4655e645e061d314889ad8b003731c6beac1f71722c1ce42c8f244a7e89322c423be0517c37e197a2faa31606213fad9b9f4c22d4c242d8fcceb220382b79e21484477c0edf5119212c98611fa0eb9a198707960faec048dedeb73418c4d87484e1801402a8de4ec4248ecd7870a563eab9f21852323271d740f3a07e8400b947a018b4a2d101c31832dd65fa2881e3c785d110c725149c02f034a1b31ba86e7ca2307678c611ad24a354793c01822277ad4afb53589379ef5680dd70b474f866244dc7cf2caf980a4f2c9b831bf551d07c3f405349028889a1e2d4c64288de546e2725abedb48046fcf459020e413b6fbe1273901e52124e208787608f88a1c6dda7fe2f69e5fa997e46e663cabac64679fec2a7b7764982c34791252f4c821c66ee7039a12eb87c8a93e39cb1ce1d0968eef53562ad5e092f78bdd39fb2ff8f10349a99f025b6e3aca0088867e28b369da4a8757543e28c47d5d36f9f746ec144978965cf24c697fa8ce7639604c7b5f0448b01c79413f0831b68bae40a30b48c69be92a2845bc29b09b52947183ef4ce697f98c38e737e646cb46f883d8fbf7a9909dac7765421248c9bc142bd0b92de8b84d19f0f1ee8fc259823a857c057fde4d0bcc33cc271104274997f492dcb7e9a4ed89443a99ee2e8b6b4d5773e4a3a224171a11a47b8b1154171442856bc177cdb8608f701d2842259e1d41d24c2f4af23974d3e20d0059af43a42a75f6ed0be7edbaed20b871968384ff41450a5ee45fbd3f00f03217df17c1022bb759432acabb63333de49fedf60d9b3dc96e69edcc2e6f9aa6486845314d0653dbeaef795dca14994cebb301f6300a206221ba2daadbe0cfa6d53776a58c5abb50a74d879aa00363fabb58c0ff0178e1b164cc6e904afcc82a4b8fa1b296f01a2271a9f8f7164f1800a7ebc37c75bcb9450a9485539d16725c2f7c3ae1dc554ac76de60fc43c9e63adedf42211dadb134b062ddb03987606c4db3de3e4b363172be0e2e84c7877151ec82b4b3e0f9e7c3990667341caf895f972f9f90691ce1e37085797dd97e7604694ea5e27cd1c5c609183eb87369a7f3cd877753d0c74bef74f0efa1e69e0f45b9f141d36cf8715c1721c00abe2e22d0cd5e620e14ee699823722182e9e17812e56e8e4e25cbe345baa87efe20348cf561f091fde3b6113b59df303c01c6abd46a0ad619ff6710d0f693795aeae66b7ba83759c68e00d565062d877a70f9a9913a39bf56cb90b1a788f415381439a1db7c9417476fa7b72102c7f551c424bb27812b066b34ff39f88300b0066103de0e7786ae20b0e69e65dc9380044d21c7fdfabe9c1e7a62593444f64c74aa81ef55fa84284221ddb5a897a69900175c47455e90e452962662c758e16054190f6cc6b5ce568ed84a8f6829c0a77efd37810194212c2ece3265d8ad36546d9bfa307ec4e0c9f2931a19a86b68b7ad84dc90991f152f6b892ed71451be807605ee18fc86a02e8e15b1a512c04eef28b6ad66a0259ab3790f9e2635634ccfa177493a31ed2b7e8908b2fce271510ccf2acc0b3e1b617e9c458932606d5aa2364f04956dc771d177c1568ec826a9e3cb47fd61d6837d7e991e7e1e1c61fba9482ead08e1f8e63204ae9cde9fdb8c0d28f61babda7a75f6a3e5f286fc461b5f9cb7d462de09a928d53058e58b5aa96bd418e776ab53a361fa1c906703097a7602fbb4a21223b9f5aaac3ac22afc471b7e51c7348bb665eba151652ab65adb957f6494325eaae9d6e3e82bb5fff1c2f30c8df1ab75534aba260bdf776041ccec929080e2b634dea5147177dff498e259d2904cb8f3778f00ff2f5cc45f308949b1b8eacb788fecb9bc50f19ef7785afe9fdc8a087599fd53ecf56fe9831c1af148d7e1e4e5f3b37820478df592f9bb3bac6f781103784391f1d5b77d31cd7e331a0ba8bec31014a62fad4557373ee8d7d984a1a0a1ce81de8c3eec3951caf178aa7aff77645eea388e355c4282faf251ef1493fb9d3ba3c9edbac3f58e8d2ffef612b671c6eb47fbbeefbc8d1bfda70cc3f9ec8c350b7630f0034596127f28ca21e3305e30a886ea0be2fb8e998679b8c3732ec1d830fa47d44dacb86f679b6a408d68582ee5dd97c71165aa942563109ce9cc4233ede042dfe4da9213130269d25efbf44ab1374a6e9b117e5ef2a481b4d94daf8f3257d168bcff9f6bc63991d4c758f5062a21bfb47c80489eb8653305fbbaae5a2a7c8477924d22d5aa57982014b3b0700b4add707efd1aeb3691a49a541f38eb92dbe0ada0686679cdbb946d863362299e602f932eb48676e30841e14c7bc45e618d6346c28b9b1a731f13a886caacc6818151c4c969b355babc38ac2c7defc59179f0d94c3c486555ec886456e236179ab1ed7dd08bcb2df556143a5aad6044ffd4e024108074ecca2ee7f300a316681787aed619b03d846c71e811c3fd09fb980d1ff86cd2f45bbf96f20ff215e85dd27613be688eb2b4c3ad6e73a0c151556aafb6b80fc9854d1496100c20153eaf3e416a34aa5883500cf0aff26185fb199f7ef2463702853ee70e12cfea29a454c72a97b3ff19c09ea360848f71301d019801ddd4aea4e22c8b07a075c50b9a1f87b47bfd0ae6192f2e7bdb2a0292e4d999b206763b9099160472e3ac94e71e48bcc64a3192db34dc9e7bb5a22306d03357f84e4c91944fb72b75a747ce5485961176f0a3106e84bef942e38404e6be8acdb821a18c5970d596d5110cbda01908a10d704b2b394604988d5c962aed6b78589a77448304de231650905c037142bea5e3e5dd929e63fe8bd6850a813c568bc8f51bbf7f50b040ec4eab63f26e7a00004f0e2e74d122326ce9445881db48396a0f8788d5f0dd07c7c5d0304231bf7e8a5d91c740261aef1f6e9c4aeee1b9cc6ae9daac0fe0b0baad32641b53eb0b6ff4d4f4b836f9f265b771cd618d3b028d37785a70c004682ef250c13889b0e280cdf531a77f8acbe2f254a719fa1783e797f960e493ca78c64ee399ea518540f6934692e649c28aaaca1ad3eda000df612f332dff7e3a6e0dab133e0878f3015bc51d28e0c983776f424254bc5e476cb48daa22610bb7c594c57368eea094a58a84db432ea1de08459eb4916755039aeb2b7767fe701b8e81921b7eb03069776dae6523861f16804c7ba33e4320280ce250809ed976f560d9809de63c61c1cf42457d0e3e85d919900dc2e3c8905d395cdfbdb80c8e17a2ee32f0d42c9087f2969e2fb13a1791a3e720cffedc259dad06628d9a05076c8cad474d75a2023a22ae63121050f2a5f73afc6050cc017c8ac4a04ad9a15f37d1701f0ab8f47bc60dacaa08094d3957b1f80eaecd27e9d2657867b70c18c9c7054eb010843ed5bdd0aaf0276deb7813fb9f3061d2bd4fa781f07d27c9225fcf94e81de6f804cdb5dc2c23648cf14ea2cddb2cec4a8ed6a224afda8448aad752c4283d59f19f464e16eb61a44df4c9a91a554e142ed860dc0cbb303d2f888d4ca5ab20f4b41dd7e90d82914ddf5610b2dac83f3a1f91e03eaacfeedf853889f7f4dad54eafe0d3cdce8f8790e25f2109da4f4eaab366237b82a2ef19945ff23b830bafa580b1b697e90534b2fe65189cc054088608396ca3c17b7594864162afdfd0d7c03dc43a0c7fc9f843dedd28c0596f197f986c3a044dacdd659c68fea61f8a498449c629af1a055fd9bb56db4f095fb4279d0f69a7bf3b5e24c6d35fe63b73ec7d883fec1ccf22f2b06af4b15e248901583226c4672393ab00a47c1ef99ba1bf3008ab86fa8f95ca987419b796d02d37b3ca5995e61e231aa54552777addc033f05a1f603ffbcb84fe06f5fcca6f4889a2cb37a409d4760e4e0e91237d40275f623b0e34faaa590544b8ebb02426b3b1136dff214f503adf207b81844d3f60369db1675fb55a7fc4bf4d6d0d71ae88d9b4d065408cd9f975293cfd16cfa8eb6bf32d305b4a0ff0fea7f2d83b58e51402f5077b77353b0a71af20b2a384bb37efb4880d805e1275dea5ea23ff3f98726c556b682701cc1ecd9691da2962e83ec7eb9203a3ead7e53826505fe400b3b1ccde309e70d804eba6769f29f97138d673e9d5dc1bc462187888f7a8eed87e3d6ba50c18bc012bde3dedc5b841f174b3d5cac3faf3f9b6d954888d6ea9553afe44cb7d7b130509b31e9e0ec6400488f0681fca1a6e1b2a9884b2a38d541bbe9b57ac762a31c695af0aac85f89c3772285f1c7d31a66f8d254f687e7865944eae286f0bc67fab311d71c14646be7592a431a00f016fe19ee66dfacae32ca243d573d2ea300045abd8e4727cb4640d074094881ea5b03eae891c61ffbf5c4db8984ee328604f63d9e12faf337603fdf9b43100041b2ce3caae383facce9c17b001ebecaa0f3f55be534142f1cd41839a5050f7b08cf5946ca236ed1f3d53bb8f70ec44a31ffad932678daf97f7fe6cefa394f1e94e9dd13de77adb4522af28a56fc3edb2be57ed648bd24c620791f57fe6d5ee55abcb2a465c4478a162bcf386c2b08e0111f739827b4563dbb5a7fa0b8a6c3b951bbe9e7947e8321db046fb45b82f910ea6d32743a53c2241cf46ba1f1c0dbc5ad191abd3ca489f9316330cdadc1959669e18d14857427efda17f2aaee0128eb12b163d88b0a5b7ff191a30fc6648d31badbc509b35efc202d11733a0310be4d2a238a9053a69d88dabeaf9ca7fe6aebfdc2392c614cd143f2553877ef8244ba2902800dc1fd643e9eefd809a6c5fba39a35c16da57f92efbefd20f68a06b08bde105a6c766423576a798e088ad10e9a9bbe01b04a1eea8baf82ef7db5130d760c41eca9a8d552295118fe8e20ba315298b8972a12d3c6b85a3154800eae17dae65c3d85bc01a64e76951a6606d9703cf9d2e21f918e0488b9ad6ec8dce07d09b7d8ca95269de6c140c1b83e3fbd186bb033a1620fa9344394a35deca395e93a128f332f61898da01c7952909118c4e05d096ae032e724f111cfc65c599f5683a00db8742214fdb1ed6f559d6c736f2621d17a70e1883f00873e38b5286e04385279886c579bb8acebb517083c320e194659044233b2282d826d4dd1cf399066eb9980c909a019b35c1e7987c1071e0577e19b1a8d587b0e036c0e00e51140c2817012f6b3174650a9ad01784e479bf00fe0594f3f29e5da1f756facf1bba4cce9656da383e60f7f313c2bf7f6aa38cfc51085f0d9629252f5719645f0831c9756d14f6e128b85eb66352d4cb4a6738387426c7a34005ed6be7a202ef6965c4273dcc978b54f268b929c4b04ba26fc33e12d20aa40dbd11061b015f908b556b45cf8db558b8e27d9b8818cbfa427f94ae844087b6d40016bf8ab405e25a1306d776ccf8f8fb9325a202dd607fdfcb0dc4f2dccccc3e4aa49e9e28d1530d7f1caddac1e36f1e6000ce4e321f9d3338ce8980fca314e629b28d28dbb6fdda080b8e12a5ab26058d03c2ed642cb02959e03e9d8a9561730419035c5f5a1c971d7cfe6c85a883e81af8225c3f6c79e7b86b398716d03e4a5b6232eac0579ae43eef35e218a43748bc0d7628de345e8722ab3476beafe2aab17f7c1af9289401c68634a069b39c4b0bec7592f355f9cc2b14887c08e31185f784557c7dd9bc43fdd62866130addc07099b53f001bc39b6a5cc64b02f35cb87ddf95167209a6c0c55dd64e8729616ef4487fbde3221220ffa8b4b5a01c09c19883a4b547e586d7bde77783e8891fbc67f693c543511106d6f9b8d8b4874ef90c7cfa8a27f4af055bbdacfa190c2496d2d00d01dbc631d5e936c06c76922fcfc670564d7f6ad5225708b5cdb937d34fdbf3f643a54df9cbcfec6f6d611089a6ffa92c15e3156c2503a6459b92a00c05ac4e593119ea98fb22f02f6b4d21bf1cd3d8029856a01e49a019710cf38814bebe10d969da999193a847d2cf095fa0fc0a169876256cfbd3f1ad05509313191efe91942be3281bc4ddca95359b7cbd7b2c277185f537bf985cb9cf9cadc4983b7a9427cd668e893f2024940661cada455c3b0321e5df0a27f5a677da43d120edddc4723525aacf17b629d51e6025abae2167dda7b86d1e830ef3217fe668f2e3c7e629e54dae3b2663a1be1aa0e72b7e39904c5c054ab99292e0c01509d5852a56408a5c4f75b2b57ecfc632a381e800467be3aad8edb82762afe72b2e631ec57fd3f2729894573abea8f5dcaa8b979939ffc092ffb2796813b1b946fa2719fa88d549f7add02f3ecf464426d64eb010a7f233619483506ba107223a8c8fe510c18643ebd66546ce20260347a3ffec6b2c241e7738c1f63a07a14c1157db79e48c1b146ca40ac00a349af3d13ae89f2117ea0e81bfe179740d2960a8cf0a61e3f61f737c0d49fd5bbfadce25293e9b8f14204533bec93d3d31eea334b3ab47ed142a6a28fcee482ec6d243ce60be9f1f9032af5cf99ef72d2e4eb08db51ff7ea4d032c7bece5c5b08721ae25be60c07a5a1016b143aafce5467eab85cbc5da0dd27fb1e273168bfaf6628e7774ae344f223ab3997b2db3144edbba6524916944a9b8694428d5dce36dadf54f8ee2991f3e5b42a846e4daf9018334876663d4a92b7c5f9db363258504622341c90b2f4bbad8a8befc21bf02aaddfdd43a7a70df7ddd3c46adee568ce66784d9295b64a9d89aaa0dc23646d3db6918450a11d341018ec225694e41441de83a2bfd99e58e96f28db6965832ec9857872d0628f73f22ff78f42034ed009dc9fada735174c91655a4334dcab990afc1cff742f70b7635dc06e4170ef9e595e7090e93ffe3447669d6a63c6d9e8691321194956b85acd03eb74a84245e31088097901c0af02b2ad2a2d674d98fdc1d73ae09e08d454e6a0f05b958b55dd0295a6b35eea81d8baeaf67c26ee31ec5cd0a913e3e708adb9a920981b3ddd59fd21781d7cb8c984c562a53238b7db6fe71c47f92800dab5ca58dc6563a2df8b350b3c3b916b68c1a190be8e0e7f865165e6d1dda14237524e02c79f45d6d55f7996c7e94a4198f54033c2967c6045c9947054aee30938701b784accf658ad0d3fc4e2b6c751e537c4fdcec6e364af0915faf68721ce180038dff27e5fd53a392c7d3a896f85dc792646766f889e3ed84161cccb21bb9d4697ef37c31c780c4666700340e21e6f8c9b9db722ce5bb7bf7156fcd04bdcf135f05c388b3d49c2896ec4a747593c75e4e1921a941bc687f408c2f4073b000616248f6880848cb8bff114cb1c66fd9569fe56d9f4fff3e10c0fef9d0540233237fe28b8750222014db3bd09ee5058c8de10633117d3a0d3e4ca8490ce10e74ecab6d21528390f7e051123f70a89dc2cc2b9e095a3aee0e77f931501b83f6d54bf4f610d0b214f21a33a939b41c11f029b9be55711ffa68a0efe09972f9ccb8f0727b593d5c2a84dd60e46ef91d63ce792503177447ef0b47aa4c8213e220d55bcda91d1fad8141b24613b70ffff57ed1c317ab49941fc9d6c61402cb65f9fea2350850e397d49eeb2193d2558d378e8434e57e464054de16435c3d780b62677cd0977385c3dd18e97d0dddfd6024043da689485dc5edc64d609693a6c8d0a0a84c9c8394c5d059433127ce15f042ea0f0e38e7dea570dc3f887ac9fd52302c3d901d8925e0880b7051db18d4b103007219802b6679b3567b5e7ae6359159c4c623e64544b704be5991506f959150eef7c80424ef4e2cb82a685885a07f03ef2a29c8ef8443655cc4fcfd03ace5fa7e80095f1335e8fa1b6cc52bb45ae3d88198d0500877cd8bdc324a7917cbf8b344c8b6b3ba9a9e146b50c4973285727ce05ca5d72570df49bc37ffe5cdc9d293ccd3ce3f2ecda61aa52c0d18e73a4722cdcfc79a8e41630ef8d0bcd9a67324a700ddf2d2633c44fb30f0dffecd11b42bb1861b1e4ccb7faabe1223a2a437609263247cdc15eb0f1f8d5fab482d4f203b1325719906bf9a5fa1e185bfd7b2c4aa5c5d15d36ee3ec9800c040f85d5694b3400a478a467eacdd4b158a46af86c3e8dc9993c8394e8e20b9f88ef022ec75df260019ddd7edcb86ad647d1ed3a83d152ac4512ed45badb7156b6199dcfc27e19c8e85ed1d8536050b315797a071e44cec8aab98e0577b0bcdf13f44959346f9cf32e3417370699c83eeb99ce862b6ba77758b53597419874a3389066f02998b3277730a1de549a676a0054cd6bcf0f51e1b2efbc5f6869a927a846809d2df92327b0dca0e2f342216d6dcfd5952894c4d58f8c7c93f0885e4a1674a61e8761194ca7be0ba1be2f1cb47b90dd6fd6442fca5a897bccf1cbd5ad3e24d6df84bceaec8499e60b29ee66494580ffde220cb640ac1a65fa7433b5d1883171e6119601833ad1bea74da347888924a7e82364c058cd632d9c32fc82aa5047b42ca33be36bd125cd9651cfdfbd3582efca92e215456871a758c935fba765700df312d3f133ddef53fd1006b4f03e728c1000bb77cd8f60963b185820ff3993306f1eea7778d8275a2af39f50b6ab592ff1f8eaea7bf9ecb72b4a242178d7d72ee40a5f4236bfcdeaf1d01448ed4407624610b3b035ca38efd2bda23b8aa04461875f3a90ddc0ab9b901527c3c86331d241bf0c1f6ab6fb5a06a1953ad1714af6bef29ed8fca29aa81cdda13db81ba3373b2d555c3e644acaace0a95995203d980d8e815716e3beb8d57a17fbd22c6772afe66df30ddd730972059ca2df99fe47f0a8a2c53ffb38fcbcd39b0b5a18368efcd95440781c2fcc8368d694cdea41730ae3083d3d7cb3ea9fd5179b33c98d1e483368a53ee1e075585e5a870320ce4b8e1fcecf11f1eed4232805b1ac80ef8d2d37e3869ee2495a6b958f5616d4cd34a69d859b36770143357ceaf8de43768933ebb3c6af49d99a67a9887c76b6461fdc6d5976323023aba803b12ab40b5a0b91434599c1fab3bc3572d37727f621bdd2e399bd7318625b60034f3b3328a2603611116258d18a3692ae65aa129c99114a1ecbb2370d51d0e2f9f15580548f6359138e5af1bbc7ec532908722f82a3a2e646918617d1e24ccfd16f201f093339aafd6c7606573a6d6d31b1d939a6bbcc328cacc630ed17f80979c77504aed2ff7b7317147fc7991594c923c8554a8c27e6b41235b5152ced7783f45b377e72d02451ed6f2181fca65f86fab0901c2d892c08368edca9907ef63684140c832352723ed46cda8d5f43b6f706e03e99d4cb6e326545fe057f2d34919ce2721f254a3829fdead00e154beda82d044c3b7aa4c619e5a8a2dba5560c7bd123137c0ef8f4574b5780070f3973ede75512892abf7f932e7df1a976d96ec4e766c9c2d37a006b4cee8336aa095249a787af466634cc3e5723393a2b9e9156f15d0f496d3231f080e34c53ff51ce569a1be50cd9be5e7a3b70677f50dd27a5b74ba78f7818c457e1417a3eba17dc5b996cbf33e5662a39a07fcfb738d8a5842c9c83b360b33d406d2b6cdc616ab9971304ad6272be0adfee5b8ecb5f6b20495833aef36c050a39e50559d9294f672d3034c50b6c5e71327d418fa926440d26757a3026f024dbbe7e157945a4a98a36632591bb7553f6cb1b8e50b5b6eee2fec66f3d67cb248bdd336f062fad8c969655e525c24bd3a6e5938a7a53b830453e8a7850e4ed2a4f93f67345094442a5ffe56ac7c66cc012c06473a1807c59dabfcaa8578b02c1c6805147b481d774c82405a3bc466841da1dfa97a8b554c84d307a28c7ea819944cc982f9d6ccb031e410238dd370fa739889ef82b57a6951ceabe79f7dba4de5e8b96d7217d347af9850578a5efbc18a5a9923bd725418d63d1c76e4572d2541ea59efbea4932f87c9c4a417ea998a3fda511e082fb87b2683c6bfbe1b21b9becd068bf93d5c1391a4606063a72f7da67931275f74d04d3b80cadba91cd225e9794b7337839e90563cb733bbf49bad57fcc3c2118d5fa13c75be08cc3b2a938c23285862784c551910b38d2e25220c2a133c79a68cdbad4695bab202209af5f09d7dc672d9bc73c2675696f832e308af88dec2587f2866bbf0775e4fa66186af0c563acb8942205ddca5bdaaafb976eb5aaf7604fe6af13b2d93a41099ab5f00b07dd0823384edb17a40740058657a8a475e1761b622dbc83a3da643cb714a6b03c50777a7227a93c66f785e3ce1376317c81dc9e9c2729b4f1082fe22e9b93285ad422ef5b585339497b5bb5a73468c855b9b2cb32142d8540193f4449429d7e819306d3d5b324935daf171f2a1027d04fedbdeb23c4079787cf6f0c877a280d1c41c3d2e83f48fd36046a3b2fc7a7e97201d2c92bc942997cb410e8d3f7a856b811553306279dbe4a8152ffbce23a4688ac02898f4999f0da7551f83601f86b7726523c6ffe1b0ddb09e6eeb06b7b53180baf7a75ae2b117722e9859b7bf52570615689db06e39421a44f86430aac110a187335cc985aa88194589fde12e5ccd6e34a7fb63729acea772141960f6ca60ae9c7fa68fcbbcce3fe905a5737042d6e7e916f21ec564bc8865e59634634937b56248357488de6389d9519347ff1b9bb1126f204321af8f31e9267d572ea3a2fbd3499b610b40a6c977375eff08054b0aaa2c2a50fb9a33d13b3dfed7eac4ad22dd5e23247d95dd99f3c8a1da67bd29fcf4235b561635268467968f9af6fdeb24f91c669f845d1653bd127fe7ec6f58bc81213613c0e3825f1db1f5d861956c1006e2bbfb09254b36b1040cd9738d1ebccb5435668ecad425da0090b4c0bfb46aa70451e683b9505ded3dbe5d2c3980cff0e57b3d9932079fc0327c81929f1c66628e68093bd04f631083724bf3a4305510944a81aa12b9c6152abdbe7439054071333e4ef783fb8892bf6ea075489886f4efb69fac44941d192b7d257369e709ce4219c5ffc6ad053246ba01600b6e0a5a0a1f5abcdc908feb39317721cfc23bd1ca9901544fac2af079fe2a7247c310b3d091a1aaffead2b16b7ef69ad21c44af8c5e614fc07b57c6f43fbd2889f7b0803ce271c5bf98a46d5dd83489dfc736908bb44b5ad89ddb0015c47054a32104f34ad26166d48f9fd084c99ee0735c5a860308e40ede8a2fd8553e15718185457422824d82e84bf2e4352ca129a1defcdcd7476bf0d4e0d196d9ca66e329af8c7133351b3f56f9e34b313c132c6a10532fcd19fb83519473214ded761b42c5d2ace1cdffdf5af7886fa077c8f8a1fe7d09f0989215bf964fa18d913f22c8e7ea62e589d6a318155242a40823f39a71ebedcab503efb6d8a5e7a7e0e05320310626df604ce0df6084e9df6ae12d2a5c405f464a21301ac3e6b14f4422a42071f8de2e1dd9312c4912964002f6fddab0e1a5d15cbc07a05db5d6fd80031b1d8592b7c36ed7f8d00f350a73ec2922e6d7ae3ab58e012b5feae4881af5b0a794ad346db08ee5a515ed7f91d91c6ed1cf580744b248d4a14de7eeca4be7ccd04de7e93e323de8940f5bf754dffd96b915aca34cb3cf53c26b9f835d056a557e84557230fc2570103660861e2965a14447accdf04f1ddd3796c73f0161d8c6ef77e43d3d5c5c67f00db8052dab9d0ddddba29660cdffee54a52bca5336f0d2cf66943def1521e2308293b39fd30a62e5f91fac4d93e9126f559f72239379c431ed3fe7a5e34f66317fb68562cdcee1064b5540a54f26b0e1345e1f851b99f6fb101a1443e0f4c6b8b8a7a1c24663e32e73b455dffacb500e3a0522ad2aae68808f035647107d7aef468777073ea8430493cd3d831b2c78fb217e58b5a07c021f1f1b65e56089c61261a410ea87930b8f868edada3a54a4b4c6fc6490e45c899cdd13ced9f1fc7e8bae7ed2072c7fb3c46593dc7f96ade3f334623a3519f28a6bfdc8784b1e47824e2d029b9d87bb692664f4700889f26dc6dd92b35c327f8438b90da408212caf2c4ffb0404c06918550d75036bb868b3cdd6d5e61103f986dd49154de75abb6f8692a7f4ba408d9ef7d461cf6c741ac71b5065e8f87c69493543484cc31df4b6d500c7df646d96423166592720e48917a7c2d4feab1f2b00d18afc4210432ca74ab39be3061b1fcd5667895ff4220f5a8ac03667616016bb46dc28b24b38f05a82fe262f8b78bd3acfbfe3629ff99270d5ca16a826fab2be0ef456e659833a1b49665bfed2097e41c9ea228b609653d6f4bd0a4da27fc736fd9d7ea25eea149a47d874adac34702ef15840aaf06bc3044ecafa61bbd2e8ee2d16721b6e20ee2a0f0ed82c2b1548da83e4c3b96566f4f5f3f661de5a79da1f651d884ff96f70ede122cc18a155d75d1f5efc66a6466819b3683121557c451450f0743eaacefd0ccce28d983c09ab244b31db0e6f07150282a6846bb272cdd57269289cf66646de78e34c71eff8340e14c2ba20295bc0a666e613ff2ac57102a5127a9f92c76cd7efc0f828eb6d67cbb21f9ea39dddee2a24a9d021636252eec79e03d00cc53d5e0ce1bde5946d94bad82392ec7b8601c48f3ae5468c21edb10886f7fb7efd7abecba481daa89301246a4af792bd40277172f8d1f698ab8c2edb2533d991b0fd3d30800ef2fd430d2d3f2c1bf6d668fb882e7fb4889bdcc1251e437be7a1cc6bfcaefe22521d37cb7cc672257057883a4fdc757d1cf38f7cad24b7544dc1d10fd33e47b93418f2cb687fa8ceea1f4b1c8dff27787c874933be67dd0cbaaf57743adec1c0aead0918d03da27f096f2311a31ce100e350783d9113056d0d47bf199799076e374a9635b0215f45c007cb520d9bc7457e4153e559fd7095ac1fd5e82964efd8adc1fe2ce671262bb22bc87cc5f613f9f820fa5ec258ea5fd27961d8ee82358b28ae31e74b6d80a559648989c3997bf1270dc08962206113205e24a9ede3ed5f14eb17ede5ce10b791853e11f4b1bdb8c9d9a9e3852569fb2441acee2790a003a33ec8c7bd0820c961243755b40d246a4b46d50ce0270171db867450afe620c40f3382d4149be6d224f8c1a5f6ea405349492a85f2a305c2b5ef7c46b036c47e7bd9f4013fb4d542969e842e38dd07d528e554d41a24834000f9fade5702d84b4ff3e501ae20bb4e99a6f6aad4f9586fc07a3eacaeea33b7c72864988dd3a2a50271ce6979d468e9c479819ed1b0461f0dae9ed51c688d302e8d1887305894debea48db188c88068eaa1df136167d3e2105331acdd1ef1236ee31f15390350badd6c0c0af70be330cf5ed2f6832ad17ba374ddc1df0c503ad89c64933864cf954b810d3b4797f299995abb9bb4f1c6528a7ca3bedeeedba73f29019beb3fb6209e2186b582bb2ffb2b6bd6bdf9957572a1a0d1a184c9b56b9abf32c24920369ae7b6a6a0f17f0e8c009c93ffcb7650d8045be3b9309ff245f8aa7e0922bf3622d6ce75db0c1ae102b9a42fb257fbcec11052b882e0bff1c8a6d7df076e897db748ac8083610926970eed144dfb0060d31953933062833448990ee29717ab3cee61c227c12c9afbc5c850f2cd6279bf753ecbf7492ff7eb0d047053d907996b2d243799c2cc9e1eff341fed582d2b040be4cc47921f1af1403f9e2295d0132c55eeaa8be790e5ac248a123876939bcf5b6a81ad86179ce87905a530d424abbda9ea9b82de2ab704d6f39d4c4d245e192b049fe2368889bdcd39ad6ebe106a20871b3f3961e09b547fbac825fda14db9c1b4e6a9759277915f40542a8aa5a96d16e98b34d952e0c473a2e59e76975724921ff184545fbc21fb1f2861ab51cf6d2b8758e89f86423bc4ef0ad4af558dfe192ea64a25f2c128e311f66c6e8ba424228d77b8fa05fb6cf96c148a170de356fac5f56ff1b49b2fa62152b8e6ba9b874f25e12aa4b10380b4219d49908552783b670147746cdadf1841973ff9bc64ce411266acff31b75577e097700f3e24a4463a3c2568cc1b0efcbbe745a0070f0bc243869974a406295b395304e3ae55d8c2431f4a0385989dec2f71bb340f78d6af67664a26e747c17fea49531fb2f001d82a5e74fa21d1494bad892610df74b58fecdd97475af2b18dd85f05aa4c26bb938e5ce7a75d3851a4e34b38f6b11e3c549b2ff7346e8b090e7dd7a9d2153a33281755237e1d1b0d816abe35f147057a5f07f46f062c0b074134d73e2779251e956c2b3251177b35e12f5f262e546a4f77f18126fed49db14affeb53c7e0aeef0ba7264089ac537c7d82bd8e44668e74a6ddda521482d626b0c65d3e271f25d62282d22e09e91b63d52bd2a17e031aea871e106e59e21e35785dfca850daeed6aa0ea471b896b9c37a229c84efc241de8e482383fcb40988aca1d77679f11ec55b08b0f1c44efffff7d5e1b7d552aeac98b84d9a54ecd732bd022dbbeebd51282b5aaefb38ed9573d16435cf65001308a1a09f4ada88f191ede5f6b54d90e91a5da6f7ede0a2fe288dda735d1c1a38fb0761941506fb740ed9d3126426f38c87dce77989597145c404b7146ae7f06f290c7bb82852975b266c4c1e00ac23c8b76afca6cce274f61db3cb9f93f8ae6ed56eb08f744007ec18e2797dd8766e5d714dd927b5a13cf20499a2fe214c3827b39c1a1696a534b3bb9a2d56270ac986a151014326316c702d08dcd0af2cad1c7c7c31f080ff6da6048014a98da62f08f296a0f06410be668b2c06bdeca4a45503921f7af34f8d8864ddf00c64c5f97ceb74fb0654c0c4d722e7fa229cf703fff7e2d9da3c4d007929db106d91e59022998f4dab143d824cc4020b6c90b2fbc56741cac09d3ba29b40a2974487e0fb8db505b7cf012faa9da744c8590ab183beafa5f2c6b1487475f8eeb56f5e2b17b2bdb0b3c156df0641d5dfa12f36777e0a4cd768789ed21faacc9a81dfd0b06fdd75f2f6849ef89ca451c15e4cca0162c3cf18a35be53e4f7d82889fca755358ab34c44b12d7500ac7c0d744c02d3f1553a5c74fac879cef2107a65773b88d2b1bf16d1ac7b2c4a4e6c7a43411aab91a86b96d6027b14c9b21a650e4648cd192cc57bd35c1c9811fe67196e3b735ba0595e12117ea1c843db2fc22e91908fbbf28ba3f8851287173f1b584fa088e4f03a507268b2d6bd158ddb9f977a802783ee35096f45cd7f0604e7948fbc559ef89354e0faf5b5b6a5822dbb67a433f7496a4bc53faa8dbbcbd2951d623283c4da64cca952fd9368f3491894f6a3911202e3092af256ebd72035eb23e82c1a8ca92a08cde3a7c50940ece6f3222ce930879dbf29f61498b998e692f4880c4057b5bdf143512fcb8ecff814597861826b421a0d923a2b4ed5d1d468cf3fabcd3ab31c51d90a41221d21f858ce407689dc4c9928cc0d9e1025becea2caeabec68aab0cf56efc0a94ec6a7cd7885fe020373e5328cd1744012077208cd5d9afd1c8f023ec911c3351470a28d2dd3b32998f856fc8796025ad118ea181e7f2536403ed0727e26f50a4c92f9b20be0a150c0adcf941603aa65664c87995c7362d69b244a2d6c6721813d473ae94f2e2c44191adaf1b275214a49abd290e6f43a68e1f870de5b9f3654d3094a7b6578fe95abdef5d1295bb23b54698a8aaaaf4e294d2d20ebfb63b63c2f97cfb589819865756f9ec9d7faec565778410eb0e18e2539e3a47ca84742788af2b37570896358abf242c198757f45565d577fc79ac533530b2a2b4e5a96a72e687f70988252f5f68ad66ba29e22cce9bf14a163f300b01ed43a7a8d51436f19964ccb1b7096690f859a25e829bce911d0939b862d9af90ae2f00351501baa308463afe77524343cfee386e1757397b974cc74b0d8eecb31bda9066ad53f0e43038da2c339b3ba1a1f8816f47cb557a2e6f34b91b80ad3cdc431838785f749cb8ccf02b6f35c78f608fbb98d72279fe6afde9811316e087af6ddd53394a9822a173c862ad32997df1e1a80f0ec53bde5ce11b424b3de39e4ff355343597957cd3c371559d83c19cc9a468cd2fba0afba5fb13506bfe3930ce2e77a49e219cfaa35f56602ed022f3d5bcbbc6155e0f134e607da1d60a2166ce35e351cf77795579b8ac732c1e03d27e2161574960dbc01b5b2906630a8026ce1d962fd3f778fd5d85095ae75de8a160f90e5456d791906f5f7f0587681f25bad57c5e6878cdfc454edeab7e1acaedd7386385c149277c36777e43a0707fab00bbb114dd649455b7fb361175fe68887192c349868708c67318d1b3522f8948f1c997f374f11edaa04a93afb63edd9004169e52bbc7bbcf45e94b274534ed8df98264f6c548e46b1bd0699fba42432e9dd6b8510fe414ade2425af8f3799ea4605099354d4b63c73bc402f857f143555aff7ef345fc73cc6dc434fe937daaabe074dc74303555ee3f31e23d54e8a99c0cd50240c822f24f877a773a072c7f5a94261e606b741b68b6a324d3a8787b670000da568656b934511d9d5527b1d3758b0c9ff5f1d2bfc4ef3e0b155f81ed4dce1de474bb9df86b4c5b272a0d4d291f4bdda51a92843e0bfbee0388e889d13fb6aab9492b192e507dc315a05dcb7ac6f034266670b01840cfcef91ca3e436cad0cc5cb1a5aed3851ceaab0e476c2abc620dc684d9857565eb9d7c343a627641ebf4b807ef111afc8210205a81848c69d0f14d3547d35fcc49050776ddc79a83e7dec22926b32367e153deb6b16f65fb94faca8a93369238f6b11e02bf87e60d578c522e958cfec68c630a5c837ff4f94d0790f89029bfe6369fb319af06742da43f4e20e3ae9c046e5add8d296e465632d9acacd417002f5f463a04a5d7aead0068665ce2ea8b5c97fd6d137eb6aba41e27cd27a22c69e90ba9a82d2114099bf0ca2b1cd4d227395ff7e51bf0e62d9159b0ce09af7f850c1f812e148c391c93beeef70a833a783fdde89134a0b9112b7d70f78d52935a4920a96becbb89dd9bfe375a21d61fb1c7dd23458f932341d0583f2d9d8241ec6c8c42b7a71aa32c9b2962644f3c5f282e04579204b27f7119a28c59c3b38d8f2c21576cc3ad7228f7c4404379a174b912ea9ec571fb6e49f9ee93533bf7edbffbcae7318304e54743bdf5c36fcb718933331d00ecbf61faa205d940f7f0b8b895a82b37763204b99826f82bbda6e3ce9522ff1c03f4b57da250d1920735fb47c405b4306ac48412b050a02f649781c19a94094c6173a2f831e2eba56d39eb136c194f2ce48131f9ad741f33a636b0885e9000d8106c2fcdaf8eae04dd8254b7d7fd506172a9dd7658e7e70ac01b8e87af03017656bac995a87db1ef96466ebcefbf6b87c2a79dbe9e77b57d0c93f5fe4924be8bd08e576252621f3994181c2a4fa63c9cf294915fc9848b806e6f2427769c6e4fa741e736186e33de3786d61e55e3bafd5269a1639b5359e15bd00e15a7021a1e79a3319cdfd5cd22887ff3b4ae6d8d151f02be0a27e251821725e2495f9603253f6719707d76f3d291b70dfba383d9a08f1eb060e3c3c3de85af3e5170ef71effdd59270dc6b06549caa2575fc85f4cbd5bccb3bf81a7c6d8fb50bc3ef62712c586352a22c3264f2fe82fd957263542a09f766ca143fb7a011d4c387c4b86bd5f1721e32cfb621c4ec12f946d3837a10aa997dc9ff7483ed2a05d2de135e51a1ef4d4a820992584b64d89f91e136c901a04b83048933485ea376e49a2a61f9b2c523e108711a6a5280f8c96821c6b9feb37ce8489d737016d10ba72b24a5745056c36b10cd594cf3f7631900538dce478ca57b68fec5f99c87fee567334ecb82072078b68379a512252c8f41ecf383cdc6cc6fc8b0a1e01f08675cc8c0dd30493ceaa3ae919f2fc70ec97fed9f7602da136f33e91800a441dfb66fec85d716d71ec28e87aa284e3c95c30110a30b1a5525c4b1132ac7fd322a882df7b242dadda060aec3da1f651bcb2f7a8b54a8b5f1229e4d8133e7d7a1c2450afa0c0895b42a966bfbbae694a7c07bf9ec710d49470eaa3d3a9c0339d681c67c2d6c672bc86b4d9db738cb7e0eabf1a231c1cfe45c697f6244de60aa16d798eedfa5595f6f06b6ebf57f57930909156ac4361eeccdb32d769d7f74501c527235e11b26455e946efb454b0ee756ad2b080b8b30b66357fbb811e356fc6786643e717e2da993b2f0717d05fdae1664bffd5975174e810e4437e0a4632eb000412e8f0f1a088f61fb53f45399b99daf5d423101516017e63a8001b6bac38a365c45be4de8259f3b2cb8335850ffdec7abd8524507c08cc7ededa4de34d784be8643ae3b3289dbe607397c155c177caf191c5e2b14bbe4a37d5ca6fbcfadd6f51b7ba54abb264acbbf341048e16b32557abf6112c0335ff3f8bd79a4d455c905761ef23eeea9e898094a07db8ef8e43f4ebf830a99264386c95fe47c7ca7564452d80d530f33fe348ae0e0c8185e29c51517f9ebc5edbaa4db56ef5d896a4a57e9b56a3234894c2d370ecb854a2a7a9a208152b2221e130fd1e182573a3c21259074c9440a3aebfcacc4efb4bebd103928bd36f15db7e6bd5edecc54a9ae370746bb118c263b8db7f284b260101f76b18f6f045c1411d9505dc471beff2e702ba460d85e3362df6390732f94af0048a6e4dc7c5e2b6496b95ad41621e9f8f1b9370727f9c7ea23ba0137e05b98874a4603d4c7fa86ceb029a66068c32913927cf540f8c9efc24b3148cf9faf993ff823708803697e6dfae06ea6eec535ee2cfd81ba961588075f02c03411dc949fc4213de44dcbbec0685721aa3f9d9738c808a9ede2dd65c7fa60f4b4988afa071ae998e3a7e606f5fb2cc97959f42441b6c3f72f1a0f4d7bfd83d65facc5c7c6a81d52a6e7fd03abe8a32e6d8079a5532edfbd89ece066bdc52d1d97485028cd035f3900756cc09fa1d5c36a7d0cb931a0c17f0cfafe93a2d0b89d8fa8281d01b97b4f7dece1f00327d1bd1cd96b99a6fda1cf94ad2cb2bdf80aa4342f7abf3c026fdd3315021a5d09ffc557c11799e94e529881c2e638cd108836c379dbcc001a92eeecbf73e8c02fed19d71cb9e7243859e137c2b50b5d036b29d9d7522f2e0d45759cbe67998d856cfd4cc2964abee15d17c288706c160f66d3a40882d400ea69d3dc61932b7645eafe413f0aeec2097afdffbf2dc077cc09e38b7ac17cb4acd9d2879b36633eba8329501880acf14633ff84bd4222bebdaf7659d8c9d1a8b48ed98738f3c0986be3e15925a36613689653cfd60affd6779e4be521762b012013e1b65029c7ea4d2aefbd33f84d60fb501b7d0ddaee4dda3cf745f9989b892afddfb8401556fe9c264dbd6f4d0f4e975765ee87b78c69eb9810a4b4b317f6b0749eddd2d89ff1cb8efa606b99ca584122e39da1c07205d24b9299a20934e9bbf37a242207e7c32e018f1cd0bc932ffd3f5ebca87e7e63aa2ed348e9dc93bfc9ec66ff11f0c79d91f9bc6a9fd1079ad9eec37f57e798e8a0a581e2cd404d8d2b80793a24b01202c0696d6211bd020d794abcccfc0d7938348d27f890005cc014fe5e273f6bdf1316271c0b788ab3f36140d9edc8576505f994d41fa2bd39d4ba594a988fb8757e7a12a2779a4a9767da81940587bbb2b3157247d944732378b2ea27028decc5b0b976833562b8a64b6a22e171802a3606341db5c7e8939d45b7b8fa682c3f37ef7d76888dedb0ffb6603dae255bbe88a5d6c25781d66d552e4f29d9560241df6030e0a0019be60ff2845947a6b79a6557543f4e3b1f7a2750d44531011b4a2b54fe9c863a1d57453266685cd1e865c6ce7c3de27495700881ab75544d41f8b4574d763288d06580355f81afeaa1aaf562aca223e612ee0072f15c30a4e3cc54d6eca013f9845fad3a7ddb12fb703093f8c2c17913aad0394cf88906d228b4b36d1d62e9a1dd79364e1d1a0320ff2e3199343f033f1c007146e501c86ca0a4c3cd6a83ba13d2031d0ad8d6cfbb920ec8d1e76312d78c38bc0cf019e6c30dcaf70385dc9741e7e39d180fd642fef329ca27474a7d4d94c20837f8b917e531ea28beb01003436fea7fa6b2ff9e12d5c327d0a168887ce1ceccf874438e4e82b8c755aa8a3c2ec47732d4a6cc899cf73e0f6280e51d39b1af306fc08d9f42235b4ad671d4f56218e2419cdaf37df918a7db5aade8b7fd9208612069feae1955c0f03fdc6bea3cbf311bd5a61e3f789933551e91bab467b06d6e5355845b889eb06be416810f06b434d99eb91805b70c1ff651057c412cf2fadc5844279f7239906697a0b04fec0cd23aa5b7c414b6e17e989475f30d689b8da35d628923f4c62e61b726fdd49862b10e5e78f2095f1fc7d8f6e9c5e60ec14cd8fa25d9b03c596c05a6f4f3317c8a52cc84c5538f068bad61d1ede78115137e00a695054159e39fde9ce2fcecffffbfb38847f0f00a492b0596cc868aea61360b7bcbc5edac93318e9a05d1a2d65ddb49502916aee729f6f463749922c54d5d370ce6e4b872dc3f70270a3535e143ff255c257e4958f9d540b138193ff6918c62633d029a49aa1532f8fa51d24278a5b55c7fd8b25090ac3694322f67d4d75c8ea7d7e73ec0dad9a909e1296240c1d836eca99c2a70db90b8d132bee9e148b38020888c89538ab8ad5c3a3c86530d6c0c062aeb28c876536816bda64a7a990d67432c573af99ed8cbd2b7f2317a780b9f4da6532a450f7f19a1931c818fd0b05daa203c8cdf2f19f624716f69cdfbb0f63a42a5de0d31f78f054fd0bbf4b1a35e3571e4072ba4d029e9e8c3ac89449a161d6b39a5e7053d5c583859ea9263b3119212e427698754cba2ae604c94f26aa0f155e898d548a9b58d74b6ada22a69f9239fa0648eeb45c3796a8623a1b98d7ba308c776a2488953d08c1238ad54f581ef6e525e499ff9ded01887ff28cfdfc57d8e041d6b128ba35343de18603a7c2541f787a6429b50e853247db6029a527a93fa467cc4ea7e7d82dfceba6ce6816e3fc65233d7b7a4d6901d63935cf6633fcb86f9832104abeabed7ea526c290e01f37bdf37f6b7c6e61427f2e16247882f657314009ac33133ba4e235ddb5e3874ce495fa536a8ae43b929010f087c8f57c2aa0ee17d4e9cd5e309f46d6feae86502808804ea882a70274a26be45418a0fe2e417d5686f9842b9c4e48ecf3f5cfc74ee6ec31953bcc0b2b29c96e3a89f4c2b006462f500208a170309b4917673090fbd7b8278680c09350511487ff0d838633eba3a04b2c28560118f3eca187039aea30c13f86a96a6f2d9b3632322a697b17e0ee91ee55b6d00c3d1b2d3536367736626591bc166b370acd9415a02a70ef4ea18c4b856053104c62d2a2a035a6a6cecef7501e8a72517d91d3024dabca5fc69f495de948f74110ab523c9813493ee0ab0181bc7ec6b34ee3b06fab4db88214b2c4397c6a8676d67462fa70be4caad2c138c40186850212655c1880b1b31888e040512e9d634d327195a4d892bf26ed4da9b8638634db98653abb3f2b95702c06db7955b92d2fd08c5796c78c1e22afaa867b98f1db140232e7778741f001115812a2a2ad45f24e0836e35c6ebf55369a56b3472f201809c2b098f0a0ac2334fef33089a92111b95d50163795e471368ac6249b458e5fec810bae5d9a6e177cc6838e8e8a8546ea20dd8381ac557e900477c1502c2330e7d5f4017f855dccd49fc953fec8970088da9a56829feb657a25d4871b35937461b46b0ab9f07157bdcc31ee769173853c3f78c30c429f68bc9feb5dea778ee7f4b30e6af9087f66f3848afd30b1bca2f58c2528493fd28b127e54fe8337e3255a0b9344c339bf0970be3c3dc39b5cd3a53414379d3cd819c7cb2a0f88ef0195f4c2a99152cd2231c9444069df726a6294fbefb1bee6f4bf5366daca09293d618361a417d03f05007d78a0368d8d4935bc662c2b767ec1e52aadae0dd2300be09f9ec8e88af677e855c1bad08ecc71dd0535d43da3b2594ee5425a3ad25b222f9b41b2a21a2e7fbc868610d4dddaa97eb6c836612f7e92ec1e1e90b0bdf88bbc98bfcab504854dbc56100a18d4af0e9d626b1c9b23ef5b316308b6165d795e144dd8baf4210772f5049cedab85934ef83f39dcde6cbdbd37105de27dff4c6ba49bb1679f5da7c6f4c84e7cafe323312bfa2ac0c220d82dad680f4fab3987fe8c25e6ba0445b71ddd1b570a5c70ca50c1c667a75fd5c29afc93eae0dfaf5f33c1271503596f0da8138cfd1b96f67bcfb72acb47bf04e1cbdb11fdab02d24252d2f30c1f23d06a1f00fbff6ee848c0939035d08a6f39c1dccb74ab65e35ce3ad0bb52be470e17d5cca2d4e64543898e7be7692d837e614c2d9d06e5c1d4b8fd7123b0377f62761f5e8b3f081125c30bc6280e729815f559c2080d4c6f5e27ab7ba605db8454cf403bcfa0d36ad0b0b712e53bd382cfd7607565889c6f41fbd8f01d45f6221dab5169f50fe86b1788428c1406f7b59756d85fef64da04829f4dac9a2a549c5867be02f0315bdd59fefe250e8b39bcc919e58d9d9d7526055cd43a371b11e66628ca1e196f16a6a36c3973a1d8497098653d316f1aa4ec2551f49d14e7af217849d756ad725934895f16733cee14009a90813fdead6facc7a5992d5c6ff579ac87600dfbf57120cd8383b9bc65b1b899c8a91f8723f35055aa667822a14bd3f9b3d5009826e9920dd3a836a3f16810819ffabd41a811c90a4ac5e1eb85db3c577f38d45f065776ada9d6d6432df93cd20ad11ad192232015bb81f5f38752601f32c98d923873c94d7ef2118e0a3a8c6bdbfeaef3a782920d133968020087759af6a6193608863e7c70741e5e2cbf18899b74aeed9e0a4dd94345ef4c1a2fba7a84f903f90dc1bfed3ed5c1dbf2f7e3fb040a52c8de62b7aec7946d5c7d2f4606a359ee8bdf4d4ee669051cf055688b719f28801c57eecdd95f982138724375c9f87167ddd4d3342f3ffef935a24614b503df68bef5a2d594d218196fc81e75ec8a1b9ed0736e707a0d3deb8ca2103990a0111a68f403570ba86369131cdaee5a7658cbc7b3c18e71189770ac5db5827e9a673e602e4279d4cc18d18c905f652e15238ec22edbd0cde1365712748faa43a927d372ecf71fc37532f3377b3416c6bc6c919fef659fe0669a651c14e4926b0d686601701dff53213010b49e7e16221d4a21d4c5ce82a2ac997db49c0ebaa3163fa7a38723952c30dcedfc1e8db75bed902cab7482adb1ffd6ded6fc56568bd76f52872300a0ec249a9b28a04db9c216d57f20ac12d61cf57d2b90640aab928d2a8e6e31b8526583a061c5b6a55a4cebb5d9f84b89e2914b9a24846479c1b4dcb61cdffc2355be12a98a77f423c8cf419a7a6670e98b7a0ee03368b769ad4cd0aa0a0c655fa28b354c5ec51636defc7bdba83cf57d8e7bb700c86285374d8c658fe46343590173552b7022155869aca8973b025abaab49abc572065ac68c92e0cf3f3dcb53840e7f1d5405d046d3721a3d400b73e93ef39a891c562760ed57dcb8f249ab3d736a76fc2315b7023141aae75a4479e01bf875a4eeb201f8b86d2305b1413119a89b8994c29122b8ee9b6cc32b26fa43742be232f50f6173187c0048901e07a567cdc48134d304feb5db85c84cc0b14417177716589d6b1efbe7ecd447d2f39af6f06659beccd0f9dc3ae48779f9977125fbe3ec30d78976f680b1f7e9073c7cc79a7fc2f43336f49a5af20809e40d006eec6be30674d238a3a29fd8216b1d35cc3da605f0841ec2bc12aff5dc1dd891eb40bb5ac00983b80f903e35852c22e94a4b8333b0cde20df2ef1b8117dab4041bfb165517eef1e914c6693ae710834ad95db5820c39f01740c41fa97b12dcb4d78da91c6a6df11f7afa054f1d5abea70048d5262b43f8e81a3e569b7ece2e26fc1a60f1d590c7b4a6c4e4417d339cd3e8716417d5305e198ee6036945b7f71c8ac93de58b313bb801d8258b66fdb218ea51b1bf54f13215e17a972a54a353f5985d507389c7095527f7c215af10d7ee516937fc06d1c986cbb0c499194d6878f6b2265b6814454a0ca18f1777b16c0280b5fb64e6181e7cc957c6d620766500d80bc3e8d6182038088097b8edcbb88f4349007cbf0b2f183121d2308bcc0e83e979f147b394135d0702a0f355c5aa91e36e1099ea66cb0c410444896a37d70380dfc5bccee652438f915b918dd2bf3cee4b38c46f90534de242e872e1b101ed2c1ee3cf25dd9af1752b34514fd4681e86ac80c22511ce2e583b3943f050e745fe25f2cd0867506cec0bcb23f84d31e13c7e05a78accaeca7789f93f9d43313d2f5273f1e2a72064e3dde8053c564dbc1fb1a1ceead798ad0b16000b5fed92f3e4f468fa4db0b00d676a831e5618b1934e7268a4d7c7ab0806c20c0eabc0573b6a2721cec7fb61e2b68a1c5a2678c1ffa549b81a3af209104acd62f69b550df75aaeec35c3f33831071002e254a91aefc373d04a742856e00503a28eb286e99cac688a57fb23ae3400690cf3d2710f1e381e47f9d4efda221f96e92fc9e677cd64023401b889b68d1a2b74d42d705a79cb86169d0e59d9b060eb2af1a6375faf4d97b2902c94e4b972558e9fb3336ddd61e7414b3987737588f6d775b43ac8ddfb646c007c03f22af5c7a9966f072ab1cbc145e83cdfa72a6f613cdcf766c80f55a10a3e5fc3e3b8b9b70cce833619be6c282f3fa20ff21f17655fa4b5906e59b46ed59fa09b2bdbe5f737c36ec203188071e9a34d4e088faa23408e54bb43857e2301b66c60dc8fc8230b0081ef5d54bfd601d9fcbad327ca0500de83ad060a2371ce696f77c2b4407235449141f71c2a20a83e5d4eadb37a41dd3446a896954fb9e661998a171d58b394b3cfca53103e12411068a582cad7af1c27e8373326926c32953997a191fc013e4e9da4226d7beafaf6106bf09ee04b3b658ae2acd29bf27bd4cd606d52c6ae2966e578daf932b8cd381fa6c81b9a7432e372896f0150b654252e8ccc0b7c3404ed70f0c2898f89036946389790de6f874fdf748dee27dcfed93c7e93c32b56d5d771cb04a026bd09a16fd8602e7d260369fa3f814575fdb9861d30afbcfa926dee913b623cec9c6ac8217f7f281d7503372dea3ea2f492caa4f7d53055501ed8ca5cd15fa392a27ad4ce55f4d1012472e21dcc59cdde4ad488f568c497edcdde988845326df7444416a8cd0f49ba8f66a006f7e5d5ab128d7f7b403af3a5f9e9c3c625edc5ca66d00aea8e0240d8c6e987af0f45687c6db17dc75c509092be32ae7a4a2417ba3f70d821c5a128f82a143b6df38f537b303330a07b8b18faf5cc1b4af2757953478487ad825cd79d45a1d75bc4727be71854eae7f7d37598e86c165f327797bc54d4c3b2b8432922dc130b5ec18ab3189682cc4f2e72d8fafa6d938dc3bc091c9d79b26a983992f0165cc2f14a234aefdcfc40d369dfa0c200771dae5637285d98242f6cf38fd03b8de8c540cd5eb94995a8f9f5d5383faf431eda0092102345a372e54b74638f0cbe2cf0f84caa8e64933f04adfcd3bd81a4991b1976c5216a3085c8ea7ab2f3955008eba61f1f23b318409836247299eb516d8c9747a9edc51b9bc7d9171e06c4beac6611152a4889f0b933c7abf8a2dafa00d850e845c99d9e682bb9661bfd62f3956756724bab904e43f47d4a2d1e2f57264ca1f4d2f899adc031e5bb5a68f795bfe5a361e883af421aea6c6c71fee1f5fddbc73563f2f79fb3eee03ad87975ec35d935d2be4eea1f54f66333a6d09e4feea5e1ee826363fc8f33ddbb3783235ae96f71e7527bb6e6e296abe7826f8f157e9c6d17b4e88aee8b6f4517fbf3189838110cf511c63f7bc6cfe3d2b028452af05f7de15747d92cdcbea5bd212e9c933b08e2345695dc50dcb0410c4a48f25926f553e971593b4bdc93b4818b5800341f7590c2eac302976afed309c7593667eadb6bfcbd71b1ee3d1c6f54d1c89735e43a953468318d6ca8fa8dbe395d0b05603cfe4e53dbc44df050dd9e189413bcbf20ed3876da52e78cec6c0a46e17013c05d056704580923a3fd82dd98528cfef6e958443faa7dd66b8a6d2e8a72161b70d03a2879027dc55a9479269ec2d90c77cddcffea13521d85790925f13c80a5ef269f19037da66dcacc9a54f60d5e306572ec5341c268b83577abe957d004e6f998a4d8fa145e2700dce5f1e3b59790adbde3b6a997f1b63f4f7e9905277485a564e02da038c6f6669214c5727c1258032087c41735dc7e5d2d13db34ca363d3c3459211cef1e1e2bad04e423a39f5389d3338f205da1d85ea4912b361180cad930edc7dd743ad56e075dd562ee7b1b4c078cb5a02ed6aacd606d73be83819dd18fd4dc8b95cd33b5f8084257b14da1a36e20e21da3d97d078be1a93c1d59146de3cb5520fb77ad103e36776f86be227ac1c2f53d0c104c80a267e949557913841b2618555cabf81a51939cd0901c0eecbb2b4450987944e1acb7e8dabd09a07379cffe82563f6ac2b8154c57020f30257a65517c9f25de2c4739f5278d083a5561d0fd2d035e7c03aba727fc5935b8fc2231618bb880ebf0a989984a8c0d5d6bb6c689ec730d7bed8f42ce7f80ba91973fc98225f9f2fbb954ddcd4b382f81967479873b3e77cb0fa89c56992d8a6c20c10d3b8a284b71fd8ef6689abde6f649bdb5a49b85e351d235d55b4ffa5c2af9a35f6c052d9b954ce57ab0f90ceda0df9e73cac835f102593929bbccaeb06818167ca6ac7c68ad53babff748e2c762c51d0695071e24bc35fe900278d573b5ca711e28cf7c7be3e24deed35ff2f83382e9b36eb3ff907dc5e88d39b9313c71badd81aacfc94932bc1c7d707a7ce1f3a891be77eaab81a00f9cd4f19bbef506204c6d66373d08be714315c08d386a729651209cacd3cb2a64ec2d096460e1ba221fafd29b7c13a6c171440b768b23288f00609736d59c89541e8e0e820059b5f78f73507530120f99c9cb97af62deef17470e3e03870bd39c6e8af664ca818c8c13c8faf58a9e75c2211fffd3cdb1da71269979e0d3dc041911a173b836f7000050ef3f24f064cff1c7294d3b96a391107708f4c0a333a8b2562f59f8a2edecfd842a8494e93c4523af94ff794f0ed3fdeda02182eed2e3344b70a3a004e29724166bfad9d05002bf835f1d3a8af96de086f771c934a997deff6f87c28175284e2d472171634a35cfd24a966b8a13b5b22dc1ee65b43ec1450acef2cbd4941a06459f42f853fd4784a2bde0384c341d470e7e13008c44a1d586f54751afa63b6b12f6d040dfc3395bd56084811f1259ca023976caa6c6ccd42ba5df74ec69acedfb329500e1a18e03e0577fae18886f29be411721b5dab3d67bef306388e3268a00bafbbf476be1d088fe46d586fa2e8d5fed898f2850629d85557c97d257642a9c034d31aa78c146296f128d7c5eb666acd444c35c418e6c9c91ded0da6aa2615797bbc06740c28dcf3c553e31d5ac0546446a96c5a69f685f7d0ae6a16ed5248fe5f9d78fb58d2411166ac4befa8e60f1a53fc7a2f50365f96912e581caf46250d1954d1f23ef2627f5a8b426a41b04e46de18b81e2a0c917706771f384d67d365ca6c2cbdd56dc5424097647203d1ea467c9987f2c7bf38d45dfee53f60231797ccc320dec61d6ef9a044d8eba3e087d2980a7060a0a0aeae2c417f9820c65f12a46c0bc717052b7e3ca1895918437994df27fa28989aba547b11cc51714a795fdec9e73b3fa9e07096829adb850bda6e0f89f3fdf8aa72943abb326defec44d6830bd9c50d2fe668c1277b34a062274bde9cbfa1da78249c3bcb93348c0a90e3111e1bcd802387b7b30d7050bea00c5b16289ad490aa04d5846a69f2d6fe57e5d936aeaba678742554d29aa4f90ae67c880cd7d06503bf0d7632626df696b9300f5f67a888b0e44ef7d9b24f4ce9a522b059ec3233d9d10cd5074ba53f8693930a776c9437f20df5807dc0df990b79ff37f2c4acd6ac95c08a450f8d48a0461a5a46e2e5c0a7c5022eade3b7e14705ff9ff378a84dc00efaecad3b621ea72c5ad981f8bd16b9654d8af9bbdb9161818b76f8df0db2d1f1bf048da5c3be8ac1228ef5856012a91dfdccbffe4b9a8541a9cda8cbbc58d926ab7a6c8391e502327d605a272ae682f4f5cd75f9b713983d39f583df1adf8749a895ef72aeb4a15bc8718ee6058c6318211221f2badcdff6f81bcaf90e5a1ac32f943cc999958d22c608b82b0785d6c9b4f5a23abe23ba3a56eb05815400471b458f604b93ee26d7c10346f325042246f1ada8b39c33cc7e59af072133bd2f507afcd50cfd6525c03a17a7a856ab366bf0b820c42f23517f774e39fd4c777ff30e9f591fa52cec4c6a1a0787dfdafca7420e135fb3bc2c129dd93f7756a1857d90455c7bbdd712a0f78acf26fad78b5ada20fa509ec7f33954f7d44bd0ede8c9092ef52255636a9725effbf974eb377fea16cfb18f2733f539c81c29b4d7dd489d3bb8680fbb89f4c8cfceec5f4c6bec6c5d69507d116a80eaadaaf979337b856df80ac0e16c92843af09e7ec75c8cb6ac143742e7f8af075daecfb8c37e51e490fff2c70b62aeb5246782e8578f67faa9b49854a48e5ffb17aa279ea0c3fa99f656c2865cb730c14d35aa45622afa18fd3e81f4a2c6c7c27fbfb40c1acebaa5046423fdfb1012f888927ac0c121e81afd5ff7a0c8968def28f165daed449d3334315ef9246fc2a70ef2b9e154e8a1937e0cfae73b8365bf30459350e9f767a7c25891f9f34a3a28f3c1df1e524428c1e4b104f56368057b5e49d914b0f1eff40fd406d3bcbd6352b23862ab81831654960fdced322d0ca9e59fc1ad8396c658e68ef82eaa9399da6c7b2e37f1f114ee94488a1d396e07c51b91f13ff93e94fa285929152590bcd898d45fec4ca4f95df2acac6967f5b6993a4387b49631f8bc2e58a80c975adb329f4f918362a8df4e758fc22b10abdea4f51c615800e33b447924ddb29ab11ae92a3f9b0b45835e187e2e919de7d8274352de26d0fd6ef82734d91a8987d2df62711a51511b8a16be21ac64ba8f1c5098fdf826672d034fa7a10066a371d652498e0e5c75a7e61a047290049ba78964dcc7112ad4280842decc7da27a2dcc8b00e328585419ca87fd0c553086d900319dc13a160d3263253361454bc709dd80df72effb82add7a564d624b12053586d561a44e4100016e9ddc4da4c7c3e7d84596f632f0997d9e561e2902c5af9fd7e379ea1bc58e8140cbba3724cb7b179df0b6a34195c62c9a330f015230a40120484308f150cc2375becb8e36a9e52b7130596de9da5dd321b0d6154f07c7efd3120b5d2de35910e775b76685cd26e1cadadad3b95145f520c6e95731d4038aff1802b9aac5f492c2529272d4ed328c674a4becacd3e4b671ef3ece72b797addab710b8b1a09781fdab6867ea6c910622678db5630475873f9cc0bea06fdf334cfdc1fd188eaf117d4fd74db2743dafa203eb0bfe01defa610d95ad9a854c684a17cf3ea68ff44c9eaa12515a04a6691ea5f78de51c163763016086221a0cc41390d4cd6706d4994612c0e6110041c51eb9c115c7031ea30389d5362db782018c555d0f156005906be056dcd88dfd6a3a4eb9da4f5451323368acce7afe546ba1d4b2d13744c92d077ee47113ffeb2b402c2087442411ed8508f29930787dcc860ea071c96dae6d309ae99e671d1430f4e99b5039519511c5f2fea65068a5ffd713cc8323e88049df9816396ee8bb284b73a72ea88688730312b204d4503b35ed5e9f81e3a31dfb4b1d9c0d141e36cab65fb7fffa454e8f40dc1e579a92e3003db1fb35552b180722df8dccd0291ba6e9f9c1f70b3c76a2ef5da160e080b7b5fd5f47353fd8578f16553dfac6892442dc358b4e44da035b7d53c13b256060a44abcd2e011aaafd513b81170eb4fb5eb2b9c5bd728b134e3f97595f1abfd3a1086787e10a18cdbe600f2dc0002042b92fadcc4b0cb8b159c1f0489b688129ccd32b4f4783b36f251de8f2ad5a305b2cf24f3236db704e2f7bd0654cebb4438f213ac33e0ee9767a224ded59d7bb711aa4e80845a5b21d3704abdbd2522568f22170c96ed238a9090db918441c4be420ef4beb3452d4e0633788825e735ed0eb032c46736afb2de77e92c01f81f8fc4543d93cee59d819c7d54d88128110aa810bf33b3dc7d758daa0a21d33444eecfc412e75e2c6d55ec36c5acf05c631e8f791f12346f6f568ed88d6afdab92a451c5b4da55813e2808cdd65685cb1c2d05433c08023c8e9c13b04b56e68ad9c26974a6dd979a5bb789c1a6af1ab48872fbfc63ba3607a425c733b5baf894cdf49888e504efe34dccacbfde8b68ad8f59b14c50443afd08cf44e2c4011a59834451e91f8499201f8c6b977d1a64341385c333c3cf3c9e4ab8481a35ec2821d3101851b3969f423714425354c2e7860539a08b9e8f419e5cff8875bd6bedebc9d98034711dda5b434decd45c93edcb2e1173670f40fdba6688349713560e3abf9cb51e47742b4fe856d81d2bb49f6419a3e3f1e0eec329fcffe2eebb3c8eb99e46f4c25ab39497cc2266eae30293257482fbb28bc8affd8b80d4097e373d653d582d4a7905b19b3fe69a0f0f52f57acae9be48533a6f3211d7db0c899e542d78badb7fecfd91639c6245ade4c4bea3a2643ff6ab2b210689b2c762eafa3bd209fd1e3f7f822aecd2286fcc89b59f6c1d661950ab9d7ae41f03cdb84b6dc1818d36327bce6d37feba1af55c83f0b8eb1fc5341c95efe40106ef3fe68b2ee5437a076deac0d318529fa3d7c56aff9702c5fbe323f0decace4c508ebf708d7bdd428cb9eb9f90ed4be258697ffa489571e59de729614a4e56b1cf381e9b49e7a82f817e97e6854fac85254d54ed12f3e4c4eb879755072292c7b9d19eeac6624f2d955d201c216a48fc595bc789149db0fee02d1ddb7870d3f2ac4cd70a2915be4cf04755cfdc161c85d1a9856ca7d064510705ae854b2a8870de225611651133201e9bacbd27c0ee321a2a4c5bbd596cd544ac44176d7fed049d14f1f90930ec8842ce5ae846d45070bf15d2caf1181b8c0e29c8047ee62248de785be1125eba7edd23efffbe1f70936c1a83d3f001fdd7fdbe8f64cf44fcc15689849990c0ab907d2490b8793dbc8f1eaa5f4b0bc0faa5cd5cd3722969118919f05bd72e7d7b33728fbbc9e0385cefcd72a21ef0b82585e171993d291687ec9f06a55732e2c38e120516f79f734ed47851de1aef2a1553e2490a01ab0bc5942c5e985025e619cf1b08276594a17457bd546db10b3bbf985edae3652eb25c38d720d295e1f15ed22664a190f8a8a4fb56600cf9b7f208d984d332b44fde619f86e811f23a2a68a0e588886cff4b54f281728102f90bae8e35c469dd02d810be28580ed4c0e3fc853e08d8eaf68927f88f9142de0da93b7467359585793bfb377de351d56a24c61f2629f9be6b341192aa84e325d33df7938f4cc90d5178df614376227c919888f8280f581d2a6bcc23744d6a11b73edc08f1d61c1c402aa1f0ff93a430e2744b8aa91710fa8591c4879650cb7fd0e425425545182e933bbd0f63ccadf75050b1763819a686a2b5a88804b213a400bb0c410a56b03ffea81dd11cdf7f67383bac7d0e397f13bf8de3f3f448fdc7bfb3a0ab384f6998c84f206fb3d422c0b478e64d56960e48524f8ca01b0cb8174f9d5285b6e85f649e76ad9847dc7ec23f220bad461e1ba3e390e206d0b1417d0512b3ae0962d87543eaab319a8fcfd054501c4afe8299898e22670860de3caaf402e0848f1dd2f729e083fa5587874f1daec23cbcba7133398b6ddfd7287dff0a87db8c03a8b82068cf1bbcd40bbe6fac49f57eb32aeeec93bc7f98c045af95aedb57aaeebaf20ad13443ddd2a5df07dcfc194ec5f09269ffa14ce4da732c09cbe8a22ca6e98d165d6d763ac851aa9d841df280c7e311723db2c0b103ed610d358ea5fa415f46d0e2c46b88f92f78b01a7a9491d84adbe1155d25047c58cdcf3679ef043227e73e943f25fd0f40421e24e1ddc39cc3b28c12bacb02599df10effae4c35af68335510a8b4527a264cb43c84a9cd8312990a696b5020e055bcf376d07088dcd3463f60b7832a11139c55d0986b66bd68141a0041ab0156e3f61735c357210ea84068f5d75340bf09dc0283c1c01fb589545e6b610a64068677ac0ab22364d427cb8178a2160aef55fd280e7a5a2053ccbf4be81ba2f6c2613b27ad225901971ae306fe73e28737ba6f9855f7509209891b75c4bae229419284b4c592b4666e12ed1ebb306f934bec879fa2e17a8e69e6f6febc42a44a475517ecfe9f05df69b844b82a01897d3f1548a22f0ef39af1b13989982a1b338382b1452f71d386683ffc982d7d89e04385d50586e4e429501287fec1d0de14e4d364959728c058cb28f5e94b6e27f71bfccdbc3f239cd0ccd96dd4786ac1026ba88ffd5341b43b9a446c6b31adf06bc82eddf11d72582bf73ab791a7df96f538c0f3c9808880ac89ed8f7b7db5e93af46b52f208ae1c38e895d46c4b791e4dc72e867a6212da62055272c7e94931abc6818ee0ab9add0af41de45dbf211eacdad88769b542c29788d79e884d7ec0feca791eaf2d5e9527fbae8c2dfa46a9865499515e992ed65222b6cdad1b44bbbe019456a9a68003c6301615f6ff1576b02288d9017d64bb15637f6e774d718195e825540f230f41a5c2a334c0a45ac9945c9a481ca93a24dbe7a3b2fe1592a43ca4eb697aa3695e0f72a82356024933d2ed708dbfa8ba5d4a0736995653e7ee28a756e8b950f2b6917d87327221a41b9987994473b1c6082a859da2040d283232750ed26d9d078cffea0c399bb7e234833b519c83e23a26607681980afab23305ea490e66f2c7059464d6c38ca370e15b56e7c5637dee96a348495d50cf5a8e294a6b3f2ccc73c405412b6a2dc6ade313b66a8f17e64e2908cb8f10dfef0a6d345dfd15e5013c1c06f59282606b050126c4c70ea47df6a3ed297024ae7086bce48bfcbd08864b3c1712431ae3954ccd070a05e0b69a56e920e8e5be536d3b05c16db35d914a7bb5db124c20ed8d318ff2d3b617b84528a09d9fbba7713ac65b55906352024d2bef830f375aa5ca2cacd8101cd3a2560f5cf02b729ec043c864e01d64761b52bdb1fb32662441a0aa7a10b847dac81351f67f97cf0bf8dde0bd8f268914194324cbc44ee7959819f4d4c61abe7a7b12d9fd00d1130382df45ff51aa33daf4af9e7fec97feaa5ff7f7d72d5adea45cf6d55e6a8a59a3df2f1099c7dfccc0ffe99a4de243a43751fd642d93c7655e3475358802eb7e670003921b82816f3664fcf7ce81b4f50cf37122725c7546ec77931403507157db260ea888deae401c491758fa2d6e49cb5427ed3f6e243afed45308eecb2268649caad18b4b179e1c60bb1d78773f1ad7ff0553b4638a4f6617c99f4465855bd453fc20738cee6ba2237a76ead0036fd14a552a02a9651857f801654cf2fc89ef36d469e761774d84034df0ffba4ed14f74c99b05a15b07d3b9d1c578bdc72bcef4aedabf905a313c629ba07479c22b62e0538c15fd32aa143657c313b10d5f2b6be05cec1d55f30a000bf30bf545a88e2f4351676aba489e0cb054792505fe064a56b74a6c29c544a46ccb402db9cd52a16bb36ef91b6ad54f73e84a68f9adaa90a0428ed3a924f79c3392230cb43df71a804e0b82902ca29064baddfa70a240aca2d37c3d6fba404aa16a663b84956bf0d9192205a19406ca6f1bfa7a758ed93cff0610e837abc7d187a9a43566326e0b4ea25afd3ba122f4c05b24a557126c6dc600d3f5c8d47bfec9fee551e6ebd4c4c1a33e273cdbb5da72fe85ff8d7bb4c2ada771f02ec1cc55f3c681a8a176121dfffb09f6efa5fe7ad84663990d2b7cbee3fc89fc3aa52f538e962f26b46fc99a3e03b6e15fa42828dc1f9904c917c140d4ffe535f296677cae806f1fa219c18a133e36b025d828b987db9aa8641a6d6dc0a06bab63f2d46579ea94affa0b447a2cb6867fd329f64f6a53ece77dfadb8a269da91ccc5aa55daffddb0cc649025f3a005758488f24734c640dfa98d4acc309b8fbf331505755cf1d053236a06a260cc86b47108d1e0c063e995fdd916f753314ce640b102966db793ec5bcffccc9c3c46e959cc81a8dc5004582041fa083f4e68354f813d82820cbe4c7f7a6dd57654560fa9fcb48bf59ac2e06905061c56063a34e1e62ee0f644f7148cff1a49d650f797189214faef4d48d031c6ba5049be9a73dbd38a03ec514568398069def732a275de381945bd99f35760ec04d3189bbdec2fa1390428b2c953735b2877da79767ecbfff43c140c26d91401cde4cb51014f48e81c3fbe9d0a6f23f3382fc4beee8e46cc6d895d6c3b4db44f222faab66d4f43f93394bae5b33c25264d700270b02c868225803cf5806f2a802a987a41fbbdcd4d7451ca3c31e8b57f48e789fac8cdd05c20ef068618cc6e0cfc461ea3ebb0a4fabfa971166bada272b164f39576f69776e555f6e47451f05735933e96bbe8aaf512bcefa243de4ef35bc927ad9e1a7eea894079cf0f69b3b23d871640f4627931a8eec9f0d51d018fe422cdba620b1e554577acfe96993fc8ba569b643b5fd71756d902791ffa62cc61c6cbf0b6327669b1578b70e2406cecaf7f50eb9d2740fb85c80ead340279c001a41a72d05f23fe4878b88419f5361e2ab5e23e0e5845d63e6f8f4958c5d2009002ea249426d387d3ef21826c07cb47757b9f3c7c3db64f6b20325e14c7d363915dfa567fddb468910f161f6e443b1908a61b68de795718f1caa4cf87c1065719bbb854d21774bea234d5d4107c2f50daf9141f85b8fe5c3a8942962896b5abd7ba6f11d2aaa989f8c55d659e71e4028159a703d0f2fb7aeb18f1c15df6c492c0ac5c8b44c9c3bac767fce9692785e39a87e126a23239b13923720609d6b4d9338a35d5cfe88790b882afff54fe19388181d21c4ec564d4f9e2268eee6d4ecccd772d0aa477c8182a50bbca3499168256129711ac57897077f0a8155e0650b46b6933d7c9977371200131370434efa63f01ec25f72bf044a5702e1b1cfbc74316f3595ad300f44b780606d5c5a03adeedf7e35a373619474b516ed70cf0b42497d23997a98ba9121301df807387218041b5ae2f1db8dc4699ee992bb960f60626d79b57f033e5a5efde76786484a82bbc04a27833d7892a85944dbcc61f90221403a59dc7c69a128f404857e1d7872567a110c587add03a06eed21a1804a38225530b9c0f0de2bad3c0cf2fcc0e445bc787a18d5671a037e9cab73112c8060568399ba4794accde42fd6d05599486c2d104bdc1a22157361a7cf08dbca11f93c93fe28c69e11a9d8737c69bd323cdbfa7f2b192120e7e4c6df158c08255397a275d5966449f47c3d82bb403668e89358dee5570627d493ccb5d5e3d138868194e1398f85b63a71b00f8bda3aee92c5f6acce5dc6488de284be071d660eae0972e38e100e4607921e91b18a6ff7bd28dc575e946f14c05aa0c0d7f63b99a4c78746c64eeb5e5575081eaca9356f0a9a74bf398d8300ea2f74df865c5090338ffeaf1fea10b2f897fc3c55958a0e2e3a2d801ad60bc542ae95bd73ff4d47addbe5f7722e74543cc3a2af50f38fdd877abc38830ff458a53f45132cf99af6509d3bf9834b32c2ff569871d867eda5d971ca791eb9cb872585ffa81ef8e72e69b19819d77b9d8ed08f91588a3674301a3006a940988b01d837bb0d26c3f0102a89169f044115bfa80c61cd5806aed7e76e9d499e780d1f7d137de10bc7d6d60989ee98431520b48f12bc055505d3c2d48b989bb071729a3b89ab287ab0d84a8b59f9b2ca73a19a365aff4ecd8457b6701ac471238535fc5a6fe62dcab4b7efcbf0b4e0c5483250558c957937f281128a4a72732f00804cf0448a90be77f79fbcac016d4ef682af0cc2b48c715279b453511cfd7bd2f3af7810c3cac9330cd1e40406c577b3549673866dbb6a2fd7dae373f2e4775a9eb1d80183b2176a75fd7d1b182b2b0008df68e1615b3b76ccfbb5af8b7d41cf23e3423437d4caa2f57a7b4a93410a9ccc34509950d5ce708ea32869f537ac80e51d45caf4c04dac326d6bfc7c5104a30a9b3e2cdb48da2d7e7d052a374ad92f60b7cc526d3b34a713100c791937b8636dd244ef280cb526835e460201b8d794f7baefae10dabb0609442db98aeabe0aaf1530257e3f9ad3162b6db5427b67bc252c910b86fb6a6f6ef7b470b53c6c3de59c48fb4b4e7d1ad8b75c047ece66653cc601c35c659b6b916b69463d071ce21affc2f1016a82671051b451e5fd9ba8a5cbeb72bd089380cc8221ea3020eb5388e503b8e4a4ff59667dd8ce6af26a4852487936a2b4e4d65f50af6ea5ab84119afb42fc554c4d156192a8f05726bf52772c081237a2b447107439c584fc802e7f285bec296c0767334f56add76da85b820c0560261f5068858c30e0a454c2111746914ab748c029c7c21639ea69e8da4bcfc84dc019e32efb88897e46267bc5f80416e8cc53eca771bfb74a1b3a044f9826e014111d142a3cfd4d7d7c68bf30000f277738ea72c042ae0b1fd90129e4344258492ee17b321886f9d69784cdaafc9a7edafd97f074699003caa726cc4841353969701e2f681451d35ec2bd14f4d2a7d45cc1fcdf949e09a511a446227fdba86c7962379a096c2c263f04e6258cb77b935685907b4ddf032cafe2ec3fce02b10f7fce54e5bf8a6bd3d801a10b1f4a7a26efb469e23736d3bfe260ad7f7d71f7f6641cb3ab87c55f355e8525d42ad84f79641bf37f47e7885339297f0a0c4e9bf229d47f3ca03dd9681011329f43a2716c5454bfa27581ad46ddba9a93c436ac81f2d01560be112e266aa54b2c8ef751140a9200040d9c151ef2b6cfb379f0c4a77249865de8dc1dd2a872bc2c585acf054220c33b88c27b0ad6fcdffc6b10b5d57c50cc2a24e1023069e68ae5c74a98e7d1982e90a6afbeb0aa9d98a4e1b202e626823cdb4b9740865b6c9b6871da93098e1d38124e7a07fb42082e0dbfe80bdc226e8b7d7b0802b803850b4e3974031219632469fabfb083c1c0ff784774d550eff7a20f1ec9baef44a14d79f0aad7683386177aa8bf68b0986140a198ab310d0d8c3ebb6877a714becdb77a2c10b721b312f7044eb9ee347c8d914b37246c32d5234d9f67584ef1ca1bf21e3e2e999cdd2b2f1c85370359c13cc080778738f17aa8a68e1536290a996895d2bd939dbc3c8c3451180fa82e3bf657028457e656b0ac898fb1479f5ca09b21029d12f93c11ec0ff250ece9669916bcd466882891f45aa2c2babffc833ef96f4710844676d4933196b71ceab3b86436b339896c1554bed1e5668873aaad14f9a113982c60aa2bb9f55ac6ca9cb3390b6a1a1067e79db7078ab801452398ab22fae2783e69500a7103d2f9f08a69a508ffc7223c2fdfc4b974360298580b1dae50e26004b3d35558e20200cf7d67842927e27954db4dbddbcb83ce43c0f8a068255edf4fcbc53d1b537f4cfbebe09cef3f7acd95f47a23d11f62a15c9ae1901ddc98ceb38bfffa04b14f18b9705c5f02ca9286dc3c981ca7492fe9dd7c8de97c2fc334471199a2f9f3fd70779c7eb39bb7a95123d0d0b3690428745a92a482ca98ec9a4b4b6953fd957f6f6cf20649a74942aa1d9c15ea5942f6548bebc9ae3d38809b99e3faa2b95905e83f66b9b312f50e73bb18845ebdf781f31a618f35bde5dd32f2c2c71de1645e18a81376dd3aafb51ffa45519c3edf1f5425a09a6a2fd9f64adbde438fcd41fc5d0f7689c74372f3c57c337e2c54a286acf8d0cc52d9841adb9fbd3abd4f1ad5e645abc8018406b1aed96afcb89a9a0a64a2d77f814e4cebfe4d091adc4fb76110b437d5d023952b2b7966a45134a38949238ed4bd2071c269fbb0d1638bb399ab92dfd60d9322be5afe8cfd116e8870e034394b90ddd94fe61726420dacc4f6217d64416dd8ea2923a8266a81d83ea9ee61fd38a3edf7820d2ba33270a632e56a0b9dcb0d615e3ceed3a3051cdb3a4a1af807880398d288561015c066297c955d20e00f564f54a2e9daa6704ae4231e1bfe7bf5aa606243fb965723201f9a83fb4fe9e7b0d34dc3b5de3f82e38a65f11f320489d5b95b9e726ba48ee9c084b3cb4417806aa35b129c27913c9485af51244d65d0de87e1df6dba03493e48cf6aff69d6c128b41169141b6dba8f0923d0f64b93f80a8e619645fe9d8018fb15a105576a4c578ce5b40c99f4dcaebf70ce1f1f4e73a6e829e724b2fe0954d2feb19a10572a9111eb017456d0b47b9c005060688966f32008902deb50761cfdd41e4bf3f5bf787bed7f734e1cbb61a31e36debab566fa941450a03a0e9679ff75e05e3d064c4dd5090e6a2173bafcbf3c9de14dbbb4d2781c1744895a0dfefe110a426061ad80be4e6cfff160a62dbaeb5204e81736134e1998746faa224906b4875b2a86b1fcc9e07c343b82d002f2c8b17f727cdcf859fd2281ebcbffbb6ed9bddf5d26d5733701a329174e9a0763168749a01505e454097b3274da63e58ad17251f78aa21a3d476f03e054d1bf6104b1840b0cd382f5310f05ba4e25d85b5fd8c25079880a252d5f35db58782b96b04ad7bde7c820be15a15b9d2e10f023ccaf7dcb13448ae823fe1140887083ade1333a3708626d9c38ad7efdc1cd1a3ba1564c1f795ddb72f37168ccabe02210674b9d3169c2cf355b8cd34a5d6bac10b0ab4a80c3aedf3f2c46f3df719fde52502eab1bca3300082751933062e1cd780c0dfd35cd57826f59326d9cd6d4c3541f54bda0ad1576332207096b8a96e1f5e8842063865a2e430d23e35e1a44e330e8517fd3e8b1002f830683a4f26cb4d92ab9fba8380124e6c7fc89bb7598ddffc4ee04b2351731cdde7387b1fdf7811040e401ddad71e06ecc9f1067c388f631de80e65767688036f1925a07ce16a74c027a394eb9714e37344d8e281701618c2ddb5378eb37d620315fad4e2ae156beca13864fcf886f64c4dce485e15695fb7a542e28712bbe4c4484dfbc21023d17302b35ff80c6b8416e43fbbb7f0eacd69371223fc365b9c192e6a0325fc615adc4b7b8ffde7581986c18b95e178847ad1c6b6bf7413acc5b602cda6e2e47051932357660e1ca562cedb4fb8086bce100130bb01f0fd9efc666f727e74f74f4984b90967be1cbce76433200e029e017a730b0d4ca21b606f08386b41d6151d54b3ffbc96c2965b893adcdfe7bab50b6623b9522a76750b2f57c91c30711a8dab89e7b006952a06b312a7c7ccef594fc94cc6d6187d5cda2d7cd79bc78a12382a6f72712990e45acb1dbbaf688a96dcba7200988f0af91772392b3900c1fe2b7fbc2a9e10df3d484b7c00e0ac7904a1f43f436f330758786c668d681976845dace2c82d41bbc97aafe359b7ec037b74adddc83acf2da9349e3e1a993b335e5ee74e4c42c98ddf1716c789ec362c1c3c9aebb2472b85bb838f98ab3d37b4d6cd085d2b82d5075f4f842a00c08583a9153c402ef3610474d3edb5a8267d51356ab549f2882efc716f584b8ad5fa5b7f13e0e9fca0cf0269abdcb8bebbfdfd910f05fb7704be630298da6a466d13b7e5a36efa0acac263c72dd9b79a009b9c7f30042176da15e4f245993087f0ff772f5724a8b23ce9217ffcf016dc16d6e16b185e3cfafac373d903e615c55cab94c6c62fbb77cf032ed765c7fdbd5aed9b9bcb671e725c4190ba181f8419c67490559576751a49aa5f398b073b23ade2a2f7af906486f4c635c39db88646ad12f58bc045487a3d9055bedf0dde757019009ba43fd6920a00c33fed39f6b2e242495254aebd5768b474e51f554af3117652f343bad5e0bc7f3bf982ab93cf322d209e47b63bb97e93073ba3dbaee3ffc75d6f8194fb6e717aa12326c637e515cd3d727898ad4b7a521b837dc69ff010581ed8b8b3afc3f8b0e9bc38abb2ebfba55c797b5267fc38c581caf16caac5a987b70455eea63b3eaebbc1f0ab6c692589c4eecadec8b89da342d8385d9d494e3ba6f009d49c92806afb24a65f6ff71fdde6519bb4e1472faef7c7ce8752ae678db0984ae9688492eb2344bcf90d4da30c4d61019a689d6fd59e3fa8abd35dd89e66c1345cc4cecfcf09b9b9779b9d77bb23d201568a5c2f456227fa9f08cf54308cef45c2c37201020ec4797636704aaa3666877635c8146d89059a8fafe764ea965397e4cab22673050a4a5d30495748b2542481612b6d437c5bc96c09f08c72e0a3549835d0590b0da05a20dfa01a21ee380175bfde9d3e58369bfd4ef14a944f36c3b03ed19ca738896bb38f8f1a61bd91506bb1ff8297c24d54fba8437b125c9f5be21c893ef7f03ccf34dfa05b0fbb2fb249ea6e7ba80114607b31f8afae3c04a9059008b60ad5780a50ebfa7e3c1b7cad7901dd83525debc4d29e3f26ade3d3923726285fe82568b21fbc0ae6b00792a684c19ea240b9894897e92f2923c41b7dd55c62c7d03b4a0d0149356da122c78dc6ef68397ef2f14acb596ba19cc56a0dbbd61ab665dec3871b6dc9f7f6636c763676f43b882e004a7d43b36ad3530c45747e66596866d9fefca2f28fd907ecb026a521c7a1397123494ea09915dd92456889fb19d94f96dd3bdf7f1b753596b98bd097c42956fbe4252ae7b066984ad8207b636ace924def1bcee9e6dd1de941fcda1b88d864cb13c0c1d3220570f378ac0b46b67f23f35a3cfaf7887efaa7797b13d5ed31f160f851f16253fe61ee4aa5e6b000e2612ea0d8566fc4fd44a690d4ad8de5a1581437fed430beb0a3bb4e8b65747990730f2a52b657e4f36d6ef13923635d5ee165bee6403a1ef39ff025306e3411c00c3fe992d364dbeb08c2a83f447faac5a685a13762e35c061dcc86fa15e869dc5e09f5f2823c47782ada75455b845074b2379333492e278d443e93d030705a366df77ae01431376992d4a76f8c79e96a28b1385077a149240baba71addcc61f9731e1a492d39d7eb90ff5b536846164212b07e7753bc57f491277c62f5e3b4a96ecccc9dd71a843e10042f32e3fb3194728cca278ccb8388b0208f0caf534469590e96c0b446bbbbde9c8fa3d7923819a6a596901c9f28921fedf081061576fa6827b58e6b2c26702827d7678b5fa70cdd86ad2ca8b684939076f2e94b90751a9d7dae60611779ea2b6d09d3a18d687e216e766c719da0dd77e5e124fa4156483e6025233f27831d5ce7ac7eb0d82dae6aa52008df6ef7f655ca0466b6211d633ca69758ebaf724774f44440e8d0cc8c26e8f29cfa5698539e090356a360eaf156bfadcfe2d60da3474a91fa077dfcec25fec44e1c8dc6ccca61c3faffb5addda9b67e5fb69258aa3f90e662dc2aa7a5f89c4a4078284016d1f6ca5050c208fd2818828d54797ff4a07a1c514434ade6caf1f3f015d6dda4e55f511b2efd589b6d9b3bcf6eabd26ae74af8a714bd3bce100f70ff52e7c65b04f61e9ecec2696ac8ea7a8cd11a2e122db21078de2a32e668fdb84d6dcb4ff8ec489d6068844cf98ad02f227ed0d175c0ef255f83fc18d31abe43c8b5572617733d493fce77a7d3da15b3a0e9acd5bafd4ccd6f251e2ff05b19ca9c93a354827e3a51b62b0175c8160a0ff591855ef1cd6d19cc8c9f0d94c0a950f960a35812586de6d478b7ac664d83b500f04b2a6fd4cf32428679fd0c6c8a14d17be78bfc9da7640a15ce781b805fa306c9ff985c92a9e2cb7bf11b2337c6ef8b88832e1d745a37f3ce7f6ed5e652de8b3b4db809026de848d7638b2e95fda5ff0b1bb0b9d277efd97caba943619bfe3b4150c7618684bf4cb418d64a91312c762a4ec0afad34364f13ebf4a9dcb87c74c849513765d7b7c134b1e68a1fe2c73394e60ed1e603d10681259f0fa5d0d89403e82d266abc24f411a1771b2130e2a3da0d39b8275fde4f56f05b1da7e778ed5d933668ebfde869b80931d10ea5d93c8ffbcfc00f8ff047bb303e6613e5a308d72f4fc477492f6a5f0cfea6a17042180cb52292504768541bf86faac491afeb6796b3f8ecc021082d3e771feb7017d1c64b8b5b47b79b93161cf8c794db88fabf2d944b84293432bc1f3a63ba15558c4b9560a8577d1a48119bb216d9d0b445a97efd84b5f01499d8b859d5f495dbe4c72c215d772ac5b666699f9f3a88a00a97538ed90cd67ab95e3b3a6da6139976ac602688dfded18832f8e49e8d40e19e6b64d2bad6fbce2540a9049824362a8180429192ac1db46589ec8e876cfa54b3db631eb8312ec83705675ad3c0004f151d34010e470707a5f987af0f175b0571259a145ee84def9fc7b8dca40970b4a0874e9f714162fcd80e0ede1795402ce1599edc8c17c6cd9768efc69f29a42f7088faa43e2aeb18ffbcfc09f95718e6d4cf8074704b257e4ecd0e76bfda8cda384853d216fd1760787bce0fe593e06cb38f52f27367d50fd858707fe3d90f735420f5f8134ff2f8cfeb91cbb3a52ff2329a29faf13fa6be45ff520de81c373fb027050b1e18e08e92d9a32594881b17415f74119985965627eb7e748d72c6b5e9c2e4b14c77f36f041f057dc0bcadb37528ded06b0f78320c0a5cc1c38aaac187b42af6e1bc5dfd9b79251c87e9adad68c4b190104bbef4415200131f788ad0a8b83fad722f6c74e0ff8438eb0e2f8aaf5c00e70a4d330558174b32161b6467314592bbadd9efa3f3286fe2f217efe8812e2a37ad87e5b25a29994abb325a2af72da428b9fb8b5e08dee6d448fd2cdeb2e770a0896d1ceec597b5208decd7e3bf200a3409b8d011f2d4ca16d237676d04224b4f3da48acf1848bc5ece27f392124d9a2e1d8eaefa99f3c23fc1a60c83386c338f52c898c2cffe3df869d675e890fffd2b982400d26361124a001da558d0ff6547f0555a6d39348c9559c9077dce87d6d8f4ce50e9e6d20e47934a4740b272da4c29b8f5a29ff7de2467d538c4546c4818909bc4cda1227d1bc8caeaa16055cc19ed297157a5d4015ee5a09fbf853f9d2e066cccc9d1f790204c199586351226b278a9801aa42533ddf6b124838d986bebc6699edddd0c3776051bbf1ab949fbf990a5d45ae3d0dc428f0b78462255a71ed3ee516705852f3e48dd2c668ac7de2e8b8773b237fb9643acefc3dc2d694f1178d48e2e94112286fa941d78bed7449e8984dbd44fcb90d9e006acfcedbedc79b80a68a64f376d305ceddbf185e0efa3fefe4149aebb1e89c9041f1ecf5e4ec7f6f596d1a6256836d5048bdd3fe65434b53bd4bbaea63041057c4cf0ad18caffa2162200b14a5a6a4b01dbd3c796e52dec2f52eefc2688135b16a2646e488c85dce362121b479156227d44856cb62179d7696eb2905f01f34930bcae93bfa957df9e072c0ee1068b54753113e38d705feaabe82bb4fcfe8647cd143418ea13ba30330819240ce99f0bef4660ca05199c2c227d4f46984b18e8ca0daffc9fe30c75fe7788864b4a18ab94dc3d2fcc24dc3707f9392211b9b095e571add6bb3f3bb02614ef4a6d7f29a9f28b8b4ebee588641f2433070980bc7ad995e1fe85b244c4821c99eee3dc3b21d73a2ed6f92bc073371ee64068424667ff28301940c241b66d1c37283ec90599b44d1d9c8546449051648573063a27dbdad98921123294dd260425965fb0e74dbfda2c125aded0e6d2fa7ac1597a1aabbe3903e1b30190ede50a41b1b7236e7fe322be052c473c56030308cac69a2e207acc45b0d74a383906a8c493f4a8db7c1a192b1e13c75bd29f309e8d467d7c9002f7767d42ec5d2e269abd62dc75e270a1b4b86207bac3d5122df1f7337185c0c0073e1228782d858ad0ce4817c6495a62bdce8f74833a3fe0b48a74d6c71ea47124f1b0ff1ab80538a7f8042c30b17cc1aeaeeba6d4fa714827c45d7d91185373346ee5b75d4f0d417f2b03f11976b5cab278d38cf91d46e77768db8590fb24132ab710d0ef415e0773cc329f8d0bf87b0a65188efd8145924d48a99e0add0225cfeef9f0e13e20c88de276955969d81f054983d51744824e46742741dd2675ffce0a5a45e1771a49de73fdd0c9b7c9d9f0b72f1203d5948c7a821f6f6dc654a10a45799158e9044405da65859c02f98755f15a3c70541fd9a307321f919b1af0d0e1eaa344ccdf35bcde071b93ea785104785ee750aa758c281fc6b38cc01b9fa3e0d641a64b49d2a7374c3da4c9bd4773937b90a1749ecfa03b473a4e4b883b0521df82dcf1509ef299624e7285e7a76f1099b10b890b6d048fcb1dc1e1d0e75ca4d0f6e8809fbe2a6a024056501f3a99cdbd8165356c68d0c522bad96dd789013ab720d616777f30211434ed90799b978231384554e7ca6bde4e39b156a6e8203158e4f38ba522dddd6403c1927b9b1d01496099916040512420ba69cda1e6770fe5d54ea9419995585a84002493cd555c1aed1bcc89d7a5bae1c0068a1de97d62137a154638e69e6e040957b44aaf0eae5e857e03b21838a24624ca71b405f222201d3fed6dabd22d78c410e8b588e110b53cc667a8e374e76fd06765e488e1852f0b9428dff5e9ded6e4445214f6a46442e48cab10899835bb105c9b90739cae278e5ab5f295684041a0e39208b713e37f3aeb9656fb160d97746fa4739fd12b286a7eb828285f3ade26ab78569142dedea2fb0129281a4963fb0b04e981b4a6717e07192e77fbb6c0f19d5baf4eb83d93db9911fc459580142b6bcc972036929c64fe23d2f436310989a8b88b3db981852030339735b5ef45e0cbf57a85247bc5d5a1ce737b68301d9e6d036c7bff0b52aa7802ad5f4e6f6dd9fc13a02c92fb4cc5e15509903ae5e89274f857daf0a3f9b427631862ba9a5e08ae9a0bde91c8babc3d03bfbbe421eac48c443b56bfcf3590e91557d66ecfcd6199687f463d406b5c80b3325f7472ab209a8e849210e9683583f4f65dd652320a8bdfdf9d63928496acfe1202092e554a4ee063fbcb271795853ab67f26360151690ee9331ed0bbe573b356489cd94c956ae4a84d4619035f4d430bd80cf2e630d169138900b7801576558901013325114601f1ef579eb855172cc68c91de914a457cac464330637a67f2aebef9ff21dd593250646c8c3fcb8e407dd3c681fdd54e8a85eb34114166b54c818493de6ea1f509d4bc2ebf1dc6f7bfd34753f1ec884e3211d8823a7544e078a4c4a1dba67827e9b3bc85478636bd37e1c1461dee0b1782301b94ade4b7588bf663f42a5ad6783cd05d8caaa81ceb3dbc2e33524ee8460d8cd668a5af848cc87bb0b162ba9f2051952bcaeb3b05dce07f9e45d8d795b0bc9045ade8b0ed14464c222fececdca32ff8721483174dd35ce700ec9e249c0101fe8a91197a4aa37676ed366f9508d87acd3eb07d6cc4e798981982e268aef8c9f116634412e2cf686fa31ececee5fe3605566832a5a2e5f8541a69eb152c2822d172e5f398fe90192f7fd471337840cd3fc6b6fd53d95db23f3c96f673915a6bea68a05b1acad9151d043a20657e67d8929bc8c627367b141ec609307e75e7f243047a30525e55cdbfc7a0d302117d3a6ea4862ed6c51bcece2b4d4c1a3d4529794202bb397444afb832bba1fe3b4349f02222524afa320d2b3817ae3a0eeb9538938adfee3b7d4e1049da9105a46600eab009dc0a49cd8a7854b9d25cab28b03891ef68839e5e968fa1e2d27434909abd685b76280041e6ead280577b13ce8a855760e804bdf2dbb4a43222b8d79905462759d86fe4a33ae9cb7b87facd240da18cc46f3f4fa06073eb3609d19eedb256b8de2402a1ab6e6d068a2a948c6a191c2fe46154d50ad83428c8cba789ac298017c633529383bdb1e2f2b589490197f86b276f955a0b02a458232aba11d1ba9bc46494d0f4596e7b532207463fd38f32fee6a79fe722ff4d4b84352cbcb24197fa3a5f8d06a515efccf8d46f77c72201916135f1c96204cb39f492c44d8a0cc0c3330a590d9ebcc7fdaaa5d4d19880523d0ef6ae4b96f44a721c521dcce20c60c74fd1931b825f9f8762951a6cd4222d61568bdd6959b98d58a641c2866b49b24aad120f567ad35bf9966b6492c9f7566bd9e2ab00ccacf2145f793c4b4da0ed2351110580cfa7901bbb9954290a0cfe7b1bb710f8035d04b604df22267b33acd09d2acf1712c592e975cc75bc92017b459255d867b1ef20a630e4a4d6b1e5fa18d7389d51bd68f8b80b219d226cbc822fea529e303a310626486f27541b87b10b224cb3afd25a56aef6af24d3aa84a333a667d2f1952b6cdcc0b81d5638185484486d62099a211dac600bdf0afcd1fc5e6c79cf0653e21579bfaa4b2f76e4e44c7bdeb464c34823e697dd7a7f6c78b58749a0d4864f82919c5c67853b34fac58775769cf43f7971b6468f76524c5cb887c1843c04b760c24063a2577a190643f378c579678b358e3dd7285bc3b375b81a31ae8b351657730ae77f56cb719028c4fd5d60454c44e84cf8082b22294275e8b573b1c6010857498f15f482e9af6a0e054fd4a34f30bdaa4c96dc8ac923414e4a7b42dfc26c94c737206ee69b3aade9bb4e8cf34a9379866f7363cb3bb71e024375d3e798a81ad6d3cce3a99d20f85237d284b00726ffa4a682ba578cfa059dc742aaf2c0c40ca42400a110bad8ac63287b9885f32221222523ac92622222722f364e92624c23d9895ca4339102708ae7c4dd78bd46a9c72141870b69413ec1772fa8aa617fdee841fbd23d6caae369e414fac1ffeb19f8a45a07df4193d7fad0e9eb96139bed45aff8e2861985bd0aaec4536ca18665914dd4b17483680b940d2e293ad40ca83e8aba51701d2764b64b3298fcea45ee606521d3604de16f109286456422645e05db8ba844a779353a1f6f86099e3ee3e6025c8bde31613ee4d72b4ea1384147f4e895c84c4918a2930b025aba6b6451337311b1bd384062a3078081287a9c46b2222bd4548e599daa5898ed6533333e0e84416f502a9d6ea429a1d3f1e285833e30f45280e5420aca37b0f31257a4c80d8e07cae3ae829a2b3aefb21dfc758add25f52fd0ca95f7235a3ea792452789cc9d44a81d8fc9deb5306955aa7d8104e4ca1c960862f3db6ab6ed0b59ae6f71b12de454df7089292366d506c5e3627b6b4b3a3805b90b0a00493673f328d42e24f93b99e60eb7da6587c46ab20cceb9272b858181e8280afea775f56f6a76d46b064d790c9dc7437517d117779b16a4c2015c2f8f5fa1dda69dd6a87a42f5ebd37ed89c2dada9ece6d6fa937f101511a3406d6ff070dc023a024364cb2b8a67ce92d6c3797e02eb04ad46afe8635cd58d9db409f3fca5ed64cc062f165474d993774ad3e98bb49c7f763a6f812d4430cc166f9ec1e83af06f4879a59d0d0b9a6b0d72b6a3ac9bd57a87d28a1bc14f20d401e03c862806df24c5f9aeef4c35817d9f0a03c0ab0419d0c2eca1659d7031478f2bec54a4c24f7035406b7664327800b84eae395365ddffd33e89cfc2fc80b2344a56236a0db2220d98a57100bc60d5080b8c673511480937afec03380cbaaa86292ece3a79da5358f9e359cfd117088ab7cd9ec9cca2e7fc1bf6d660d9a1c94d1902d7ea156a4f66608e352203cc13e57880d57d449bce89331b99df30fa346b6631bdaa388c1ddb2fa40dcb1ffd438723d2028ff73c2cd389b176b024657a8a3ecfc59d696ac9c3c5d1fe858a905ff8045ca81fb4c21690da5b4c550417811832925cd26f605165147cd7d009b31883d2db03939e6679a4547034d8bc9715fe901f041923d7081b730f5f471468746f40dab2177509ce92359faba5f619ac62693e998c198c9da4c86e4a64bbe1fb55e2a0499c0b162799f2aea0b7b7dc17074734b84bd8fe10f1189610e85a18440b1c816a055a572b5f5e3dcf3311e3c7b282228a51434aa992e7c329b8031986406848eaf54bf706713bd9ea5ed266f3f22c1f2156b330599f201c12efc6cc8c42c1ab433448401a42fb009f5af7a6999d57ab39334921c2ebd8aea372329509f82927368c9ff196a8d69ae22eb9ee936ef0dfd2aa9481a04fda9c53636a6b60880e295dc69d734c83d5532400ac3a1b7b49765f47409715e0874f67f4a8b1ce125a1f9bfc5c5508fce823f5419d4a0496a485af42a49cfeb83ea3f9357d52e11e2e7c1d109e0eedde7835fb13b9847fc17041b24b65032fe71beef4937063b0610f473915f6d5ff753a5e8f8ca803676d662d54324d7186bbd50bcfbfe1c9d37af0b4ef94e12126a673d8a3083edaa4c37fcd557c9eb7fc458d930c97c83c8bfe72e3bf86fd7272005c2812ea87fdbe731cf6613716c749b84bc493833aecb3e1f0dd28d0e96c4f44992a38821a158a289616c037a199dd6ab33626dd58fcdd6b3e7554d97c1c15cc1a02b14e37143c4a275b28fc2a0ec5e5a1c6c40b8d3df9b2fcc54393e7bcbea53f56ef79a80df33996367cd4a8695906f7df9226e4f1ec1d495408830bf94dcb750788ddd57cc2eedffc59abefea75fedff0f73e6c66c6bea62ffc2fb8d092e374e2362d18b3e1ff37355b1b2cf3e704b1306fd3e0df925b624c20848fc1c636c5a6ff8d6aa639b4243679230fa12e0aaf2ec09641355fbee8525d2e3a72d7ffd28736f2b93730e4e19d2375b1e67851e8f2960e821112d74f8335c2305c8757ca2ecb1318aa1bf95d928f5f9ccad1958ff28d78fa0be941f9d0d2ded390d29b8283ebf9226e4ddc12630e4df93112938214e04e6309c587200fff4ba0bcdf585f3225676e6fdbf518bd0d36477fcf5bc50d4584f6ca8caff2d6f93bd12536a1cc83158d55e5c065865baf8b1706247591475fb0825e237650b3d009a2d0b9f3c74066fa537f2f9842a17e0976322ab565d360137a9cb4e34f42a1b45854f979dc3a33189ebf2202aebbea38eff37970dc4e8fce6ec72e277c8cc239da194841716e5a04b47ba45d6e2b63848eeb0b4ba5a04a82c78799627b121669037455e00016eb957219243c37e9bf5c33772781dbfd34ede224d858055a947ac062e1f635502e9f94fb90abd33c2c1f479ab141532b64d7affd545808008997f93cc5a7ebd4d6a74e60308bda1614ce8ca0c64ed9cb991276747057b377f1e33374dd6c9f1bd583a1a32a8541d4e25cc0ae1433903ea81877815312a2b4fa89c2ad2df92e01d26b3321ece25a21746402d2b0391676d63e6249b86c02d54f6c1b0936d90e77ee8c1d01ff488a1bab57e9112b7eb40b24928a8c41ac850c18da939b0c2b928093a53e130aead0e273e5707c968cd867bdc7bb9443688c275ff2b64847cf0c4d526620f2f73afb5ff940229306cebccac1b16a9e87c9288567ded2c4592f79ff320ad91e37270b2aa0729fb7e0a8913da0cb98be7559cf4bf25dfaafccdb82df2fcc5880f93448be5490717419ecc179c107552cc1594681ee78f69dda07ba414f2905664200ce03b4854fe27f1e55b9e5a108a015e15923b05293eaa557b6a584d7f83610748e85724d234c97876816bcaee8538d8fc97433bbe7ced514f764187e8494663487db7d2391a4183c5d82dd43ccae13e865217846ec8102b060c02226635e543cfd7ea999137d1ddeb8a9086d682f7694769fbd96a78801506b778196f1f2da58898ca4183624c8576fe7074dd34d0fc3c8d14b4fa728bab277f8614c536ee05fa021a1052fde936ce4706c8fde07feec488df843fbaccf47f266cc3c11a1172acdd1dc5c2ee3b1263222681a0b7a9a4acf4195001195f584f11cfffbe5f1dac02fb72b62129dfd47776f75a378c944a89c445653b14fe9b0d9652cb4e8e2c0bb5df4c42a8b1b6f7b4bd085af80ae35d04d71d7609326e21cb159b539f4df569192ddd25b4789668ce5a1fb67b173fbaeae3547f0f3341067c07bac5d2fac504702a245bb9102f2b5d686d7f54154efe64c41ca3f5e1f569b0667681f01f2a48f42fb383d1422d9814f1309a4eeb8b4544659572c7a9dbdf3cb9930deb8f678aabe900734b8971175eb770f6fe02702e49a52c84c6acc41428b6b5b4f240b3c4916f3d5631b81271102e22979181db25f5887659fb4537122aad8bb4b6d86646aecf320a249a60fae03e6d9901f251b4c6019f79e501b72fb8352e601e9857759b0200ece227e62675a73b5c5c9ecd158823f3b829609717882ee986e47810a776bf1e04010ea39fe9d33c13bfce491139373c5cea7d4d1f237a3154770085e1caaefb3fbf81d6a774d448746a475a1edea51fa67643cd10024c277463fa9eadb274191cf9f5ce6d7b78e8ec59c36f39fdd544b610099b68643d6dbe40083de9b5cb7b4f8da43bbe06160dc369432d83b6ad7f979fa740c76f72e01d13c48f0cffdad3cb57a65b8a00ee238d30374bdae2bf7890fa0803e88dd532ac641a8a2b403eefbc52fd962772e34f4e87812b1eaca209001dc7794fcf6b952bbad3d471d5e76952fa250bc4f50ce3c717710c70b114b4135de11403357857ca6a10ff6c7bcdadb0c8ba08dcd6aaafa0c3cdf1d55f37b5f9fc37862ef044305b91ae83471091de41be2cdca27d3f4eb4888b818612a85930e47acc77481d44011b99d0d46e528962c546c519d33ff04b0d82d33efa7ca4350cb6024cb370d0aa4455ba2bf27335e3942291a1fa489986deb5ce38c89f246bcb159c97ca476d1aad6d43ae6cf592e7c4000f7479f98f34df11be112fb794c6253a22a9dc8440465a2096ac934f4eaf106da8a20b6c67b7c84fc66cafa3e2bd2534c9a2e9235785ffd5b451984f0df98c5b50334629608688a28ad286d3ae35f0ba40c42d1832732fda28b4d33148f4af141df30b0a7758cbc2d4bacf401d3017cb9ecd0090255e082f70931e11bbcc78aed484ac67e1d47efe13db3c4cb17c22af73f91a199ab0b33a955f8564890f768e111865488e561ea611530a791282ea79354c1159d2751d5b9c25f4418d00a04b2fb42136835f450292549ede72cc2599e35ea32ce9420471290f77c14e4a8b538d0491d7c0fb049dd67ba6052b4d793b9c4f57036d0cccabe296a9034d8d7eb6ca8cabb7c39f1cf1e3d5b0308b8bdce7110ed4fcfdc7cf80a87ff956688a5ec48da8f9e37b946426b3b11799966d1ab4e3f3faf2806204a798c8ba84710d210280161ff222405ca116bacbeed197a9df6f4baa4a17188fce2776241e97a0c220628271c006f460662aa83754ce8e1f0260254867b752c0bbe2d6ccc59687473cba1961d7692e6a9d4c5112f3ec3fbe0daf0e54aaf9430b2498b951c57c51c3003afc2532f6dc1543d4ef39562699997116612bc968308aec0267283c4d4af06c01ccba95847821d33fca4770ef41ceeaf9ff4ea19ffe2a63bcd65b6d582fed353a9fea5d7e8a6664ba64bffd4517d1bc0be74ca9e60f28476777d4d719a8b355c16cd8fbd97a48f08e17a78d0850bdf37fd8634cd151766cfe7974874c7c9e46185cdd8e3191ebcee08b50e40e32660371c2c8fc997164a3f2f401d2fb87f0dee94bca84a7a8d2c217b2844541e168f8828377f15f560bb50c0afa3f5a5c0a2f311bec6d9f8a1ddcae84b1adbab3ffd7ce3a4c48b5c029a6da62db80be1c0c58e4345a220135abe62c16e8568bbce0143d885f2b6a0ed0290f117029ac0e5263a49dd40a0bc09b31bfefce210ee232f832a0b79c64a7df40fd33a3e49614978a4f7678a3a0847785210a1343e7a376fae91d929080af9bb06aeee0dda841a8a4cfd22bc790dd7ce561fcbb6c94b8ae511a54dea5af456faff923b282493823271307b1578706ab5479852b505eb6b9a5f66fbfdd3e8131d759062bc3ec71aa4c4c27dc75e532304d814989a89a5f330703df756e32182c7ca2a5df8278c58a62afe3127f9dad4ab2dd1cb94fc271d6d3a9c39c055dccf7c580e448f678824df6803369979fbfd201ac7f49a288a5dbf949ae78fce1386678bdef14144e519698370f1393f329bf684a15da2805cb8160a2dc2f73cbd6d661501c3f9b02ed4a54108decf12b40bebf39b2a54ec6fbc5c92365c0b5cc557982dabfedbe1c6ad4c3e451142a6d6437e58d47acc16cf1647ef55df1f1d963da5205a9f6318a5b6295442192bb3bdf41bc7cc66318ad0b8561b6346ee8e77725847680dd620b2d1818b325a847b68750b1ad265185e79f9ae340b8fcc84509f99062096e998c699e2ee11885f860ed4ee12bffed49dc8790280714b22a7027a1e9e85499e81fab63e7fc9fc985016a8b94e2dc5dd618bd9c2b8b7000a2959af1fd4ddf12aa178f6ce6a744ad19696d3f12a6f5a78bd1b991b7f8117527c322b84c6332bfe0888f40fff674b7329aaf948ced7b1c23817b9b8b576cfa34d468da3dce66a2c57946a09f8171cea7df038bd0668da6e14fa4f94f43503c5d9f7667f8997a7b3cd2f9d06b23911923b4532e7822235ca35795ff28c2f64e268640860df89cfefdea18d67150197cd33060166f08c412427d5c4f5a7d5f7e7894311b33a21b783777ebde2e73646933d516b09bc47178e5f4697bdd85de4a1487071f65ecff0f38a8781b5ada047b96b1153a2a9d7683f97434f3b8f832ee829221fb49c0ce085a81132c7c3636be304e06723b7e56125a340d8200e99a15cbb6efbda4cc6171de1371e668749071ca66ac3722bc52dd7f6008055d80bedb845374d3ab29e26e1d1e428f7eb010bfad301836a02adfcb8d4c10e13a84042048a9ac4a2e4e8f2fda8a8f95687721536c45bf6406f4f94a0092420ed77eb18a1e23fb767472529302a1dda4a78996cfc313372822a8710997943ba64c8bb7dd0e9f5e94a08e8e319a242b80da069c61979411f8f03d8544f99a2ded1f44df115a982e9dc34202337e47a6d09da9803ec632225ed45883bca33656265a96116bd77b9248a2284240de95985fd1bcaf760b6175b072af643473bac41081038f8ead0794066257c346a834f3af4e2200eb9e0c55c86e9e14fadc8fc96b744bc7dc2008abb1ba403706014178d060256ace513305b5b3bda5c25c7899215980775eae8b0f27e8f00c30b78608908f0dcf1d73a2825f72aa0a33e0416c2a5158f799e0819c678139e5d8207a929859017ce3d1b9d2912147f9986226f2b54c59203879f2cc642aac4439bfef5b0d51d584c1044d5d7e4c8edc4741894bcdd83b531040802e89b0eca4ab05c0ea4834b24482ce920765b2bbe612fbfeb778b8273e67ff122093d0ebdd8aa5a423a3d03487f990c154d13fa474f0a75f5c15a9c300c8a14319b0a8d7104b18a455450463f2cd3c200f04b532381d65951a6af31c15f8a0852a2609f72146f1ccd7119c97404b71a8bf9d5f701b189e36f536da41034342e3c0945f09386f1b4c429941d70968df1879ac47088ecaf764683e6bd1316631dfae53b7e4bfd81536f8b76e2973ec38bbedc5374e202e048935cbab09cc3c411e3d6fa3b109448334192731ab37f1a8d881ce44aa41f53c74b584c18d0c2b9b607a71de1a187645fe196f7eda14597c4114ce53e87234585f5ffa65fce03e7489c9e9d006cd50db974999d9d152aca051c07a448a89cc16479abc3336d075c18981b8ec5e567e0447f7b7046060ee03237bfc0b3e5e3d21765c046c8aa226da8d3dba4016ebcbe423efcd8da8754d763e6e3fee77aa55a17da6186f3b3bd83872da0dfd10df1cdefcb91944313afa749d4083004b8d4c42fcd181576fd12307ff2b7b95401a2ebdc031409be456e34358eadc5129455ea7648c89ffd29b6b79546afd48710f265dd3662e14323984c6ea7ab7efa387f8a09950f40196f026e735282db094c1b4175bb28b4a735436354eb2052acd494bc5611568a7136f8b8da6090c62fae198a2e5d156bfdd660b215a8f6199696bbb551100235bcc73c48e93b1f790456f584c210142a6b831fd650a8d6f5540164657e701976ab27e62777c6354f161a15aa4cc8b91cecf07973f5996489b886007e960368693fa27d4b73c811aa05fe65fbb08cd507873afcd42c46542cb01531b31e2b37b5e46830e7f1e1adc4b9a305ab5742e4ca0e4a06cc1b9671eba828c0309ee8eefd92774563dc76fb82d3514a699a8ac9ce6a8944d92025833d0edf0c9357dc2ffcde38eeff1fb3fd36fcee3e86b46399646c81a0e8631208d5b42db9afefe5f369ece46e93111032fefd94c7993d3334101e20f6faa059ead24e00bcfe431921f0c89e443b6393d44e129a0c9e46fc246be36e6f40075e00e8e9786df7f009deb76cb8853be17b42d57b3d3624e184e57e9ffea6aa9baa35caad4963cce24bc07d42e882dccfecce62fb0753e9935fc8c8a414c0ad2705632c493057146fd8a5e3196edf3134ba5830c042cbcd9305517e9ec6734ee5481ceb5d5bb42039a7679437b56ba9363a1f8bbafbfb8ab705ee40bd7a58afd2d55f29947c9fb41a0334d50a762306e52c20d0b83cf2febeb75bc2f903dcbfe4df65d91a973679c052a3357e22fb61097fd85fc1fbf5e42b364b152a62e0863f7c06c102329e80380f820fe2881a4748dfa654cbd88aaa7b4a687a49d86b5b8bdb9978ba28eb885870faa41b8fe9d9a09af3c2bf7d774fe6f6a54590e1548f09f0f763a30511b928e0c04ccdbcbcdaeccac4312b000c0fcd177e9c8fafb20c12908cc2b8923c2cf6cdf0cefd76ae8795ec85579dcd5eab288711f1bbaec56827a098d0bdcb5e604dc0c7e44554be12b7561d4eb909ba788435f2612204031f3ac76bff3b5797335b89cd8cfcad8142f01fc73bb1973fd9f56f237ccea73a0c6ffa4fdd8f847b955941ffdcc2ba6171393a871bbd947ec5e435ff361e9201300404fa9d72030e3f833a7cf7fb1faf298db51f836f88bce934534bcd92ce954b30f5a1dbb5c272c59387f6771dd6ba1b30d0ff8c9a5019893e7866caec77ee6e787d668227cea05e2bd61452869ff55a06a23f750505f07530b97ee22d2e2dff0814069b67ec81722b8263aff3f17d9ddbebbc1bc378421fb2d85f3e54e0da90c701d2c40b712608525b18793b1390d47601def141da36a1a7fd8a04ce898b05604d1e32cfc83ee96fded1ce46ced2fa484fcd52961076a465fca6c134edc92772c16e76824fa5ea82480dee961606f6cec58fe871dc1468080bd58ceb221b86a2e4c8dc218b0c158ffa3a970ed017f2f0903316309879a77300c835bd24f276cd79e1877e030462c40b5e7d60f0dd81c01040477723777cd06a3f3454aef4287b74adf89c10eae4154d58e35b30034634d00293b9a43f59521432169a3da8e50ea00d0f58eeba8175691614beb4c3b368907e58bad6be58e18dbcae8f48cebc06465412e26bf700472304a8fad638cdec7d8c173661691bffc9a11f1349b6c74e7fe405733ab7b5490a07a2f1712cdab99c9024fe2306b9a51ce0ecde8723df6c0a60815e1a3078756c40ecbfedf2562722ff9dc1eacd80e3352535b73828dee4e35fd48ca063af9b23989ddbf36049cb3b16b8296696dd752a1ece16f13ab5e206c69c78c69f9e1c3d1c20ebd77839fcc070319ff9b484b0a38e41d2cc89c0e573b36f751644c6ac3fc20b2c28ce1a1b242736b313aceef4e77afab61f458dfc830004da706633483a37532163e9d161d3a49bdd590c0702f14ef41c313be86f9e031341cf846fc1f888f4bc41dcdaa3f04d98b5c2120cfdd36bf5a50e59f5f9a23e2ab8694ae8e2e0e32a602f4ed8727c680673cd90cad2cbea5b5143bd9a77b895fadbcfbb0c7ecfd70ad7aec026bcc2920a10e9c2d7e4d83bebc31b32bf57ca7528a00bebb9496fd803cc6ab5236c5c60e4140635fb8827a3f0288bd3381c4ad5575d159fc0f388ca996b63d83e37bf5676bf37c609026a920fd1f105ef512f38fc39091762fd94fdbd6df1e984f6d2d8d71394f9518eb501ceaf9c45a71fbc87b9db215214534bb259bf823fdd73ff5abdf1122dda318b2514765160b0db30f38c1a0b6fb207ff8cda8fb507ad50291b72b6cf2415f571417730a86cb41b2df0f7a9f5b36db0f02d6d1d08e794bab2391d418732d4431fb63bb9962242f3c9cc334b2b36b8890c2bfb98f6dcae17ac39b0367e28d0fb5898d2ec7caf991806f470477742b472398a084f35bbe4a1c56db7960a9c5f768a8eefae7855ecbf1a3d1ac7780f02b6e6e3281196229915e98a51539033eeeb5090abf179187d7c57cef30771f9e36c1f09a1de743339c483c078c5887f9b2d85a54c5b56fc7cf34cfa1be782b1f6e345bcb4998afa070340152b28fe03487b582865cda3a0f9ce0c596d5935cf00d51da1bdbcb4bcb4a865d1d26c18cd57f3d40bc4ee0647c156adc60b407127a8e12cff2a7cb7b4d34b62f22faf3872608f5ecb571496f0d0a4959345657c74a032b7b9beb9856abb634564650ad300dba964dfb2d3752a5f39d51bf615614660ece2086e1dc6fd6b656c8fecedf3935af67098f8c9de0046d80cd271a7652f6836d0b5ca3830a5ddd28bc1f02c7bc1a0bc5c52e08b9c387af61e4951edc40cbde9ff986ae927f08eedff0989211cda061feb1621ab1e03db35e3493060baef5df3fc92efe74ef97775407bee4fc387c6df25e0ba077db416063cc2bc3cbb61c584726c08bfa25d41f92c20ef10cc889ca5ff62e0bd858978e1c26c6fce65087605e474d7c820219b0e22ed272b4db35b5715b84584803423506f181aea48e7c3b5ebb937355a1ad2daa14c3cfd8dc70dd43d32a72e482ecc9a1030eb40f946ea434ebc9d597a2922acc2cb4a9acee984c9515ded43e6114b5f2241370f09030d7eb645fb610a4c701292bfd7633a6acb242b6c62552dbb9fa6c72f325b0ff07ea5b085cb1c3660a2203bc549e847fba00729d601d03b56186722c4d71dffd8979215ee5230890e8a2379d0e100b9bc3d691221de8ec01007d992a395dce0f6c98a4537fd25a6ab58672f7edc016dfae3e4af86ed38d3f60c363b3ef3185f68b9de124fc52b68b1f7183e9aea0ae3a8ffa48fe201bd8a5b1d5e00b3ffe0b504ee9b9b86f9f021ae571444f2e5b7f3f38ef0d99aa24a21f540e8c1109d364ec3d1b0d8922c2cda126c121316091d747683970905c1b320029b22e35e30513e98a5ed915457b5ddccc5c7018d436f6e4e6fba581040522890f29dad1148ab61fb5813a388010ebdb69ddf6498cae815543fc8ad816be160a7131202a6d2da21e6f9d45db92f052a99608d78aba6c2e4f1c784d76692d6027e9aa5eb2ab9a0452c6de04ea2002c6665d14c5dea8ca8cb577daf29eeb2392d7d52172d702d131dd367f589fcf691bd4270f25fc143f3333cfe80861d249b47b2894277cd41a940e2bb96eb7440fe8b1b0dee01347859503a1932a4c25f538e425de96a50a1f09d9b98a49fd617214f2d60d25f7f54472b7abcb1b83a1e0313c36886905afd4d939c5c022d8f6976e410ac7f70bcc363989340c119c919942c13ef6a72db51e2ef0772c625a1b323ed0c7540506f47337faa2b556faa37bd7e8f421277f14a996294f12983e402e18348ed8caf63b786897bc878176f70a79962e9321e532a59392524322cefa6a5a8b6b5b56d7c6413e4a25258d02b5d8dce9a6aeeb0c67ad1d4f59bde431605e2592fccc08050f2e7b718d3ea60461b7e2feb98290f1e94c21f6b232d46030f01baa471037236e99e48e11af8a3ca9849fb625df0595e1952130daa4272e2519881a4e14604c63b4d8cc21e06b1f5e47c5bc565daf9378828c4709e1af64726c6e376b14a90efb7a2cc4350cd306eac6bcd9c06d3b10d564a7b5754840a60294e640a7058ab71da9580ec7548b3d87adf07bb55e057605cd1bce7932ccd8ac3c1e2ddf7a0f1b534db623a24033ec47417bbd1020865668b642b80dfd646d79c19611efc4decd733b15ddb3a78acaf97915d8eb6c3d295002cfa85d54b387d5e59906d849fdd8752b4167524d38bfd1c009f58e0e901a08d8c3073b456dacd0a5b495d89b389384c1b848de3b10be40cdfcbe9bb9db803b397e588b491259de88f28fc36848b9783c50c2286acfa4df0198a6af5bca8842cbb9a6ebc4b4dd1f8feb133c2753c5f55281daacd0223446a1876d1c19ee88f86827aa3bb2c411f9872ebb78f6c560e712b8e4f66f4fd2823d471684979eee650528b81b4e331eced8dc71cd2331dd7a96324978b08ed25b53b132f91f747350c32b4864e99e0cd1142dcc6611e3d5ff8fb71e9945f4824678cc02ae4379ef32442d8d1ae717243726c169b2b37935fc15b01387767447caf481c2a83ea7ab137db38cdcf2efd7200c49f552a272db30dd113e0d70522fddc886e83aed5f4a90ec1bd8711c23d6d3a9b66daef6b7f57cb9f809cff39ad45f62d0f41f7237d0c4fde46e61d4a649a7190404986b70fc47180ec9e4dc267481be7b9af0e379e05ac403d35fa975e264e5426f0ab4f64be7b01be9ac3c4ce188bb7886c3a1e2f29d5b1583eeda2be37615aebb150e05072902f3fad6307844318f8cd31bfa2dd37d4932e11462e99324d8b3f90da6479da26c8795d78289f78cf0ad383984d558fcbeff64549720deba2687740dab6ced253f61c7cbb620224324011505a7a657b28acb88d06fe695488e14f1c577e12a618a70915d3cdfae29fee386f4dfffcbc1d339287decabfd996b43b312a1f24f71bbe1be342ab120e87fff9cf0b4f27132fdc0c72b04183a944f41dd4cfe6e31d7ea35c684ba1a3940205362d1bc05555d096377d2a1c15c7c37684280798bcef467a4f5f59a76e18766148fa5e1f4e2ee381fdea5ccb41cc748e15f75ba3f1e0ae013b4f3c1022b9d0c6c5d2912b24cf19876524da9ac0d97c523b2afadc53b8f2db2d4dec83935a9ef324bca57ccfcee44959fc3895c0881d27d6a93a5a61dc2af43594f8b2898669bd2f92c98e089ae73e1ac39eb4390dc7afa34f616ba3d73042420f535a3348e03ac96b6a090c6fe4e87279f26e8cece987626175a14277da73c0720f50d1b4be2116a6b9f7ffeb4b7d61431154dd21e5047f3c2d0df7823c07cfce2ea5d5929fc713b6802397dfe6bcc362492b8962c171d0205b426ae7865ccda410aafd366b7cf1d3e294749f4f7b7d9453b2fd20a151d6fd4e982aef81d3aec38c2c1f35d64ce492d5dc2d6c0f9e5e05170b4d11d5ddc2bc45d4a039d2712a27b450cdb16233c8484c349bd2adb3a62cacd8538aebeb961c49c03f36efb57cb88cf178e60e0d60c7c12d43ca387dec7450ab82d5673b7b6361716956a94a1bce34551a2573f10f426c640ba458ff2b5f04a70e7f7c93fb46e774d255fb02f3f5458278fd9cd0193ec65273e86af4724931a9724cb68939ede66f21fc928304717817411c42e287366e7bf10d387354c1b738e0f56322c9e3d04c318f25c02f0884680352fefa406c27dc0b7a3cfd32e80fe52169c0c6df98749a1c02102e82d1283130d2efc8bd22250777155b502947b909d564ba78856f9b1cf007f2056c199cf106471d77eaee1ae200f859609ba7a80f6b4e6a39d01fed8677829b2208f22060ebd0fa0cb2841c0ec31852a87709a62a13641ed3afe77f939707115d78c958f672c76d3545dd36b08e6c3d79fa9a0aa044265b354e9a72007e1558e9337641636373a3a8549072445d6dfcf39b7ced56a7e54bf612a12f6ea89cf023d2c6dbff5b0c8653147a80181a8f6048f36a230dc0daa4347c6364703259182792b13d4114f87849d75f22a239e659320278f14f9d1082739b2f3fbe5b2fddd103becad158d5e83cef5817bf252d55e9c9937a38ef137177b8c0e351bd54140f4770c7708da50aaa7806b7d4d9421e85b31a833eebc4465813a6d9de1668973899201feee1c92f3e2a41a0023d12b6b6e6e1d75760d8530f6729af06688188b261a128fa58683dea3267c70d8bd3503da88ce57836c952524ced42e3ce3e8d82dbff72744cfcba258a21c40fc9801ea6ed9366f359989bec25f2cf262414e542c89a955d98fde96c9ab9dae8cb18dc095600d76d0162e59a8e016a0759a327cf3ce24959a18fe8851edf0408f6185187e58522468534074f765829e4d8e7040c98b246d05937bc31a570229b4718e985c49fe9f01ad12053f70dc8c032e7a35dce450fc2b187ac106025c07f84146b7a72d777ac3bb20248324e583318d70dc44c2dd561fce7e93ea511c39568228639e1f383b2f991b813972d560cedfe614419d7f39c4246c5be04d104294ac5d4551f0411424879ec43c85d52273040aaf9ad58bd0bf26cdd32fd90ba3655022599259d2985fa510fbad2a6e9e13b7da28a001e9833ce43e4da1ed6ba294cba9f0fd3ada0d2bda87195714cf8fb3642b9d490e0e3c8e5fad577c7cedc7ea9d187c44daa2fb736db0ebd35099a9986bc0feab5703127f1a76853cfd551253dc2fbdee631458faf70b86bc1fa2c5ac0401e9c13f2c8720162ab69af828a11052a0fc8ce58c46133481d1888fc8a134ee98413c5f0680fd9cd7f5d6f9f28e808140a517da999e500634e2311b48b244fc98820074422a1e0a187a4cac035ff05561cedaf4bdc8227f2aa8736301890c3617e65148412826490f0de2da90e0317589e40bb13fbd26ce4ea00e61fbe7aa8b2d02c723eb86f357ddfc9a12cccd03ac1d9e25359e0115685e2e54bb318708d28be7c518211a8fec1b2904b4c94121652270cd13262f41b99a1306e8d2be64ca5317a2406befcd081c7f3dcfc03ab7ddeb33fc5c4942ea5f36ead07431bc9dc9f92ce34f5e88b9ed67b2ae9edb288bca857fe30f773f484d8e71d4d56ef1b77d73dd5e20b9deef7cfca3c56e09308d66009a0787b82d3f0ddbc86633b14ee07ca6aafe226e474ac8a4330e312851f30315a30269b1a5aa0399cdc33514c26c6d388ad865b2845274996f124d912c16c2d38c646432414f288de305891bc6bb537debff7abeef371a02281d4f9e3888eb2ae940d805e1796e8749225e4f7f1f3e6e9bbc354ff0b3ad8ba76825913cacf43892838aad49ad8641b8d45aac8aa09c4e7abb09b97e9a63292a698718209d94ae030c23dff9a57a364f6dd75ea6b22410da0750c8e52491434934beba00a7929c98f6c37b7914149aebb39ee162e43c5454b38bdcd34cff7200e1c499c986b6a9bfa0978beb446e50245a67a9b88a5f6fafaae396788c6bc4f1ba40ba7bd4208ce1775f81f15651dc0e580a3af5317531fedd60c2dc1c476e5f01a53d3da3e444b04af356248b17afe143673898ac9a7d5d0608662c2d160babd83d8795cd853c8e689e3b5424f36d6e021bf1b7202ae50ff25dc6c5289001d3a6ae3cd940721ecaa6dcfea7521de5d3fcfec1d2fafd5d1cc3f77f5f81007b01fcddcc33b96a3310a1ad84e22300eb47256a3ee3add2b1b8e16fd7df0082a0cee7777709538dc829cc07770e6ec026bc97fe2166d653e9bbea61055e7d95b8b99198bf3cbdf4ab8f2539370403210447111698cc20a076f0af16a5bf61f6d835fa939961b0e147cc4070ca6607023d50599bcc342a34c4299d7a9b5e7fc956dce75870a5c5edcaebea85b5f82ea9fc762a412420611b10462f63e0dbcd7055b0cff539d2ef0a5b2efa87e0fca3f8f0fbf3f11a2b5d07244069ed8d1ce129a7ae6789f73a07deaf03ab688a8064cc3ed6ff3e9b71609b9ae83aaea5575a74e257e3ee708b927ac14dfed5a676b488943ac767c776a27dfaae0e32f4970b7ac0d4796070e81d537499a6cf178420af66903dcb95e79d2c3ef068545b5bf428023eb21264092dcda0f5fafddb275aab9e8c437e20bce759a7582df6e1e74aa13590ccbceba7a20584d5b0fdeecff403696ac455757ece1773d3ad9a9147b6b52b28859c3a34e1e429c80d07094965cf70d18d0848eada227c1dff028a11876d0ae1f443adf6d28f8f027647af2ac33e50d3b44f20a45b16f280205a6c47cb417c545a0db53b8d08dd8945186860f121d7edbec2999ba357f6128554351d9cb558bc2847a446796d1de8465a3662562393bd60b9685ee905ddfcfeb960984f0d68d10e6de42d8c3c6a3bc14b454e26834d9bb842bb3bd0d867a048eda54e03e267f98e789d0fe3abc380c4c3ee25f6450bb60dfc55e05ae93dc1c316689a7f250a0905ff00a13f12c95a229646b937bcd1999546179553a3f479c20c3074a6b9cc209d2301c360a13715a0540a4695e998214efa8339217435887c2690c56861cd3003417be4f5b608e988093f3d0ee7fd27959537945a739eec0fa08826bce71ae846ca8f8fa9e4cc71b726048479d6e42d3c279400979507eefcce21caf279bec6189ad033fac3acb8ccf0b355a2c462f8443df035fd713ea2022a86d93d67fd37d15a611ab7adff2dda14992e4f2bf4896725f2e7677d927f6f3326dd1df8f7a897ca1f17988754f9594b863ac81a35fd95fdeccdebb271c596934948a93c23e745253a3256525fea59a9fd46b9af71899dc68ee3d902fbbc18e27d73a0de0e61b4e0683c31c62dd54e413211e80081fe7f4fa0db081037c202589f747ec5c2ec0dda54601e9048823fdc9f3931620684445e0abf479a638d361a631305daa0cf21439434c1b8c8196afc35fa32b079ee3ca3ca8e6d5c17f2f8286946dae92748552da34e5f18f98ddfe544fa7f3c1043a827e0f47f6f49bc1da847bb4d2c3194a3d23db2ec9c9d737009e20cebd2ff791b285e9d7e63f6322dd04fd974ab6c66e29832860392068e8c0a81e676dcb6eb354a82bb0ffaa058d54463ad6e1a236afccd4e38d96066afd2643e5d207ba33f2c0cbe37ddd2ccb2024caf827ce29e2609d1ef959bf09d2db09e087c5f7f50af65eeb817ad27c353d00f5d97617fc24ea03d756b195ef87ffaff850ffd1436c8a74f243ce8bb7fa88e68cc9de3bb817acbde902f32a675b78c83cd3234541d2c71579f59019ae3701d3998696eb1fa66c799e0a0a28f5253385f9d539db5a1a6fc124818b31443bad629814df2323ef2af483491066e8a15f3202bc28f4e2ce40fe3332a6bc9442689e929d78d2ab0335ea428e646469de258d6424d05949691643f6ab861259e4c47d249e7c4dea4508744ba73dff592e613d2fb1011185586c4202ddf662b07cbd51662aef9ecb25c0605ac672c394fce13a295f3206d539b99426be48a0f039741803a65ad87df2a4f41a1fdbd2c0e3476836ac58784cb1c468c0fe3b7be8e889339d7a567cc5586862f9e5145bcf50980528a0309706f0bd02862a0deccd41ee6088f210c9c133a02e139bb99c8be28c9fdeadb6f9c1322fad99a29b9eccd24f5a6c09ccbb02573df7c01429ad166e034fe4f5088652852831c860299b72b82ad3d6ded07758f08ba73242a2f4053cbc0530b4858657133bd3b87bd6bba3535ed8034d9b4941b3b29be745454ef2f920e990e944241acbe34cf5f95f750ff45891464b4c8febfc4540ad6f58fe4475484791d3d723190b6bc8f1f5d3ead0701b3f43d3f515debbd49ef05cbf3baadeed5ea582a06e0d70539bee90cdc78fc90836d1c0ae6f4c8282e8453fa5fc616a76827b3a4ffeb9c7be243e450f2bf1ce151b0957eb76ac10a16b0f06525e7564a7d324dc4d93993c96765eed8e2390b29bbddbf2ca7d89c7582f1c7da7bbbccaae2d0a9d09fc9281ce320be77c6ba5d5d417d531445afb0461cbcfd32686b752a3b78e77885b30e2b8745bd163b509347aebdf38381f3b1e5eca4f67c51b8b867857f9071528f3f9c9e9c2b8b104ee1d16043d8ae3fbdb4df52abf07b9bfab5462ce8ee49646a5d30968b35b01928f816bced22adf5234e779fc8f7cb76abc849aa42ce4bda650a527cce70a8a9861d563ebfed30b9dd92de2dc16564c3c0dccd012fdbf4de69fbc53805d14371aed0b24d1ad18d21432aa935bcd981b9993b9c16e4e67130676a06a2d0caac20d45da0d3a9caee4803ad46110fde21199e0f1b4261264c69736ec390dd7a38a94d19bcdb879a40d73c820e15bd1ab54268ec7cf7bc3732ccc6b0b2125b0a09db0f3eab96e48021e476ac25165208655adb61d367e30e1143468eaa789aac4f43daa32136943c4dea8fb0b8ad272a682ced233931006698453d1ff029835dab5c256f97647e4dd5c0a072f2b25331af3c24870066258aa2956804f8370278a9d5929c591a282019bbb27716543a24436c0663e707d28534e5865e5d0d978c9f80978f36144d178b651d6f81dcf1b9ccd04b3c8828585d81422078d45ed95358cfd4d5fe8e765335b7e5f3d637fcee4b8b7822b9830b6221be7c17c43e66e7e2462b9d647c4fb172d7b37230bc7c5e2eab65282f1891f8f8ef80047bb62497737ac5fe5bd5aae984fbfd5f452a50df1cf02675a9fbaed4437f4b1450a26ae18c232ebf81924502fabbae2c43ba24c50237c55b42b92c173da555ddd30e454bcdfaeb7e87bf7463ff520e12ae3be890ca288229e9eb7a8658b6cc370b290656d49f578b9a36ed08715120aa3bc70618ed5d20e427da9a96e018b07bb0a22d8105a9e6ee081d46b205bc2b12cec4a94b43ffade523e78676ad93b573cf38fe67f1ce4d5924815231d59eefea67c18500e9acf1aac4e75b34e168907fd3c3153d512a0f3500efea52c7acfc77af1068de700dbafb37762c9cad0d59258648c5cdcf57f0b0d3ae240e79d3b1f6ac10392e404a86383d5e4dcb59f208e104918e4cccede724b08dbdce4a0248bebeae36305a692841e61bfbfbdeb5e628ea5a22472ed8ff987b67c463f08183703af28341da2c62a81eb17ee96440e346e6ebeff2524b10e606448451241bf9d76b3eadbf5968bf4e1044b5107893c8fb608a46f2cf64510b4ed3f59ad3ea47f9581199ba3b59280ef855b59577798621fe75ad78b0ca6e66509ed1f3aa14c0cfd1329172561664697de5fe5cd647a08207dfd49a49da6fc2c0c5aaa50b056f12514dbe788108d83e000ea43dbef19611ea3f40039f2c1ed291723920d5a581095d0d25fb980e3707a250047e1a267f5513c930a5f8d4bdd9e0569f631d965d355cc5a7d8f7d36ee49236defc5ab31b333e20cc91c819bfbce2516f0d5b0374e93a69ad607984f81740585129de6249cc5ec9bccca477f635f3092f47a0d6e928104483ea48b2b9ea1c1d211909b6ee1dc6b29fea67d25faf96b14a196212c47a9d8784194d52bc4c8559cde496fb69325989ec9c246f198e5fcf09aad9cf40a25cd7698c358423fff881e56191d6603e6f804e6cb77dbf8803743586685b713791ad13afca29e1aeb3e5bda677a6ec50cd0dc709686030b7518dc550c0f6946662d52a59fd72a258696a80d95fd6dc1f8ffbb9685c3ec7460b365c9f192c399f73b018309c9a17e11bed560d43a6400edd4cefcef5de80fda109a813a29c7d0e54a0fa3eafa9d3f7bf4f5b751d159c72951c3827dc5760854af941fddb16b1ea9291201ef93247a5244307519e20d2796dc097629a6d87323fecc0ca9cc3636d3d5fda9c312f1ed70c31fa5aa542fd8ee1c901635e41c6e290e57c8c80e86ec7d68b0e15d1a1fe990f90f1df0da25b90c1ee2ad85e52406e02f24aa1e0110b2f9e4e08717f4dc5f389981507263607a4a1fd1b5f239525c84e646a4a6c64365d43d29a6c3eb47becd1c73e1f85263c82da9da291ac360c73e5876496f07ab0ecea8db7522871310aa44c1eebc772d268df61db357eb9193f6f7e14827d027198398ba874659fc4a45f7142b730de1d518c51b6c5d647bc84d661d64bfbf34266a7050b4f16927a3d5dd56a50481e70085650281c8d749bdc42d2cb8052ab2e2cec71cc9068c6e0155756f2b74b39d065252fb86ee897202e4d2081c23eb696478a6f933199ad97db20a61c2b77a6fa6021f118d856fa2fa3246d17a8d29188147bb3c7cb58c780060c73d70bb4f2457f4218ce48ad00050ffae6141bb8944045fad430e11bc9d5f5447f95fd29e62e54cd5ceb048ad701f4d335e5410a57c21eb776bd37639c0be37c9182ecfe54194c625c9e761e2cf1d60d3516fe204c4a94a63fbdcfc5409b0f386420a223fad103d16a30f1381d2211e406a185c86e6a6a235c5cda6b9828c24585d98205edfdd8cd0033012220dfd700b0eed86aece67b4dfa82c061d2c15c65088d79aef6aa245bc1b4c3382a83959474d15a5a18421ad50e3f1718b0185e6b7d96c8443c7c59887920262d94b410ce1ec7b8cbdba5ad17099f5a9d24de427c3d42dbb6947bf5eb1be8d7b255dd7d045aa52451cb09df6bd07c00149cdbbfa083b8333a90ab8b44ecab62237d4c5d0d06b5e9c3002c0c8f723ec1ea6c034451480381ecf7d20617d2a5c4540f7bd6fc4748c0569b3e2969337630c6bd1f440cb78b120d09d4aab75ede049da4743caf3b91048bfc2379ebc928bc61ed596ec74f0c256f190d000000"
                 ),
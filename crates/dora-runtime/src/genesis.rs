@@ -0,0 +1,204 @@
+//! The geth-style genesis and alloc JSON formats, which are used to load the state of the
+//! [`MemoryDB`](crate::db::MemoryDB) from a file and to dump it back.
+
+use std::collections::BTreeMap;
+
+use dora_primitives::{AccountStatus, Address, B256, Bytes, U256};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The genesis accounts mapped by the addresses.
+pub type GenesisAlloc = BTreeMap<Address, GenesisAccount>;
+
+/// An account of the genesis alloc, the absent fields are zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisAccount {
+    /// The account balance, a hex or decimal string.
+    #[serde(default)]
+    pub balance: U256,
+    /// The account nonce, a number or a hex or decimal string.
+    #[serde(default, with = "quantity")]
+    pub nonce: u64,
+    /// The account code.
+    #[serde(default, skip_serializing_if = "Bytes::is_empty")]
+    pub code: Bytes,
+    /// The storage slots.
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        serialize_with = "serialize_storage"
+    )]
+    pub storage: BTreeMap<U256, U256>,
+    /// The status flags of the account in the database, it is only emitted by the dump and the
+    /// loaded accounts are created when it is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "status")]
+    pub status: Option<AccountStatus>,
+}
+
+/// A geth-style genesis file, only the alloc is used to load the state and the other header
+/// fields are kept for the callers which build the genesis block environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Genesis {
+    /// The chain config, which is kept as it is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<serde_json::Value>,
+    /// The block nonce.
+    #[serde(default, with = "quantity")]
+    pub nonce: u64,
+    /// The block timestamp.
+    #[serde(default, with = "quantity")]
+    pub timestamp: u64,
+    /// The block extra data.
+    #[serde(default)]
+    pub extra_data: Bytes,
+    /// The block gas limit.
+    #[serde(default, with = "quantity")]
+    pub gas_limit: u64,
+    /// The block difficulty.
+    #[serde(default)]
+    pub difficulty: U256,
+    /// The block mix hash, which is the prevrandao after the merge.
+    #[serde(default)]
+    pub mix_hash: B256,
+    /// The block beneficiary.
+    #[serde(default)]
+    pub coinbase: Address,
+    /// The block base fee after London.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
+    /// The genesis accounts.
+    #[serde(default)]
+    pub alloc: GenesisAlloc,
+}
+
+/// Serializes the storage slots as 32-byte hex strings like geth's state dump.
+fn serialize_storage<S: Serializer>(
+    storage: &BTreeMap<U256, U256>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        storage
+            .iter()
+            .map(|(key, value)| (B256::from(*key), B256::from(*value))),
+    )
+}
+
+/// The `u64` quantities are either numbers or hex or decimal strings, they are serialized as
+/// hex strings.
+mod quantity {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Quantity {
+        Number(u64),
+        String(String),
+    }
+
+    pub(super) fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{value:#x}"))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        match Quantity::deserialize(deserializer)? {
+            Quantity::Number(value) => Ok(value),
+            Quantity::String(value) => match value.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => value.parse(),
+            }
+            .map_err(D::Error::custom),
+        }
+    }
+}
+
+/// The status flags are serialized as their bits.
+mod status {
+    use super::*;
+
+    pub(super) fn serialize<S: Serializer>(
+        status: &Option<AccountStatus>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match status {
+            Some(status) => serializer.serialize_some(&status.bits()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<AccountStatus>, D::Error> {
+        Ok(Option::<u8>::deserialize(deserializer)?.map(AccountStatus::from_bits_truncate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::MemoryDB;
+    use dora_primitives::{KECCAK_EMPTY, address, keccak256};
+
+    const GENESIS: &str = r#"{
+        "config": { "chainId": 1337 },
+        "nonce": "0x0",
+        "timestamp": "0x5",
+        "gasLimit": "30000000",
+        "difficulty": "0x1",
+        "coinbase": "0x0000000000000000000000000000000000000000",
+        "alloc": {
+            "0x00000000000000000000000000000000000000aa": {
+                "balance": "1000000000000000000"
+            },
+            "00000000000000000000000000000000000000bb": {
+                "balance": "0x10",
+                "nonce": 2,
+                "code": "0x6001600055",
+                "storage": { "0x01": "0x02" }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_from_genesis() {
+        let db = MemoryDB::from_genesis(GENESIS).unwrap();
+        let eoa = address!("00000000000000000000000000000000000000aa");
+        let contract = address!("00000000000000000000000000000000000000bb");
+        assert_eq!(
+            db.get_balance(eoa),
+            Some(U256::from(10).pow(U256::from(18)))
+        );
+        assert_eq!(db.accounts()[&eoa].bytecode_hash, KECCAK_EMPTY);
+        let account = &db.accounts()[&contract];
+        assert_eq!(account.nonce, 2);
+        assert_eq!(account.balance, U256::from(16));
+        assert_eq!(
+            account.bytecode_hash,
+            keccak256([0x60, 0x01, 0x60, 0x00, 0x55])
+        );
+        assert_eq!(db.sload(contract, U256::from(1)), U256::from(2));
+        assert!(db.address_is_created(contract));
+    }
+
+    #[test]
+    fn test_dump_roundtrip() {
+        let mut db = MemoryDB::from_genesis(GENESIS).unwrap();
+        let contract = address!("00000000000000000000000000000000000000bb");
+        db.set_status(contract, AccountStatus::Touched);
+        let dump = serde_json::to_string(&db.dump()).unwrap();
+        let loaded = MemoryDB::from_alloc(&dump).unwrap();
+        assert_eq!(loaded.dump(), db.dump());
+        assert_eq!(loaded.accounts()[&contract].status, AccountStatus::Touched);
+        let value: serde_json::Value = serde_json::from_str(&dump).unwrap();
+        let storage = value
+            .as_object()
+            .unwrap()
+            .values()
+            .find_map(|account| account.get("storage"))
+            .unwrap();
+        assert_eq!(
+            storage[B256::from(U256::from(1)).to_string()],
+            B256::from(U256::from(2)).to_string()
+        );
+    }
+}
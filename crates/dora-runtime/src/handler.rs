@@ -1,11 +1,15 @@
 use std::sync::Arc;
 
 use crate::{
-    call::CallResult,
+    SymbolArtifact,
+    cache::ArtifactCache,
+    call::{CallMessage, CallResult},
     context::{Contract, VMContext},
     db::Database,
     result::VMError,
+    vm::VM,
 };
+use dora_primitives::{Address, JournalCheckpoint};
 
 #[derive(Debug)]
 pub struct Frame {
@@ -23,14 +27,126 @@ pub struct Frame {
     pub validate_eof: bool,
 }
 
+/// The start of a call frame, see [`VMContext::call_start`].
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum CallStart {
+    /// The call is done without executing any code, e.g., precompiles, empty code and the
+    /// failed value transfers.
+    Done(CallResult),
+    /// The frame to be executed, whose result is handled with [`VMContext::call_end`].
+    Frame(Frame, FrameReturn),
+}
+
+/// The journal state needed to finish a call frame after it is executed.
+#[derive(Debug)]
+pub struct FrameReturn {
+    /// The call message of the frame, which is only recorded for the inspector.
+    pub(crate) msg: Option<CallMessage>,
+    pub(crate) kind: FrameReturnKind,
+}
+
+impl FrameReturn {
+    #[inline]
+    pub(crate) fn new(kind: FrameReturnKind) -> Self {
+        Self { msg: None, kind }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum FrameReturnKind {
+    Call(JournalCheckpoint),
+    Create(Address, JournalCheckpoint),
+    EofCreate(Address, JournalCheckpoint),
+}
+
 pub type CallFrameHandle<DB> =
     Arc<dyn Fn(Frame, &mut VMContext<DB>) -> Result<CallResult, VMError>>;
 
+/// Returns the artifact of the frame compiled with the suspend mode, or `None` to execute the
+/// frame with the call frame handler.
+pub type SuspendFrameHandle<DB> =
+    Arc<dyn Fn(&Frame, &mut VMContext<DB>) -> Result<Option<SymbolArtifact>, VMError>>;
+
+/// Validates the block environment and the transaction without the state.
+pub type ValidateEnvHandle<DB> = Arc<dyn Fn(&VMContext<DB>) -> Result<(), VMError>>;
+
+/// Validates the transaction against the state, e.g., the caller nonce and balance, or deducts
+/// the caller balance before the execution.
+pub type TxStateHandle<DB> = Arc<dyn Fn(&mut VMContext<DB>) -> Result<(), VMError>>;
+
+/// Settles the fee after the execution with the gas used or remaining and the refunded gas.
+pub type SettleFeeHandle<DB> = Arc<dyn Fn(&mut VMContext<DB>, u64, i64) -> Result<(), VMError>>;
+
+/// The hooks of the transaction lifecycle, the defaults are the Ethereum mainnet behavior and
+/// each hook can be replaced, e.g., to exempt the system transactions of an L2 from the fee.
+///
+/// # Example:
+/// ```no_run
+/// use std::sync::Arc;
+/// use dora_runtime::{db::MemoryDB, handler::{Handler, LifecycleHandles}};
+/// let handler = Handler::<MemoryDB>::dummy().with_lifecycle(LifecycleHandles {
+///     reward_beneficiary: Arc::new(|_ctx, _gas_used, _gas_refunded| Ok(())),
+///     ..Default::default()
+/// });
+/// ```
+pub struct LifecycleHandles<DB: Database> {
+    /// Validates the environment before the state is loaded, see [`VM::validate_env`].
+    pub validate_env: ValidateEnvHandle<DB>,
+    /// Validates the transaction against the caller account, see
+    /// [`VM::validate_tx_against_state`].
+    pub validate_tx_against_state: TxStateHandle<DB>,
+    /// Deducts the maximum fee from the caller and bumps its nonce, see
+    /// [`VMContext::deduct_caller`].
+    pub deduct_caller: TxStateHandle<DB>,
+    /// Returns the fee of the remaining and refunded gas to the caller, see
+    /// [`VMContext::reimburse_caller`].
+    pub reimburse_caller: SettleFeeHandle<DB>,
+    /// Pays the priority fee of the used gas to the beneficiary, see
+    /// [`VMContext::reward_beneficiary`].
+    pub reward_beneficiary: SettleFeeHandle<DB>,
+}
+
+impl<DB: Database> Default for LifecycleHandles<DB> {
+    fn default() -> Self {
+        Self {
+            validate_env: Arc::new(|ctx| VM::validate_env(ctx)),
+            validate_tx_against_state: Arc::new(|ctx| VM::validate_tx_against_state(ctx)),
+            deduct_caller: Arc::new(|ctx| ctx.deduct_caller()),
+            reimburse_caller: Arc::new(|ctx, gas_remaining, gas_refunded| {
+                ctx.reimburse_caller(gas_remaining, gas_refunded)
+            }),
+            reward_beneficiary: Arc::new(|ctx, gas_used, gas_refunded| {
+                ctx.reward_beneficiary(gas_used, gas_refunded)
+            }),
+        }
+    }
+}
+
+impl<DB: Database> Clone for LifecycleHandles<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            validate_env: self.validate_env.clone(),
+            validate_tx_against_state: self.validate_tx_against_state.clone(),
+            deduct_caller: self.deduct_caller.clone(),
+            reimburse_caller: self.reimburse_caller.clone(),
+            reward_beneficiary: self.reward_beneficiary.clone(),
+        }
+    }
+}
+
 /// Handler acts as a proxy and allow to define different behavior for different
 /// sections of the code.
 pub struct Handler<DB: Database> {
     /// Call frame handler.
     pub call_handler: CallFrameHandle<DB>,
+    /// The compiled artifacts cache, which can be shared by multiple VMs and threads.
+    pub artifact_cache: Arc<ArtifactCache>,
+    /// Suspended frame handler, when it is set the call frames are driven by the
+    /// [`FrameScheduler`](crate::scheduler::FrameScheduler) in a loop instead of recursion.
+    pub suspend_handler: Option<SuspendFrameHandle<DB>>,
+    /// The hooks of the transaction validation, the fee payment and the fee settlement.
+    pub lifecycle: LifecycleHandles<DB>,
 }
 
 impl<DB: Database> Handler<DB> {
@@ -39,6 +155,30 @@ impl<DB: Database> Handler<DB> {
             call_handler: Arc::new(|frame, _ctx| {
                 Ok(CallResult::new_with_gas_limit(frame.gas_limit))
             }),
+            artifact_cache: Default::default(),
+            suspend_handler: None,
+            lifecycle: Default::default(),
         }
     }
+
+    /// Sets the compiled artifacts cache of the handler.
+    #[inline]
+    pub fn with_artifact_cache(mut self, artifact_cache: Arc<ArtifactCache>) -> Self {
+        self.artifact_cache = artifact_cache;
+        self
+    }
+
+    /// Sets the suspended frame handler of the handler.
+    #[inline]
+    pub fn with_suspend_handler(mut self, suspend_handler: SuspendFrameHandle<DB>) -> Self {
+        self.suspend_handler = Some(suspend_handler);
+        self
+    }
+
+    /// Sets the transaction lifecycle hooks of the handler.
+    #[inline]
+    pub fn with_lifecycle(mut self, lifecycle: LifecycleHandles<DB>) -> Self {
+        self.lifecycle = lifecycle;
+        self
+    }
 }
@@ -2,7 +2,11 @@ use dora_primitives::{Address, B256, Bytes, Env, HashMap, Log, U256};
 use std::{collections::hash_map::Entry, fmt::Debug};
 
 use crate::call::{CallKind, CallMessage, CallResult};
+use crate::db::DatabaseError;
+use crate::gas::GasSchedule;
+use crate::inspector::StepState;
 use crate::result::VMError;
+use thiserror::Error;
 
 pub use dora_primitives::{AccountLoad, SelfDestructResult, StateLoad};
 
@@ -12,6 +16,10 @@ pub use dora_primitives::{AccountLoad, SelfDestructResult, StateLoad};
 /// code retrieval, logging, and access status tracking.
 ///
 /// Implementing this trait allows a host to provide the necessary functionalities during contract execution.
+///
+/// The state accessors return a [`HostError`] when the backing store fails, which halts the
+/// execution with [`ExitStatusCode::FatalExternalError`](crate::ExitStatusCode) instead of
+/// reading a default value.
 pub trait Host {
     /// Returns a reference to the environment.
     fn env(&self) -> &Env;
@@ -19,11 +27,22 @@ pub trait Host {
     /// Returns a mutable reference to the environment.
     fn env_mut(&mut self) -> &mut Env;
 
+    /// Returns the gas schedule of the dynamic gas costs, which is the Ethereum gas schedule of the
+    /// spec by default.
+    fn gas_schedule(&self) -> GasSchedule {
+        GasSchedule::new(self.env().cfg.spec)
+    }
+
     /// Retrieves the storage value for a given account and storage key.
-    fn sload(&mut self, addr: Address, key: U256) -> Option<StateLoad<U256>>;
+    fn sload(&mut self, addr: Address, key: U256) -> Result<StateLoad<U256>, HostError>;
 
     /// Sets the storage value for a given account and storage key.
-    fn sstore(&mut self, addr: Address, key: U256, value: U256) -> Option<StateLoad<SStoreResult>>;
+    fn sstore(
+        &mut self,
+        addr: Address,
+        key: U256,
+        value: U256,
+    ) -> Result<StateLoad<SStoreResult>, HostError>;
 
     /// Get the transient storage value of `address` at `key`.
     fn tload(&mut self, addr: Address, key: U256) -> U256;
@@ -32,32 +51,79 @@ pub trait Host {
     fn tstore(&mut self, addr: Address, key: U256, value: U256);
 
     /// Load account from database to JournaledState.
-    fn load_account_delegated(&mut self, addr: Address) -> Option<StateLoad<AccountLoad>>;
+    fn load_account_delegated(
+        &mut self,
+        addr: Address,
+    ) -> Result<StateLoad<AccountLoad>, HostError>;
 
     /// Retrieves the balance of a specified account.
-    fn balance(&mut self, addr: Address) -> Option<StateLoad<U256>>;
+    fn balance(&mut self, addr: Address) -> Result<StateLoad<U256>, HostError>;
 
     /// Retrieves the code deployed at a specified account.
-    fn code(&mut self, addr: Address) -> Option<StateLoad<Bytes>>;
+    fn code(&mut self, addr: Address) -> Result<StateLoad<Bytes>, HostError>;
 
     /// Retrieves the hash of the code deployed at a specified account.
-    fn code_hash(&mut self, addr: Address) -> Option<StateLoad<B256>>;
+    fn code_hash(&mut self, addr: Address) -> Result<StateLoad<B256>, HostError>;
 
     /// Mark `address` to be deleted, with funds transferred to `target`.
     fn selfdestruct(
         &mut self,
         addr: Address,
         target: Address,
-    ) -> Option<StateLoad<SelfDestructResult>>;
+    ) -> Result<StateLoad<SelfDestructResult>, HostError>;
 
     /// Get the block hash of the given block `number`.
-    fn block_hash(&mut self, number: u64) -> Option<B256>;
+    fn block_hash(&mut self, number: u64) -> Result<B256, HostError>;
 
     /// Emit a log owned by `address` with given `LogData`.
     fn log(&mut self, log: Log);
 
     /// Host for the call-like instructions e.g., `CALL`, `CREATE`, etc.
     fn call(&mut self, msg: CallMessage) -> Result<CallResult, VMError>;
+
+    /// Called before an instruction is executed, only for the code compiled with tracing enabled.
+    #[inline]
+    fn step(&mut self, _step: &StepState<'_>) {}
+
+    /// Called after an instruction is executed, only for the code compiled with tracing enabled.
+    #[inline]
+    fn step_end(&mut self, _step: &StepState<'_>) {}
+}
+
+/// An error of the [`Host`], which halts the execution and is returned by the VM.
+#[derive(Error, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HostError {
+    /// The backing database fails.
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+    /// The host doesn't support the operation.
+    #[error("unsupported host operation: {0}")]
+    Unsupported(&'static str),
+    /// The state is changed in a static call.
+    #[error("state change during static call")]
+    StateChangeDuringStaticCall,
+}
+
+impl HostError {
+    /// Creates a database error with the message of the original error.
+    #[inline]
+    pub fn database(error: impl std::fmt::Display) -> Self {
+        Self::Database(DatabaseError::new(error))
+    }
+}
+
+impl From<HostError> for VMError {
+    fn from(value: HostError) -> Self {
+        match value {
+            HostError::Database(error) => VMError::Database(error),
+            HostError::Unsupported(operation) => {
+                VMError::Handler(format!("unsupported host operation: {}", operation))
+            }
+            HostError::StateChangeDuringStaticCall => {
+                VMError::Handler("state change during static call".to_string())
+            }
+        }
+    }
 }
 
 /// Result of a `set_storage` action.
@@ -146,8 +212,8 @@ impl Host for DummyHost {
     }
 
     #[inline]
-    fn sload(&mut self, _addr: Address, key: U256) -> Option<StateLoad<U256>> {
-        Some(match self.storage.entry(key) {
+    fn sload(&mut self, _addr: Address, key: U256) -> Result<StateLoad<U256>, HostError> {
+        Ok(match self.storage.entry(key) {
             Entry::Occupied(entry) => StateLoad::new(*entry.get(), false),
             Entry::Vacant(entry) => {
                 entry.insert(U256::ZERO);
@@ -162,10 +228,10 @@ impl Host for DummyHost {
         _addr: Address,
         key: U256,
         value: U256,
-    ) -> Option<StateLoad<SStoreResult>> {
+    ) -> Result<StateLoad<SStoreResult>, HostError> {
         let present = self.storage.insert(key, value);
 
-        Some(StateLoad::new(
+        Ok(StateLoad::new(
             SStoreResult::Slot(dora_primitives::SStoreResult {
                 original_value: U256::ZERO,
                 present_value: present.unwrap_or(U256::ZERO),
@@ -176,8 +242,8 @@ impl Host for DummyHost {
     }
 
     #[inline]
-    fn balance(&mut self, _addr: Address) -> Option<StateLoad<U256>> {
-        Some(Default::default())
+    fn balance(&mut self, _addr: Address) -> Result<StateLoad<U256>, HostError> {
+        Ok(Default::default())
     }
 
     #[inline]
@@ -194,13 +260,13 @@ impl Host for DummyHost {
     }
 
     #[inline]
-    fn code(&mut self, _addr: Address) -> Option<StateLoad<Bytes>> {
-        Some(Default::default())
+    fn code(&mut self, _addr: Address) -> Result<StateLoad<Bytes>, HostError> {
+        Ok(Default::default())
     }
 
     #[inline]
-    fn code_hash(&mut self, _addr: Address) -> Option<StateLoad<B256>> {
-        Some(Default::default())
+    fn code_hash(&mut self, _addr: Address) -> Result<StateLoad<B256>, HostError> {
+        Ok(Default::default())
     }
 
     #[inline]
@@ -208,13 +274,13 @@ impl Host for DummyHost {
         &mut self,
         _addr: Address,
         _target: Address,
-    ) -> Option<StateLoad<SelfDestructResult>> {
-        Some(Default::default())
+    ) -> Result<StateLoad<SelfDestructResult>, HostError> {
+        Ok(Default::default())
     }
 
     #[inline]
-    fn block_hash(&mut self, _number: u64) -> Option<B256> {
-        Some(Default::default())
+    fn block_hash(&mut self, _number: u64) -> Result<B256, HostError> {
+        Ok(Default::default())
     }
 
     #[inline]
@@ -223,8 +289,11 @@ impl Host for DummyHost {
     }
 
     #[inline]
-    fn load_account_delegated(&mut self, _addr: Address) -> Option<StateLoad<AccountLoad>> {
-        Some(Default::default())
+    fn load_account_delegated(
+        &mut self,
+        _addr: Address,
+    ) -> Result<StateLoad<AccountLoad>, HostError> {
+        Ok(Default::default())
     }
 
     #[inline]
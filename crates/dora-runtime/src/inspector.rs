@@ -0,0 +1,172 @@
+use crate::{
+    call::{CallMessage, CallResult},
+    context::{Contract, VMContext},
+    db::Database,
+};
+use dora_primitives::{Address, Bytes32, Log, U256};
+use std::{cell::RefCell, rc::Rc};
+
+pub mod call_tracer;
+pub mod eip3155;
+pub mod prestate_tracer;
+
+pub use call_tracer::{CallFrame, CallLog, CallTracer, CallTracerConfig};
+pub use eip3155::TracerEip3155;
+pub use prestate_tracer::{AccountState, PrestateFrame, PrestateTracer, PrestateTracerConfig};
+
+/// A read-only view of the execution state at an instruction boundary.
+///
+/// The view is only valid for the duration of the inspector callback, the stack and
+/// memory slices point into the live execution state of the compiled code.
+#[derive(Debug, Clone, Copy)]
+pub struct StepState<'a> {
+    /// The program counter of the instruction.
+    pub pc: usize,
+    /// The opcode of the instruction.
+    pub opcode: u8,
+    /// The remaining gas before the instruction is executed, or after it is executed for
+    /// [`Inspector::step_end`].
+    pub gas_remaining: u64,
+    /// The static gas cost of the instruction for [`Inspector::step`], and the total gas
+    /// consumed by the instruction (including dynamic costs and sub calls) for [`Inspector::step_end`].
+    pub gas_cost: u64,
+    /// The refunded gas accumulated in the current frame.
+    pub gas_refunded: i64,
+    /// The depth of the current call frame.
+    pub depth: usize,
+    /// The stack items from the bottom to the top.
+    pub stack: &'a [Bytes32],
+    /// The memory of the current call frame.
+    pub memory: &'a [u8],
+    /// The return data of the last sub call.
+    pub return_data: &'a [u8],
+    /// The contract being executed.
+    pub contract: &'a Contract,
+}
+
+/// The [`Inspector`] trait allows tracers to observe the execution of a transaction.
+///
+/// All methods have empty default implementations, so an inspector only implements the
+/// hooks it is interested in. The instruction hooks ([`Inspector::step`] and [`Inspector::step_end`])
+/// are only invoked for the code compiled with tracing enabled, which the compile handler
+/// does automatically when an inspector is attached to the [`VMContext`]. When no inspector
+/// is attached, the compiled code contains no instruction hooks at all.
+///
+/// Note that [`Inspector::step_end`] of a call-like instruction is invoked after the sub call
+/// has returned i.e., after [`Inspector::call_end`] or [`Inspector::create_end`] of the sub call.
+pub trait Inspector<DB: Database> {
+    /// Called before the instruction is executed.
+    #[inline]
+    fn step(&mut self, _step: &StepState<'_>, _context: &mut VMContext<DB>) {}
+
+    /// Called after the instruction is executed.
+    #[inline]
+    fn step_end(&mut self, _step: &StepState<'_>, _context: &mut VMContext<DB>) {}
+
+    /// Called before a call frame is executed. Returning a call result skips the execution
+    /// of the frame and uses the returned result instead.
+    #[inline]
+    fn call(&mut self, _msg: &mut CallMessage, _context: &mut VMContext<DB>) -> Option<CallResult> {
+        None
+    }
+
+    /// Called after a call frame is executed, the result can be modified by the inspector.
+    #[inline]
+    fn call_end(
+        &mut self,
+        _msg: &CallMessage,
+        _result: &mut CallResult,
+        _context: &mut VMContext<DB>,
+    ) {
+    }
+
+    /// Called before a create frame is executed. Returning a call result skips the execution
+    /// of the frame and uses the returned result instead.
+    #[inline]
+    fn create(
+        &mut self,
+        _msg: &mut CallMessage,
+        _context: &mut VMContext<DB>,
+    ) -> Option<CallResult> {
+        None
+    }
+
+    /// Called after a create frame is executed, the result can be modified by the inspector.
+    /// The created address is set in [`CallResult::create_address`].
+    #[inline]
+    fn create_end(
+        &mut self,
+        _msg: &CallMessage,
+        _result: &mut CallResult,
+        _context: &mut VMContext<DB>,
+    ) {
+    }
+
+    /// Called when a log is emitted.
+    #[inline]
+    fn log(&mut self, _log: &Log, _context: &mut VMContext<DB>) {}
+
+    /// Called when a contract has been self-destructed with funds transferred to the target.
+    #[inline]
+    fn selfdestruct(&mut self, _contract: Address, _target: Address, _value: U256) {}
+}
+
+/// An inspector that does nothing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpInspector;
+
+impl<DB: Database> Inspector<DB> for NoOpInspector {}
+
+/// Shared inspectors, which are useful to read the inspector data after the execution
+/// while the inspector itself is owned by the [`VMContext`].
+impl<DB: Database, I: Inspector<DB> + ?Sized> Inspector<DB> for Rc<RefCell<I>> {
+    #[inline]
+    fn step(&mut self, step: &StepState<'_>, context: &mut VMContext<DB>) {
+        self.borrow_mut().step(step, context)
+    }
+
+    #[inline]
+    fn step_end(&mut self, step: &StepState<'_>, context: &mut VMContext<DB>) {
+        self.borrow_mut().step_end(step, context)
+    }
+
+    #[inline]
+    fn call(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.borrow_mut().call(msg, context)
+    }
+
+    #[inline]
+    fn call_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        self.borrow_mut().call_end(msg, result, context)
+    }
+
+    #[inline]
+    fn create(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.borrow_mut().create(msg, context)
+    }
+
+    #[inline]
+    fn create_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        self.borrow_mut().create_end(msg, result, context)
+    }
+
+    #[inline]
+    fn log(&mut self, log: &Log, context: &mut VMContext<DB>) {
+        self.borrow_mut().log(log, context)
+    }
+
+    #[inline]
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        self.borrow_mut().selfdestruct(contract, target, value)
+    }
+}
@@ -0,0 +1,389 @@
+use super::Inspector;
+use crate::{
+    ExitStatusCode,
+    call::{CallKind, CallMessage, CallResult},
+    context::VMContext,
+    db::Database,
+};
+use dora_primitives::{Address, B256, Bytes, Log, U256};
+use serde_json::{Map, Value, json};
+
+/// The function selector of the solidity `Error(string)` revert payload.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The configuration of the [`CallTracer`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CallTracerConfig {
+    /// Only trace the top-level call frame and ignore the nested calls.
+    pub only_top_call: bool,
+    /// Record the logs emitted in each call frame.
+    pub with_log: bool,
+}
+
+impl CallTracerConfig {
+    /// Sets whether to only trace the top-level call frame.
+    pub fn only_top_call(mut self, only_top_call: bool) -> Self {
+        self.only_top_call = only_top_call;
+        self
+    }
+
+    /// Sets whether to record the logs emitted in each call frame.
+    pub fn with_log(mut self, with_log: bool) -> Self {
+        self.with_log = with_log;
+        self
+    }
+}
+
+/// A log recorded by the [`CallTracer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallLog {
+    /// The address of the contract which emitted the log.
+    pub address: Address,
+    /// The topics of the log.
+    pub topics: Vec<B256>,
+    /// The data of the log.
+    pub data: Bytes,
+    /// The number of the sub calls made by the frame before the log was emitted.
+    pub position: u64,
+}
+
+/// A call frame in geth's `callTracer` format.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallFrame {
+    /// The call type e.g., `CALL`, `DELEGATECALL` and `CREATE2`.
+    pub typ: String,
+    /// The caller of the frame.
+    pub from: Address,
+    /// The callee of the frame, or the created address for the create frames.
+    pub to: Option<Address>,
+    /// The transferred value, which is absent for the delegate and static calls.
+    pub value: Option<U256>,
+    /// The gas limit of the frame.
+    pub gas: u64,
+    /// The gas used by the frame.
+    pub gas_used: u64,
+    /// The call data, or the init code for the create frames.
+    pub input: Bytes,
+    /// The return data of the frame.
+    pub output: Bytes,
+    /// The error message when the frame failed.
+    pub error: Option<String>,
+    /// The decoded solidity revert reason when the frame reverted with `Error(string)`.
+    pub revert_reason: Option<String>,
+    /// The sub calls made by the frame.
+    pub calls: Vec<CallFrame>,
+    /// The logs emitted by the frame, only recorded with [`CallTracerConfig::with_log`].
+    pub logs: Vec<CallLog>,
+}
+
+impl CallFrame {
+    /// Returns the frame as a JSON value in geth's `callTracer` format.
+    pub fn to_json(&self) -> Value {
+        let mut frame = Map::new();
+        frame.insert("type".into(), json!(self.typ));
+        frame.insert("from".into(), json!(hex_bytes(self.from)));
+        if let Some(to) = self.to {
+            frame.insert("to".into(), json!(hex_bytes(to)));
+        }
+        if let Some(value) = self.value {
+            frame.insert("value".into(), json!(format!("{value:#x}")));
+        }
+        frame.insert("gas".into(), json!(format!("{:#x}", self.gas)));
+        frame.insert("gasUsed".into(), json!(format!("{:#x}", self.gas_used)));
+        frame.insert("input".into(), json!(hex_bytes(&self.input)));
+        if !self.output.is_empty() {
+            frame.insert("output".into(), json!(hex_bytes(&self.output)));
+        }
+        if let Some(error) = &self.error {
+            frame.insert("error".into(), json!(error));
+        }
+        if let Some(revert_reason) = &self.revert_reason {
+            frame.insert("revertReason".into(), json!(revert_reason));
+        }
+        if !self.logs.is_empty() {
+            let logs = self
+                .logs
+                .iter()
+                .map(|log| {
+                    json!({
+                        "address": hex_bytes(log.address),
+                        "topics": log.topics.iter().map(hex_bytes).collect::<Vec<_>>(),
+                        "data": hex_bytes(&log.data),
+                        "position": format!("{:#x}", log.position),
+                    })
+                })
+                .collect::<Vec<_>>();
+            frame.insert("logs".into(), Value::Array(logs));
+        }
+        if !self.calls.is_empty() {
+            let calls = self.calls.iter().map(CallFrame::to_json).collect();
+            frame.insert("calls".into(), Value::Array(calls));
+        }
+        Value::Object(frame)
+    }
+
+    /// Drops the logs of the frame and all its sub calls, which is used for the failed frames
+    /// because their logs are reverted.
+    fn clear_logs(&mut self) {
+        self.logs.clear();
+        self.calls.iter_mut().for_each(CallFrame::clear_logs);
+    }
+}
+
+/// An inspector which records the call tree of a transaction in geth's `callTracer` format.
+///
+/// # Example
+///
+/// ```no_check
+/// let tracer = Rc::new(RefCell::new(CallTracer::new(CallTracerConfig::default())));
+/// let mut vm = VM::new(VMContext::new(db, env, compile_handler()).with_inspector(tracer.clone()));
+/// let result = vm.transact()?;
+/// let frame = tracer.take().into_call_frame(result.result.gas_used());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CallTracer {
+    config: CallTracerConfig,
+    /// The call frames which are still executing.
+    stack: Vec<CallFrame>,
+    /// The depth of the executing frame, it may be larger than the stack length when
+    /// the nested calls are not traced.
+    depth: usize,
+    /// The finished top-level call frame.
+    root: Option<CallFrame>,
+}
+
+impl CallTracer {
+    /// Creates a new call tracer with the config.
+    pub fn new(config: CallTracerConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the config of the tracer.
+    pub fn config(&self) -> &CallTracerConfig {
+        &self.config
+    }
+
+    /// Returns the finished top-level call frame.
+    pub fn root(&self) -> Option<&CallFrame> {
+        self.root.as_ref()
+    }
+
+    /// Consumes the tracer and returns the top-level call frame, whose gas used is set to the
+    /// `gas_used` of the transaction receipt like geth, which includes the intrinsic gas and
+    /// the gas refunds.
+    pub fn into_call_frame(self, gas_used: u64) -> Option<CallFrame> {
+        let mut root = self.root?;
+        root.gas_used = gas_used;
+        Some(root)
+    }
+
+    /// Returns whether the frame at the current depth is traced.
+    #[inline]
+    fn is_traced(&self) -> bool {
+        self.depth == self.stack.len()
+    }
+
+    fn start_frame<DB: Database>(&mut self, msg: &CallMessage, context: &VMContext<DB>) {
+        self.depth += 1;
+        if self.config.only_top_call && msg.depth != 0 {
+            return;
+        }
+        let (to, value, input) = match msg.kind {
+            CallKind::Create | CallKind::Create2 => (None, Some(msg.value), msg.input.clone()),
+            CallKind::EofCreate => (None, Some(msg.value), msg.init_code.clone()),
+            CallKind::Delegatecall
+            | CallKind::Staticcall
+            | CallKind::ExtDelegatecall
+            | CallKind::ExtStaticcall => (Some(msg.code_address), None, msg.input.clone()),
+            CallKind::Call | CallKind::Callcode | CallKind::ExtCall | CallKind::ReturnContract => {
+                (Some(msg.code_address), Some(msg.value), msg.input.clone())
+            }
+        };
+        // The delegate calls keep the caller of the current frame, but geth reports the
+        // current contract as the caller.
+        let from = if matches!(msg.kind, CallKind::Delegatecall | CallKind::ExtDelegatecall) {
+            msg.recipient
+        } else {
+            msg.caller
+        };
+        // The top-level frame uses the transaction gas limit, which includes the intrinsic gas.
+        let gas = if msg.depth == 0 {
+            context.env.tx.gas_limit
+        } else {
+            msg.gas_limit
+        };
+        self.stack.push(CallFrame {
+            typ: call_type(&msg.kind).to_string(),
+            from,
+            to,
+            value,
+            gas,
+            input,
+            ..Default::default()
+        });
+    }
+
+    fn end_frame(&mut self, msg: &CallMessage, result: &CallResult) {
+        let traced = self.is_traced();
+        self.depth -= 1;
+        if !traced {
+            return;
+        }
+        let Some(mut frame) = self.stack.pop() else {
+            return;
+        };
+        frame.gas_used = if msg.depth == 0 {
+            // Add the intrinsic gas to the top-level frame.
+            frame.gas.saturating_sub(msg.gas_limit) + result.gas_used()
+        } else {
+            result.gas_used()
+        };
+        frame.output = result.output.clone();
+        if let Some(address) = result.create_address {
+            frame.to = Some(address);
+        }
+        if !result.status.is_ok() {
+            frame.error = Some(error_message(&result.status));
+            if matches!(result.status, ExitStatusCode::Revert) {
+                frame.revert_reason = decode_revert_reason(&result.output);
+            }
+            frame.clear_logs();
+        }
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl<DB: Database> Inspector<DB> for CallTracer {
+    fn call(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.start_frame(msg, context);
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        _context: &mut VMContext<DB>,
+    ) {
+        self.end_frame(msg, result);
+    }
+
+    fn create(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.start_frame(msg, context);
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        _context: &mut VMContext<DB>,
+    ) {
+        self.end_frame(msg, result);
+    }
+
+    fn log(&mut self, log: &Log, _context: &mut VMContext<DB>) {
+        if !self.config.with_log || !self.is_traced() {
+            return;
+        }
+        if let Some(frame) = self.stack.last_mut() {
+            frame.logs.push(CallLog {
+                address: log.address,
+                topics: log.topics().to_vec(),
+                data: log.data.data.clone(),
+                position: frame.calls.len() as u64,
+            });
+        }
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        if self.config.only_top_call || !self.is_traced() {
+            return;
+        }
+        if let Some(frame) = self.stack.last_mut() {
+            frame.calls.push(CallFrame {
+                typ: "SELFDESTRUCT".to_string(),
+                from: contract,
+                to: Some(target),
+                value: Some(value),
+                ..Default::default()
+            });
+        }
+    }
+}
+
+/// Returns the geth call type name of the call kind.
+fn call_type(kind: &CallKind) -> &'static str {
+    match kind {
+        CallKind::Call => "CALL",
+        CallKind::Callcode => "CALLCODE",
+        CallKind::Delegatecall => "DELEGATECALL",
+        CallKind::Staticcall => "STATICCALL",
+        CallKind::ExtCall => "EXTCALL",
+        CallKind::ExtStaticcall => "EXTSTATICCALL",
+        CallKind::ExtDelegatecall => "EXTDELEGATECALL",
+        CallKind::Create => "CREATE",
+        CallKind::Create2 => "CREATE2",
+        CallKind::EofCreate => "EOFCREATE",
+        CallKind::ReturnContract => "RETURNCONTRACT",
+    }
+}
+
+/// Returns the geth error message of the failed status.
+fn error_message(status: &ExitStatusCode) -> String {
+    match status {
+        ExitStatusCode::Revert => "execution reverted",
+        ExitStatusCode::CallTooDeep => "max call depth exceeded",
+        ExitStatusCode::OutOfFunds => "insufficient balance for transfer",
+        ExitStatusCode::OutOfGas
+        | ExitStatusCode::MemoryOOG
+        | ExitStatusCode::MemoryLimitOOG
+        | ExitStatusCode::PrecompileOOG
+        | ExitStatusCode::InvalidOperandOOG => "out of gas",
+        ExitStatusCode::OpcodeNotFound | ExitStatusCode::InvalidFEOpcode => "invalid opcode",
+        ExitStatusCode::CallNotAllowedInsideStatic
+        | ExitStatusCode::StateChangeDuringStaticCall => "write protection",
+        ExitStatusCode::InvalidJump => "invalid jump destination",
+        ExitStatusCode::StackUnderflow => "stack underflow",
+        ExitStatusCode::StackOverflow => "stack limit reached 1024",
+        ExitStatusCode::OutOfOffset => "return data out of bounds",
+        ExitStatusCode::CreateCollision => "contract address collision",
+        ExitStatusCode::NonceOverflow => "nonce uint64 overflow",
+        ExitStatusCode::CreateContractSizeLimit => "max code size exceeded",
+        ExitStatusCode::CreateContractStartingWithEF => "invalid code: must not begin with 0xef",
+        ExitStatusCode::CreateInitCodeSizeLimit => "max initcode size exceeded",
+        ExitStatusCode::PrecompileError => "precompiled contract failed",
+        status => return format!("{status:?}"),
+    }
+    .to_string()
+}
+
+/// Decodes the solidity `Error(string)` revert payload.
+fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    let data = output.strip_prefix(&ERROR_SELECTOR)?;
+    let offset = abi_word_to_usize(data.get(..32)?)?;
+    let len = abi_word_to_usize(data.get(offset..offset.checked_add(32)?)?)?;
+    let start = offset + 32;
+    let reason = data.get(start..start.checked_add(len)?)?;
+    String::from_utf8(reason.to_vec()).ok()
+}
+
+/// Reads an ABI encoded 32-byte word as a `usize`.
+fn abi_word_to_usize(word: &[u8]) -> Option<usize> {
+    let (high, low) = word.split_at(24);
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+    usize::try_from(u64::from_be_bytes(low.try_into().ok()?)).ok()
+}
+
+#[inline]
+fn hex_bytes<T: AsRef<[u8]>>(bytes: T) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
@@ -0,0 +1,68 @@
+use super::{Inspector, StepState};
+use crate::{context::VMContext, db::Database};
+use dora_primitives::OpCode;
+use std::io::Write;
+
+/// EIP-3155: EVM trace specification - A JSON format for EVM traces: https://eips.ethereum.org/EIPS/eip-3155
+///
+/// Every instruction is written as a JSON line into the output, for example:
+///
+/// ```json
+/// {"pc":21,"op":0,"gas":"0x4c3fe","gasCost":"0x0","memSize":32,"stack":[],"depth":1,"refund":0,"opName":"STOP"}
+/// ```
+pub struct TracerEip3155 {
+    output: Box<dyn Write>,
+}
+
+impl std::fmt::Debug for TracerEip3155 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TracerEip3155").finish_non_exhaustive()
+    }
+}
+
+impl TracerEip3155 {
+    /// Creates a new EIP-3155 tracer with the given output writer.
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self { output }
+    }
+
+    /// Creates a new EIP-3155 tracer which writes the traces into the stdout.
+    pub fn stdout() -> Self {
+        Self::new(Box::new(std::io::stdout()))
+    }
+}
+
+impl<DB: Database> Inspector<DB> for TracerEip3155 {
+    fn step(&mut self, step: &StepState<'_>, _context: &mut VMContext<DB>) {
+        let op_name = OpCode::new(step.opcode)
+            .map(|op| op.as_str())
+            .unwrap_or("INVALID");
+        let stack = step
+            .stack
+            .iter()
+            .map(|v| {
+                let v = hex::encode(v.to_be_bytes());
+                let v = v.trim_start_matches('0');
+                if v.is_empty() {
+                    "0x0".to_string()
+                } else {
+                    format!("0x{v}")
+                }
+            })
+            .collect::<Vec<String>>();
+        // Tracing must not interrupt the execution, thus the write error is ignored.
+        let _ = writeln!(
+            self.output,
+            "{{\"pc\":{},\"op\":{},\"gas\":\"0x{:x}\",\"gasCost\":\"0x{:x}\",\"memSize\":{},\"stack\":{},\"depth\":{},\"refund\":{},\"opName\":{:?}}}",
+            step.pc,
+            step.opcode,
+            step.gas_remaining,
+            step.gas_cost,
+            step.memory.len(),
+            serde_json::to_string(&stack).unwrap_or_default(),
+            step.depth,
+            step.gas_refunded,
+            op_name,
+        );
+    }
+}
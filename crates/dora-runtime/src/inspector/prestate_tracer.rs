@@ -0,0 +1,327 @@
+use super::Inspector;
+use crate::{
+    call::{CallKind, CallMessage, CallResult},
+    context::VMContext,
+    db::Database,
+};
+use dora_primitives::{Address, B256, Bytes, EvmState, HashSet, KECCAK_EMPTY, U256};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+/// The configuration of the [`PrestateTracer`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrestateTracerConfig {
+    /// Return the state differences of the transaction instead of the state before it.
+    pub diff_mode: bool,
+}
+
+impl PrestateTracerConfig {
+    /// Sets whether to return the state differences of the transaction.
+    pub fn diff_mode(mut self, diff_mode: bool) -> Self {
+        self.diff_mode = diff_mode;
+        self
+    }
+}
+
+/// The account state in geth's `prestateTracer` format, the absent fields are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountState {
+    /// The account balance.
+    pub balance: Option<U256>,
+    /// The account nonce.
+    pub nonce: Option<u64>,
+    /// The account code.
+    pub code: Option<Bytes>,
+    /// The storage slots.
+    pub storage: BTreeMap<B256, B256>,
+}
+
+impl AccountState {
+    /// Returns the account state as a JSON value in geth's `prestateTracer` format.
+    pub fn to_json(&self) -> Value {
+        let mut account = Map::new();
+        if let Some(balance) = self.balance {
+            account.insert("balance".into(), json!(format!("{balance:#x}")));
+        }
+        if let Some(nonce) = self.nonce {
+            account.insert("nonce".into(), json!(nonce));
+        }
+        if let Some(code) = &self.code {
+            account.insert("code".into(), json!(hex_bytes(code)));
+        }
+        if !self.storage.is_empty() {
+            let storage = self
+                .storage
+                .iter()
+                .map(|(key, value)| (hex_bytes(key), json!(hex_bytes(value))))
+                .collect();
+            account.insert("storage".into(), Value::Object(storage));
+        }
+        Value::Object(account)
+    }
+
+    /// Returns whether there is no field in the account state.
+    pub fn is_empty(&self) -> bool {
+        self.balance.is_none()
+            && self.nonce.is_none()
+            && self.code.is_none()
+            && self.storage.is_empty()
+    }
+}
+
+/// The output of the [`PrestateTracer`] in geth's `prestateTracer` format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrestateFrame {
+    /// The state of all the accounts accessed by the transaction before it is executed.
+    Prestate(BTreeMap<Address, AccountState>),
+    /// The state of the modified accounts before and after the transaction.
+    Diff {
+        pre: BTreeMap<Address, AccountState>,
+        post: BTreeMap<Address, AccountState>,
+    },
+}
+
+impl PrestateFrame {
+    /// Returns the frame as a JSON value in geth's `prestateTracer` format.
+    pub fn to_json(&self) -> Value {
+        match self {
+            PrestateFrame::Prestate(accounts) => accounts_to_json(accounts),
+            PrestateFrame::Diff { pre, post } => json!({
+                "pre": accounts_to_json(pre),
+                "post": accounts_to_json(post),
+            }),
+        }
+    }
+}
+
+/// An inspector which records the state accessed by a transaction in geth's `prestateTracer`
+/// format.
+///
+/// The account fields are read from the database when the top-level frame starts and ends, which
+/// is before the transaction state is committed, and the storage slots are read from the journal
+/// with their original values.
+///
+/// # Example
+///
+/// ```no_check
+/// let tracer = Rc::new(RefCell::new(PrestateTracer::new(PrestateTracerConfig::default().diff_mode(true))));
+/// let mut vm = VM::new(VMContext::new(db, env, compile_handler()).with_inspector(tracer.clone()));
+/// let result = vm.transact()?;
+/// let frame = tracer.take().into_prestate_frame(&result.state);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrestateTracer {
+    config: PrestateTracerConfig,
+    /// The account states before the transaction.
+    pre: BTreeMap<Address, AccountState>,
+    /// The accounts which do not exist before the transaction.
+    non_existent: HashSet<Address>,
+}
+
+impl PrestateTracer {
+    /// Creates a new prestate tracer with the config.
+    pub fn new(config: PrestateTracerConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the config of the tracer.
+    pub fn config(&self) -> &PrestateTracerConfig {
+        &self.config
+    }
+
+    /// Consumes the tracer and returns the prestate frame, the final `state` of the transaction
+    /// is used to compute the post state in the diff mode.
+    pub fn into_prestate_frame(self, state: &EvmState) -> PrestateFrame {
+        if !self.config.diff_mode {
+            return PrestateFrame::Prestate(self.pre);
+        }
+        let mut pre = BTreeMap::new();
+        let mut post = BTreeMap::new();
+        for (address, account) in state {
+            let prev = self.pre.get(address).cloned().unwrap_or_default();
+            let exists = !self.non_existent.contains(address);
+            // The changed storage slots, the post state omits the cleared slots.
+            let changed_slots = account
+                .storage
+                .iter()
+                .filter(|(_, slot)| slot.original_value != slot.present_value)
+                .collect::<Vec<_>>();
+            let prev_storage = changed_slots
+                .iter()
+                .map(|(key, slot)| (B256::from(**key), B256::from(slot.original_value)))
+                .collect::<BTreeMap<_, _>>();
+            if account.is_selfdestructed() {
+                if exists {
+                    pre.insert(
+                        *address,
+                        AccountState {
+                            storage: prev_storage,
+                            ..prev
+                        },
+                    );
+                }
+                continue;
+            }
+            let code = account
+                .info
+                .code
+                .as_ref()
+                .map(|code| code.original_bytes())
+                .filter(|code| !code.is_empty());
+            let mut modified = AccountState {
+                storage: changed_slots
+                    .iter()
+                    .filter(|(_, slot)| !slot.present_value.is_zero())
+                    .map(|(key, slot)| (B256::from(**key), B256::from(slot.present_value)))
+                    .collect(),
+                ..Default::default()
+            };
+            if prev.balance.unwrap_or_default() != account.info.balance {
+                modified.balance = Some(account.info.balance);
+            }
+            if prev.nonce.unwrap_or_default() != account.info.nonce {
+                modified.nonce = Some(account.info.nonce);
+            }
+            if prev.code != code {
+                modified.code = code;
+            }
+            if modified.is_empty() && prev_storage.is_empty() {
+                continue;
+            }
+            if exists {
+                pre.insert(
+                    *address,
+                    AccountState {
+                        storage: prev_storage,
+                        ..prev
+                    },
+                );
+            }
+            post.insert(*address, modified);
+        }
+        PrestateFrame::Diff { pre, post }
+    }
+
+    /// Records the account state before the transaction from the database, the account which
+    /// is already recorded is skipped.
+    fn record_account<DB: Database>(&mut self, address: Address, context: &mut VMContext<DB>) {
+        if self.pre.contains_key(&address) {
+            return;
+        }
+        let Ok(info) = context.journal.database.basic(address) else {
+            return;
+        };
+        let Some(info) = info else {
+            self.non_existent.insert(address);
+            self.pre.insert(
+                address,
+                AccountState {
+                    balance: Some(U256::ZERO),
+                    ..Default::default()
+                },
+            );
+            return;
+        };
+        let code = match info.code {
+            Some(code) => Some(code.original_bytes()),
+            None if info.code_hash != KECCAK_EMPTY && !info.code_hash.is_zero() => context
+                .journal
+                .database
+                .code_by_hash(info.code_hash)
+                .ok()
+                .map(|code| code.original_bytes()),
+            None => None,
+        };
+        self.pre.insert(
+            address,
+            AccountState {
+                balance: Some(info.balance),
+                nonce: (info.nonce != 0).then_some(info.nonce),
+                code: code.filter(|code| !code.is_empty()),
+                storage: BTreeMap::new(),
+            },
+        );
+    }
+
+    fn start_transaction<DB: Database>(&mut self, msg: &CallMessage, context: &mut VMContext<DB>) {
+        if msg.depth != 0 {
+            return;
+        }
+        // The caller and the beneficiary are modified outside the top-level frame, thus
+        // record them before the execution.
+        self.record_account(msg.caller, context);
+        if matches!(msg.kind, CallKind::Call) {
+            self.record_account(msg.recipient, context);
+        }
+        let beneficiary = context.env.block.beneficiary;
+        self.record_account(beneficiary, context);
+    }
+
+    fn end_transaction<DB: Database>(&mut self, msg: &CallMessage, context: &mut VMContext<DB>) {
+        if msg.depth != 0 {
+            return;
+        }
+        let addresses = context.journal.state.keys().copied().collect::<Vec<_>>();
+        for address in addresses {
+            self.record_account(address, context);
+            let (Some(account), Some(prev)) = (
+                context.journal.state.get(&address),
+                self.pre.get_mut(&address),
+            ) else {
+                continue;
+            };
+            for (key, slot) in &account.storage {
+                prev.storage
+                    .entry(B256::from(*key))
+                    .or_insert_with(|| B256::from(slot.original_value));
+            }
+        }
+    }
+}
+
+impl<DB: Database> Inspector<DB> for PrestateTracer {
+    fn call(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.start_transaction(msg, context);
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        msg: &CallMessage,
+        _result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        self.end_transaction(msg, context);
+    }
+
+    fn create(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.start_transaction(msg, context);
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        msg: &CallMessage,
+        _result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        self.end_transaction(msg, context);
+    }
+}
+
+fn accounts_to_json(accounts: &BTreeMap<Address, AccountState>) -> Value {
+    Value::Object(
+        accounts
+            .iter()
+            .map(|(address, account)| (hex_bytes(address), account.to_json()))
+            .collect(),
+    )
+}
+
+#[inline]
+fn hex_bytes<T: AsRef<[u8]>>(bytes: T) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
@@ -0,0 +1,352 @@
+//! Executes the legacy EVM bytecode on the revm interpreter, which is used as the baseline tier
+//! before the native artifact of the code is compiled.
+//!
+//! Only the current frame runs on the interpreter: the sub calls and creates are translated into
+//! [`CallMessage`]s and dispatched through [`VMContext::call`], so the callees may run on either
+//! tier and share the same journal, inspector and precompiles with the compiled code.
+
+use crate::{
+    ExitStatusCode,
+    call::{CallKind, CallMessage, CallResult},
+    context::VMContext,
+    db::Database,
+    handler::Frame,
+    host::{Host, SStoreResult as DoraSStoreResult},
+    result::VMError,
+};
+use dora_primitives::{
+    AccountLoad, Address, B256, Bytes, Log, SStoreResult, SelfDestructResult, StateLoad, U256,
+};
+use revm::interpreter::{
+    CallInputs, CallScheme, CreateInputs, CreateScheme, FrameInput, Host as RevmHost, InputsImpl,
+    InstructionResult, Interpreter, InterpreterAction, InterpreterResult, SharedMemory,
+    instruction_table,
+    interpreter::{EthInterpreter, ExtBytecode},
+    interpreter_types::{LoopControl, MemoryTr, ReturnData, StackTr},
+};
+use std::{cell::RefCell, cmp::min, rc::Rc};
+
+/// Executes the frame on the interpreter, the frame code must be the legacy EVM bytecode, the
+/// spec must be before Osaka and the gas schedule must be the Ethereum one.
+pub fn interpret<DB: Database>(
+    frame: Frame,
+    ctx: &mut VMContext<DB>,
+) -> Result<CallResult, VMError> {
+    let spec_id = ctx.spec_id();
+    let gas_limit = frame.gas_limit;
+    let contract = frame.contract;
+    let inputs = InputsImpl {
+        target_address: contract.target_address,
+        caller_address: contract.caller,
+        input: contract.input,
+        call_value: contract.call_value,
+    };
+    let mut interpreter = Interpreter::<EthInterpreter>::new(
+        Rc::new(RefCell::new(SharedMemory::new())),
+        ExtBytecode::new(contract.code),
+        inputs,
+        frame.is_static,
+        false,
+        spec_id,
+        gas_limit,
+    );
+    let table = instruction_table::<EthInterpreter, VMContext<DB>>();
+    let depth = frame.depth as u32;
+    loop {
+        let action = interpreter.run_plain(&table, ctx);
+        match action {
+            InterpreterAction::NewFrame(FrameInput::Call(inputs)) => {
+                let result = ctx.call(call_message(&inputs, depth))?;
+                insert_call_result(&mut interpreter, &inputs, result);
+            }
+            InterpreterAction::NewFrame(FrameInput::Create(inputs)) => {
+                let result = ctx.call(create_message(&inputs, depth, frame.is_static))?;
+                insert_create_result(&mut interpreter, result);
+            }
+            InterpreterAction::NewFrame(FrameInput::EOFCreate(_)) => {
+                // The EOF code is never interpreted, thus EOFCREATE is unreachable.
+                return Ok(CallResult::new_with_gas_limit_and_status(
+                    gas_limit,
+                    ExitStatusCode::FatalExternalError,
+                ));
+            }
+            InterpreterAction::Return { result } => {
+                return Ok(call_result(result, gas_limit));
+            }
+            InterpreterAction::None => {
+                return Ok(CallResult::new_with_gas_limit_and_status(
+                    gas_limit,
+                    ExitStatusCode::FatalExternalError,
+                ));
+            }
+        }
+    }
+}
+
+/// Converts the call inputs of the interpreter to the call message of the current frame `depth`.
+fn call_message(inputs: &CallInputs, depth: u32) -> CallMessage {
+    CallMessage {
+        kind: match inputs.scheme {
+            CallScheme::Call => CallKind::Call,
+            CallScheme::CallCode => CallKind::Callcode,
+            CallScheme::DelegateCall => CallKind::Delegatecall,
+            CallScheme::StaticCall => CallKind::Staticcall,
+            CallScheme::ExtCall => CallKind::ExtCall,
+            CallScheme::ExtStaticCall => CallKind::ExtStaticcall,
+            CallScheme::ExtDelegateCall => CallKind::ExtDelegatecall,
+        },
+        input: inputs.input.clone(),
+        init_code: Bytes::new(),
+        value: inputs.value.get(),
+        depth,
+        gas_limit: inputs.gas_limit,
+        caller: inputs.caller,
+        recipient: inputs.target_address,
+        salt: None,
+        code_address: inputs.bytecode_address,
+        is_static: inputs.is_static,
+        is_eof_init: false,
+        validate_eof: true,
+    }
+}
+
+/// Converts the create inputs of the interpreter to the call message of the current frame `depth`.
+fn create_message(inputs: &CreateInputs, depth: u32, is_static: bool) -> CallMessage {
+    let salt = match inputs.scheme {
+        CreateScheme::Create2 { salt } => Some(B256::from(salt)),
+        _ => None,
+    };
+    CallMessage {
+        kind: if salt.is_some() {
+            CallKind::Create2
+        } else {
+            CallKind::Create
+        },
+        input: inputs.init_code.clone(),
+        init_code: Bytes::new(),
+        value: inputs.value,
+        depth,
+        gas_limit: inputs.gas_limit,
+        caller: inputs.caller,
+        recipient: Address::default(),
+        salt,
+        code_address: Address::default(),
+        is_static,
+        is_eof_init: false,
+        validate_eof: true,
+    }
+}
+
+/// Pushes the sub call result into the interpreter, which is the same as the `CALL` opcodes of
+/// the compiled code.
+fn insert_call_result(
+    interpreter: &mut Interpreter<EthInterpreter>,
+    inputs: &CallInputs,
+    result: CallResult,
+) {
+    let status = result.status;
+    let returned_len = result.output.len();
+    *interpreter.return_data.buffer_mut() = result.output;
+    let _ = interpreter.stack.push(U256::from(status.is_ok() as u8));
+    if status.is_ok() || status.is_revert() {
+        interpreter.control.gas().erase_cost(result.gas_remaining);
+        let target_len = min(inputs.return_memory_offset.len(), returned_len);
+        if target_len != 0 {
+            let data = interpreter.return_data.buffer()[..target_len].to_vec();
+            interpreter
+                .memory
+                .set(inputs.return_memory_offset.start, &data);
+        }
+    }
+    if status.is_ok() {
+        interpreter.control.gas().record_refund(result.gas_refunded);
+    }
+}
+
+/// Pushes the sub create result into the interpreter, which is the same as the `CREATE` opcodes
+/// of the compiled code.
+fn insert_create_result(interpreter: &mut Interpreter<EthInterpreter>, result: CallResult) {
+    let status = result.status;
+    if status.is_revert() {
+        *interpreter.return_data.buffer_mut() = result.output;
+    } else {
+        interpreter.return_data.buffer_mut().clear();
+    }
+    if status.is_ok() || status.is_revert() {
+        interpreter.control.gas().erase_cost(result.gas_remaining);
+    }
+    let address = if status.is_ok() {
+        interpreter.control.gas().record_refund(result.gas_refunded);
+        U256::from_be_bytes(result.create_address.unwrap_or_default().into_word().0)
+    } else {
+        U256::ZERO
+    };
+    let _ = interpreter.stack.push(address);
+}
+
+/// Converts the interpreter result of the frame to the call result.
+fn call_result(result: InterpreterResult, gas_limit: u64) -> CallResult {
+    CallResult {
+        status: exit_status(result.result),
+        gas_limit,
+        gas_remaining: result.gas.remaining(),
+        gas_refunded: result.gas.refunded(),
+        output: result.output,
+        create_address: None,
+    }
+}
+
+fn exit_status(result: InstructionResult) -> ExitStatusCode {
+    match result {
+        InstructionResult::Stop => ExitStatusCode::Stop,
+        InstructionResult::Return => ExitStatusCode::Return,
+        InstructionResult::SelfDestruct => ExitStatusCode::SelfDestruct,
+        InstructionResult::Revert => ExitStatusCode::Revert,
+        InstructionResult::CallTooDeep => ExitStatusCode::CallTooDeep,
+        InstructionResult::OutOfFunds => ExitStatusCode::OutOfFunds,
+        InstructionResult::OutOfGas => ExitStatusCode::OutOfGas,
+        InstructionResult::MemoryOOG => ExitStatusCode::MemoryOOG,
+        InstructionResult::MemoryLimitOOG => ExitStatusCode::MemoryLimitOOG,
+        InstructionResult::PrecompileOOG => ExitStatusCode::PrecompileOOG,
+        InstructionResult::InvalidOperandOOG => ExitStatusCode::InvalidOperandOOG,
+        InstructionResult::OpcodeNotFound => ExitStatusCode::OpcodeNotFound,
+        InstructionResult::CallNotAllowedInsideStatic => ExitStatusCode::CallNotAllowedInsideStatic,
+        InstructionResult::StateChangeDuringStaticCall => {
+            ExitStatusCode::StateChangeDuringStaticCall
+        }
+        InstructionResult::InvalidFEOpcode => ExitStatusCode::InvalidFEOpcode,
+        InstructionResult::InvalidJump => ExitStatusCode::InvalidJump,
+        InstructionResult::NotActivated => ExitStatusCode::NotActivated,
+        InstructionResult::StackUnderflow => ExitStatusCode::StackUnderflow,
+        InstructionResult::StackOverflow => ExitStatusCode::StackOverflow,
+        InstructionResult::OutOfOffset => ExitStatusCode::OutOfOffset,
+        InstructionResult::CreateCollision => ExitStatusCode::CreateCollision,
+        InstructionResult::OverflowPayment => ExitStatusCode::OverflowPayment,
+        InstructionResult::PrecompileError => ExitStatusCode::PrecompileError,
+        InstructionResult::NonceOverflow => ExitStatusCode::NonceOverflow,
+        InstructionResult::CreateContractSizeLimit => ExitStatusCode::CreateContractSizeLimit,
+        InstructionResult::CreateContractStartingWithEF => {
+            ExitStatusCode::CreateContractStartingWithEF
+        }
+        InstructionResult::CreateInitCodeSizeLimit => ExitStatusCode::CreateInitCodeSizeLimit,
+        _ => ExitStatusCode::FatalExternalError,
+    }
+}
+
+impl<DB: Database> RevmHost for VMContext<DB> {
+    fn basefee(&self) -> U256 {
+        U256::from(self.env.block.basefee)
+    }
+
+    fn blob_gasprice(&self) -> U256 {
+        U256::from(self.env.blob_gasprice().unwrap_or_default())
+    }
+
+    fn gas_limit(&self) -> U256 {
+        U256::from(self.env.block.gas_limit)
+    }
+
+    fn difficulty(&self) -> U256 {
+        self.env.block.difficulty
+    }
+
+    fn prevrandao(&self) -> Option<U256> {
+        self.env
+            .block
+            .prevrandao
+            .map(|prevrandao| U256::from_be_bytes(prevrandao.0))
+    }
+
+    fn block_number(&self) -> u64 {
+        self.env.block.number
+    }
+
+    fn timestamp(&self) -> U256 {
+        U256::from(self.env.block.timestamp)
+    }
+
+    fn beneficiary(&self) -> Address {
+        self.env.block.beneficiary
+    }
+
+    fn chain_id(&self) -> U256 {
+        U256::from(self.env.cfg.chain_id)
+    }
+
+    fn effective_gas_price(&self) -> U256 {
+        U256::from(self.env.effective_gas_price())
+    }
+
+    fn caller(&self) -> Address {
+        self.env.tx.caller
+    }
+
+    fn blob_hash(&self, number: usize) -> Option<U256> {
+        self.env
+            .tx
+            .blob_hashes
+            .get(number)
+            .map(|hash| U256::from_be_bytes(hash.0))
+    }
+
+    fn max_initcode_size(&self) -> usize {
+        self.max_code_size().saturating_mul(2)
+    }
+
+    fn block_hash(&mut self, number: u64) -> Option<B256> {
+        Host::block_hash(self, number).ok()
+    }
+
+    fn selfdestruct(
+        &mut self,
+        address: Address,
+        target: Address,
+    ) -> Option<StateLoad<SelfDestructResult>> {
+        Host::selfdestruct(self, address, target).ok()
+    }
+
+    fn log(&mut self, log: Log) {
+        Host::log(self, log)
+    }
+
+    fn sstore(
+        &mut self,
+        address: Address,
+        key: U256,
+        value: U256,
+    ) -> Option<StateLoad<SStoreResult>> {
+        let StateLoad { data, is_cold } = Host::sstore(self, address, key, value).ok()?;
+        match data {
+            DoraSStoreResult::Slot(data) => Some(StateLoad::new(data, is_cold)),
+            DoraSStoreResult::Status(_) => None,
+        }
+    }
+
+    fn sload(&mut self, address: Address, key: U256) -> Option<StateLoad<U256>> {
+        Host::sload(self, address, key).ok()
+    }
+
+    fn tstore(&mut self, address: Address, key: U256, value: U256) {
+        self.tstore(address, key, value)
+    }
+
+    fn tload(&mut self, address: Address, key: U256) -> U256 {
+        self.tload(address, key)
+    }
+
+    fn balance(&mut self, address: Address) -> Option<StateLoad<U256>> {
+        Host::balance(self, address).ok()
+    }
+
+    fn load_account_delegated(&mut self, address: Address) -> Option<StateLoad<AccountLoad>> {
+        Host::load_account_delegated(self, address).ok()
+    }
+
+    fn load_account_code(&mut self, address: Address) -> Option<StateLoad<Bytes>> {
+        Host::code(self, address).ok()
+    }
+
+    fn load_account_code_hash(&mut self, address: Address) -> Option<StateLoad<B256>> {
+        Host::code_hash(self, address).ok()
+    }
+}
@@ -1,27 +1,59 @@
+pub mod aot;
 pub mod artifact;
+pub mod block;
+pub mod cache;
 pub mod call;
 pub mod constants;
 pub mod context;
 pub mod db;
 pub mod executor;
 pub mod gas;
+pub mod genesis;
 pub mod handler;
 pub mod host;
+pub mod inspector;
+pub mod interpreter;
+#[cfg(feature = "optimism")]
+pub mod optimism;
+pub mod parallel;
+pub mod precompile;
 pub mod result;
+pub mod scheduler;
+pub mod simulate;
 pub mod stack;
 pub mod symbols;
+pub mod system;
+pub mod trie;
 pub mod vm;
 pub mod wasm;
 
+pub use aot::{AotCache, AotCacheKey};
 pub use artifact::{Artifact, SymbolArtifact};
+pub use block::{
+    Block, BlockError, BlockExecutor, BlockOutput, Ommer, Receipt, SystemCall, Withdrawal,
+};
+pub use cache::{ArtifactCache, ArtifactKey, CacheStats};
 pub use call::{CallKind, CallMessage, CallResult, CallType, CallTypeParseError, ExtCallType};
 pub use context::{Contract, RuntimeContext, VMContext};
 pub use db::{Database, DatabaseCommit, MemoryDB};
 pub use dora_primitives::{Account, AccountInfo, AccountStatus, TransferError};
 pub use executor::{ExecuteKind, ExecutionEngine, Executor, RUNTIME_STACK_SIZE};
-pub use host::{DummyHost, Host};
+pub use genesis::{Genesis, GenesisAccount, GenesisAlloc};
+pub use host::{DummyHost, Host, HostError};
+pub use inspector::{
+    CallTracer, CallTracerConfig, Inspector, NoOpInspector, PrestateTracer, PrestateTracerConfig,
+    StepState, TracerEip3155,
+};
+pub use parallel::{HandlerFactory, ParallelBlockExecutor, VersionedDB};
+pub use precompile::{ContextPrecompile, ContextPrecompiles, PrecompileInput};
 pub use result::{ExecutionResult, HaltReason, ResultAndState, VMError};
+pub use scheduler::{FrameScheduler, SuspendedFrame};
+pub use simulate::{
+    AccountOverride, BlockOverrides, OverrideDB, Simulation, StateOverride, StorageOverride,
+};
 pub use stack::Stack;
+pub use system::Requests;
+pub use trie::{StateTrie, Trie};
 pub use vm::VM;
 
 #[repr(u8)]
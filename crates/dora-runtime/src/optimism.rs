@@ -0,0 +1,593 @@
+//! The OP-stack execution mode, which executes the deposit transactions and charges the L1 data
+//! fee of the other transactions.
+//!
+//! The mode is enabled by the `optimism` feature and the transaction lifecycle hooks returned by
+//! [`lifecycle`], the OP-stack hardfork and the transaction fields which are not in the
+//! [`TxEnv`](dora_primitives::TxEnv) are set on the [`VMContext::optimism`] environment.
+//!
+//! # Example Usage:
+//! ```no_run
+//! use dora_runtime::{
+//!     context::VMContext, db::MemoryDB, handler::Handler, optimism::{self, OpSpecId},
+//! };
+//! use dora_primitives::Env;
+//! let handler = Handler::<MemoryDB>::dummy().with_lifecycle(optimism::lifecycle());
+//! let mut ctx = VMContext::new(MemoryDB::new(), Env::default(), handler);
+//! ctx.optimism.spec = OpSpecId::Fjord;
+//! ```
+
+use std::sync::Arc;
+
+use dora_primitives::{
+    Address, B256, Bytes, Database, Env, ExecutionResult, InvalidTransaction, JournalOutput,
+    JournalTr, ResultAndState, SpecId, U256, address,
+};
+
+use crate::{context::VMContext, handler::LifecycleHandles, result::VMError, vm::VM};
+
+/// The transaction type of the deposit transactions derived from L1.
+pub const DEPOSIT_TRANSACTION_TYPE: u8 = 0x7E;
+/// The predeploy which stores the attributes of the latest L1 block.
+pub const L1_BLOCK_CONTRACT: Address = address!("0x4200000000000000000000000000000000000015");
+/// The vault which receives the base fee of the transactions.
+pub const BASE_FEE_VAULT: Address = address!("0x4200000000000000000000000000000000000019");
+/// The vault which receives the L1 data fee of the transactions.
+pub const L1_FEE_VAULT: Address = address!("0x420000000000000000000000000000000000001a");
+
+/// The storage slot of the L1 base fee in the L1 block contract.
+const L1_BASE_FEE_SLOT: U256 = U256::from_limbs([1, 0, 0, 0]);
+/// The storage slot of the L1 fee overhead before Ecotone.
+const L1_OVERHEAD_SLOT: U256 = U256::from_limbs([5, 0, 0, 0]);
+/// The storage slot of the L1 fee scalar before Ecotone.
+const L1_SCALAR_SLOT: U256 = U256::from_limbs([6, 0, 0, 0]);
+/// The storage slot of the packed base fee and blob base fee scalars since Ecotone.
+const ECOTONE_L1_FEE_SCALARS_SLOT: U256 = U256::from_limbs([3, 0, 0, 0]);
+/// The storage slot of the L1 blob base fee since Ecotone.
+const ECOTONE_L1_BLOB_BASE_FEE_SLOT: U256 = U256::from_limbs([7, 0, 0, 0]);
+/// The byte offset of the `u32` base fee scalar in the packed scalars slot.
+const BASE_FEE_SCALAR_OFFSET: usize = 16;
+/// The byte offset of the `u32` blob base fee scalar in the packed scalars slot.
+const BLOB_BASE_FEE_SCALAR_OFFSET: usize = 20;
+
+/// The calldata cost of a zero byte and a non-zero byte.
+const ZERO_BYTE_COST: u64 = 4;
+const NON_ZERO_BYTE_COST: u64 = 16;
+/// The signature bytes which are added to the data gas before Regolith.
+const PRE_REGOLITH_SIGNATURE_BYTES: u64 = 68;
+/// The Fjord linear regression of the compressed transaction size, scaled by 1e6.
+const L1_COST_INTERCEPT: u64 = 42_585_600;
+const L1_COST_FASTLZ_COEF: u64 = 836_500;
+const MIN_TX_SIZE_SCALED: u64 = 100 * 1_000_000;
+
+/// The OP-stack hardforks which change the execution rules, the Ethereum rules are still
+/// selected by the [`SpecId`] of the configuration.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OpSpecId {
+    #[default]
+    Bedrock,
+    Regolith,
+    Canyon,
+    Ecotone,
+    Fjord,
+    Granite,
+    Holocene,
+}
+
+impl OpSpecId {
+    /// Returns `true` if the given hardfork is enabled in the current one.
+    #[inline]
+    pub fn is_enabled_in(self, other: Self) -> bool {
+        self >= other
+    }
+}
+
+/// The fields of the deposit transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DepositTxEnv {
+    /// The hash which uniquely identifies the source of the deposit.
+    pub source_hash: B256,
+    /// The ETH value minted on L2 to the caller before the execution.
+    pub mint: Option<u128>,
+    /// Whether the transaction is a system transaction, which is disallowed since Regolith.
+    pub is_system_transaction: bool,
+}
+
+/// The OP-stack fields of the transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpTxEnv {
+    /// The EIP-2718 encoded transaction, which the L1 data fee is computed with. It is required
+    /// for all the transactions except the deposits.
+    pub enveloped_tx: Option<Bytes>,
+    /// The deposit fields, which are only used by the transactions of
+    /// [`DEPOSIT_TRANSACTION_TYPE`].
+    pub deposit: DepositTxEnv,
+}
+
+/// The OP-stack environment of the VM context.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpEnv {
+    /// The active OP-stack hardfork.
+    pub spec: OpSpecId,
+    /// The OP-stack fields of the current transaction.
+    pub tx: OpTxEnv,
+}
+
+/// The L1 fee parameters stored in the L1 block contract.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct L1BlockInfo {
+    /// The base fee of the L1 block.
+    pub l1_base_fee: U256,
+    /// The constant data gas overhead before Ecotone.
+    pub l1_fee_overhead: Option<U256>,
+    /// The scalar of the L1 base fee, it is scaled by 1e6.
+    pub l1_base_fee_scalar: U256,
+    /// The blob base fee of the L1 block since Ecotone.
+    pub l1_blob_base_fee: Option<U256>,
+    /// The scalar of the L1 blob base fee since Ecotone, it is scaled by 1e6.
+    pub l1_blob_base_fee_scalar: Option<U256>,
+}
+
+impl L1BlockInfo {
+    /// Reads the L1 fee parameters of the hardfork from the L1 block contract.
+    pub fn try_fetch<DB: Database>(db: &mut DB, spec: OpSpecId) -> Result<Self, DB::Error> {
+        let l1_base_fee = db.storage(L1_BLOCK_CONTRACT, L1_BASE_FEE_SLOT)?;
+        if spec.is_enabled_in(OpSpecId::Ecotone) {
+            let l1_blob_base_fee = db.storage(L1_BLOCK_CONTRACT, ECOTONE_L1_BLOB_BASE_FEE_SLOT)?;
+            let scalars = db
+                .storage(L1_BLOCK_CONTRACT, ECOTONE_L1_FEE_SCALARS_SLOT)?
+                .to_be_bytes::<32>();
+            // The scalars are not set until the upgrade transactions of the first Ecotone block
+            // are executed, which still uses the Bedrock parameters.
+            let empty_scalars = l1_blob_base_fee.is_zero()
+                && scalars[BASE_FEE_SCALAR_OFFSET..BLOB_BASE_FEE_SCALAR_OFFSET + 4]
+                    .iter()
+                    .all(|byte| *byte == 0);
+            if !empty_scalars || spec.is_enabled_in(OpSpecId::Fjord) {
+                return Ok(Self {
+                    l1_base_fee,
+                    l1_fee_overhead: None,
+                    l1_base_fee_scalar: U256::from_be_slice(
+                        &scalars[BASE_FEE_SCALAR_OFFSET..BASE_FEE_SCALAR_OFFSET + 4],
+                    ),
+                    l1_blob_base_fee: Some(l1_blob_base_fee),
+                    l1_blob_base_fee_scalar: Some(U256::from_be_slice(
+                        &scalars[BLOB_BASE_FEE_SCALAR_OFFSET..BLOB_BASE_FEE_SCALAR_OFFSET + 4],
+                    )),
+                });
+            }
+        }
+        Ok(Self {
+            l1_base_fee,
+            l1_fee_overhead: Some(db.storage(L1_BLOCK_CONTRACT, L1_OVERHEAD_SLOT)?),
+            l1_base_fee_scalar: db.storage(L1_BLOCK_CONTRACT, L1_SCALAR_SLOT)?,
+            l1_blob_base_fee: None,
+            l1_blob_base_fee_scalar: None,
+        })
+    }
+
+    /// Returns the data gas of the enveloped transaction, the signature is added before
+    /// Regolith.
+    pub fn data_gas(input: &[u8], spec: OpSpecId) -> U256 {
+        let zero_bytes = input.iter().filter(|byte| **byte == 0).count() as u64;
+        let mut gas =
+            zero_bytes * ZERO_BYTE_COST + (input.len() as u64 - zero_bytes) * NON_ZERO_BYTE_COST;
+        if !spec.is_enabled_in(OpSpecId::Regolith) {
+            gas += PRE_REGOLITH_SIGNATURE_BYTES * NON_ZERO_BYTE_COST;
+        }
+        U256::from(gas)
+    }
+
+    /// Returns the L1 data fee of the enveloped transaction, the deposits pay no L1 fee.
+    pub fn calculate_tx_l1_cost(&self, input: &[u8], spec: OpSpecId) -> U256 {
+        if input.is_empty() || input[0] == DEPOSIT_TRANSACTION_TYPE {
+            return U256::ZERO;
+        }
+        match (self.l1_blob_base_fee, self.l1_blob_base_fee_scalar) {
+            (Some(blob_base_fee), Some(blob_base_fee_scalar)) => {
+                // The base fee is weighted by the calldata cost of a byte, i.e. 16.
+                let l1_fee_scaled = self
+                    .l1_base_fee
+                    .saturating_mul(U256::from(NON_ZERO_BYTE_COST))
+                    .saturating_mul(self.l1_base_fee_scalar)
+                    .saturating_add(blob_base_fee.saturating_mul(blob_base_fee_scalar));
+                if spec.is_enabled_in(OpSpecId::Fjord) {
+                    tx_estimated_size_fjord(input)
+                        .saturating_mul(l1_fee_scaled)
+                        .wrapping_div(U256::from(1_000_000_000_000_u64))
+                } else {
+                    Self::data_gas(input, spec)
+                        .saturating_mul(l1_fee_scaled)
+                        .wrapping_div(U256::from(NON_ZERO_BYTE_COST * 1_000_000))
+                }
+            }
+            _ => Self::data_gas(input, spec)
+                .saturating_add(self.l1_fee_overhead.unwrap_or_default())
+                .saturating_mul(self.l1_base_fee)
+                .saturating_mul(self.l1_base_fee_scalar)
+                .wrapping_div(U256::from(1_000_000)),
+        }
+    }
+}
+
+/// Returns the Fjord estimation of the transaction size from its FastLZ compressed size, it is
+/// scaled by 1e6.
+fn tx_estimated_size_fjord(input: &[u8]) -> U256 {
+    let fastlz_size = flz_compress_len(input) as u64;
+    U256::from(
+        fastlz_size
+            .saturating_mul(L1_COST_FASTLZ_COEF)
+            .saturating_sub(L1_COST_INTERCEPT)
+            .max(MIN_TX_SIZE_SCALED),
+    )
+}
+
+/// Returns the length of the input compressed by FastLZ (level 1), which is the same as the
+/// `LibZip.flzCompress` of Solady used by the L1 gas price oracle.
+fn flz_compress_len(input: &[u8]) -> u32 {
+    let mut idx: u32 = 2;
+    let idx_limit: u32 = if input.len() < 13 {
+        0
+    } else {
+        input.len() as u32 - 13
+    };
+    let mut anchor = 0;
+    let mut size = 0;
+    let mut htab = [0_u32; 8192];
+    while idx < idx_limit {
+        let mut r: u32;
+        let mut distance: u32;
+        loop {
+            let seq = u24(input, idx);
+            let hash = flz_hash(seq);
+            r = htab[hash as usize];
+            htab[hash as usize] = idx;
+            distance = idx - r;
+            if idx >= idx_limit {
+                break;
+            }
+            idx += 1;
+            if distance < 8192 && seq == u24(input, r) {
+                break;
+            }
+        }
+        if idx >= idx_limit {
+            break;
+        }
+        idx -= 1;
+        if idx > anchor {
+            size = flz_literals(idx - anchor, size);
+        }
+        let len = flz_cmp(input, r + 3, idx + 3, idx_limit + 9);
+        size = flz_match(len, size);
+        idx = flz_set_next_hash(&mut htab, input, idx + len);
+        idx = flz_set_next_hash(&mut htab, input, idx);
+        anchor = idx;
+    }
+    flz_literals(input.len() as u32 - anchor, size)
+}
+
+#[inline]
+fn flz_literals(runs: u32, size: u32) -> u32 {
+    let size = size + 0x21 * (runs / 0x20);
+    let runs = runs % 0x20;
+    if runs != 0 { size + runs + 1 } else { size }
+}
+
+#[inline]
+fn flz_cmp(input: &[u8], p: u32, q: u32, r: u32) -> u32 {
+    let mut len = 0;
+    let mut limit = r - q;
+    while len < limit {
+        if input[(p + len) as usize] != input[(q + len) as usize] {
+            limit = 0;
+        }
+        len += 1;
+    }
+    len
+}
+
+#[inline]
+fn flz_match(len: u32, size: u32) -> u32 {
+    let len = len - 1;
+    let size = size + 3 * (len / 262);
+    if len % 262 >= 6 { size + 3 } else { size + 2 }
+}
+
+#[inline]
+fn flz_set_next_hash(htab: &mut [u32; 8192], input: &[u8], idx: u32) -> u32 {
+    htab[flz_hash(u24(input, idx)) as usize] = idx;
+    idx + 1
+}
+
+#[inline]
+fn flz_hash(value: u32) -> u16 {
+    let hash = (value as u64 * 2654435769) >> 19;
+    hash as u16 & 0x1fff
+}
+
+#[inline]
+fn u24(input: &[u8], idx: u32) -> u32 {
+    u32::from(input[idx as usize])
+        + (u32::from(input[(idx + 1) as usize]) << 8)
+        + (u32::from(input[(idx + 2) as usize]) << 16)
+}
+
+/// Returns `true` if the transaction is a deposit transaction.
+#[inline]
+pub fn is_deposit(env: &Env) -> bool {
+    env.tx.tx_type == DEPOSIT_TRANSACTION_TYPE
+}
+
+/// Returns the L1 data fee of the current transaction.
+pub fn l1_cost<DB: Database>(ctx: &mut VMContext<DB>) -> Result<U256, VMError> {
+    if is_deposit(&ctx.env) {
+        return Ok(U256::ZERO);
+    }
+    let spec = ctx.optimism.spec;
+    let Some(enveloped_tx) = ctx.optimism.tx.enveloped_tx.clone() else {
+        return Err(VMError::Handler(
+            "missing enveloped transaction of the L1 data fee".to_string(),
+        ));
+    };
+    // The L1 attributes are read from the database, which are set by the first deposit of the
+    // block and not changed by the other transactions.
+    let l1_block_info =
+        L1BlockInfo::try_fetch(&mut ctx.journal.database, spec).map_err(VMError::database)?;
+    Ok(l1_block_info.calculate_tx_l1_cost(&enveloped_tx, spec))
+}
+
+/// Returns the OP-stack transaction lifecycle hooks.
+pub fn lifecycle<DB: Database>() -> LifecycleHandles<DB> {
+    LifecycleHandles {
+        validate_env: Arc::new(|ctx| validate_env(ctx)),
+        validate_tx_against_state: Arc::new(|ctx| validate_tx_against_state(ctx)),
+        deduct_caller: Arc::new(|ctx| deduct_caller(ctx)),
+        reimburse_caller: Arc::new(|ctx, gas_remaining, gas_refunded| {
+            reimburse_caller(ctx, gas_remaining, gas_refunded)
+        }),
+        reward_beneficiary: Arc::new(|ctx, gas_used, gas_refunded| {
+            reward_beneficiary(ctx, gas_used, gas_refunded)
+        }),
+    }
+}
+
+/// Validates the environment, the deposits are derived from L1 thus they have no signature and
+/// fee to check.
+pub fn validate_env<DB: Database>(ctx: &VMContext<DB>) -> Result<(), VMError> {
+    if is_deposit(&ctx.env) {
+        if ctx.optimism.tx.deposit.is_system_transaction
+            && ctx.optimism.spec.is_enabled_in(OpSpecId::Regolith)
+        {
+            return Err(VMError::Handler(
+                "deposit system transactions are disabled since Regolith".to_string(),
+            ));
+        }
+        return Ok(());
+    }
+    VM::validate_env(ctx)
+}
+
+/// Validates the transaction against the caller account, the balance of the caller should also
+/// cover the L1 data fee. The nonce and the balance of the deposits are not checked.
+pub fn validate_tx_against_state<DB: Database>(ctx: &mut VMContext<DB>) -> Result<(), VMError> {
+    if is_deposit(&ctx.env) {
+        return Ok(());
+    }
+    let l1_cost = l1_cost(ctx)?;
+    VM::validate_tx_against_state(ctx)?;
+    let fee = U256::from(ctx.env.tx.gas_limit)
+        .saturating_mul(U256::from(ctx.env.max_fee_per_gas()))
+        .saturating_add(ctx.env.tx.value)
+        .saturating_add(ctx.env.calc_max_data_fee())
+        .saturating_add(l1_cost);
+    let balance_check_disabled = ctx.env.cfg.is_balance_check_disabled();
+    let caller = ctx
+        .journal
+        .load_account(ctx.env.tx.caller)
+        .map_err(VMError::database)?;
+    if fee > caller.data.info.balance {
+        if balance_check_disabled {
+            caller.data.info.balance = caller.data.info.balance.saturating_add(l1_cost);
+        } else {
+            return Err(VMError::Transaction(
+                InvalidTransaction::LackOfFundForMaxFee {
+                    fee: Box::new(fee),
+                    balance: Box::new(caller.data.info.balance),
+                },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Mints the deposit value to the caller, or deducts the maximum fee and the L1 data fee from
+/// the caller.
+pub fn deduct_caller<DB: Database>(ctx: &mut VMContext<DB>) -> Result<(), VMError> {
+    let l1_cost = if is_deposit(&ctx.env) {
+        let mint = U256::from(ctx.optimism.tx.deposit.mint.unwrap_or_default());
+        let caller = ctx
+            .journal
+            .load_account(ctx.env.tx.caller)
+            .map_err(VMError::database)?;
+        caller.data.info.balance = caller.data.info.balance.saturating_add(mint);
+        U256::ZERO
+    } else {
+        l1_cost(ctx)?
+    };
+    // The gas price of the deposits is zero, thus only their nonce is bumped.
+    ctx.deduct_caller()?;
+    if !l1_cost.is_zero() {
+        let caller = ctx
+            .journal
+            .load_account(ctx.env.tx.caller)
+            .map_err(VMError::database)?;
+        caller.data.info.balance = caller.data.info.balance.saturating_sub(l1_cost);
+    }
+    Ok(())
+}
+
+/// Reimburses the caller with the remaining gas, the deposits prepay no gas thus nothing is
+/// reimbursed.
+pub fn reimburse_caller<DB: Database>(
+    ctx: &mut VMContext<DB>,
+    gas_remaining: u64,
+    gas_refunded: i64,
+) -> Result<(), VMError> {
+    if is_deposit(&ctx.env) {
+        return Ok(());
+    }
+    ctx.reimburse_caller(gas_remaining, gas_refunded)
+}
+
+/// Pays the priority fee to the beneficiary, the base fee to the [`BASE_FEE_VAULT`] and the L1
+/// data fee to the [`L1_FEE_VAULT`], the deposits pay no fee.
+pub fn reward_beneficiary<DB: Database>(
+    ctx: &mut VMContext<DB>,
+    gas_used: u64,
+    gas_refunded: i64,
+) -> Result<(), VMError> {
+    if is_deposit(&ctx.env) {
+        return Ok(());
+    }
+    ctx.reward_beneficiary(gas_used, gas_refunded)?;
+    let l1_cost = l1_cost(ctx)?;
+    let base_fee = if ctx.spec_id().is_enabled_in(SpecId::LONDON) {
+        U256::from(ctx.env.block.basefee as u128 * (gas_used - gas_refunded as u64) as u128)
+    } else {
+        U256::ZERO
+    };
+    for (vault, fee) in [(L1_FEE_VAULT, l1_cost), (BASE_FEE_VAULT, base_fee)] {
+        let vault = ctx.journal.load_account(vault).map_err(VMError::database)?;
+        vault.data.mark_touch();
+        vault.data.info.balance = vault.data.info.balance.saturating_add(fee);
+    }
+    Ok(())
+}
+
+/// Applies the deposit gas rules to the output of the transaction.
+///
+/// Before Regolith, the deposits use the whole gas limit and the successful system deposits use
+/// no gas. The deposit which fails the validation is still included in the block, the state is
+/// reverted except the mint and the nonce bump of the caller, and it is reported as a revert
+/// without output which uses the whole gas limit.
+pub(crate) fn deposit_output<DB: Database>(
+    ctx: &mut VMContext<DB>,
+    output: Result<ResultAndState, VMError>,
+) -> Result<ResultAndState, VMError> {
+    if !is_deposit(&ctx.env) {
+        return output;
+    }
+    let is_regolith = ctx.optimism.spec.is_enabled_in(OpSpecId::Regolith);
+    let is_system_transaction = ctx.optimism.tx.deposit.is_system_transaction;
+    let gas_limit = ctx.env.tx.gas_limit;
+    match output {
+        Ok(mut output) => {
+            if !is_regolith {
+                let used = if output.result.is_success() && is_system_transaction {
+                    0
+                } else {
+                    gas_limit
+                };
+                match &mut output.result {
+                    ExecutionResult::Success {
+                        gas_used,
+                        gas_refunded,
+                        ..
+                    } => {
+                        *gas_used = used;
+                        *gas_refunded = 0;
+                    }
+                    ExecutionResult::Revert { gas_used, .. }
+                    | ExecutionResult::Halt { gas_used, .. } => *gas_used = used,
+                }
+            }
+            Ok(output)
+        }
+        Err(VMError::Transaction(_)) => {
+            let mint = U256::from(ctx.optimism.tx.deposit.mint.unwrap_or_default());
+            let caller = ctx
+                .journal
+                .load_account(ctx.env.tx.caller)
+                .map_err(VMError::database)?;
+            caller.data.info.balance = caller.data.info.balance.saturating_add(mint);
+            caller.data.info.nonce = caller.data.info.nonce.saturating_add(1);
+            caller.data.mark_touch();
+            let JournalOutput { state, .. } = ctx.journal.finalize();
+            let gas_used = if is_regolith || !is_system_transaction {
+                gas_limit
+            } else {
+                0
+            };
+            Ok(ResultAndState {
+                result: ExecutionResult::Revert {
+                    output: Bytes::new(),
+                    gas_used,
+                },
+                state,
+            })
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn test_flz_compress_len() {
+        assert_eq!(flz_compress_len(&[]), 0);
+        assert_eq!(flz_compress_len(&hex!("facade")), 4);
+        assert_eq!(flz_compress_len(&[0; 1000]), 21);
+        assert_eq!(flz_compress_len(&[42; 1000]), 21);
+    }
+
+    #[test]
+    fn test_data_gas() {
+        let input = hex!("00facade");
+        assert_eq!(
+            L1BlockInfo::data_gas(&input, OpSpecId::Regolith),
+            U256::from(4 + 3 * 16)
+        );
+        assert_eq!(
+            L1BlockInfo::data_gas(&input, OpSpecId::Bedrock),
+            U256::from(4 + 3 * 16 + 68 * 16)
+        );
+    }
+
+    #[test]
+    fn test_calculate_tx_l1_cost_bedrock() {
+        let l1_block_info = L1BlockInfo {
+            l1_base_fee: U256::from(1_000),
+            l1_fee_overhead: Some(U256::from(1_000)),
+            l1_base_fee_scalar: U256::from(1_000),
+            ..Default::default()
+        };
+        // (4 + 3 * 16 + 1000) * 1000 * 1000 / 1e6
+        let cost = l1_block_info.calculate_tx_l1_cost(&hex!("00facade"), OpSpecId::Regolith);
+        assert_eq!(cost, U256::from(1_052));
+        // The deposits pay no L1 fee.
+        let cost = l1_block_info.calculate_tx_l1_cost(&hex!("7efacade"), OpSpecId::Regolith);
+        assert_eq!(cost, U256::ZERO);
+    }
+
+    #[test]
+    fn test_calculate_tx_l1_cost_ecotone_and_fjord() {
+        let l1_block_info = L1BlockInfo {
+            l1_base_fee: U256::from(1_000),
+            l1_base_fee_scalar: U256::from(1_000),
+            l1_blob_base_fee: Some(U256::from(1_000)),
+            l1_blob_base_fee_scalar: Some(U256::from(1_000)),
+            ..Default::default()
+        };
+        // The scaled fee is 1000 * 16 * 1000 + 1000 * 1000 = 17e6.
+        // (4 + 3 * 16) * 17e6 / 16e6
+        let cost = l1_block_info.calculate_tx_l1_cost(&hex!("00facade"), OpSpecId::Ecotone);
+        assert_eq!(cost, U256::from(55));
+        // The estimated size is at least 100e6, thus 100e6 * 17e6 / 1e12.
+        let cost = l1_block_info.calculate_tx_l1_cost(&hex!("00facade"), OpSpecId::Fjord);
+        assert_eq!(cost, U256::from(1_700));
+    }
+}
@@ -0,0 +1,504 @@
+//! The parallel block executor, which executes the transactions optimistically on a thread pool
+//! in the Block-STM style and commits them in the block order.
+//!
+//! Each round executes the pending transactions in parallel on top of the multi-version memory,
+//! i.e., the state changes of the executed but not yet committed transactions over the database.
+//! Every database read of a transaction is recorded, then the transactions are validated and
+//! committed in order: a transaction whose reads still match the committed state is committed,
+//! otherwise it is re-executed in the next round together with the later transactions whose
+//! reads are invalidated by it. The first uncommitted transaction always reads the committed
+//! state, thus every round commits at least one transaction and the results are exactly the same
+//! as the sequential [`BlockExecutor`](crate::block::BlockExecutor).
+//!
+//! The beneficiary rewards are accumulated during the execution and paid in order on commit,
+//! otherwise all the transactions would conflict on the beneficiary balance.
+
+use std::{collections::BTreeMap, mem, sync::Arc};
+
+use dora_primitives::{
+    AccountInfo, Address, B256, Bytecode, CfgEnv, DatabaseCommit, DatabaseRef, Env, EvmState,
+    HashMap, Journal, JournalOutput, JournalTr, TxEnv, U256,
+};
+use parking_lot::{RwLock, RwLockReadGuard};
+use rayon::prelude::*;
+
+use crate::{
+    block::{
+        Block, BlockError, BlockOutput, SystemCall, apply_balance_increments, balance_increments,
+        merge_state, system_call_state,
+    },
+    context::VMContext,
+    db::Database,
+    executor::RUNTIME_STACK_SIZE,
+    handler::Handler,
+    result::{ResultAndState, VMError},
+    system::{self, MAINNET_DEPOSIT_CONTRACT_ADDRESS},
+    vm::VM,
+};
+
+/// Function type which creates the handler of the VM on each worker thread.
+pub type HandlerFactory<DB> = Arc<dyn Fn() -> Handler<VersionedDB<DB>> + Send + Sync>;
+
+/// A state location read or written by the transactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MemoryKey {
+    /// The balance, nonce and code of the account.
+    Basic(Address),
+    /// The storage slot of the account.
+    Storage(Address, U256),
+}
+
+/// The value of a [`MemoryKey`].
+#[derive(Debug, Clone)]
+enum MemoryValue {
+    Basic(Option<AccountInfo>),
+    Storage(U256),
+}
+
+impl PartialEq for MemoryValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            // The code is compared by its hash, it may be loaded lazily by the database.
+            (Self::Basic(Some(a)), Self::Basic(Some(b))) => {
+                a.balance == b.balance && a.nonce == b.nonce && a.code_hash == b.code_hash
+            }
+            (Self::Basic(None), Self::Basic(None)) => true,
+            (Self::Storage(a), Self::Storage(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The multi-version memory, which keeps the writes of the uncommitted transactions by their
+/// indices in the block.
+#[derive(Debug, Default)]
+struct MvMemory {
+    data: HashMap<MemoryKey, BTreeMap<usize, MemoryValue>>,
+    /// The codes of the created contracts, which are immutable by their hashes.
+    codes: HashMap<B256, Bytecode>,
+}
+
+impl MvMemory {
+    /// Returns the value written by the closest transaction before the index.
+    fn read(&self, key: &MemoryKey, index: usize) -> Option<&MemoryValue> {
+        self.data
+            .get(key)?
+            .range(..index)
+            .next_back()
+            .map(|(_, value)| value)
+    }
+
+    fn insert(&mut self, index: usize, execution: &Execution) {
+        for (key, value) in &execution.writes {
+            self.data
+                .entry(*key)
+                .or_default()
+                .insert(index, value.clone());
+        }
+        for (hash, code) in &execution.codes {
+            self.codes.insert(*hash, code.clone());
+        }
+    }
+
+    fn remove(&mut self, index: usize, execution: &Execution) {
+        for (key, _) in &execution.writes {
+            if let Some(versions) = self.data.get_mut(key) {
+                versions.remove(&index);
+                if versions.is_empty() {
+                    self.data.remove(key);
+                }
+            }
+        }
+    }
+}
+
+/// The state shared by the worker threads.
+struct SharedState<DB> {
+    /// The database with the committed state.
+    db: RwLock<DB>,
+    memory: RwLock<MvMemory>,
+}
+
+/// The database of a transaction executed by the [`ParallelBlockExecutor`], which reads the
+/// writes of the earlier uncommitted transactions and records all the reads for the validation.
+pub struct VersionedDB<DB> {
+    shared: Arc<SharedState<DB>>,
+    /// The index of the transaction in the block.
+    index: usize,
+    reads: HashMap<MemoryKey, MemoryValue>,
+}
+
+impl<DB: DatabaseRef> VersionedDB<DB> {
+    fn new(shared: Arc<SharedState<DB>>, index: usize) -> Self {
+        Self {
+            shared,
+            index,
+            reads: Default::default(),
+        }
+    }
+
+    /// Returns the index of the transaction in the block.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    fn read(&mut self, key: MemoryKey) -> Result<MemoryValue, DB::Error> {
+        let value = self.shared.memory.read().read(&key, self.index).cloned();
+        let value = match value {
+            Some(value) => value,
+            None => read_db(&self.shared.db.read(), &key)?,
+        };
+        // The journal caches the loaded state, thus only the first read matters.
+        self.reads.entry(key).or_insert_with(|| value.clone());
+        Ok(value)
+    }
+}
+
+fn read_db<DB: DatabaseRef>(db: &DB, key: &MemoryKey) -> Result<MemoryValue, DB::Error> {
+    Ok(match *key {
+        MemoryKey::Basic(address) => MemoryValue::Basic(db.basic_ref(address)?),
+        MemoryKey::Storage(address, index) => MemoryValue::Storage(db.storage_ref(address, index)?),
+    })
+}
+
+impl<DB: DatabaseRef> Database for VersionedDB<DB> {
+    type Error = DB::Error;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        match self.read(MemoryKey::Basic(address))? {
+            MemoryValue::Basic(info) => Ok(info),
+            MemoryValue::Storage(_) => unreachable!("basic key holds the account info"),
+        }
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        if let Some(code) = self.shared.memory.read().codes.get(&code_hash) {
+            return Ok(code.clone());
+        }
+        self.shared.db.read().code_by_hash_ref(code_hash)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        match self.read(MemoryKey::Storage(address, index))? {
+            MemoryValue::Storage(value) => Ok(value),
+            MemoryValue::Basic(_) => unreachable!("storage key holds the slot value"),
+        }
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.shared.db.read().block_hash_ref(number)
+    }
+}
+
+/// The speculative execution of a transaction.
+struct Execution {
+    result: Result<ResultAndState, VMError>,
+    /// The deferred beneficiary reward.
+    reward: U256,
+    reads: HashMap<MemoryKey, MemoryValue>,
+    writes: Vec<(MemoryKey, MemoryValue)>,
+    codes: Vec<(B256, Bytecode)>,
+}
+
+impl Execution {
+    fn new(
+        result: Result<ResultAndState, VMError>,
+        reward: U256,
+        reads: HashMap<MemoryKey, MemoryValue>,
+    ) -> Self {
+        let mut writes = Vec::new();
+        let mut codes = Vec::new();
+        if let Ok(ResultAndState { state, .. }) = &result {
+            for (address, account) in state {
+                if !account.is_touched() {
+                    continue;
+                }
+                let info = if account.is_selfdestructed() {
+                    AccountInfo::default()
+                } else {
+                    account.info.clone()
+                };
+                if let Some(code) = &info.code {
+                    codes.push((info.code_hash, code.clone()));
+                }
+                writes.push((MemoryKey::Basic(*address), MemoryValue::Basic(Some(info))));
+                for (index, slot) in &account.storage {
+                    if slot.is_changed() {
+                        writes.push((
+                            MemoryKey::Storage(*address, *index),
+                            MemoryValue::Storage(slot.present_value),
+                        ));
+                    }
+                }
+            }
+        }
+        Self {
+            result,
+            reward,
+            reads,
+            writes,
+            codes,
+        }
+    }
+}
+
+/// Executes the transactions of the blocks in parallel and commits their state changes into the
+/// database, the outputs are the same as the ones of the sequential
+/// [`BlockExecutor`](crate::block::BlockExecutor).
+///
+/// # Example
+///
+/// ```no_check
+/// let mut executor = ParallelBlockExecutor::new(db, cfg, compile_handler, 8)?;
+/// let output = executor.execute_block(block)?;
+/// let db = executor.into_db();
+/// ```
+pub struct ParallelBlockExecutor<DB> {
+    shared: Arc<SharedState<DB>>,
+    cfg: CfgEnv,
+    handler: HandlerFactory<DB>,
+    pool: rayon::ThreadPool,
+    /// The deposit contract whose logs are parsed as the EIP-6110 deposit requests.
+    deposit_contract: Address,
+}
+
+impl<DB> ParallelBlockExecutor<DB>
+where
+    DB: DatabaseRef + DatabaseCommit + Send + Sync + 'static,
+{
+    /// Creates a new parallel block executor with the number of worker threads, the handler of
+    /// each VM is created by the factory on the worker thread.
+    pub fn new(
+        db: DB,
+        cfg: CfgEnv,
+        handler: impl Fn() -> Handler<VersionedDB<DB>> + Send + Sync + 'static,
+        workers: usize,
+    ) -> anyhow::Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(workers.max(1))
+            .thread_name(|index| format!("dora-executor-{index}"))
+            // The native code needs a large stack for the deep calls.
+            .stack_size(RUNTIME_STACK_SIZE)
+            .build()?;
+        Ok(Self {
+            shared: Arc::new(SharedState {
+                db: RwLock::new(db),
+                memory: Default::default(),
+            }),
+            cfg,
+            handler: Arc::new(handler),
+            pool,
+            deposit_contract: MAINNET_DEPOSIT_CONTRACT_ADDRESS,
+        })
+    }
+
+    /// Sets the deposit contract of the chain, it is the mainnet deposit contract by default.
+    #[inline]
+    pub fn with_deposit_contract(mut self, deposit_contract: Address) -> Self {
+        self.deposit_contract = deposit_contract;
+        self
+    }
+
+    /// Returns the database with the committed state.
+    #[inline]
+    pub fn db(&self) -> RwLockReadGuard<'_, DB> {
+        self.shared.db.read()
+    }
+
+    /// Returns the database with the committed state.
+    pub fn into_db(self) -> DB {
+        match Arc::try_unwrap(self.shared) {
+            Ok(shared) => shared.db.into_inner(),
+            Err(_) => unreachable!("the versioned databases are dropped after the execution"),
+        }
+    }
+
+    /// Executes the block, the state changes of the system calls and the transactions are
+    /// committed into the database in order, thus the database is partially updated when an
+    /// error is returned.
+    pub fn execute_block(&mut self, block: Block) -> Result<BlockOutput, BlockError> {
+        let system_calls = system::pre_block_calls(
+            &mut VersionedDB::new(self.shared.clone(), 0),
+            self.cfg.spec,
+            &block,
+        )?;
+        let increments = balance_increments(self.cfg.spec, &block);
+        let env = Env {
+            block: block.env,
+            tx: TxEnv::default(),
+            cfg: self.cfg.clone(),
+        };
+        let mut output = BlockOutput::default();
+        // The system calls usually touch the same system contracts, they run sequentially
+        // before the transactions.
+        for SystemCall { address, data } in system_calls.into_iter().chain(block.system_calls) {
+            let mut vm = VM::new(self.context(env.clone(), 0));
+            let state = system_call_state(address, vm.system_call(address, data))?;
+            self.commit(&mut output.state, state);
+        }
+
+        let transactions = block.transactions;
+        let mut executions: Vec<Option<Execution>> = transactions.iter().map(|_| None).collect();
+        output.receipts.reserve(transactions.len());
+        output.results.reserve(transactions.len());
+        // The index of the first uncommitted transaction.
+        let mut next = 0;
+        while next < transactions.len() {
+            let pending = (next..transactions.len())
+                .filter(|index| executions[*index].is_none())
+                .collect::<Vec<_>>();
+            let results = self.pool.install(|| {
+                pending
+                    .par_iter()
+                    .map(|&index| (index, self.execute(&env, &transactions[index], index)))
+                    .collect::<Vec<_>>()
+            });
+            {
+                let mut memory = self.shared.memory.write();
+                for (index, execution) in results {
+                    memory.insert(index, &execution);
+                    executions[index] = Some(execution);
+                }
+            }
+
+            while let Some(execution) = executions.get(next).and_then(Option::as_ref) {
+                let valid = self
+                    .validate(&self.shared.memory.read(), next, execution)
+                    .map_err(|error| BlockError::Transaction { index: next, error })?;
+                if !valid {
+                    break;
+                }
+                let execution = executions[next].take().expect("the execution exists");
+                self.shared.memory.write().remove(next, &execution);
+                let tx = &transactions[next];
+                let available = env.block.gas_limit - output.gas_used;
+                if tx.gas_limit > available {
+                    return Err(BlockError::GasLimitExceeded {
+                        index: next,
+                        gas_limit: tx.gas_limit,
+                        available,
+                    });
+                }
+                let ResultAndState { result, state } = execution
+                    .result
+                    .map_err(|error| BlockError::Transaction { index: next, error })?;
+                self.commit(&mut output.state, state);
+                self.pay_reward(&env, next, execution.reward, &mut output.state)?;
+                output.push_result(tx.tx_type, result);
+                next += 1;
+            }
+            if next < transactions.len() {
+                self.invalidate(&mut executions, next)
+                    .map_err(|error| BlockError::Transaction { index: next, error })?;
+            }
+        }
+        let mut vm = VM::new(self.context(env, 0));
+        let state = apply_balance_increments(&mut vm, &increments)?;
+        self.commit(&mut output.state, state);
+        if let Some((requests, states)) =
+            system::post_block_requests(&mut vm, self.deposit_contract, &output.receipts)?
+        {
+            for state in states {
+                self.commit(&mut output.state, state);
+            }
+            output.requests = Some(requests);
+        }
+        Ok(output)
+    }
+
+    /// Creates the VM context of the transaction at the index.
+    fn context(&self, env: Env, index: usize) -> VMContext<VersionedDB<DB>> {
+        VMContext::new(
+            VersionedDB::new(self.shared.clone(), index),
+            env,
+            (self.handler)(),
+        )
+    }
+
+    /// Executes the transaction at the index speculatively.
+    fn execute(&self, env: &Env, tx: &TxEnv, index: usize) -> Execution {
+        let mut env = env.clone();
+        env.tx = tx.clone();
+        let mut context = self.context(env, index);
+        context.deferred_reward = Some(U256::ZERO);
+        let mut vm = VM::new(context);
+        let result = vm.transact();
+        let reward = vm.context.deferred_reward.unwrap_or_default();
+        let reads = mem::take(&mut vm.context.journal.database.reads);
+        Execution::new(result, reward, reads)
+    }
+
+    /// Returns `true` if the reads of the transaction at the index match the current state
+    /// seen by it.
+    fn validate(
+        &self,
+        memory: &MvMemory,
+        index: usize,
+        execution: &Execution,
+    ) -> Result<bool, VMError> {
+        let db = self.shared.db.read();
+        for (key, value) in &execution.reads {
+            let current = match memory.read(key, index) {
+                Some(current) => current.clone(),
+                None => read_db(&*db, key).map_err(VMError::database)?,
+            };
+            if current != *value {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Discards the execution of the invalid transaction and the later executions whose reads
+    /// are invalidated, they are re-executed in the next round.
+    fn invalidate(
+        &self,
+        executions: &mut [Option<Execution>],
+        invalid: usize,
+    ) -> Result<(), VMError> {
+        let mut memory = self.shared.memory.write();
+        for (index, slot) in executions.iter_mut().enumerate().skip(invalid) {
+            let valid = match slot {
+                Some(execution) if index > invalid => self.validate(&memory, index, execution)?,
+                _ => false,
+            };
+            if !valid {
+                if let Some(execution) = slot.take() {
+                    memory.remove(index, &execution);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Pays the deferred reward of the committed transaction at the index to the beneficiary.
+    fn pay_reward(
+        &self,
+        env: &Env,
+        index: usize,
+        reward: U256,
+        block_state: &mut EvmState,
+    ) -> Result<(), BlockError> {
+        let mut journal = Journal::new(VersionedDB::new(self.shared.clone(), index));
+        journal.set_spec_id(self.cfg.spec);
+        let beneficiary =
+            journal
+                .load_account(env.block.beneficiary)
+                .map_err(|err| BlockError::Transaction {
+                    index,
+                    error: VMError::database(err),
+                })?;
+        beneficiary.data.mark_touch();
+        beneficiary.data.info.balance = beneficiary.data.info.balance.saturating_add(reward);
+        let JournalOutput { state, .. } = journal.finalize();
+        self.commit(block_state, state);
+        Ok(())
+    }
+
+    /// Commits the state changes into the database and merges them into the block state.
+    fn commit(&self, block_state: &mut EvmState, state: EvmState) {
+        self.shared.db.write().commit(state.clone());
+        merge_state(block_state, state);
+    }
+}
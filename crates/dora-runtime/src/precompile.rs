@@ -0,0 +1,349 @@
+//! The precompiles available for a VM context, i.e., the builtin precompiles of the spec and the
+//! custom ones registered by the chain, the custom precompiles take precedence over the builtin
+//! ones at the same address.
+
+use std::sync::Arc;
+
+use crate::{
+    ExitStatusCode,
+    call::{CallKind, CallMessage, CallResult},
+    gas::GasSchedule,
+    host::{Host, HostError, SStoreResult},
+    inspector::StepState,
+    result::VMError,
+};
+use dora_primitives::{
+    AccountLoad, Address, B256, Bytes, Env, HashMap, Log, PrecompileError, PrecompileOutput,
+    PrecompileResult, PrecompileSpecId, Precompiles, SelfDestructResult, SpecId, StateLoad, U256,
+    address,
+};
+use num_bigint::BigUint;
+
+/// The address of the MODEXP precompile.
+pub const MODEXP_ADDRESS: Address = address!("0x0000000000000000000000000000000000000005");
+/// EIP-7823: The upper bound of the base, exponent and modulus lengths of MODEXP.
+pub const MODEXP_MAX_INPUT_LEN: usize = 1024;
+/// EIP-7883: The minimum gas cost of MODEXP.
+const MODEXP_MIN_GAS: u64 = 500;
+
+/// Function type of a stateless precompile, which is called with the input and gas limit.
+pub type PrecompileHandle = Arc<dyn Fn(&[u8], u64) -> PrecompileResult>;
+
+/// Function type of a stateful precompile, which can read and write the state through the host.
+pub type StatefulPrecompileHandle =
+    Arc<dyn Fn(&PrecompileInput<'_>, &mut dyn Host) -> PrecompileResult>;
+
+/// The call input of a stateful precompile.
+#[derive(Debug, Clone)]
+pub struct PrecompileInput<'a> {
+    /// The call data.
+    pub data: &'a Bytes,
+    /// The gas limit of the call.
+    pub gas_limit: u64,
+    /// The caller of the precompile.
+    pub caller: Address,
+    /// The address whose storage is accessed, which differs from the precompile address for
+    /// the `DELEGATECALL` and `CALLCODE` calls.
+    pub target_address: Address,
+    /// The call value.
+    pub value: U256,
+    /// Whether the call is static, the state changes through the host fail the call with the
+    /// static state change error.
+    pub is_static: bool,
+}
+
+/// A custom precompile registered on the [`VMContext`](crate::context::VMContext).
+#[derive(Clone)]
+pub enum ContextPrecompile {
+    /// The precompile which only depends on its input.
+    Stateless(PrecompileHandle),
+    /// The precompile which accesses the state through the host, it can be static called as
+    /// long as it only reads the state, a state change fails the call with the static state
+    /// change error.
+    Stateful(StatefulPrecompileHandle),
+}
+
+impl ContextPrecompile {
+    /// Creates a stateless precompile.
+    #[inline]
+    pub fn stateless(f: impl Fn(&[u8], u64) -> PrecompileResult + 'static) -> Self {
+        Self::Stateless(Arc::new(f))
+    }
+
+    /// Creates a stateful precompile.
+    #[inline]
+    pub fn stateful(
+        f: impl Fn(&PrecompileInput<'_>, &mut dyn Host) -> PrecompileResult + 'static,
+    ) -> Self {
+        Self::Stateful(Arc::new(f))
+    }
+}
+
+impl std::fmt::Debug for ContextPrecompile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stateless(_) => f.write_str("Stateless"),
+            Self::Stateful(_) => f.write_str("Stateful"),
+        }
+    }
+}
+
+/// The host of a stateful precompile in a static call, which forwards the reads to the inner
+/// host and refuses the state changes.
+pub(crate) struct StaticHost<'a> {
+    host: &'a mut dyn Host,
+    state_changed: bool,
+}
+
+impl<'a> StaticHost<'a> {
+    #[inline]
+    pub(crate) fn new(host: &'a mut dyn Host) -> Self {
+        Self {
+            host,
+            state_changed: false,
+        }
+    }
+
+    /// Returns `true` if the precompile tried to change the state.
+    #[inline]
+    pub(crate) fn state_changed(&self) -> bool {
+        self.state_changed
+    }
+}
+
+impl Host for StaticHost<'_> {
+    #[inline]
+    fn env(&self) -> &Env {
+        self.host.env()
+    }
+
+    #[inline]
+    fn env_mut(&mut self) -> &mut Env {
+        self.host.env_mut()
+    }
+
+    #[inline]
+    fn gas_schedule(&self) -> GasSchedule {
+        self.host.gas_schedule()
+    }
+
+    #[inline]
+    fn sload(&mut self, addr: Address, key: U256) -> Result<StateLoad<U256>, HostError> {
+        self.host.sload(addr, key)
+    }
+
+    fn sstore(
+        &mut self,
+        _addr: Address,
+        _key: U256,
+        _value: U256,
+    ) -> Result<StateLoad<SStoreResult>, HostError> {
+        self.state_changed = true;
+        Err(HostError::StateChangeDuringStaticCall)
+    }
+
+    #[inline]
+    fn tload(&mut self, addr: Address, key: U256) -> U256 {
+        self.host.tload(addr, key)
+    }
+
+    fn tstore(&mut self, _addr: Address, _key: U256, _value: U256) {
+        self.state_changed = true;
+    }
+
+    #[inline]
+    fn load_account_delegated(
+        &mut self,
+        addr: Address,
+    ) -> Result<StateLoad<AccountLoad>, HostError> {
+        self.host.load_account_delegated(addr)
+    }
+
+    #[inline]
+    fn balance(&mut self, addr: Address) -> Result<StateLoad<U256>, HostError> {
+        self.host.balance(addr)
+    }
+
+    #[inline]
+    fn code(&mut self, addr: Address) -> Result<StateLoad<Bytes>, HostError> {
+        self.host.code(addr)
+    }
+
+    #[inline]
+    fn code_hash(&mut self, addr: Address) -> Result<StateLoad<B256>, HostError> {
+        self.host.code_hash(addr)
+    }
+
+    fn selfdestruct(
+        &mut self,
+        _addr: Address,
+        _target: Address,
+    ) -> Result<StateLoad<SelfDestructResult>, HostError> {
+        self.state_changed = true;
+        Err(HostError::StateChangeDuringStaticCall)
+    }
+
+    #[inline]
+    fn block_hash(&mut self, number: u64) -> Result<B256, HostError> {
+        self.host.block_hash(number)
+    }
+
+    fn log(&mut self, _log: Log) {
+        self.state_changed = true;
+    }
+
+    /// The sub calls are static, the creates and the value transfers are refused.
+    fn call(&mut self, mut msg: CallMessage) -> Result<CallResult, VMError> {
+        if msg.kind.is_create() || (matches!(msg.kind, CallKind::Call) && !msg.value.is_zero()) {
+            self.state_changed = true;
+            return Ok(CallResult::new_with_gas_limit_and_status(
+                msg.gas_limit,
+                ExitStatusCode::StateChangeDuringStaticCall,
+            ));
+        }
+        msg.is_static = true;
+        self.host.call(msg)
+    }
+
+    #[inline]
+    fn step(&mut self, step: &StepState<'_>) {
+        self.host.step(step)
+    }
+
+    #[inline]
+    fn step_end(&mut self, step: &StepState<'_>) {
+        self.host.step_end(step)
+    }
+}
+
+/// The precompiles of a VM context.
+#[derive(Debug, Clone)]
+pub struct ContextPrecompiles {
+    /// The builtin precompiles of the spec.
+    builtin: &'static Precompiles,
+    /// The custom precompiles, which override the builtin ones at the same address.
+    custom: HashMap<Address, ContextPrecompile>,
+}
+
+impl ContextPrecompiles {
+    /// Creates the precompiles with the builtin ones of the spec.
+    #[inline]
+    pub fn new(builtin: &'static Precompiles) -> Self {
+        Self {
+            builtin,
+            custom: Default::default(),
+        }
+    }
+
+    /// Creates the precompiles of the spec, the MODEXP precompile is replaced by
+    /// [`osaka_modexp`] since Osaka as the builtin one doesn't follow the Osaka rules.
+    pub fn from_spec_id(spec_id: SpecId) -> Self {
+        let mut precompiles = Self::new(Precompiles::new(PrecompileSpecId::from_spec_id(spec_id)));
+        if spec_id.is_enabled_in(SpecId::OSAKA) {
+            precompiles.insert(MODEXP_ADDRESS, ContextPrecompile::stateless(osaka_modexp));
+        }
+        precompiles
+    }
+
+    /// Returns the builtin precompiles of the spec.
+    #[inline]
+    pub fn builtin(&self) -> &'static Precompiles {
+        self.builtin
+    }
+
+    /// Registers the custom precompile at the address, returns the previous custom one.
+    #[inline]
+    pub fn insert(
+        &mut self,
+        address: Address,
+        precompile: ContextPrecompile,
+    ) -> Option<ContextPrecompile> {
+        self.custom.insert(address, precompile)
+    }
+
+    /// Removes the custom precompile at the address, the builtin one, if any, is not removed.
+    #[inline]
+    pub fn remove(&mut self, address: &Address) -> Option<ContextPrecompile> {
+        self.custom.remove(address)
+    }
+
+    /// Returns `true` if there is a builtin or custom precompile at the address.
+    #[inline]
+    pub fn contains(&self, address: &Address) -> bool {
+        self.custom.contains_key(address) || self.builtin.contains(address)
+    }
+
+    /// Returns the custom precompile at the address.
+    #[inline]
+    pub fn get_custom(&self, address: &Address) -> Option<&ContextPrecompile> {
+        self.custom.get(address)
+    }
+
+    /// Returns the addresses of all the builtin and custom precompiles.
+    #[inline]
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> {
+        self.builtin
+            .addresses()
+            .filter(|address| !self.custom.contains_key(*address))
+            .chain(self.custom.keys())
+    }
+}
+
+/// The MODEXP precompile since Osaka, i.e., the EIP-198 precompile with the input bounds of
+/// EIP-7823 and the gas cost of EIP-7883.
+pub fn osaka_modexp(input: &[u8], gas_limit: u64) -> PrecompileResult {
+    let length = |offset: usize| U256::from_be_slice(&right_padded(input, offset, 32));
+    let (base_len, exp_len, mod_len) = (length(0), length(32), length(64));
+    let max_len = U256::from(MODEXP_MAX_INPUT_LEN);
+    if base_len > max_len || exp_len > max_len || mod_len > max_len {
+        return Err(PrecompileError::Other(
+            "MODEXP input length exceeds the EIP-7823 bound".to_string(),
+        ));
+    }
+    let (base_len, exp_len, mod_len): (usize, usize, usize) =
+        (base_len.to(), exp_len.to(), mod_len.to());
+    let data = input.get(96..).unwrap_or_default();
+    // The first 32 bytes of the exponent.
+    let exp_head = U256::from_be_slice(&right_padded(data, base_len, exp_len.min(32)));
+    let gas_used = osaka_modexp_gas(base_len, exp_len, mod_len, exp_head);
+    if gas_used > gas_limit {
+        return Err(PrecompileError::OutOfGas);
+    }
+    let base = BigUint::from_bytes_be(&right_padded(data, 0, base_len));
+    let exponent = BigUint::from_bytes_be(&right_padded(data, base_len, exp_len));
+    let modulus = BigUint::from_bytes_be(&right_padded(data, base_len + exp_len, mod_len));
+    // The result is zero if the modulus is zero.
+    let mut output = vec![0; mod_len];
+    if modulus != BigUint::ZERO {
+        let result = base.modpow(&exponent, &modulus).to_bytes_be();
+        output[mod_len - result.len()..].copy_from_slice(&result);
+    }
+    Ok(PrecompileOutput::new(gas_used, output.into()))
+}
+
+/// EIP-7883: Returns the gas cost of MODEXP since Osaka.
+fn osaka_modexp_gas(base_len: usize, exp_len: usize, mod_len: usize, exp_head: U256) -> u64 {
+    let max_len = base_len.max(mod_len) as u64;
+    let complexity = if max_len <= 32 {
+        16
+    } else {
+        2 * max_len.div_ceil(8).pow(2)
+    };
+    let head_bits = exp_head.bit_len().saturating_sub(1) as u64;
+    let iterations = if exp_len <= 32 {
+        head_bits
+    } else {
+        16 * (exp_len as u64 - 32) + head_bits
+    };
+    (complexity * iterations.max(1)).max(MODEXP_MIN_GAS)
+}
+
+/// Returns the `len` bytes of the data at the offset, which are right padded with zeros.
+fn right_padded(data: &[u8], offset: usize, len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    if offset < data.len() {
+        let end = data.len().min(offset + len);
+        bytes[..end - offset].copy_from_slice(&data[offset..end]);
+    }
+    bytes
+}
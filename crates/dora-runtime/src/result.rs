@@ -9,6 +9,7 @@ use std::{fmt::Debug, string::String};
 /// This enum covers various error categories:
 /// - `Transaction`: Errors related to transaction validation.
 /// - `Header`: Errors related to block header validation.
+/// - `TxGasLimitGreaterThanCap`: The transaction gas limit exceeds the EIP-7825 cap.
 /// - `Database`: Errors related to database operations.
 /// - `Custom`: A custom error message.
 /// - `Precompile`: Errors occurring within a precompiled contract.
@@ -16,6 +17,7 @@ use std::{fmt::Debug, string::String};
 pub enum VMError {
     Transaction(InvalidTransaction),
     Header(InvalidHeader),
+    TxGasLimitGreaterThanCap { gas_limit: u64, cap: u64 },
     Database(DatabaseError),
     Compile(String),
     Precompile(String),
@@ -27,6 +29,11 @@ impl fmt::Display for VMError {
         match self {
             Self::Transaction(e) => write!(f, "transaction validation error: {}", e),
             Self::Header(e) => write!(f, "header validation error: {}", e),
+            Self::TxGasLimitGreaterThanCap { gas_limit, cap } => write!(
+                f,
+                "transaction validation error: gas limit {} exceeds the cap {}",
+                gas_limit, cap
+            ),
             Self::Database(e) => write!(f, "database error: {}", e),
             Self::Handler(e) => write!(f, "handler error: {}", e),
             Self::Compile(e) => write!(f, "compile error: {}", e),
@@ -47,6 +54,14 @@ impl From<InvalidHeader> for VMError {
     }
 }
 
+impl VMError {
+    /// Creates a database error with the message of the original error.
+    #[inline]
+    pub fn database(error: impl fmt::Display) -> Self {
+        Self::Database(DatabaseError::new(error))
+    }
+}
+
 impl From<DatabaseError> for VMError {
     fn from(value: DatabaseError) -> Self {
         VMError::Database(value)
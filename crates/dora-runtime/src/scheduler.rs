@@ -0,0 +1,362 @@
+//! A frame scheduler which drives the call frames in a loop instead of recursion.
+//!
+//! The EVM code compiled with the suspend mode returns [`ExitStatusCode::Suspend`] on the call and
+//! create operations, with the sub call message saved in the runtime context instead of calling it
+//! on the host. The scheduler keeps the memory, stack and gas of the suspended frame, pushes the sub
+//! call frame and runs it, and then resumes the suspended frame at its resume index with the sub
+//! call result. Thus the native stack usage does not grow with the call depth.
+
+use crate::{
+    ExitStatusCode, SymbolArtifact,
+    call::{CallMessage, CallResult},
+    context::{Contract, InnerContext, PendingCallKind, RuntimeContext, VMContext},
+    db::Database,
+    handler::{CallStart, Frame, FrameReturn},
+    host::HostError,
+    result::VMError,
+    stack::Stack,
+};
+use dora_primitives::Bytes32;
+use std::mem;
+
+/// A call frame driven by the [`FrameScheduler`], which keeps its execution state between the
+/// suspensions.
+pub struct SuspendedFrame {
+    artifact: SymbolArtifact,
+    inner: InnerContext,
+    contract: Contract,
+    stack: Box<Stack>,
+    stack_size: u64,
+    gas_limit: u64,
+    gas_remaining: u64,
+    /// The sub call which the frame is waiting for.
+    pending: Option<PendingCallKind>,
+    frame_return: FrameReturn,
+}
+
+/// The status of a [`SuspendedFrame`] after it is executed.
+enum FrameStatus {
+    /// The frame is suspended with the sub call message, `None` means the sub call is already
+    /// done by the runtime and the frame can be resumed immediately.
+    Suspended(Option<CallMessage>),
+    /// The frame is returned with the result.
+    Returned(CallResult),
+}
+
+impl SuspendedFrame {
+    fn new<DB: Database>(
+        artifact: SymbolArtifact,
+        frame: Frame,
+        frame_return: FrameReturn,
+        ctx: &mut VMContext<DB>,
+    ) -> Self {
+        let spec_id = ctx.spec_id();
+        let gas_limit = frame.gas_limit;
+        let RuntimeContext {
+            mut inner,
+            contract,
+            ..
+        } = RuntimeContext::new(
+            frame.contract,
+            frame.depth,
+            frame.is_static,
+            frame.is_eof_init,
+            ctx,
+            spec_id,
+            gas_limit,
+        );
+        inner.suspend = true;
+        Self {
+            artifact,
+            inner,
+            contract,
+            stack: Box::default(),
+            stack_size: 0,
+            gas_limit,
+            gas_remaining: gas_limit,
+            pending: None,
+            frame_return,
+        }
+    }
+
+    /// Returns the contract of the frame.
+    #[inline]
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    /// Returns the depth of the frame in the call stack.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.inner.depth
+    }
+
+    /// Returns the memory of the frame.
+    #[inline]
+    pub fn memory(&self) -> &[u8] {
+        &self.inner.memory
+    }
+
+    /// Returns the stack items of the frame from the bottom to the top.
+    #[inline]
+    pub fn stack(&self) -> &[Bytes32] {
+        self.stack.as_slice(self.stack_size as usize)
+    }
+
+    /// Returns the gas limit of the frame.
+    #[inline]
+    pub fn gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Returns the remaining gas of the frame, the gas limit of the pending sub call is already
+    /// charged and the unused part is returned on resume.
+    #[inline]
+    pub fn gas_remaining(&self) -> u64 {
+        self.gas_remaining
+    }
+
+    /// Returns the resume index where the frame continues, `0` means the frame is not started.
+    #[inline]
+    pub fn resume_at(&self) -> u32 {
+        self.inner.resume_at
+    }
+
+    /// Executes the frame until it is suspended on a sub call or returned, the result of the
+    /// pending sub call is written back to the frame before it is resumed.
+    fn execute<DB: Database>(
+        &mut self,
+        ctx: &mut VMContext<DB>,
+        sub_call_result: Option<CallResult>,
+    ) -> Result<FrameStatus, VMError> {
+        let mut context = RuntimeContext {
+            inner: mem::take(&mut self.inner),
+            contract: mem::take(&mut self.contract),
+            host: ctx,
+        };
+        if let (Some(kind), Some(result)) = (self.pending.take(), sub_call_result) {
+            let (value, gas_returned) = context.resume_call(kind, result);
+            // Replace the placeholder value pushed by the suspended operation.
+            if let Some(top) = self.stack.as_mut_slice(self.stack_size as usize).last_mut() {
+                *top = value;
+            }
+            self.gas_remaining += gas_returned;
+        }
+        let status = self
+            .artifact
+            .execute_suspended(
+                &mut context,
+                &mut self.gas_remaining,
+                &mut self.stack,
+                &mut self.stack_size,
+            )
+            .map_err(|err| VMError::Handler(err.to_string()));
+        let status = match status {
+            Ok(status) if status == ExitStatusCode::Suspend.to_u8() => {
+                let msg = context.take_pending_call().map(|pending| {
+                    self.pending = Some(pending.kind);
+                    pending.msg
+                });
+                Ok(FrameStatus::Suspended(msg))
+            }
+            Ok(_) => {
+                // Flush the step end hook of the last instruction for the traced code.
+                context.trace_step_end(
+                    context.gas_remaining(),
+                    self.stack.as_slice(self.stack_size as usize),
+                );
+                Ok(FrameStatus::Returned(CallResult {
+                    status: context.status(),
+                    gas_limit: context.gas_limit(),
+                    gas_remaining: context.gas_remaining(),
+                    gas_refunded: context.gas_refunded(),
+                    output: context.return_bytes(),
+                    create_address: None,
+                }))
+            }
+            Err(err) => Err(err),
+        };
+        self.inner = context.inner;
+        self.contract = context.contract;
+        status
+    }
+}
+
+/// Drives the call frames of a call message in a loop.
+///
+/// The frames whose code is compiled with the suspend mode by the
+/// [`Handler::suspend_handler`](crate::handler::Handler::suspend_handler) are suspended on the
+/// sub calls and pushed onto the frame stack, the other frames, e.g., WASM code, precompiles and
+/// the traced code, are executed with the call frame handler as before.
+///
+/// # Example
+///
+/// ```no_check
+/// let mut scheduler = FrameScheduler::new();
+/// let mut result = scheduler.start(&mut ctx, msg)?;
+/// while result.is_none() {
+///     // Inspect the suspended frames before the next sub call is started.
+///     let frame = scheduler.frames().last().unwrap();
+///     assert!(frame.resume_at() > 0);
+///     assert!(scheduler.next_call().is_some());
+///     result = scheduler.step(&mut ctx)?;
+/// }
+/// ```
+#[derive(Default)]
+pub struct FrameScheduler {
+    frames: Vec<SuspendedFrame>,
+    /// The sub call message of the top frame waiting to be started.
+    next_call: Option<CallMessage>,
+}
+
+impl FrameScheduler {
+    /// Creates a new frame scheduler.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the suspended frames from the bottom to the top of the call stack.
+    #[inline]
+    pub fn frames(&self) -> &[SuspendedFrame] {
+        &self.frames
+    }
+
+    /// Returns the sub call message of the top frame, which is started on the next
+    /// [`FrameScheduler::step`].
+    #[inline]
+    pub fn next_call(&self) -> Option<&CallMessage> {
+        self.next_call.as_ref()
+    }
+
+    /// Runs the call message until all its frames are returned.
+    pub fn run<DB: Database>(
+        &mut self,
+        ctx: &mut VMContext<DB>,
+        msg: CallMessage,
+    ) -> Result<CallResult, VMError> {
+        let mut result = self.start(ctx, msg)?;
+        loop {
+            match result {
+                Some(result) => return Ok(result),
+                None => result = self.step(ctx)?,
+            }
+        }
+    }
+
+    /// Starts the call message and runs its frames until the first suspension, returns the
+    /// result when the call message is done.
+    pub fn start<DB: Database>(
+        &mut self,
+        ctx: &mut VMContext<DB>,
+        msg: CallMessage,
+    ) -> Result<Option<CallResult>, VMError> {
+        debug_assert!(self.frames.is_empty() && self.next_call.is_none());
+        self.next_call = Some(msg);
+        self.step(ctx)
+    }
+
+    /// Starts the sub call of the top frame and runs the frames until the next suspension,
+    /// returns the result when the call message is done.
+    pub fn step<DB: Database>(
+        &mut self,
+        ctx: &mut VMContext<DB>,
+    ) -> Result<Option<CallResult>, VMError> {
+        let mut result = match self.next_call.take() {
+            Some(msg) => self.start_frame(ctx, msg)?,
+            None => None,
+        };
+        while let Some(frame) = self.frames.last_mut() {
+            match frame.execute(ctx, result.take()) {
+                Ok(FrameStatus::Suspended(Some(msg))) => {
+                    self.next_call = Some(msg);
+                    return Ok(None);
+                }
+                Ok(FrameStatus::Suspended(None)) => {}
+                Ok(FrameStatus::Returned(frame_result)) => {
+                    let frame = self.frames.pop().expect("the returned frame exists");
+                    result = Some(ctx.call_end(frame.frame_return, frame_result));
+                }
+                Err(err) => {
+                    let frame = self.frames.pop().expect("the failed frame exists");
+                    result = self.fail_frame(ctx, frame.frame_return, frame.gas_limit, err)?;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Starts the frame of the call message and pushes it when its code can be suspended,
+    /// otherwise the frame is executed with the call frame handler and the result is returned.
+    ///
+    /// The errors of the sub calls are converted to the failed results in the same way as the
+    /// sub calls on the host.
+    fn start_frame<DB: Database>(
+        &mut self,
+        ctx: &mut VMContext<DB>,
+        msg: CallMessage,
+    ) -> Result<Option<CallResult>, VMError> {
+        let gas_limit = msg.gas_limit;
+        let (frame, frame_return) = match ctx.call_start(msg) {
+            Ok(CallStart::Done(result)) => return Ok(Some(result)),
+            Ok(CallStart::Frame(frame, frame_return)) => (frame, frame_return),
+            Err(err) if !self.frames.is_empty() => {
+                Self::record_error(ctx, err);
+                return Ok(Some(Self::failed_result(gas_limit)));
+            }
+            Err(err) => return Err(err),
+        };
+        let artifact = match ctx.handler.suspend_handler.clone() {
+            Some(suspend_handler) => suspend_handler(&frame, ctx),
+            None => Ok(None),
+        };
+        let result = match artifact {
+            Ok(Some(artifact)) => {
+                let frame = SuspendedFrame::new(artifact, frame, frame_return, ctx);
+                self.frames.push(frame);
+                return Ok(None);
+            }
+            Ok(None) => ctx.invoke_call_handler(frame),
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(result) => Ok(Some(ctx.call_end(frame_return, result))),
+            Err(err) => self.fail_frame(ctx, frame_return, gas_limit, err),
+        }
+    }
+
+    /// Finishes the started frame which fails with the error, the state changes of the frame are
+    /// reverted and all its gas is consumed. The error is returned for the outermost frame.
+    fn fail_frame<DB: Database>(
+        &self,
+        ctx: &mut VMContext<DB>,
+        frame_return: FrameReturn,
+        gas_limit: u64,
+        err: VMError,
+    ) -> Result<Option<CallResult>, VMError> {
+        let result = ctx.call_end(frame_return, Self::failed_result(gas_limit));
+        if self.frames.is_empty() {
+            return Err(err);
+        }
+        Self::record_error(ctx, err);
+        Ok(Some(result))
+    }
+
+    /// Returns the result of a sub call which fails with an error, which consumes all its gas.
+    fn failed_result(gas_limit: u64) -> CallResult {
+        let mut result = CallResult::new_with_gas_limit_and_status(
+            gas_limit,
+            ExitStatusCode::FatalExternalError,
+        );
+        result.gas_remaining = 0;
+        result
+    }
+
+    /// Same as the sub calls on the host, the database error is reported after the transaction.
+    fn record_error<DB: Database>(ctx: &mut VMContext<DB>, err: VMError) {
+        if let VMError::Database(error) = err {
+            ctx.host_error
+                .get_or_insert_with(|| HostError::Database(error));
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! The simulation of a call without persistent side effects, e.g., for `eth_call`, with the
+//! state and block overrides and the relaxed transaction checks.
+
+use dora_primitives::{AccountInfo, Address, B256, BlockEnv, Bytecode, Env, HashMap, U256};
+
+use crate::{
+    context::VMContext,
+    db::Database,
+    handler::Handler,
+    result::{ResultAndState, VMError},
+    vm::VM,
+};
+
+/// The storage override of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageOverride {
+    /// Replaces the whole storage, the slots which are not set are zero.
+    Replace(HashMap<U256, U256>),
+    /// Overrides the slots, the other slots are kept.
+    Diff(HashMap<U256, U256>),
+}
+
+/// The state override of an account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountOverride {
+    /// Overrides the balance.
+    pub balance: Option<U256>,
+    /// Overrides the nonce.
+    pub nonce: Option<u64>,
+    /// Overrides the code.
+    pub code: Option<Bytecode>,
+    /// Overrides the storage.
+    pub storage: Option<StorageOverride>,
+}
+
+/// The state overrides by the account addresses.
+pub type StateOverride = HashMap<Address, AccountOverride>;
+
+/// The overrides of the block environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockOverrides {
+    /// Overrides the block number.
+    pub number: Option<u64>,
+    /// Overrides the block timestamp.
+    pub timestamp: Option<u64>,
+    /// Overrides the block base fee.
+    pub basefee: Option<u64>,
+    /// Overrides the block beneficiary.
+    pub coinbase: Option<Address>,
+}
+
+impl BlockOverrides {
+    /// Applies the overrides to the block environment.
+    pub fn apply(&self, block: &mut BlockEnv) {
+        if let Some(number) = self.number {
+            block.number = number;
+        }
+        if let Some(timestamp) = self.timestamp {
+            block.timestamp = timestamp;
+        }
+        if let Some(basefee) = self.basefee {
+            block.basefee = basefee;
+        }
+        if let Some(coinbase) = self.coinbase {
+            block.beneficiary = coinbase;
+        }
+    }
+}
+
+/// The database which applies the state overrides on top of the inner database, the inner
+/// database is never written.
+#[derive(Debug, Clone)]
+pub struct OverrideDB<DB> {
+    db: DB,
+    accounts: StateOverride,
+    /// The overridden codes by their hashes.
+    codes: HashMap<B256, Bytecode>,
+}
+
+impl<DB> OverrideDB<DB> {
+    /// Creates a new database with the state overrides.
+    pub fn new(db: DB, accounts: StateOverride) -> Self {
+        let codes = accounts
+            .values()
+            .filter_map(|account| account.code.as_ref())
+            .map(|code| (code.hash_slow(), code.clone()))
+            .collect();
+        Self {
+            db,
+            accounts,
+            codes,
+        }
+    }
+
+    /// Returns the inner database.
+    #[inline]
+    pub fn into_inner(self) -> DB {
+        self.db
+    }
+}
+
+impl<DB: Database> Database for OverrideDB<DB> {
+    type Error = DB::Error;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        let info = self.db.basic(address)?;
+        let Some(account) = self.accounts.get(&address) else {
+            return Ok(info);
+        };
+        let mut info = info.unwrap_or_default();
+        if let Some(balance) = account.balance {
+            info.balance = balance;
+        }
+        if let Some(nonce) = account.nonce {
+            info.nonce = nonce;
+        }
+        if let Some(code) = &account.code {
+            info.code_hash = code.hash_slow();
+            info.code = Some(code.clone());
+        }
+        Ok(Some(info))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        match self.codes.get(&code_hash) {
+            Some(code) => Ok(code.clone()),
+            None => self.db.code_by_hash(code_hash),
+        }
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        match self
+            .accounts
+            .get(&address)
+            .and_then(|account| account.storage.as_ref())
+        {
+            Some(StorageOverride::Replace(slots)) => {
+                Ok(slots.get(&index).copied().unwrap_or_default())
+            }
+            Some(StorageOverride::Diff(slots)) if slots.contains_key(&index) => Ok(slots[&index]),
+            _ => self.db.storage(address, index),
+        }
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.db.block_hash(number)
+    }
+}
+
+/// A call simulation with the overrides, which runs the transaction of the environment and
+/// returns its result and state changes without committing them.
+///
+/// # Example
+///
+/// ```no_check
+/// let result = Simulation::new()
+///     .with_account_override(address, AccountOverride {
+///         balance: Some(U256::MAX),
+///         ..Default::default()
+///     })
+///     .with_block_overrides(BlockOverrides {
+///         number: Some(1),
+///         ..Default::default()
+///     })
+///     .disable_nonce_check()
+///     .run(&mut db, env, compile_handler())?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Simulation {
+    /// The state overrides.
+    pub state: StateOverride,
+    /// The block overrides.
+    pub block: BlockOverrides,
+    /// Skips the nonce check of the caller.
+    pub disable_nonce_check: bool,
+    /// Skips the balance check of the caller, the missing fee is added to its balance.
+    pub disable_balance_check: bool,
+    /// Skips the check of the gas price against the block base fee.
+    pub disable_base_fee: bool,
+}
+
+impl Simulation {
+    /// Creates a new simulation without overrides.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the state of the account.
+    #[inline]
+    pub fn with_account_override(mut self, address: Address, account: AccountOverride) -> Self {
+        self.state.insert(address, account);
+        self
+    }
+
+    /// Sets the block overrides.
+    #[inline]
+    pub fn with_block_overrides(mut self, block: BlockOverrides) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Skips the nonce check of the caller.
+    #[inline]
+    pub fn disable_nonce_check(mut self) -> Self {
+        self.disable_nonce_check = true;
+        self
+    }
+
+    /// Skips the balance check of the caller.
+    #[inline]
+    pub fn disable_balance_check(mut self) -> Self {
+        self.disable_balance_check = true;
+        self
+    }
+
+    /// Skips the base fee check.
+    #[inline]
+    pub fn disable_base_fee(mut self) -> Self {
+        self.disable_base_fee = true;
+        self
+    }
+
+    /// Runs the transaction of the environment with the overrides, pass the database by
+    /// mutable reference to keep it after the simulation.
+    pub fn run<DB: Database>(
+        self,
+        db: DB,
+        mut env: Env,
+        handler: Handler<OverrideDB<DB>>,
+    ) -> Result<ResultAndState, VMError> {
+        self.block.apply(&mut env.block);
+        env.cfg.disable_nonce_check |= self.disable_nonce_check;
+        env.cfg.disable_balance_check |= self.disable_balance_check;
+        env.cfg.disable_base_fee |= self.disable_base_fee;
+        let db = OverrideDB::new(db, self.state);
+        VM::new(VMContext::new(db, env, handler)).transact()
+    }
+}
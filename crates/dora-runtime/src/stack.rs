@@ -9,6 +9,18 @@ impl Stack {
     pub const fn new() -> Self {
         Self([Bytes32::ZERO; MAX_STACK_SIZE])
     }
+
+    /// Returns the first `len` stack items from the bottom to the top.
+    #[inline]
+    pub fn as_slice(&self, len: usize) -> &[Bytes32] {
+        &self.0[..len.min(MAX_STACK_SIZE)]
+    }
+
+    /// Returns the first `len` stack items from the bottom to the top as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self, len: usize) -> &mut [Bytes32] {
+        &mut self.0[..len.min(MAX_STACK_SIZE)]
+    }
 }
 
 impl Default for Stack {
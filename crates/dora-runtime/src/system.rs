@@ -0,0 +1,236 @@
+//! The system contracts called before and after the transactions of a block, i.e. the EIP-4788
+//! beacon roots and EIP-2935 block hash history updates before the block, and the EIP-7002
+//! withdrawal and EIP-7251 consolidation requests after the block. The requests and the EIP-6110
+//! deposit requests parsed from the deposit contract logs are returned as the EIP-7685 requests.
+
+use dora_primitives::{Address, B256, Bytes, EvmState, SpecId, U256, address, b256};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    block::{Block, BlockError, Receipt, SystemCall, system_call_state},
+    db::Database,
+    vm::VM,
+};
+
+/// The EIP-4788 beacon roots contract.
+pub const BEACON_ROOTS_ADDRESS: Address = address!("0x000f3df6d732807ef1319fb7b8bb8522d0beac02");
+/// The EIP-2935 block hash history contract.
+pub const HISTORY_STORAGE_ADDRESS: Address = address!("0x0000f90827f1c53a10cb7a02335b175320002935");
+/// The EIP-7002 withdrawal requests contract.
+pub const WITHDRAWAL_REQUEST_ADDRESS: Address =
+    address!("0x00000961ef480eb55e80d19ad83579a64c007002");
+/// The EIP-7251 consolidation requests contract.
+pub const CONSOLIDATION_REQUEST_ADDRESS: Address =
+    address!("0x0000bbddc7ce488642fb579f8b00f3a590007251");
+/// The deposit contract of the Ethereum mainnet, whose logs are parsed as the EIP-6110 deposit
+/// requests.
+pub const MAINNET_DEPOSIT_CONTRACT_ADDRESS: Address =
+    address!("0x00000000219ab540356cbb839cbe05303d7705fa");
+/// The topic of `DepositEvent(bytes,bytes,bytes,bytes,bytes)` emitted by the deposit contract.
+pub const DEPOSIT_EVENT_SIGNATURE: B256 =
+    b256!("0x649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5");
+
+/// The EIP-7685 request type of the EIP-6110 deposit requests.
+pub const DEPOSIT_REQUEST_TYPE: u8 = 0x00;
+/// The EIP-7685 request type of the EIP-7002 withdrawal requests.
+pub const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+/// The EIP-7685 request type of the EIP-7251 consolidation requests.
+pub const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
+
+/// The size of the ABI encoded data of a deposit event.
+const DEPOSIT_EVENT_DATA_SIZE: usize = 576;
+/// The offsets and sizes of the pubkey, withdrawal credentials, amount, signature and index
+/// fields in the deposit event data.
+const DEPOSIT_EVENT_FIELDS: [(usize, usize); 5] =
+    [(160, 48), (256, 32), (320, 8), (384, 96), (512, 8)];
+
+/// The EIP-7685 requests of a block, each request is the request type followed by the request
+/// data, and the requests without data are omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Requests(Vec<Bytes>);
+
+impl Requests {
+    /// Appends the request of the type, the request is omitted if the data is empty.
+    pub fn push_request(&mut self, request_type: u8, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        let mut request = Vec::with_capacity(data.len() + 1);
+        request.push(request_type);
+        request.extend_from_slice(data);
+        self.0.push(request.into());
+    }
+
+    /// Returns the requests in the order of their types.
+    #[inline]
+    pub fn requests(&self) -> &[Bytes] {
+        &self.0
+    }
+
+    /// Returns the requests in the order of their types.
+    #[inline]
+    pub fn into_requests(self) -> Vec<Bytes> {
+        self.0
+    }
+
+    /// Returns the `requests_hash` of the block header, i.e. the SHA-256 hash of the SHA-256
+    /// hashes of the requests.
+    pub fn requests_hash(&self) -> B256 {
+        let mut hasher = Sha256::new();
+        for request in &self.0 {
+            hasher.update(Sha256::digest(request));
+        }
+        B256::from_slice(&hasher.finalize())
+    }
+}
+
+/// Returns the system calls executed before the transactions of the block, i.e. the EIP-4788
+/// beacon root update since Cancun and the EIP-2935 block hash history update since Prague.
+///
+/// The calls are skipped for the genesis block and when the contracts are not deployed.
+pub fn pre_block_calls<DB: Database>(
+    db: &mut DB,
+    spec_id: SpecId,
+    block: &Block,
+) -> Result<Vec<SystemCall>, BlockError> {
+    let mut calls = Vec::new();
+    if block.env.number == 0 {
+        return Ok(calls);
+    }
+    if let Some(root) = block.parent_beacon_block_root {
+        if spec_id.is_enabled_in(SpecId::CANCUN) && has_code(db, BEACON_ROOTS_ADDRESS)? {
+            calls.push(SystemCall::new(
+                BEACON_ROOTS_ADDRESS,
+                Bytes::copy_from_slice(root.as_slice()),
+            ));
+        }
+    }
+    if let Some(parent_hash) = block.parent_hash {
+        if spec_id.is_enabled_in(SpecId::PRAGUE) && has_code(db, HISTORY_STORAGE_ADDRESS)? {
+            calls.push(SystemCall::new(
+                HISTORY_STORAGE_ADDRESS,
+                Bytes::copy_from_slice(parent_hash.as_slice()),
+            ));
+        }
+    }
+    Ok(calls)
+}
+
+/// Executes the EIP-7002 and EIP-7251 system calls after the transactions of the block and
+/// parses the EIP-6110 deposit requests from the logs of the receipts, returns the requests and
+/// the state changes of the calls, or `None` before Prague.
+///
+/// Unlike the pre-block calls, the block is invalid if the request contracts are not deployed.
+pub fn post_block_requests<DB: Database>(
+    vm: &mut VM<DB>,
+    deposit_contract: Address,
+    receipts: &[Receipt],
+) -> Result<Option<(Requests, Vec<EvmState>)>, BlockError> {
+    if !vm.spec_id().is_enabled_in(SpecId::PRAGUE) {
+        return Ok(None);
+    }
+    let mut requests = Requests::default();
+    requests.push_request(
+        DEPOSIT_REQUEST_TYPE,
+        &parse_deposit_requests(deposit_contract, receipts)?,
+    );
+    let mut states = Vec::with_capacity(2);
+    for (request_type, address) in [
+        (WITHDRAWAL_REQUEST_TYPE, WITHDRAWAL_REQUEST_ADDRESS),
+        (CONSOLIDATION_REQUEST_TYPE, CONSOLIDATION_REQUEST_ADDRESS),
+    ] {
+        if !has_code(&mut vm.journal.database, address)? {
+            return Err(BlockError::SystemCall {
+                address,
+                error: "the system contract is not deployed".to_string(),
+            });
+        }
+        let output = vm.system_call(address, Bytes::new());
+        let data = output
+            .as_ref()
+            .ok()
+            .and_then(|output| output.result.output().cloned())
+            .unwrap_or_default();
+        states.push(system_call_state(address, output)?);
+        requests.push_request(request_type, &data);
+    }
+    Ok(Some((requests, states)))
+}
+
+/// Returns the concatenated EIP-6110 deposit requests of the deposit events in the receipts.
+pub fn parse_deposit_requests(
+    deposit_contract: Address,
+    receipts: &[Receipt],
+) -> Result<Vec<u8>, BlockError> {
+    let mut requests = Vec::new();
+    for log in receipts.iter().flat_map(|receipt| &receipt.logs) {
+        if log.address != deposit_contract || log.topics().first() != Some(&DEPOSIT_EVENT_SIGNATURE)
+        {
+            continue;
+        }
+        let data = &log.data.data;
+        if data.len() != DEPOSIT_EVENT_DATA_SIZE {
+            return Err(BlockError::InvalidDepositEvent(format!(
+                "invalid data size {}",
+                data.len()
+            )));
+        }
+        let word = |offset: usize| U256::from_be_slice(&data[offset..offset + 32]);
+        for (i, (offset, size)) in DEPOSIT_EVENT_FIELDS.into_iter().enumerate() {
+            if word(i * 32) != U256::from(offset) {
+                return Err(BlockError::InvalidDepositEvent(format!(
+                    "invalid offset of the field {i}"
+                )));
+            }
+            if word(offset) != U256::from(size) {
+                return Err(BlockError::InvalidDepositEvent(format!(
+                    "invalid size of the field {i}"
+                )));
+            }
+            requests.extend_from_slice(&data[offset + 32..offset + 32 + size]);
+        }
+    }
+    Ok(requests)
+}
+
+/// Returns `true` if the contract has code.
+fn has_code<DB: Database>(db: &mut DB, address: Address) -> Result<bool, BlockError> {
+    let info = db.basic(address).map_err(|err| BlockError::SystemCall {
+        address,
+        error: err.to_string(),
+    })?;
+    Ok(info.is_some_and(|info| !info.is_empty_code_hash()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dora_primitives::keccak256;
+
+    #[test]
+    fn test_deposit_event_signature() {
+        assert_eq!(
+            keccak256("DepositEvent(bytes,bytes,bytes,bytes,bytes)"),
+            DEPOSIT_EVENT_SIGNATURE
+        );
+    }
+
+    #[test]
+    fn test_requests_hash() {
+        // The hash of the empty requests is the SHA-256 hash of the empty input.
+        assert_eq!(
+            Requests::default().requests_hash(),
+            b256!("0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        let mut requests = Requests::default();
+        requests.push_request(DEPOSIT_REQUEST_TYPE, &[]);
+        requests.push_request(WITHDRAWAL_REQUEST_TYPE, &[1, 2]);
+        assert_eq!(requests.requests(), &[Bytes::from_static(&[1, 1, 2])]);
+        let mut hasher = Sha256::new();
+        hasher.update(Sha256::digest([1, 1, 2]));
+        assert_eq!(
+            requests.requests_hash(),
+            B256::from_slice(&hasher.finalize())
+        );
+    }
+}
@@ -0,0 +1,740 @@
+//! The Merkle Patricia trie and the roots of the state, storages, receipts and logs.
+//!
+//! The [`Trie`] keeps the references of the unchanged nodes, thus the root is recomputed by only
+//! hashing the nodes on the paths of the changed keys. The [`StateTrie`] applies the state
+//! changes of the transactions or blocks and recomputes the storage roots of the changed accounts
+//! only, which makes it cheap to compute the state root after each block.
+
+use std::mem;
+
+use alloy_rlp::{EMPTY_STRING_CODE, Encodable, Header, RlpEncodable};
+use dora_primitives::{
+    Account, AccountInfo, AccountStatus, Address, B256, EvmState, HashMap, HashSet, KECCAK_EMPTY,
+    Log, U256, b256, keccak256,
+};
+
+use crate::block::Receipt;
+use crate::db::MemoryDB;
+
+/// The root of an empty trie, i.e., the hash of the RLP encoded empty string.
+pub const EMPTY_ROOT_HASH: B256 =
+    b256!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+
+/// The node of the trie, the cache keeps the reference of the node in its parent, i.e., the
+/// encoded node if it is shorter than 32 bytes, otherwise the encoded hash of the node.
+#[derive(Debug, Clone, Default)]
+enum Node {
+    #[default]
+    Empty,
+    Leaf {
+        path: Vec<u8>,
+        value: Vec<u8>,
+        cache: Option<Vec<u8>>,
+    },
+    Extension {
+        path: Vec<u8>,
+        child: Box<Node>,
+        cache: Option<Vec<u8>>,
+    },
+    Branch {
+        children: Box<[Node; 16]>,
+        cache: Option<Vec<u8>>,
+    },
+}
+
+impl Node {
+    #[inline]
+    fn leaf(path: Vec<u8>, value: Vec<u8>) -> Self {
+        Self::Leaf {
+            path,
+            value,
+            cache: None,
+        }
+    }
+
+    #[inline]
+    fn extension(path: Vec<u8>, child: Node) -> Self {
+        if path.is_empty() {
+            child
+        } else {
+            Self::Extension {
+                path,
+                child: Box::new(child),
+                cache: None,
+            }
+        }
+    }
+
+    #[inline]
+    fn branch(children: [Node; 16]) -> Self {
+        Self::Branch {
+            children: Box::new(children),
+            cache: None,
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    fn insert(self, path: &[u8], value: Vec<u8>) -> Self {
+        match self {
+            Self::Empty => Self::leaf(path.to_vec(), value),
+            Self::Leaf {
+                path: leaf_path,
+                value: leaf_value,
+                ..
+            } => {
+                if leaf_path == path {
+                    return Self::leaf(leaf_path, value);
+                }
+                let common = common_prefix(&leaf_path, path);
+                let mut children: [Node; 16] = Default::default();
+                children[leaf_path[common] as usize] =
+                    Self::leaf(leaf_path[common + 1..].to_vec(), leaf_value);
+                children[path[common] as usize] = Self::leaf(path[common + 1..].to_vec(), value);
+                Self::extension(path[..common].to_vec(), Self::branch(children))
+            }
+            Self::Extension {
+                path: extension_path,
+                child,
+                ..
+            } => {
+                let common = common_prefix(&extension_path, path);
+                if common == extension_path.len() {
+                    let child = child.insert(&path[common..], value);
+                    return Self::extension(extension_path, child);
+                }
+                let mut children: [Node; 16] = Default::default();
+                // The unchanged child keeps its cached reference.
+                children[extension_path[common] as usize] =
+                    Self::extension(extension_path[common + 1..].to_vec(), *child);
+                children[path[common] as usize] = Self::leaf(path[common + 1..].to_vec(), value);
+                Self::extension(path[..common].to_vec(), Self::branch(children))
+            }
+            Self::Branch { mut children, .. } => {
+                let index = path[0] as usize;
+                children[index] = mem::take(&mut children[index]).insert(&path[1..], value);
+                Self::Branch {
+                    children,
+                    cache: None,
+                }
+            }
+        }
+    }
+
+    fn remove(self, path: &[u8]) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Leaf {
+                path: leaf_path, ..
+            } if leaf_path == path => Self::Empty,
+            node @ Self::Leaf { .. } => node,
+            Self::Extension {
+                path: extension_path,
+                child,
+                cache,
+            } => {
+                let Some(rest) = path.strip_prefix(extension_path.as_slice()) else {
+                    return Self::Extension {
+                        path: extension_path,
+                        child,
+                        cache,
+                    };
+                };
+                let child = child.remove(rest);
+                if child.is_empty() {
+                    Self::Empty
+                } else {
+                    child.with_prefix(&extension_path)
+                }
+            }
+            Self::Branch { mut children, .. } => {
+                let index = path[0] as usize;
+                children[index] = mem::take(&mut children[index]).remove(&path[1..]);
+                let mut remaining = children
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, child)| !child.is_empty())
+                    .map(|(index, _)| index);
+                match (remaining.next(), remaining.next()) {
+                    (None, _) => Self::Empty,
+                    // The branch with a single child is merged into the child.
+                    (Some(index), None) => {
+                        mem::take(&mut children[index]).with_prefix(&[index as u8])
+                    }
+                    _ => Self::Branch {
+                        children,
+                        cache: None,
+                    },
+                }
+            }
+        }
+    }
+
+    /// Prepends the path to the node.
+    fn with_prefix(self, prefix: &[u8]) -> Self {
+        match self {
+            Self::Leaf { path, value, .. } => Self::leaf([prefix, &path].concat(), value),
+            Self::Extension { path, child, .. } => {
+                Self::extension([prefix, &path].concat(), *child)
+            }
+            node => Self::extension(prefix.to_vec(), node),
+        }
+    }
+
+    /// Returns the RLP encoding of the node, the references of the children are cached.
+    fn encode(&mut self) -> Vec<u8> {
+        let mut payload = Vec::new();
+        match self {
+            Self::Empty => return vec![EMPTY_STRING_CODE],
+            Self::Leaf { path, value, .. } => {
+                hex_prefix(path, true).as_slice().encode(&mut payload);
+                value.as_slice().encode(&mut payload);
+            }
+            Self::Extension { path, child, .. } => {
+                hex_prefix(path, false).as_slice().encode(&mut payload);
+                payload.extend(child.reference());
+            }
+            Self::Branch { children, .. } => {
+                for child in children.iter_mut() {
+                    payload.extend(child.reference());
+                }
+                // The keys have no values on the branches.
+                payload.push(EMPTY_STRING_CODE);
+            }
+        }
+        let mut out = Vec::with_capacity(payload.len() + 3);
+        Header {
+            list: true,
+            payload_length: payload.len(),
+        }
+        .encode(&mut out);
+        out.extend(payload);
+        out
+    }
+
+    /// Returns the reference of the node in its parent.
+    fn reference(&mut self) -> Vec<u8> {
+        if let Self::Leaf {
+            cache: Some(cache), ..
+        }
+        | Self::Extension {
+            cache: Some(cache), ..
+        }
+        | Self::Branch {
+            cache: Some(cache), ..
+        } = self
+        {
+            return cache.clone();
+        }
+        let encoded = self.encode();
+        let reference = if encoded.len() < 32 {
+            encoded
+        } else {
+            alloy_rlp::encode(keccak256(&encoded))
+        };
+        if let Self::Leaf { cache, .. }
+        | Self::Extension { cache, .. }
+        | Self::Branch { cache, .. } = self
+        {
+            *cache = Some(reference.clone());
+        }
+        reference
+    }
+}
+
+/// Returns the nibbles of the key.
+fn nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+fn common_prefix(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+/// Returns the hex prefix encoding of the nibbles.
+fn hex_prefix(nibbles: &[u8], leaf: bool) -> Vec<u8> {
+    let flag = if leaf { 2 } else { 0 };
+    let mut out = Vec::with_capacity(nibbles.len() / 2 + 1);
+    let rest = if nibbles.len() % 2 == 1 {
+        out.push(((flag + 1) << 4) | nibbles[0]);
+        &nibbles[1..]
+    } else {
+        out.push(flag << 4);
+        nibbles
+    };
+    out.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+    out
+}
+
+/// An in-memory Merkle Patricia trie.
+///
+/// The keys must be prefix-free, e.g., the hashed keys of the secure tries or the RLP encoded
+/// indices of the receipts trie.
+#[derive(Debug, Clone, Default)]
+pub struct Trie {
+    root: Node,
+}
+
+impl Trie {
+    /// Creates an empty trie.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the trie is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_empty()
+    }
+
+    /// Inserts the value at the key.
+    pub fn insert(&mut self, key: &[u8], value: Vec<u8>) {
+        self.root = mem::take(&mut self.root).insert(&nibbles(key), value);
+    }
+
+    /// Removes the value at the key.
+    pub fn remove(&mut self, key: &[u8]) {
+        self.root = mem::take(&mut self.root).remove(&nibbles(key));
+    }
+
+    /// Returns the root hash, only the nodes changed after the last call are hashed.
+    pub fn root(&mut self) -> B256 {
+        keccak256(self.root.encode())
+    }
+}
+
+/// The account leaf of the state trie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RlpEncodable)]
+pub struct TrieAccount {
+    pub nonce: u64,
+    pub balance: U256,
+    pub storage_root: B256,
+    pub code_hash: B256,
+}
+
+/// The account of the [`StateTrie`].
+#[derive(Debug, Clone, Default)]
+struct TrieEntry {
+    nonce: u64,
+    balance: U256,
+    code_hash: B256,
+    storage: Trie,
+}
+
+impl TrieEntry {
+    fn new(info: &AccountInfo) -> Self {
+        Self {
+            nonce: info.nonce,
+            balance: info.balance,
+            code_hash: info.code_hash,
+            storage: Trie::new(),
+        }
+    }
+
+    fn set_storage(&mut self, index: U256, value: U256) {
+        let key = keccak256(index.to_be_bytes::<32>());
+        if value.is_zero() {
+            self.storage.remove(key.as_slice());
+        } else {
+            self.storage
+                .insert(key.as_slice(), alloy_rlp::encode(value));
+        }
+    }
+}
+
+/// The state trie with the storage tries of the accounts, which is updated with the state
+/// changes and recomputes the roots incrementally.
+///
+/// # Example
+///
+/// ```no_check
+/// let mut trie = StateTrie::from_db(&db);
+/// let output = executor.execute_block(block)?;
+/// trie.apply(&output.state);
+/// let state_root = trie.root();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StateTrie {
+    trie: Trie,
+    accounts: HashMap<Address, TrieEntry>,
+    /// The accounts changed after the last root computation.
+    changed: HashSet<Address>,
+}
+
+impl StateTrie {
+    /// Creates an empty state trie.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the state trie with the accounts and the present values of their storage slots.
+    pub fn from_accounts<'a>(
+        accounts: impl IntoIterator<Item = (&'a Address, &'a Account)>,
+    ) -> Self {
+        let mut trie = Self::new();
+        for (address, account) in accounts {
+            trie.insert_account(
+                *address,
+                &account.info,
+                account
+                    .storage
+                    .iter()
+                    .map(|(index, slot)| (*index, slot.present_value)),
+            );
+        }
+        trie
+    }
+
+    /// Creates the state trie with the existing accounts of the database.
+    pub fn from_db(db: &MemoryDB) -> Self {
+        let mut trie = Self::new();
+        for (address, account) in db.accounts() {
+            let info = AccountInfo::from(account.clone());
+            let is_empty = info.nonce == 0
+                && info.balance.is_zero()
+                && (info.code_hash == KECCAK_EMPTY || info.code_hash.is_zero());
+            if account.status.contains(AccountStatus::LoadedAsNotExisting)
+                && !(account.status.contains(AccountStatus::Touched) && !is_empty)
+            {
+                continue;
+            }
+            trie.insert_account(
+                *address,
+                &info,
+                account
+                    .storage
+                    .iter()
+                    .map(|(index, value)| (*index, *value)),
+            );
+        }
+        trie
+    }
+
+    /// Inserts the account with its storage, the previous account at the address is replaced.
+    pub fn insert_account(
+        &mut self,
+        address: Address,
+        info: &AccountInfo,
+        storage: impl IntoIterator<Item = (U256, U256)>,
+    ) {
+        let mut entry = TrieEntry::new(info);
+        for (index, value) in storage {
+            entry.set_storage(index, value);
+        }
+        self.accounts.insert(address, entry);
+        self.changed.insert(address);
+    }
+
+    /// Removes the account with its storage.
+    pub fn remove_account(&mut self, address: Address) {
+        self.accounts.remove(&address);
+        self.changed.insert(address);
+    }
+
+    /// Applies the state changes, e.g., the state of a [`ResultAndState`](crate::ResultAndState)
+    /// or a [`BlockOutput`](crate::BlockOutput), in the same way as they are committed into the
+    /// [`MemoryDB`].
+    pub fn apply(&mut self, state: &EvmState) {
+        for (address, account) in state {
+            if !account.is_touched() {
+                continue;
+            }
+            if account.is_selfdestructed()
+                || (account.is_loaded_as_not_existing() && account.is_empty())
+            {
+                self.remove_account(*address);
+                continue;
+            }
+            let entry = self
+                .accounts
+                .entry(*address)
+                .or_insert_with(|| TrieEntry::new(&account.info));
+            if account.is_created() {
+                entry.storage = Trie::new();
+            }
+            entry.nonce = account.info.nonce;
+            entry.balance = account.info.balance;
+            entry.code_hash = account.info.code_hash;
+            for (index, slot) in &account.storage {
+                if slot.is_changed() || account.is_created() {
+                    entry.set_storage(*index, slot.present_value);
+                }
+            }
+            self.changed.insert(*address);
+        }
+    }
+
+    /// Returns the storage root of the account.
+    pub fn storage_root(&mut self, address: &Address) -> B256 {
+        self.accounts
+            .get_mut(address)
+            .map_or(EMPTY_ROOT_HASH, |entry| entry.storage.root())
+    }
+
+    /// Returns the state root, only the accounts changed after the last call are rehashed.
+    pub fn root(&mut self) -> B256 {
+        for address in mem::take(&mut self.changed) {
+            let key = keccak256(address);
+            match self.accounts.get_mut(&address) {
+                Some(entry) => {
+                    let account = TrieAccount {
+                        nonce: entry.nonce,
+                        balance: entry.balance,
+                        storage_root: entry.storage.root(),
+                        code_hash: entry.code_hash,
+                    };
+                    self.trie.insert(key.as_slice(), alloy_rlp::encode(account));
+                }
+                None => self.trie.remove(key.as_slice()),
+            }
+        }
+        self.trie.root()
+    }
+}
+
+/// Returns the state root of the accounts.
+#[inline]
+pub fn state_root<'a>(accounts: impl IntoIterator<Item = (&'a Address, &'a Account)>) -> B256 {
+    StateTrie::from_accounts(accounts).root()
+}
+
+/// Returns the root of the trie with the RLP encoded indices as the keys.
+pub fn ordered_trie_root<T: AsRef<[u8]>>(values: impl IntoIterator<Item = T>) -> B256 {
+    let mut trie = Trie::new();
+    for (index, value) in values.into_iter().enumerate() {
+        trie.insert(&alloy_rlp::encode(index), value.as_ref().to_vec());
+    }
+    trie.root()
+}
+
+/// Returns the receipts root of the block.
+#[inline]
+pub fn receipts_root(receipts: &[Receipt]) -> B256 {
+    ordered_trie_root(receipts.iter().map(encode_receipt))
+}
+
+/// Returns the EIP-2718 encoding of the receipt, the typed receipts are prefixed with the
+/// transaction type.
+fn encode_receipt(receipt: &Receipt) -> Vec<u8> {
+    let payload_length = receipt.success.length()
+        + receipt.cumulative_gas_used.length()
+        + receipt.logs_bloom.length()
+        + alloy_rlp::list_length(&receipt.logs);
+    let mut out = Vec::with_capacity(payload_length + 4);
+    if receipt.tx_type != 0 {
+        out.push(receipt.tx_type);
+    }
+    Header {
+        list: true,
+        payload_length,
+    }
+    .encode(&mut out);
+    receipt.success.encode(&mut out);
+    receipt.cumulative_gas_used.encode(&mut out);
+    receipt.logs_bloom.encode(&mut out);
+    alloy_rlp::encode_list(&receipt.logs, &mut out);
+    out
+}
+
+/// Returns the hash of the RLP encoded logs.
+#[inline]
+pub fn logs_hash(logs: &[Log]) -> B256 {
+    let mut out = Vec::with_capacity(alloy_rlp::list_length(logs));
+    alloy_rlp::encode_list(logs, &mut out);
+    keccak256(&out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    fn account(balance: u64) -> AccountInfo {
+        AccountInfo {
+            balance: U256::from(balance),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_root() {
+        assert_eq!(Trie::new().root(), EMPTY_ROOT_HASH);
+        assert_eq!(keccak256([EMPTY_STRING_CODE]), EMPTY_ROOT_HASH);
+        assert_eq!(StateTrie::new().root(), EMPTY_ROOT_HASH);
+        assert_eq!(ordered_trie_root(Vec::<Vec<u8>>::new()), EMPTY_ROOT_HASH);
+    }
+
+    #[test]
+    fn test_trie_known_vectors() {
+        // The `hex` vector of the ethereum/tests `trieanyorder.json`.
+        let mut trie = Trie::new();
+        trie.insert(&hex!("0045"), hex!("0123456789").to_vec());
+        trie.insert(&hex!("4500"), hex!("9876543210").to_vec());
+        assert_eq!(
+            trie.root(),
+            b256!("285505fcabe84badc8aa310e2aae17eddc7d120aabec8a476902c8184b3a3503")
+        );
+        // The short values are inlined into their parents and the long ones are hashed.
+        assert_eq!(
+            ordered_trie_root([vec![b'a'], vec![b'b'; 40], vec![b'c']]),
+            b256!("dfcad4994eed82941058f180bfcf1944cd943d4f317ac0f05d5529830b4030ed")
+        );
+    }
+
+    #[test]
+    fn test_state_trie_known_root() {
+        let code_hash = keccak256(hex!("5f5455600101"));
+        let mut trie = StateTrie::new();
+        trie.insert_account(
+            Address::with_last_byte(1),
+            &AccountInfo {
+                nonce: 1,
+                balance: U256::from(10).pow(U256::from(18)),
+                ..Default::default()
+            },
+            [],
+        );
+        assert_eq!(
+            trie.root(),
+            b256!("9c12f277e702a26a12a6dbc78d5ee8a5e1594e9365b0228e9f81a03853754ddd")
+        );
+        trie.insert_account(
+            Address::with_last_byte(2),
+            &AccountInfo {
+                code_hash,
+                ..Default::default()
+            },
+            [
+                (U256::from(1), U256::from(1)),
+                (U256::from(2), U256::from(0xdead)),
+                // The zero values are not stored.
+                (U256::from(3), U256::ZERO),
+            ],
+        );
+        assert_eq!(
+            trie.storage_root(&Address::with_last_byte(2)),
+            b256!("38af574480b5fe5902106d7c027d46336252f877c89bac10f2c37dae771b42b7")
+        );
+        assert_eq!(
+            trie.root(),
+            b256!("2ad56c2ad6ab976f6c346c5ba6825e61b3257e58f59bf7b377e66e82a259b074")
+        );
+    }
+
+    #[test]
+    fn test_trie_matches_hash_builder() {
+        use alloy_trie::{HashBuilder, Nibbles};
+
+        for count in [1_u64, 2, 3, 17, 256] {
+            // The pseudo-random keys with the values of 1 to 64 bytes, sorted for the builder.
+            let mut entries = (0..count)
+                .map(|i| {
+                    let key = keccak256(((count << 32) | i).to_be_bytes());
+                    let value = keccak256(key).repeat(2)[..1 + key[0] as usize % 64].to_vec();
+                    (key, value)
+                })
+                .collect::<Vec<_>>();
+            entries.sort();
+            let mut trie = Trie::new();
+            let mut builder = HashBuilder::default();
+            for (key, value) in &entries {
+                trie.insert(key.as_slice(), value.clone());
+                builder.add_leaf(Nibbles::unpack(key), value);
+            }
+            assert_eq!(trie.root(), builder.root());
+        }
+    }
+
+    #[test]
+    fn test_trie_is_order_independent() {
+        let keys = (0..64_u64)
+            .map(|i| keccak256(i.to_be_bytes()))
+            .collect::<Vec<_>>();
+        let mut forward = Trie::new();
+        for key in &keys {
+            forward.insert(key.as_slice(), key.to_vec());
+        }
+        let mut backward = Trie::new();
+        for key in keys.iter().rev() {
+            backward.insert(key.as_slice(), key.to_vec());
+        }
+        assert_eq!(forward.root(), backward.root());
+    }
+
+    #[test]
+    fn test_trie_incremental_updates() {
+        let keys = (0..64_u64)
+            .map(|i| keccak256(i.to_be_bytes()))
+            .collect::<Vec<_>>();
+        let mut trie = Trie::new();
+        for key in &keys {
+            trie.insert(key.as_slice(), vec![1]);
+        }
+        let root = trie.root();
+        // Update and remove with the cached references.
+        for key in keys.iter().step_by(3) {
+            trie.insert(key.as_slice(), vec![2]);
+        }
+        for key in keys.iter().skip(1).step_by(3) {
+            trie.remove(key.as_slice());
+        }
+        // Removing a missing key is a no-op.
+        trie.remove(keccak256([0xff]).as_slice());
+        let mut expected = Trie::new();
+        for (index, key) in keys.iter().enumerate() {
+            match index % 3 {
+                0 => expected.insert(key.as_slice(), vec![2]),
+                1 => {}
+                _ => expected.insert(key.as_slice(), vec![1]),
+            }
+        }
+        assert_ne!(trie.root(), root);
+        assert_eq!(trie.root(), expected.root());
+
+        for key in &keys {
+            trie.remove(key.as_slice());
+        }
+        assert!(trie.is_empty());
+        assert_eq!(trie.root(), EMPTY_ROOT_HASH);
+    }
+
+    #[test]
+    fn test_state_trie_apply() {
+        let (a, b) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let mut trie = StateTrie::new();
+        trie.insert_account(a, &account(10), [(U256::from(1), U256::from(1))]);
+        trie.insert_account(b, &account(20), []);
+        trie.root();
+
+        let mut changed = Account::from(account(11));
+        changed.mark_touch();
+        changed.storage.insert(
+            U256::from(2),
+            dora_primitives::StorageSlot::new_changed(U256::ZERO, U256::from(2)),
+        );
+        let mut destroyed = Account::from(account(0));
+        destroyed.mark_touch();
+        destroyed.mark_selfdestruct();
+        trie.apply(&[(a, changed), (b, destroyed)].into_iter().collect());
+
+        let mut expected = StateTrie::new();
+        expected.insert_account(
+            a,
+            &account(11),
+            [
+                (U256::from(1), U256::from(1)),
+                (U256::from(2), U256::from(2)),
+            ],
+        );
+        assert_eq!(trie.root(), expected.root());
+        assert_eq!(trie.storage_root(&a), expected.storage_root(&a));
+        assert_eq!(trie.storage_root(&b), EMPTY_ROOT_HASH);
+    }
+}
@@ -1,22 +1,25 @@
 use std::{
     cmp::Ordering,
+    mem,
     ops::{Deref, DerefMut},
 };
 
 use dora_primitives::{
-    Account, B256, Bytes, Cfg, DatabaseCommit, Env, InvalidHeader, InvalidTransaction,
-    JournalOutput, JournalTr, SpecId, TransactionType, U256, eip4844,
+    Account, Address, B256, Bytes, Cfg, DatabaseCommit, Env, InvalidHeader, InvalidTransaction,
+    JournalOutput, JournalTr, SpecId, TransactionType, TxEnv, TxKind, U256, eip4844,
 };
 
 use crate::{
     ExitStatusCode,
     call::{CallKind, CallMessage, CallResult},
+    constants::{SYSTEM_ADDRESS, SYSTEM_CALL_GAS_LIMIT, TX_GAS_LIMIT_CAP},
     context::VMContext,
-    db::{Database, DatabaseError},
-    gas::{self, InitialGas},
+    db::Database,
+    gas::{self, GasSchedule, InitialGas},
     result::{
         ExecutionResult, HaltReason, OutOfGasError, Output, ResultAndState, SuccessReason, VMError,
     },
+    scheduler::FrameScheduler,
 };
 
 /// EVM/WASM instance containing internal VM context and run actions
@@ -41,53 +44,116 @@ impl<DB: Database> VM<DB> {
     /// This function will validate the transaction.
     #[inline]
     pub fn transact(&mut self) -> Result<ResultAndState, VMError> {
-        let gas = self.preverify_transaction().inspect_err(|_| {
-            self.clear();
-        })?;
-
-        let output = self.transact_preverified(gas);
+        let output = self
+            .preverify_transaction()
+            .and_then(|gas| self.transact_preverified(gas));
         self.clear();
+        // The failed deposits are still included in the block.
+        #[cfg(feature = "optimism")]
+        let output = crate::optimism::deposit_output(&mut self.context, output);
         output
     }
 
+    /// Executes a system call from the [`SYSTEM_ADDRESS`] to the contract with the call data,
+    /// e.g., the pre-block calls of EIP-4788 and EIP-2935.
+    ///
+    /// The call is not validated and pays no fee, and the accounts of the system address and the
+    /// beneficiary are not included in the output state.
+    pub fn system_call(
+        &mut self,
+        address: Address,
+        data: Bytes,
+    ) -> Result<ResultAndState, VMError> {
+        let tx = TxEnv {
+            caller: SYSTEM_ADDRESS,
+            kind: TxKind::Call(address),
+            data: data.clone(),
+            gas_limit: SYSTEM_CALL_GAS_LIMIT,
+            gas_price: 0,
+            ..Default::default()
+        };
+        let tx = mem::replace(&mut self.context.env.tx, tx);
+        let output = self.system_call_inner(address, data);
+        self.context.env.tx = tx;
+        self.clear();
+        let mut output = output?;
+        output.state.remove(&SYSTEM_ADDRESS);
+        output.state.remove(&self.context.env.block.beneficiary);
+        Ok(output)
+    }
+
+    fn system_call_inner(
+        &mut self,
+        address: Address,
+        data: Bytes,
+    ) -> Result<ResultAndState, VMError> {
+        let ctx = &mut self.context;
+        ctx.set_precompiles();
+        let call_msg = CallMessage {
+            kind: CallKind::Call,
+            input: data,
+            init_code: Bytes::new(),
+            value: U256::ZERO,
+            depth: 0,
+            gas_limit: SYSTEM_CALL_GAS_LIMIT,
+            caller: SYSTEM_ADDRESS,
+            recipient: address,
+            salt: None,
+            code_address: address,
+            is_static: false,
+            is_eof_init: false,
+            validate_eof: true,
+        };
+        let mut result = FrameScheduler::new().run(ctx, call_msg)?;
+        ctx.last_frame_return(&mut result);
+        self.output(result)
+    }
+
     /// Pre verify transaction inner.
     #[inline]
     fn preverify_transaction(&mut self) -> Result<InitialGas, VMError> {
-        self.validate_env()?;
-        let gas = Self::validate_initial_tx_gas(&self.context.env, self.spec_id())?;
-        self.validate_tx_against_state()?;
+        let lifecycle = self.context.handler.lifecycle.clone();
+        (lifecycle.validate_env)(&self.context)?;
+        let gas = Self::validate_initial_tx_gas(
+            &self.context.env,
+            self.spec_id(),
+            &self.context.gas_schedule(),
+        )?;
+        (lifecycle.validate_tx_against_state)(&mut self.context)?;
         Ok(gas)
     }
 
-    fn validate_env(&mut self) -> Result<(), VMError> {
-        let spec_id = self.spec_id();
-        if spec_id.is_enabled_in(SpecId::MERGE) && self.env.block.prevrandao.is_none() {
+    /// Validates the block environment and the transaction without the state, which is the
+    /// default hook of [`LifecycleHandles`](crate::handler::LifecycleHandles).
+    pub fn validate_env(ctx: &VMContext<DB>) -> Result<(), VMError> {
+        let spec_id = ctx.spec_id();
+        if spec_id.is_enabled_in(SpecId::MERGE) && ctx.env.block.prevrandao.is_none() {
             return Err(VMError::Header(InvalidHeader::PrevrandaoNotSet));
         }
         if spec_id.is_enabled_in(SpecId::CANCUN)
-            && self.env.block.blob_excess_gas_and_price.is_none()
+            && ctx.env.block.blob_excess_gas_and_price.is_none()
         {
             return Err(VMError::Header(InvalidHeader::ExcessBlobGasNotSet));
         }
-        let tx_type = self.env.tx.tx_type;
-        let base_fee = if self.env.cfg.is_base_fee_check_disabled() {
+        let tx_type = ctx.env.tx.tx_type;
+        let base_fee = if ctx.env.cfg.is_base_fee_check_disabled() {
             None
         } else {
-            Some(self.env.block.basefee as u128)
+            Some(ctx.env.block.basefee as u128)
         };
 
         match TransactionType::from(tx_type) {
             TransactionType::Legacy => {
                 // Check chain_id only if it is present in the legacy transaction.
                 // EIP-155: Simple replay attack protection
-                if let Some(chain_id) = self.env.tx.chain_id {
-                    if chain_id != self.env.cfg.chain_id() {
+                if let Some(chain_id) = ctx.env.tx.chain_id {
+                    if chain_id != ctx.env.cfg.chain_id() {
                         return Err(VMError::Transaction(InvalidTransaction::InvalidChainId));
                     }
                 }
                 // Gas price must be at least the basefee.
                 if let Some(base_fee) = base_fee {
-                    if self.env.tx.gas_price < base_fee {
+                    if ctx.env.tx.gas_price < base_fee {
                         return Err(VMError::Transaction(
                             InvalidTransaction::GasPriceLessThanBasefee,
                         ));
@@ -102,13 +168,13 @@ impl<DB: Database> VM<DB> {
                     ));
                 }
 
-                if Some(self.env.cfg.chain_id()) != self.env.tx.chain_id {
+                if Some(ctx.env.cfg.chain_id()) != ctx.env.tx.chain_id {
                     return Err(VMError::Transaction(InvalidTransaction::InvalidChainId));
                 }
 
                 // Gas price must be at least the basefee.
                 if let Some(base_fee) = base_fee {
-                    if self.env.tx.gas_price < base_fee {
+                    if ctx.env.tx.gas_price < base_fee {
                         return Err(VMError::Transaction(
                             InvalidTransaction::GasPriceLessThanBasefee,
                         ));
@@ -121,13 +187,13 @@ impl<DB: Database> VM<DB> {
                         InvalidTransaction::Eip1559NotSupported,
                     ));
                 }
-                if Some(self.env.cfg.chain_id()) != self.env.tx.chain_id {
+                if Some(ctx.env.cfg.chain_id()) != ctx.env.tx.chain_id {
                     return Err(VMError::Transaction(InvalidTransaction::InvalidChainId));
                 }
 
                 Self::validate_priority_fee_tx(
-                    self.env.max_fee_per_gas(),
-                    self.env.max_priority_fee_per_gas().unwrap_or_default(),
+                    ctx.env.max_fee_per_gas(),
+                    ctx.env.max_priority_fee_per_gas().unwrap_or_default(),
                     base_fee,
                 )?;
             }
@@ -138,21 +204,21 @@ impl<DB: Database> VM<DB> {
                     ));
                 }
 
-                if Some(self.env.cfg.chain_id()) != self.env.tx.chain_id {
+                if Some(ctx.env.cfg.chain_id()) != ctx.env.tx.chain_id {
                     return Err(VMError::Transaction(InvalidTransaction::InvalidChainId));
                 }
 
                 Self::validate_priority_fee_tx(
-                    self.env.max_fee_per_gas(),
-                    self.env.max_priority_fee_per_gas().unwrap_or_default(),
+                    ctx.env.max_fee_per_gas(),
+                    ctx.env.max_priority_fee_per_gas().unwrap_or_default(),
                     base_fee,
                 )?;
 
                 Self::validate_eip4844_tx(
-                    self.env.blob_versioned_hashes(),
-                    self.env.max_fee_per_blob_gas(),
-                    self.env.blob_gasprice().unwrap_or_default(),
-                    self.env.cfg.blob_max_count(spec_id),
+                    ctx.env.blob_versioned_hashes(),
+                    ctx.env.max_fee_per_blob_gas(),
+                    ctx.env.blob_gasprice().unwrap_or_default(),
+                    ctx.env.cfg.blob_max_count(spec_id),
                 )?;
             }
             TransactionType::Eip7702 => {
@@ -163,18 +229,18 @@ impl<DB: Database> VM<DB> {
                     ));
                 }
 
-                if Some(self.env.cfg.chain_id()) != self.env.tx.chain_id {
+                if Some(ctx.env.cfg.chain_id()) != ctx.env.tx.chain_id {
                     return Err(VMError::Transaction(InvalidTransaction::InvalidChainId));
                 }
 
                 Self::validate_priority_fee_tx(
-                    self.env.max_fee_per_gas(),
-                    self.env.max_priority_fee_per_gas().unwrap_or_default(),
+                    ctx.env.max_fee_per_gas(),
+                    ctx.env.max_priority_fee_per_gas().unwrap_or_default(),
                     base_fee,
                 )?;
 
                 // The transaction is considered invalid if the length of authorization_list is zero.
-                if self.env.tx.authorization_list.is_empty() {
+                if ctx.env.tx.authorization_list.is_empty() {
                     return Err(VMError::Transaction(
                         InvalidTransaction::EmptyAuthorizationList,
                     ));
@@ -186,18 +252,26 @@ impl<DB: Database> VM<DB> {
         };
 
         // Check if gas_limit is more than block_gas_limit
-        if !self.env.cfg.is_block_gas_limit_disabled()
-            && self.env.tx.gas_limit > self.env.block.gas_limit
+        if !ctx.env.cfg.is_block_gas_limit_disabled()
+            && ctx.env.tx.gas_limit > ctx.env.block.gas_limit
         {
             return Err(VMError::Transaction(
                 InvalidTransaction::CallerGasLimitMoreThanBlock,
             ));
         }
 
+        // EIP-7825: Transaction gas limit cap
+        if spec_id.is_enabled_in(SpecId::OSAKA) && ctx.env.tx.gas_limit > TX_GAS_LIMIT_CAP {
+            return Err(VMError::TxGasLimitGreaterThanCap {
+                gas_limit: ctx.env.tx.gas_limit,
+                cap: TX_GAS_LIMIT_CAP,
+            });
+        }
+
         // EIP-3860: Limit and meter initcode
-        if spec_id.is_enabled_in(SpecId::SHANGHAI) && self.env.tx.kind.is_create() {
-            let max_initcode_size = self.env.cfg.max_code_size().saturating_mul(2);
-            if self.env.tx.data.len() > max_initcode_size {
+        if spec_id.is_enabled_in(SpecId::SHANGHAI) && ctx.env.tx.kind.is_create() {
+            let max_initcode_size = ctx.max_code_size().saturating_mul(2);
+            if ctx.env.tx.data.len() > max_initcode_size {
                 return Err(VMError::Transaction(
                     InvalidTransaction::CreateInitCodeSizeLimit,
                 ));
@@ -273,26 +347,30 @@ impl<DB: Database> VM<DB> {
         Ok(())
     }
 
-    /// Validates transaction against the state.
-    #[inline]
-    fn validate_tx_against_state(&mut self) -> Result<(), VMError> {
-        let spec_id = self.spec_id();
-        let tx_caller = self.context.env.tx.caller;
-        let caller_account = self
-            .context
+    /// Validates the transaction against the caller account, which is the default hook of
+    /// [`LifecycleHandles`](crate::handler::LifecycleHandles).
+    pub fn validate_tx_against_state(ctx: &mut VMContext<DB>) -> Result<(), VMError> {
+        let spec_id = ctx.spec_id();
+        let tx_caller = ctx.env.tx.caller;
+        let caller_account = ctx
             .journal
             .load_account_code(tx_caller)
-            .map_err(|_| VMError::Database(DatabaseError))?;
-        Self::validate_tx_against_account(caller_account.data, &self.context.env, spec_id)
+            .map_err(VMError::database)?;
+        Self::validate_tx_against_account(caller_account.data, &ctx.env, spec_id)
             .map_err(VMError::Transaction)?;
 
         Ok(())
     }
 
     /// Validate initial transaction gas.
-    fn validate_initial_tx_gas(env: &Env, spec_id: SpecId) -> Result<InitialGas, VMError> {
+    fn validate_initial_tx_gas(
+        env: &Env,
+        spec_id: SpecId,
+        schedule: &GasSchedule,
+    ) -> Result<InitialGas, VMError> {
         let gas = gas::calculate_initial_tx_gas(
             spec_id,
+            schedule,
             &env.tx.data,
             env.tx.kind.is_create(),
             &env.tx.access_list,
@@ -382,6 +460,7 @@ impl<DB: Database> VM<DB> {
     /// Transact pre-verified transaction.
     fn transact_preverified(&mut self, gas: InitialGas) -> Result<ResultAndState, VMError> {
         let ctx = &mut self.context;
+        let lifecycle = ctx.handler.lifecycle.clone();
         // Pre execution
         let pre_exec_gas_refund = {
             // Load access list and beneficiary if needed.
@@ -389,7 +468,7 @@ impl<DB: Database> VM<DB> {
             // Set precompile addresses into the warm preloaded address list.
             ctx.set_precompiles();
             // Deduce caller balance with its limit.
-            ctx.deduct_caller()?;
+            (lifecycle.deduct_caller)(ctx)?;
             // Apply EIP-7702 auth list
             ctx.apply_eip7702_auth_list()?
         };
@@ -416,7 +495,9 @@ impl<DB: Database> VM<DB> {
                 is_eof_init: false,
                 validate_eof: true,
             };
-            let mut result = ctx.call(call_msg)?;
+            // The frames are driven in a loop when the suspend handler is set, otherwise the
+            // scheduler calls the handler recursively as the sub calls on the host.
+            let mut result = FrameScheduler::new().run(ctx, call_msg)?;
             ctx.last_frame_return(&mut result);
             result
         };
@@ -434,9 +515,9 @@ impl<DB: Database> VM<DB> {
                 result.set_refund(0);
             }
             // Reimburse the caller with gas that were not used.
-            ctx.reimburse_caller(result.gas_remaining, result.gas_refunded)?;
+            (lifecycle.reimburse_caller)(ctx, result.gas_remaining, result.gas_refunded)?;
             // Reward beneficiary
-            ctx.reward_beneficiary(result.gas_used(), result.gas_refunded)?;
+            (lifecycle.reward_beneficiary)(ctx, result.gas_used(), result.gas_refunded)?;
         }
         // Returns output of transaction.
         self.output(result)
@@ -444,6 +525,11 @@ impl<DB: Database> VM<DB> {
 
     /// Build output using the call result
     pub fn output(&mut self, result: CallResult) -> Result<ResultAndState, VMError> {
+        // The host error of a sub call may be swallowed as a failed call by its caller, so the
+        // first one recorded in the transaction fails it regardless of the exit status.
+        if let Some(error) = self.context.take_host_error() {
+            return Err(error.into());
+        }
         // Used gas with refund calculated.
         let gas_refunded = result.gas_refunded as u64;
         let gas_used = result.gas_used() - gas_refunded;
@@ -599,7 +685,9 @@ impl<DB: Database> VM<DB> {
                 reason: HaltReason::InvalidEXTCALLTarget,
                 gas_used,
             },
-            ExitStatusCode::FatalExternalError => return Err(VMError::Database(DatabaseError)),
+            ExitStatusCode::FatalExternalError => {
+                return Err(VMError::database("fatal external error"));
+            }
         };
 
         Ok(ResultAndState { result, state })
@@ -608,6 +696,7 @@ impl<DB: Database> VM<DB> {
     #[inline]
     fn clear(&mut self) {
         self.context.journal.clear();
+        self.context.host_error = None;
     }
 }
 
@@ -663,10 +752,7 @@ mod tests {
         ctx.journal.spec = SpecId::CANCUN;
         let result = ctx.call(msg.clone()).unwrap();
         assert_eq!(result.gas_used(), 1349);
-        let result = ctx
-            .call_precompile(msg.code_address, &msg.input, msg.gas_limit)
-            .unwrap()
-            .unwrap_or_default();
+        let result = ctx.call_precompile(&msg).unwrap().unwrap_or_default();
         assert_eq!(result.gas_used(), 1349);
     }
 }
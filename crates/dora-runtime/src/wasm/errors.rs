@@ -1,3 +1,4 @@
+use crate::{ExitStatusCode, host::HostError};
 use thiserror::*;
 use wasmer::MemoryAccessError;
 
@@ -27,3 +28,9 @@ impl From<MemoryAccessError> for Escape {
         Self::Memory(err)
     }
 }
+
+impl From<HostError> for Escape {
+    fn from(_: HostError) -> Self {
+        Self::Exit(ExitStatusCode::FatalExternalError.to_u8())
+    }
+}
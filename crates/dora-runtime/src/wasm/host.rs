@@ -113,9 +113,8 @@ pub fn account_balance(
         runtime_context
             .host
             .balance(address)
-            .unwrap_or_default()
-            .data
-    });
+            .map(|state| state.data)
+    })?;
     let data: [u8; 32] = data.to_be_bytes();
     host.write_slice(dest, &data)?;
     Ok(())
@@ -135,10 +134,8 @@ pub fn account_code(
         runtime_context
             .host
             .code(address)
-            .unwrap_or_default()
-            .data
-            .to_vec()
-    });
+            .map(|state| state.data.to_vec())
+    })?;
     let code_slice = data_slice(&code, offset, size);
     host.write_slice(dest, code_slice)?;
     Ok(code_slice.len() as u32)
@@ -155,10 +152,8 @@ pub fn account_code_size(
         runtime_context
             .host
             .code(address)
-            .unwrap_or_default()
-            .data
-            .len()
-    });
+            .map(|state| state.data.len())
+    })?;
     Ok(size as u32)
 }
 
@@ -174,10 +169,8 @@ pub fn account_codehash(
         runtime_context
             .host
             .code_hash(address)
-            .unwrap_or_default()
-            .data
-            .0
-    });
+            .map(|state| state.data.0)
+    })?;
     host.write_slice(dest, &hash)?;
     Ok(())
 }
@@ -195,9 +188,8 @@ pub fn sload(
         runtime_context
             .host
             .sload(target_address, key)
-            .unwrap_or_default()
-            .data
-    });
+            .map(|state| state.data)
+    })?;
     let value: [u8; 32] = value.to_be_bytes();
     host.write_slice(dest, &value)?;
     Ok(())
@@ -214,11 +206,8 @@ pub fn sstore(
     let value = host.read_u256(value)?;
     with_runtime_context(|runtime_context| {
         let target_address = runtime_context.contract.target_address;
-        runtime_context
-            .host
-            .sstore(target_address, key, value)
-            .unwrap_or_default();
-    });
+        runtime_context.host.sstore(target_address, key, value)
+    })?;
     Ok(())
 }
 
@@ -262,8 +251,7 @@ pub fn block_hash(
     dest: GuestPtr, // *mut u8
 ) -> MaybeEscape {
     let host = HostInfo::from_env(&mut env)?;
-    let hash = with_runtime_context(|runtime_context| runtime_context.host.block_hash(number))
-        .unwrap_or_default();
+    let hash = with_runtime_context(|runtime_context| runtime_context.host.block_hash(number))?;
     host.write_slice(dest, &hash.0)?;
     Ok(())
 }
@@ -555,7 +543,7 @@ pub fn selfdestruct(
         runtime_context
             .host
             .selfdestruct(runtime_context.contract.target_address, address)
-    });
+    })?;
     Ok(())
 }
 
@@ -948,8 +936,8 @@ fn intern_call(
 ) -> EscapeResult<(u8, u32)> {
     // Load account and calculate gas cost.
     let mut account_load = match runtime_context.host.load_account_delegated(to) {
-        Some(account_load) => account_load,
-        None => return Err(Escape::Exit(ExitStatusCode::FatalExternalError.to_u8())),
+        Ok(account_load) => account_load,
+        Err(_) => return Err(Escape::Exit(ExitStatusCode::FatalExternalError.to_u8())),
     };
     if call_type != CallType::Call {
         account_load.is_empty = false;
@@ -3,6 +3,7 @@
 //! ```shell
 //! cargo install --path .
 //! dora-blocktest run data/blocks
+//! dora-blocktest replay --rpc-url http://localhost:8545 --cache-dir rpc-cache 20000000
 //! ```
 use anyhow::Result;
 use clap::{Args, Parser, Subcommand};
@@ -12,9 +13,12 @@ use dora_primitives::AccessListItem;
 use dora_primitives::Bytes;
 use dora_primitives::keccak256;
 use dora_primitives::spec::SpecId;
-use dora_primitives::{Address, B256, HashMap, U256, as_u64_saturated};
+use dora_primitives::{
+    Address, B256, BlockEnv, HashMap, SignedAuthorization, TxEnv, U256, as_u64_saturated,
+};
+use dora_runtime::block::{Block, BlockError, BlockExecutor, Withdrawal};
 use dora_runtime::context::VMContext;
-use dora_runtime::db::MemoryDB;
+use dora_runtime::db::{MemoryDB, RpcDB};
 use dora_runtime::executor::RUNTIME_STACK_SIZE;
 use dora_runtime::vm::VM;
 use dora_tools::find_all_json_tests;
@@ -22,7 +26,8 @@ use indicatif::{ProgressBar, ProgressDrawTarget};
 use revm::ExecuteCommitEvm;
 use revm::primitives::TxKind;
 use revm::{MainBuilder, MainContext};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{error, info};
@@ -39,6 +44,8 @@ struct Cli {
 enum Commands {
     /// Run Dora block tests with given parameters
     Run(RunArgs),
+    /// Replay the historical blocks on the state fetched from a JSON-RPC endpoint
+    Replay(ReplayArgs),
 }
 
 #[derive(Args)]
@@ -46,6 +53,18 @@ struct RunArgs {
     path: Vec<PathBuf>,
 }
 
+#[derive(Args, Clone)]
+struct ReplayArgs {
+    /// The JSON-RPC endpoint of an archive node
+    #[arg(long)]
+    rpc_url: String,
+    /// The directory which caches the fetched blocks and state for the offline reruns
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+    /// The block numbers
+    blocks: Vec<u64>,
+}
+
 pub type Test = HashMap<String, Suite>;
 pub type WSet = HashMap<Address, HashMap<U256, U256>>;
 
@@ -102,6 +121,115 @@ pub struct Account {
     pub storage: HashMap<U256, U256>,
 }
 
+/// A block fetched by `eth_getBlockByNumber` with the full transactions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcBlock {
+    number: U256,
+    parent_hash: B256,
+    miner: Address,
+    gas_limit: U256,
+    timestamp: U256,
+    difficulty: U256,
+    mix_hash: Option<B256>,
+    base_fee_per_gas: Option<U256>,
+    excess_blob_gas: Option<U256>,
+    parent_beacon_block_root: Option<B256>,
+    transactions: Vec<RpcTransaction>,
+    withdrawals: Option<Vec<RpcWithdrawal>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcTransaction {
+    from: Address,
+    to: Option<Address>,
+    input: Bytes,
+    gas: U256,
+    gas_price: Option<U256>,
+    max_fee_per_gas: Option<U256>,
+    max_priority_fee_per_gas: Option<U256>,
+    max_fee_per_blob_gas: Option<U256>,
+    nonce: U256,
+    value: U256,
+    chain_id: Option<U256>,
+    access_list: Option<Vec<AccessListItem>>,
+    blob_versioned_hashes: Option<Vec<B256>>,
+    authorization_list: Option<Vec<SignedAuthorization>>,
+}
+
+impl RpcTransaction {
+    fn tx_env(&self) -> TxEnv {
+        let mut tx = TxEnv {
+            caller: self.from,
+            kind: match self.to {
+                Some(to) => TxKind::Call(to),
+                None => TxKind::Create,
+            },
+            data: self.input.clone(),
+            gas_limit: as_u64_saturated!(self.gas),
+            gas_price: self
+                .max_fee_per_gas
+                .or(self.gas_price)
+                .unwrap_or_default()
+                .saturating_to(),
+            gas_priority_fee: self.max_priority_fee_per_gas.map(|fee| fee.saturating_to()),
+            max_fee_per_blob_gas: self
+                .max_fee_per_blob_gas
+                .unwrap_or_default()
+                .saturating_to(),
+            blob_hashes: self.blob_versioned_hashes.clone().unwrap_or_default(),
+            nonce: as_u64_saturated!(self.nonce),
+            value: self.value,
+            // The legacy transactions before EIP-155 have no chain id.
+            chain_id: self.chain_id.map(|chain_id| as_u64_saturated!(chain_id)),
+            access_list: self.access_list.clone().unwrap_or_default().into(),
+            authorization_list: self.authorization_list.clone().unwrap_or_default(),
+            ..Default::default()
+        };
+        let _ = tx.derive_tx_type();
+        tx
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcWithdrawal {
+    index: U256,
+    validator_index: U256,
+    address: Address,
+    amount: U256,
+}
+
+impl From<&RpcWithdrawal> for Withdrawal {
+    fn from(withdrawal: &RpcWithdrawal) -> Self {
+        Self {
+            index: as_u64_saturated!(withdrawal.index),
+            validator_index: as_u64_saturated!(withdrawal.validator_index),
+            address: withdrawal.address,
+            amount: as_u64_saturated!(withdrawal.amount),
+        }
+    }
+}
+
+/// A receipt fetched by `eth_getBlockReceipts`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcReceipt {
+    gas_used: U256,
+    status: Option<U256>,
+}
+
+/// The block to replay with its receipts and the chain id of the endpoint, which is cached as
+/// a whole for the offline reruns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReplayBlock {
+    chain_id: U256,
+    block: RpcBlock,
+    receipts: Vec<RpcReceipt>,
+}
+
 #[derive(Debug, Error)]
 #[error("Test {name} suite {suite_name:?} failed: {kind}")]
 pub struct TestError {
@@ -116,6 +244,24 @@ pub enum TestErrorKind {
     LogsRootMismatch { got: B256, expected: B256 },
     #[error("state root mismatch: got {got}, expected {expected}")]
     StateRootMismatch { got: B256, expected: B256 },
+    #[error("rpc database error: {0}")]
+    RpcDB(#[from] dora_runtime::db::RpcDBError),
+    #[error("block cache error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("block execution error: {0}")]
+    BlockExecution(BlockError),
+    #[error("tx {index} gas used mismatch: got {got}, expected {expected}")]
+    GasUsedMismatch {
+        index: usize,
+        got: u64,
+        expected: u64,
+    },
+    #[error("tx {index} status mismatch: got success {got}, expected success {expected}")]
+    StatusMismatch {
+        index: usize,
+        got: bool,
+        expected: bool,
+    },
     #[error("unknown private key: {0:?}")]
     UnknownPrivateKey(B256),
     #[error(transparent)]
@@ -378,6 +524,113 @@ fn execute_test(path: &Path) -> Result<(), TestError> {
     Ok(())
 }
 
+/// Returns the block with the transactions, its receipts and the chain id, which are read from
+/// the cache directory when they are fetched before.
+fn fetch_block(
+    db: &mut RpcDB,
+    number: u64,
+    cache_dir: Option<&Path>,
+) -> Result<ReplayBlock, TestErrorKind> {
+    let path = cache_dir.map(|dir| dir.join(format!("block-{number}.json")));
+    if let Some(path) = path.as_ref().filter(|path| path.exists()) {
+        return Ok(serde_json::from_slice(&std::fs::read(path)?)?);
+    }
+    let tag = format!("{number:#x}");
+    let replay = ReplayBlock {
+        chain_id: db.request("eth_chainId", json!([]))?,
+        block: db.request("eth_getBlockByNumber", json!([tag, true]))?,
+        receipts: db.request("eth_getBlockReceipts", json!([tag]))?,
+    };
+    if let Some(path) = path {
+        std::fs::write(path, serde_json::to_vec(&replay)?)?;
+    }
+    Ok(replay)
+}
+
+/// Replays the block on the state after its parent block with the block executor, i.e., along
+/// with the system calls and the withdrawals, and compares the gas used and the status of the
+/// transactions with the receipts.
+fn replay_block(args: &ReplayArgs, number: u64) -> Result<(), TestError> {
+    let error = |kind: TestErrorKind| TestError {
+        name: format!("block {number}"),
+        suite_name: None,
+        kind,
+    };
+    let mut db = RpcDB::new(args.rpc_url.clone(), number.saturating_sub(1));
+    if let Some(cache_dir) = &args.cache_dir {
+        db = db
+            .with_cache_dir(cache_dir)
+            .map_err(|err| error(err.into()))?;
+    }
+    let ReplayBlock {
+        chain_id,
+        block,
+        receipts,
+    } = fetch_block(&mut db, number, args.cache_dir.as_deref()).map_err(error)?;
+    let timestamp = as_u64_saturated!(block.timestamp);
+    let spec_id = get_block_spec(timestamp, number);
+    let mut block_env = BlockEnv {
+        number: as_u64_saturated!(block.number),
+        beneficiary: block.miner,
+        gas_limit: as_u64_saturated!(block.gas_limit),
+        timestamp,
+        difficulty: block.difficulty,
+        prevrandao: block.mix_hash,
+        basefee: as_u64_saturated!(block.base_fee_per_gas.unwrap_or_default()),
+        ..Default::default()
+    };
+    if let Some(excess_blob_gas) = block.excess_blob_gas {
+        block_env.set_blob_excess_gas_and_price(
+            as_u64_saturated!(excess_blob_gas),
+            spec_id.is_enabled_in(SpecId::PRAGUE),
+        );
+    }
+    let mut env = Env::default();
+    env.cfg.chain_id = as_u64_saturated!(chain_id);
+    env.cfg.spec = spec_id;
+    let mut executor = BlockExecutor::new(VMContext::new(db, env, compile_handler()));
+    // The ommer rewards don't change the receipts of the block, thus the ommers are not fetched.
+    let output = executor
+        .execute_block(Block {
+            env: block_env,
+            transactions: block
+                .transactions
+                .iter()
+                .map(RpcTransaction::tx_env)
+                .collect(),
+            parent_hash: Some(block.parent_hash),
+            parent_beacon_block_root: block.parent_beacon_block_root,
+            withdrawals: block
+                .withdrawals
+                .as_ref()
+                .map(|withdrawals| withdrawals.iter().map(Withdrawal::from).collect()),
+            ..Default::default()
+        })
+        .map_err(|err| error(TestErrorKind::BlockExecution(err)))?;
+    for (index, (result, receipt)) in output.results.iter().zip(&receipts).enumerate() {
+        let expected = as_u64_saturated!(receipt.gas_used);
+        if result.gas_used() != expected {
+            return Err(error(TestErrorKind::GasUsedMismatch {
+                index,
+                got: result.gas_used(),
+                expected,
+            }));
+        }
+        // The receipts before Byzantium have the state root instead of the status.
+        if let Some(status) = receipt.status {
+            if result.is_success() != !status.is_zero() {
+                return Err(error(TestErrorKind::StatusMismatch {
+                    index,
+                    got: result.is_success(),
+                    expected: !status.is_zero(),
+                }));
+            }
+        }
+        info!("replayed block {} tx {}", number, index);
+    }
+    Ok(())
+}
+
 fn get_block_spec(timestamp: u64, block_number: u64) -> SpecId {
     if timestamp >= 1710338135 {
         SpecId::CANCUN
@@ -434,5 +687,21 @@ fn main() -> Result<()> {
             }
             Ok(())
         }
+        Commands::Replay(replay_args) => {
+            let replay_args = replay_args.clone();
+            let builder = std::thread::Builder::new().stack_size(RUNTIME_STACK_SIZE);
+            let handle = builder
+                .spawn(move || {
+                    for number in &replay_args.blocks {
+                        match replay_block(&replay_args, *number) {
+                            Ok(_) => info!("block {} replayed", number),
+                            Err(e) => error!("Replay failed: {:?}", e),
+                        }
+                    }
+                })
+                .unwrap();
+            handle.join().unwrap();
+            Ok(())
+        }
     }
 }
@@ -7,20 +7,20 @@
 //! cargo install --path .
 //! dora-ethertest run tests/GeneralStateTests
 //! ```
-use alloy_rlp::{RlpEncodable, RlpMaxEncodedLen};
 use anyhow::Result;
 use clap::{Args, Parser, Subcommand};
 use dora::compile_handler;
 use dora_primitives::{
-    AccessList, Address, B256, Bytecode, Bytes, Env, GAS_PER_BLOB, Log, SignedAuthorization,
-    SpecId, SpecName, StorageSlot as EvmStorageSlot, TxKind, U256, as_u64_saturated,
-    calc_excess_blob_gas, keccak256,
+    AccessList, Address, B256, Bytecode, Bytes, Env, GAS_PER_BLOB, SignedAuthorization, SpecId,
+    SpecName, StorageSlot as EvmStorageSlot, TxKind, U256, as_u64_saturated, calc_excess_blob_gas,
+    keccak256,
+};
+use dora_runtime::{
+    MemoryDB, RUNTIME_STACK_SIZE, VM, VMContext,
+    trie::{logs_hash, state_root},
 };
-use dora_runtime::{Account, MemoryDB, RUNTIME_STACK_SIZE, VM, VMContext};
 use dora_tools::find_all_json_tests;
-use hash_db::Hasher;
 use indicatif::{ProgressBar, ProgressDrawTarget};
-use plain_hasher::PlainHasher;
 use revm::{ExecuteEvm, MainBuilder, MainContext};
 use serde::{Deserialize, Serialize, de};
 use std::{
@@ -30,7 +30,6 @@ use std::{
 };
 use thiserror::Error;
 use tracing::{error, info};
-use triehash::sec_trie_root;
 
 /// Gas consumption of a single data blob (== blob byte size)
 pub const TARGET_BLOB_NUMBER_PER_BLOCK_CANCUN: u64 = 3;
@@ -201,57 +200,6 @@ pub enum TestErrorKind {
     },
 }
 
-#[inline]
-fn log_rlp_hash(logs: &[Log]) -> B256 {
-    let mut out = Vec::with_capacity(alloy_rlp::list_length(logs));
-    alloy_rlp::encode_list(logs, &mut out);
-    B256::from_slice(keccak256(&out).as_slice())
-}
-
-#[inline]
-pub fn state_merkle_trie_root<'a>(
-    accounts: impl IntoIterator<Item = (&'a Address, &'a Account)>,
-) -> B256 {
-    B256::from_slice(
-        trie_root(accounts.into_iter().map(|(address, acc)| {
-            (
-                address,
-                alloy_rlp::encode_fixed_size(&TrieAccount::new(address, acc)),
-            )
-        }))
-        .as_slice(),
-    )
-}
-
-#[derive(RlpEncodable, RlpMaxEncodedLen)]
-struct TrieAccount {
-    nonce: u64,
-    balance: U256,
-    root_hash: B256,
-    code_hash: B256,
-}
-
-impl TrieAccount {
-    fn new(_address: &Address, acc: &Account) -> Self {
-        Self {
-            nonce: acc.info.nonce,
-            balance: acc.info.balance,
-            root_hash: sec_trie_root::<KeccakHasher, _, _, _>(
-                acc.storage
-                    .iter()
-                    .filter(|(_k, v)| !v.present_value.is_zero())
-                    .map(|(k, v)| {
-                        (
-                            k.to_be_bytes::<32>(),
-                            alloy_rlp::encode_fixed_size(&v.present_value),
-                        )
-                    }),
-            ),
-            code_hash: acc.info.code_hash,
-        }
-    }
-}
-
 /// This type keeps track of the current value of a storage slot.
 #[derive(
     Debug, Copy, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
@@ -306,30 +254,6 @@ impl StorageSlot {
     }
 }
 
-#[inline]
-pub fn trie_root<I, A, B>(input: I) -> B256
-where
-    I: IntoIterator<Item = (A, B)>,
-    A: AsRef<[u8]>,
-    B: AsRef<[u8]>,
-{
-    sec_trie_root::<KeccakHasher, _, _, _>(input)
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
-pub struct KeccakHasher;
-
-impl Hasher for KeccakHasher {
-    type Out = B256;
-    type StdHasher = PlainHasher;
-    const LENGTH: usize = 32;
-
-    #[inline]
-    fn hash(x: &[u8]) -> Self::Out {
-        keccak256(x)
-    }
-}
-
 fn should_skip(path: &Path) -> bool {
     let path_str = path.to_str().expect("Path is not valid UTF-8");
     let name = path.file_name().unwrap().to_str().unwrap();
@@ -544,7 +468,7 @@ fn execute_test(path: &Path) -> Result<(), TestError> {
                 let mut vm = VM::new(VMContext::new(db.clone(), env, compile_handler()));
                 let res = vm.transact_commit();
                 // Calculate the logs root.
-                let logs_root = log_rlp_hash(res.as_ref().map(|r| r.logs()).unwrap_or_default());
+                let logs_root = logs_hash(res.as_ref().map(|r| r.logs()).unwrap_or_default());
                 // Check result and output.
                 match res {
                     Ok(res) => {
@@ -623,7 +547,7 @@ fn execute_test(path: &Path) -> Result<(), TestError> {
                         let state_list = db_state.iter().filter(|(_, acc)| {
                             !acc.is_loaded_as_not_existing() || acc.is_touched() && !acc.is_empty()
                         });
-                        let state_root = state_merkle_trie_root(state_list);
+                        let state_root = state_root(state_list);
                         if state_root != test_case.hash {
                             let kind = TestErrorKind::StateRootMismatch {
                                 got: state_root,
@@ -0,0 +1,132 @@
+//! Access list generation with the `eth_createAccessList` semantics, the EIP-2930 access list is
+//! built from the accounts and storage slots loaded into the journal by the transaction.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use dora_primitives::{
+    AccessList, AccessListItem, Address, B256, Env, EvmState, HashSet, TxKind, spec::SpecId,
+};
+use dora_runtime::{
+    db::Database,
+    precompile::ContextPrecompiles,
+    result::{ExecutionResult, VMError},
+    simulate::Simulation,
+};
+
+use crate::compile_handler;
+
+/// The max number of the runs with the generated access list before it is stable.
+pub const MAX_ACCESS_LIST_ITERATIONS: usize = 16;
+
+/// The output of the access list generation.
+///
+/// Note that the access list is not guaranteed to save gas, each listed account or slot saves only
+/// 100 gas when it is accessed, while the always warm recipient still costs 2400 gas when its
+/// storage slots are listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListOutput {
+    /// The accessed accounts and storage slots sorted by the addresses and the keys.
+    pub access_list: AccessList,
+    /// The execution result with the access list.
+    pub result: ExecutionResult,
+    /// The gas used with the access list.
+    pub gas_used: u64,
+    /// The gas used without an access list.
+    pub gas_used_without_access_list: u64,
+}
+
+/// Creates the access list of the transaction of the environment, the access list of the
+/// transaction is ignored. The nonce of the caller is not checked.
+#[inline]
+pub fn create_access_list<DB: Database>(
+    env: Env,
+    db: &mut DB,
+) -> Result<AccessListOutput, VMError> {
+    create_access_list_with_simulation(env, db, Simulation::new().disable_nonce_check())
+}
+
+/// Creates the access list of the transaction of the environment with the simulation overrides
+/// and checks, the access list of the transaction is ignored.
+///
+/// The sender, the recipient, the precompiles and, since Shanghai, the coinbase are always warm,
+/// thus they are only included when their storage slots are accessed. The transaction is executed
+/// again with the generated access list until the list is stable, since the warm accounts and
+/// slots may change the execution, e.g., by the gas left checked by the contract. An error is
+/// returned when the list is still changing after [`MAX_ACCESS_LIST_ITERATIONS`] runs.
+pub fn create_access_list_with_simulation<DB: Database>(
+    mut env: Env,
+    db: &mut DB,
+    simulation: Simulation,
+) -> Result<AccessListOutput, VMError> {
+    let precompiles = ContextPrecompiles::from_spec_id(env.cfg.spec);
+    let excluded = excluded_addresses(&env, &precompiles);
+    env.tx.access_list = AccessList::default();
+    let output = simulation
+        .clone()
+        .run(&mut *db, env.clone(), compile_handler())?;
+    let gas_used_without_access_list = output.result.gas_used();
+    let mut access_list = collect_access_list(&output.state, &excluded);
+    for _ in 0..MAX_ACCESS_LIST_ITERATIONS {
+        env.tx.access_list = access_list.clone();
+        let output = simulation
+            .clone()
+            .run(&mut *db, env.clone(), compile_handler())?;
+        let next = collect_access_list(&output.state, &excluded);
+        if next == access_list {
+            return Ok(AccessListOutput {
+                access_list,
+                gas_used: output.result.gas_used(),
+                result: output.result,
+                gas_used_without_access_list,
+            });
+        }
+        access_list = next;
+    }
+    Err(VMError::Handler(format!(
+        "access list is not stable after {MAX_ACCESS_LIST_ITERATIONS} iterations"
+    )))
+}
+
+/// Returns the addresses which are warm without the access list, i.e., the addresses preloaded
+/// into the journal by the context.
+fn excluded_addresses(env: &Env, precompiles: &ContextPrecompiles) -> HashSet<Address> {
+    let recipient = match env.tx.kind {
+        TxKind::Call(address) => address,
+        TxKind::Create => env.tx.caller.create(env.tx.nonce),
+    };
+    let mut excluded = precompiles
+        .addresses()
+        .copied()
+        .chain([env.tx.caller, recipient])
+        .collect::<HashSet<_>>();
+    // EIP-3651: Warm COINBASE
+    if env.cfg.spec.is_enabled_in(SpecId::SHANGHAI) {
+        excluded.insert(env.block.beneficiary);
+    }
+    excluded
+}
+
+/// Returns the access list of the accounts and storage slots loaded into the state.
+fn collect_access_list(state: &EvmState, excluded: &HashSet<Address>) -> AccessList {
+    let accessed = state
+        .iter()
+        .map(|(address, account)| {
+            let keys = account
+                .storage
+                .keys()
+                .map(|key| B256::from(*key))
+                .collect::<BTreeSet<_>>();
+            (*address, keys)
+        })
+        .filter(|(address, keys)| !keys.is_empty() || !excluded.contains(address))
+        .collect::<BTreeMap<_, _>>();
+    AccessList(
+        accessed
+            .into_iter()
+            .map(|(address, keys)| AccessListItem {
+                address,
+                storage_keys: keys.into_iter().collect(),
+            })
+            .collect(),
+    )
+}
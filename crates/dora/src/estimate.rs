@@ -0,0 +1,139 @@
+//! Gas estimation with the `eth_estimateGas` semantics, the transaction is simulated at the gas
+//! cap and the minimal gas limit that succeeds is searched by bisection.
+//!
+//! All the simulations run on the same artifact cache, thus the contracts are only compiled by
+//! the first run.
+
+use core::fmt;
+
+use dora_primitives::{Bytes, Env};
+use dora_runtime::{
+    constants::gas_cost::CALL_STIPEND,
+    db::Database,
+    result::{ExecutionResult, HaltReason, VMError},
+    simulate::Simulation,
+};
+
+use crate::compile_handler;
+
+/// Errors of the gas estimation, which are reported by the run at the gas cap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EstimateGasError {
+    /// The transaction reverts with the output.
+    Revert { output: Bytes, gas_used: u64 },
+    /// The transaction runs out of gas at the gas cap.
+    OutOfGas { gas_limit: u64 },
+    /// The transaction halts with a reason other than out of gas.
+    Halt { reason: HaltReason, gas_used: u64 },
+    /// The transaction is invalid or the database fails.
+    VM(VMError),
+}
+
+impl fmt::Display for EstimateGasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Revert { output, .. } => write!(f, "execution reverted: {}", output),
+            Self::OutOfGas { gas_limit } => {
+                write!(
+                    f,
+                    "out of gas: gas required exceeds allowance {}",
+                    gas_limit
+                )
+            }
+            Self::Halt { reason, .. } => write!(f, "execution halted: {:?}", reason),
+            Self::VM(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<VMError> for EstimateGasError {
+    fn from(value: VMError) -> Self {
+        Self::VM(value)
+    }
+}
+
+/// Estimates the minimal gas limit of the transaction of the environment, the gas limit of the
+/// transaction is the gas cap. The nonce of the caller is not checked.
+#[inline]
+pub fn estimate_gas<DB: Database>(env: Env, db: &mut DB) -> Result<u64, EstimateGasError> {
+    estimate_gas_with_simulation(env, db, Simulation::new().disable_nonce_check())
+}
+
+/// Estimates the minimal gas limit of the transaction of the environment with the simulation
+/// overrides and checks, the gas limit of the transaction is the gas cap.
+pub fn estimate_gas_with_simulation<DB: Database>(
+    mut env: Env,
+    db: &mut DB,
+    simulation: Simulation,
+) -> Result<u64, EstimateGasError> {
+    let cap = env.tx.gas_limit;
+    let (gas_used, gas_refunded) = match run_with_gas_limit(&mut env, db, &simulation, cap)? {
+        ExecutionResult::Success {
+            gas_used,
+            gas_refunded,
+            ..
+        } => (gas_used, gas_refunded),
+        ExecutionResult::Revert { gas_used, output } => {
+            return Err(EstimateGasError::Revert { output, gas_used });
+        }
+        ExecutionResult::Halt {
+            reason: HaltReason::OutOfGas(_),
+            ..
+        } => return Err(EstimateGasError::OutOfGas { gas_limit: cap }),
+        ExecutionResult::Halt { reason, gas_used } => {
+            return Err(EstimateGasError::Halt { reason, gas_used });
+        }
+    };
+    // The refunds are only paid back after the execution, so the gas spent before the refunds
+    // is the lower bound.
+    let mut lo = (gas_used + gas_refunded).saturating_sub(1);
+    let mut hi = cap;
+    // Most transactions succeed with the spent gas plus the 1/64 retained by the calls and the
+    // call stipend, try it before the bisection.
+    let optimistic = (gas_used + gas_refunded + CALL_STIPEND) * 64 / 63;
+    if optimistic < hi {
+        if succeeds(&mut env, db, &simulation, optimistic)? {
+            hi = optimistic;
+        } else {
+            lo = optimistic;
+        }
+    }
+    while lo + 1 < hi {
+        // Most transactions need a gas limit close to the lower bound.
+        let mid = ((lo + hi) / 2).min(lo.saturating_mul(2)).max(lo + 1);
+        if succeeds(&mut env, db, &simulation, mid)? {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    Ok(hi)
+}
+
+/// Returns `true` if the transaction succeeds with the gas limit, the failures caused by the
+/// low gas limit, e.g., the intrinsic gas or the out of gas reverts in the calls, are `false`.
+fn succeeds<DB: Database>(
+    env: &mut Env,
+    db: &mut DB,
+    simulation: &Simulation,
+    gas_limit: u64,
+) -> Result<bool, EstimateGasError> {
+    match run_with_gas_limit(env, db, simulation, gas_limit) {
+        Ok(result) => Ok(result.is_success()),
+        Err(EstimateGasError::VM(VMError::Transaction(_))) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+fn run_with_gas_limit<DB: Database>(
+    env: &mut Env,
+    db: &mut DB,
+    simulation: &Simulation,
+    gas_limit: u64,
+) -> Result<ExecutionResult, EstimateGasError> {
+    env.tx.gas_limit = gas_limit;
+    let result = simulation
+        .clone()
+        .run(&mut *db, env.clone(), compile_handler())?;
+    Ok(result.result)
+}
@@ -1,5 +1,8 @@
+mod access_list;
+mod estimate;
 #[cfg(test)]
 mod tests;
+mod tiered;
 
 pub use dora_compiler as compiler;
 pub use dora_ir as ir;
@@ -8,6 +11,10 @@ pub use dora_primitives::IsWASMBytecode;
 pub use dora_runtime as runtime;
 use dora_runtime::DatabaseCommit;
 
+pub use access_list::{
+    AccessListOutput, MAX_ACCESS_LIST_ITERATIONS, create_access_list,
+    create_access_list_with_simulation,
+};
 pub use dora_compiler::{
     Compiler,
     context::Context,
@@ -17,24 +24,35 @@ pub use dora_compiler::{
     wasm::{self, WASMCompileOptions, WASMCompiler},
 };
 pub use dora_primitives::{
-    Bytecode, Bytes, Bytes32, EVMBytecode, Env, TxKind, WASMBytecode, spec::SpecId,
+    B256, Bytecode, Bytes, Bytes32, CodegenOptions, EVMBytecode, Env, OptimizationLevel, TxKind,
+    WASMBytecode, spec::SpecId,
 };
 pub use dora_runtime::context::RuntimeContext;
 pub use dora_runtime::executor::{ExecuteKind, Executor};
 pub use dora_runtime::stack::Stack;
 pub use dora_runtime::{
+    aot::{AotCache, AotCacheKey, AotCacheStats},
     artifact::{Artifact, SymbolArtifact},
+    cache::{ArtifactCache, ArtifactKey, CacheStats},
     call::CallResult,
     context::VMContext,
+    gas::GasSchedule,
     handler::{Frame, Handler},
+    inspector::{Inspector, StepState},
+    interpreter::interpret,
     result::{ExecutionResult, VMError},
+    scheduler::{FrameScheduler, SuspendedFrame},
+    simulate::{AccountOverride, BlockOverrides, Simulation, StorageOverride},
     vm::VM,
 };
 pub use dora_runtime::{
     db::{Database, MemoryDB},
     result::ResultAndState,
 };
-use std::sync::Arc;
+pub use estimate::{EstimateGasError, estimate_gas, estimate_gas_with_simulation};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+pub use tiered::{TieredCompiler, TieredConfig};
 
 /// Run EVM or WASM with the environment configuration for the execution, given state database and return the execution result and final state.
 ///
@@ -58,48 +76,236 @@ pub fn run<DB: Database + DatabaseCommit + 'static>(
     VM::new(VMContext::new(db, env, compile_handler())).transact_commit()
 }
 
-/// Compile Handler for the VM.
+/// Simulates the transaction of the environment with the state and block overrides, the state
+/// changes are returned without being committed into the database.
+#[inline]
+pub fn simulate<DB: Database + 'static>(
+    env: Env,
+    db: DB,
+    simulation: Simulation,
+) -> Result<ResultAndState, VMError> {
+    simulation.run(db, env, compile_handler())
+}
+
+/// Compile Handler for the VM, the compiled artifacts are shared by all the VMs in the process
+/// through [`ArtifactCache::global`].
 #[inline]
 pub fn compile_handler<DB: Database>() -> Handler<DB> {
+    compile_handler_with_cache(ArtifactCache::global())
+}
+
+/// Compile Handler for the VM with the artifact cache, the VMs created with the same cache never
+/// compile the same code twice.
+pub fn compile_handler_with_cache<DB: Database>(cache: Arc<ArtifactCache>) -> Handler<DB> {
+    compile_handler_with_codegen(cache, CodegenOptions::default())
+}
+
+/// Compile Handler for the VM with the artifact cache and the LLVM code generation options, e.g.,
+/// the optimization level and the target CPU. The options are a part of the artifact key, thus
+/// the handlers with different options on the same cache never share the artifacts.
+pub fn compile_handler_with_codegen<DB: Database>(
+    cache: Arc<ArtifactCache>,
+    codegen: CodegenOptions,
+) -> Handler<DB> {
     Handler {
-        call_handler: Arc::new(|frame, ctx| {
+        call_handler: Arc::new(move |frame, ctx| {
             // When meets empty account code, just return the default call result.
             if frame.contract.code.is_empty() {
                 return Ok(CallResult::new_with_gas_limit(frame.gas_limit));
             }
             let code_hash = frame.contract.hash.unwrap_or_default();
-            let spec_id = ctx.spec_id();
-            // When code hash is empty, we do not save the artifact
+            // When an inspector is attached, compile the EVM code with the tracing hooks. The traced
+            // artifact is cached under its own key as it is slower than the normal one.
+            let key = ctx
+                .artifact_key(code_hash)
+                .with_codegen(&codegen)
+                .with_tracing(ctx.is_inspecting() && !frame.contract.code.is_wasm());
             let artifact = if !code_hash.is_zero() {
-                let artifact = ctx.get_artifact(code_hash);
-                if let Ok(Some(artifact)) = artifact {
-                    artifact
-                } else {
-                    let artifact = build_artifact::<DB>(&frame.contract.code, ctx.spec_id())
-                        .map_err(|e| VMError::Compile(e.to_string()))?;
-                    ctx.set_artifact(code_hash, artifact.clone());
-                    artifact
-                }
+                let code = &frame.contract.code;
+                ctx.handler
+                    .artifact_cache
+                    .get_or_try_insert_with(key, ArtifactCache::estimated_size(code), || {
+                        compile_artifact(code, key, &codegen)
+                    })
+                    .map_err(|e| VMError::Compile(e.to_string()))?
             } else {
-                build_artifact::<DB>(&frame.contract.code, ctx.spec_id())
+                // When code hash is empty, we do not save the artifact
+                build_artifact_with_key::<DB>(&frame.contract.code, key, &codegen)
                     .map_err(|e| VMError::Compile(e.to_string()))?
             };
-            let runtime_context = RuntimeContext::new(
-                frame.contract,
-                frame.depth,
-                frame.is_static,
-                frame.is_eof_init,
-                ctx,
-                spec_id,
-                frame.gas_limit,
-            );
+            execute_artifact(artifact, frame, ctx)
+        }),
+        artifact_cache: cache,
+        suspend_handler: None,
+        lifecycle: Default::default(),
+    }
+}
+
+/// Compile Handler for the VM which drives the call frames of the EVM code in a loop with the
+/// [`FrameScheduler`], the EVM code is compiled with the suspend mode thus the native stack usage
+/// does not grow with the call depth. The WASM and traced code still runs with the call frame
+/// handler of [`compile_handler`].
+#[inline]
+pub fn suspend_compile_handler<DB: Database>() -> Handler<DB> {
+    suspend_compile_handler_with_codegen(ArtifactCache::global(), CodegenOptions::default())
+}
+
+/// Compile Handler for the VM like [`suspend_compile_handler`] with the artifact cache and the
+/// LLVM code generation options, which apply to both the suspended and the call frame artifacts.
+pub fn suspend_compile_handler_with_codegen<DB: Database>(
+    cache: Arc<ArtifactCache>,
+    codegen: CodegenOptions,
+) -> Handler<DB> {
+    compile_handler_with_codegen::<DB>(cache, codegen.clone()).with_suspend_handler(Arc::new(
+        move |frame, ctx| {
+            let code = &frame.contract.code;
+            if code.is_empty() || ctx.is_inspecting() || code.is_wasm() {
+                return Ok(None);
+            }
+            let code_hash = frame.contract.hash.unwrap_or_default();
+            let key = ctx
+                .artifact_key(code_hash)
+                .with_suspend(true)
+                .with_codegen(&codegen);
+            let artifact = if code_hash.is_zero() {
+                build_artifact_with_key::<DB>(code, key, &codegen)
+            } else {
+                ctx.handler.artifact_cache.get_or_try_insert_with(
+                    key,
+                    ArtifactCache::estimated_size(code),
+                    || compile_artifact(code, key, &codegen),
+                )
+            };
             artifact
-                .execute(runtime_context)
-                .map_err(|err| VMError::Handler(err.to_string()))
+                .map(Some)
+                .map_err(|e| VMError::Compile(e.to_string()))
+        },
+    ))
+}
+
+/// Compile Handler for the VM with tiered execution, the legacy EVM code runs on the interpreter
+/// until its native artifact is compiled in the background by the tiered compiler.
+pub fn tiered_compile_handler<DB: Database>(tiered: Arc<TieredCompiler>) -> Handler<DB> {
+    let cache = tiered.cache().clone();
+    let compile =
+        compile_handler_with_codegen::<DB>(cache.clone(), tiered.config().codegen.clone())
+            .call_handler;
+    Handler {
+        call_handler: Arc::new(move |frame, ctx| {
+            let code = &frame.contract.code;
+            // The traced, WASM and EOF code is always compiled, so is the code since Osaka or
+            // under a custom gas schedule as the interpreter only implements the Ethereum rules
+            // before Osaka, e.g., the `CLZ` opcode is missing.
+            if code.is_empty()
+                || ctx.is_inspecting()
+                || code.is_wasm()
+                || code.is_eof()
+                || ctx.spec_id().is_enabled_in(SpecId::OSAKA)
+                || ctx.gas_schedule() != GasSchedule::new(ctx.spec_id())
+            {
+                return compile(frame, ctx);
+            }
+            let code_hash = frame.contract.hash.unwrap_or_default();
+            // The code without hash e.g., the `CREATE` init code can't be cached, it usually runs
+            // only once thus is always interpreted.
+            if code_hash.is_zero() {
+                return interpret(frame, ctx);
+            }
+            let key = ctx
+                .artifact_key(code_hash)
+                .with_codegen(&tiered.config().codegen);
+            let artifact = tiered
+                .artifact(key, code)
+                .map_err(|e| VMError::Compile(e.to_string()))?;
+            match artifact {
+                Some(artifact) => execute_artifact(artifact, frame, ctx),
+                None => interpret(frame, ctx),
+            }
         }),
+        artifact_cache: cache,
+        suspend_handler: None,
+        lifecycle: Default::default(),
+    }
+}
+
+/// Executes the frame with the compiled artifact.
+fn execute_artifact<DB: Database>(
+    artifact: SymbolArtifact,
+    frame: Frame,
+    ctx: &mut VMContext<DB>,
+) -> Result<CallResult, VMError> {
+    let spec_id = ctx.spec_id();
+    let runtime_context = RuntimeContext::new(
+        frame.contract,
+        frame.depth,
+        frame.is_static,
+        frame.is_eof_init,
+        ctx,
+        spec_id,
+        frame.gas_limit,
+    );
+    artifact
+        .execute(runtime_context)
+        .map_err(|err| VMError::Handler(err.to_string()))
+}
+
+/// Compiles the code to the native artifact with the code generation options, the native object
+/// code is loaded from or saved into the AOT cache when it is enabled.
+pub(crate) fn compile_artifact(
+    code: &Bytecode,
+    key: ArtifactKey,
+    codegen: &CodegenOptions,
+) -> anyhow::Result<SymbolArtifact> {
+    match aot_cache() {
+        Some(cache) if code.is_wasm() => build_wasm_artifact_with_aot_cache(
+            code.bytecode(),
+            key.code_hash,
+            key.spec_id,
+            WASMCompileOptions::default().codegen(codegen.clone()),
+            cache,
+        ),
+        Some(cache) => build_evm_artifact_with_aot_cache(
+            code,
+            key.code_hash,
+            evm_compile_options(key, codegen),
+            cache,
+        ),
+        // The database type is not used by the compiler.
+        None => build_artifact_with_key::<MemoryDB>(code, key, codegen),
+    }
+}
+
+/// Build the EVM or WASM bytecode to the native artifact with the spec, gas schedule, suspend and
+/// tracing mode of the artifact key.
+fn build_artifact_with_key<DB: Database>(
+    code: &Bytecode,
+    key: ArtifactKey,
+    codegen: &CodegenOptions,
+) -> anyhow::Result<SymbolArtifact> {
+    if code.is_wasm() {
+        build_artifact_with_codegen::<DB>(code, key.spec_id, codegen)
+    } else {
+        build_evm_artifact::<DB>(code, evm_compile_options(key, codegen))
     }
 }
 
+/// Returns the EVM compile options of the artifact key and the code generation options.
+fn evm_compile_options(key: ArtifactKey, codegen: &CodegenOptions) -> EVMCompileOptions {
+    EVMCompileOptions::default()
+        .spec_id(key.spec_id)
+        .gas_schedule(key.gas_schedule)
+        .suspend(key.suspend)
+        .tracing(key.tracing)
+        .codegen(codegen.clone())
+}
+
+/// Returns the process-wide AOT cache, which is enabled by setting the `DORA_AOT_CACHE_DIR`
+/// environment variable to the cache directory.
+pub fn aot_cache() -> Option<&'static AotCache> {
+    static AOT_CACHE: OnceLock<Option<AotCache>> = OnceLock::new();
+    AOT_CACHE.get_or_init(AotCache::from_env).as_ref()
+}
+
 /// Run hex-encoded EVM or WASM bytecode with custom calldata and return the execution result and final state.
 ///
 /// # Arguments
@@ -151,20 +357,146 @@ pub fn run_with_context<DB: Database>(
 pub fn build_artifact<DB: Database>(
     code: &Bytecode,
     spec_id: SpecId,
+) -> anyhow::Result<SymbolArtifact> {
+    build_artifact_with_codegen::<DB>(code, spec_id, &CodegenOptions::default())
+}
+
+/// Build the EVM or WASM bytecode to the native artifact with the LLVM code generation options.
+pub fn build_artifact_with_codegen<DB: Database>(
+    code: &Bytecode,
+    spec_id: SpecId,
+    codegen: &CodegenOptions,
 ) -> anyhow::Result<SymbolArtifact> {
     if code.is_wasm() {
-        build_wasm_artifact::<DB>(code.bytecode(), WASMCompileOptions::default())
+        build_wasm_artifact::<DB>(
+            code.bytecode(),
+            WASMCompileOptions::default().codegen(codegen.clone()),
+        )
     } else {
-        build_evm_artifact::<DB>(code, EVMCompileOptions::default().spec_id(spec_id))
+        build_evm_artifact::<DB>(
+            code,
+            EVMCompileOptions::default()
+                .spec_id(spec_id)
+                .codegen(codegen.clone()),
+        )
     }
 }
 
+/// Build the EVM or WASM bytecode to the native artifact, the native object code is loaded from the
+/// AOT cache when it is found, otherwise the code is compiled and saved into the cache.
+pub fn build_artifact_with_aot_cache<DB: Database>(
+    code: &Bytecode,
+    code_hash: B256,
+    spec_id: SpecId,
+    cache: &AotCache,
+) -> anyhow::Result<SymbolArtifact> {
+    if code.is_wasm() {
+        build_wasm_artifact_with_aot_cache(
+            code.bytecode(),
+            code_hash,
+            spec_id,
+            WASMCompileOptions::default(),
+            cache,
+        )
+    } else {
+        build_evm_artifact_with_aot_cache(
+            code,
+            code_hash,
+            EVMCompileOptions::default().spec_id(spec_id),
+            cache,
+        )
+    }
+}
+
+/// Build the EVM bytecode to the artifact with the compile options, the native object code is
+/// loaded from the AOT cache when it is found, otherwise it is saved into the cache.
+pub fn build_evm_artifact_with_aot_cache(
+    code: &EVMBytecode,
+    code_hash: B256,
+    opts: EVMCompileOptions,
+    cache: &AotCache,
+) -> anyhow::Result<SymbolArtifact> {
+    // The cached objects are executed by this process as well.
+    pass::check_host_target(&opts.codegen)?;
+    let key = AotCacheKey::new(code_hash, opts.spec_id, opts.cache_key());
+    if let Some(executor) = cache.load(&key, ExecuteKind::EVM) {
+        return Ok(SymbolArtifact::new(executor));
+    }
+    let executor = build_evm_executor(code, opts, true)?;
+    // The cache is best-effort, the artifact is still usable when it fails to be saved.
+    let _ = cache.store(&key, &executor);
+    Ok(SymbolArtifact::new(executor))
+}
+
+/// Build the WASM bytecode to the artifact with the compile options, the native object code is
+/// loaded from the AOT cache when it is found, otherwise it is saved into the cache.
+pub fn build_wasm_artifact_with_aot_cache(
+    code: &WASMBytecode,
+    code_hash: B256,
+    spec_id: SpecId,
+    opts: WASMCompileOptions,
+    cache: &AotCache,
+) -> anyhow::Result<SymbolArtifact> {
+    let Some(options) = opts.cache_key() else {
+        return Ok(SymbolArtifact::new(build_wasm_executor(code, opts, false)?));
+    };
+    // The cached objects are executed by this process as well.
+    pass::check_host_target(&opts.codegen)?;
+    let key = AotCacheKey::new(code_hash, spec_id, options);
+    if cache.contains(&key) {
+        let context = Context::new();
+        let instance = WASMCompiler::new(&context, opts.clone()).build_instance(code)?;
+        if let Some(executor) = cache.load(&key, ExecuteKind::new_wasm(instance)) {
+            return Ok(SymbolArtifact::new(executor));
+        }
+    }
+    let executor = build_wasm_executor(code, opts, true)?;
+    // The cache is best-effort, the artifact is still usable when it fails to be saved.
+    let _ = cache.store(&key, &executor);
+    Ok(SymbolArtifact::new(executor))
+}
+
 /// Build the EVM bytecode to the artifact
 pub fn build_evm_artifact<DB: Database>(
     code: &EVMBytecode,
     opts: EVMCompileOptions,
 ) -> anyhow::Result<SymbolArtifact> {
+    Ok(SymbolArtifact::new(build_evm_executor(code, opts, false)?))
+}
+
+/// Build the EVM bytecode to the executor, the native object code can be dumped from the executor
+/// when `enable_object_dump` is set. The target of the code generation options must run on the
+/// host CPU since the executor runs the JIT code.
+fn build_evm_executor(
+    code: &EVMBytecode,
+    opts: EVMCompileOptions,
+    enable_object_dump: bool,
+) -> anyhow::Result<Executor> {
+    pass::check_host_target(&opts.codegen)?;
+    compile_evm_executor(code, opts, enable_object_dump)
+}
+
+/// Build the EVM bytecode to a native object file with the compile options, the target CPU and
+/// target features may be foreign to the host, e.g., to compile the AOT objects for other
+/// machines, since the object code is not executed by this process.
+pub fn build_evm_object_file(
+    code: &EVMBytecode,
+    opts: EVMCompileOptions,
+    path: &Path,
+) -> anyhow::Result<()> {
+    compile_evm_executor(code, opts, true)?.dump_to_object_file(path);
+    Ok(())
+}
+
+/// Compiles the EVM bytecode to the executor without checking the target against the host.
+fn compile_evm_executor(
+    code: &EVMBytecode,
+    opts: EVMCompileOptions,
+    enable_object_dump: bool,
+) -> anyhow::Result<Executor> {
     let spec_id = opts.spec_id;
+    let gas_schedule = opts.gas_schedule;
+    let codegen = opts.codegen.clone();
     // Compile the contract code
     let program = Program::from_opcodes(code.original_byte_slice(), code.eof().cloned());
     let context = Context::new();
@@ -178,13 +510,19 @@ pub fn build_evm_artifact<DB: Database>(
         &dora::pass::PassOptions {
             code_size: program.code_size(),
             spec_id,
+            gas_schedule,
             ..Default::default()
         },
     )?;
     pass::run(&context.mlir_context, &mut module.mlir_module)?;
+    pass::set_target(&context.mlir_context, &mut module.mlir_module, &codegen)?;
     debug_assert!(module.mlir_module.as_operation().verify());
-    let executor = Executor::new(module.module(), Default::default(), ExecuteKind::EVM);
-    Ok(SymbolArtifact::new(executor))
+    Ok(Executor::new_with_object_dump(
+        module.module(),
+        codegen.opt_level,
+        ExecuteKind::EVM,
+        enable_object_dump,
+    ))
 }
 
 /// Build WASM opcode to the artifact
@@ -192,6 +530,19 @@ pub fn build_wasm_artifact<DB: Database>(
     code: &WASMBytecode,
     opts: WASMCompileOptions,
 ) -> anyhow::Result<SymbolArtifact> {
+    Ok(SymbolArtifact::new(build_wasm_executor(code, opts, false)?))
+}
+
+/// Build WASM opcode to the executor, the native object code can be dumped from the executor
+/// when `enable_object_dump` is set. The target of the code generation options must run on the
+/// host CPU since the executor runs the JIT code.
+fn build_wasm_executor(
+    code: &WASMBytecode,
+    opts: WASMCompileOptions,
+    enable_object_dump: bool,
+) -> anyhow::Result<Executor> {
+    pass::check_host_target(&opts.codegen)?;
+    let codegen = opts.codegen.clone();
     let context = Context::new();
     let compiler = WASMCompiler::new(&context, opts);
     // Compile WASM Bytecode to MLIR WASM Dialect
@@ -209,12 +560,13 @@ pub fn build_wasm_artifact<DB: Database>(
         },
     )?;
     pass::run(&context.mlir_context, &mut module.mlir_module)?;
+    pass::set_target(&context.mlir_context, &mut module.mlir_module, &codegen)?;
     debug_assert!(module.mlir_module.as_operation().verify());
 
-    let executor = Executor::new(
+    Ok(Executor::new_with_object_dump(
         module.module(),
-        Default::default(),
+        codegen.opt_level,
         ExecuteKind::new_wasm(instance),
-    );
-    Ok(SymbolArtifact::new(executor))
+        enable_object_dump,
+    ))
 }
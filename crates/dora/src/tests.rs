@@ -1,9 +1,29 @@
 use crate::run_bytecode_hex;
 use dora_primitives::spec::SpecId;
 
+mod access_list;
+mod aot;
+mod block;
 mod bytecode;
+mod cache;
+mod codegen;
+mod database;
+mod estimate;
+mod file_db;
+mod gas_schedule;
+mod inspector;
+mod lifecycle;
 mod operations;
+#[cfg(feature = "optimism")]
+mod optimism;
+mod osaka;
+mod parallel;
+mod precompile;
 mod results;
+mod rpc_db;
+mod simulate;
+mod suspend;
+mod tiered;
 pub(crate) mod utils;
 mod wasm;
 
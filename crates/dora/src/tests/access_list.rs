@@ -0,0 +1,100 @@
+use dora_compiler::evm::program::Operation;
+use dora_primitives::{AccessListItem, Address, B256, U256, address, spec::SpecId};
+use num_bigint::BigUint;
+
+use crate::create_access_list;
+use crate::tests::utils::default_env_and_db_setup;
+
+const OTHER: Address = address!("0000000000000000000000000000000000000099");
+
+/// Reads the storage slot 1 of the contract and the balance of the other account and the
+/// identity precompile.
+fn access_operations() -> Vec<Operation> {
+    let balance = |address: Address| {
+        [
+            Operation::Push((20_u8, BigUint::from_bytes_be(address.as_slice()))),
+            Operation::Balance,
+            Operation::Pop,
+        ]
+    };
+    let mut operations = vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::SLoad,
+        Operation::Pop,
+    ];
+    operations.extend(balance(OTHER));
+    operations.extend(balance(address!(
+        "0000000000000000000000000000000000000004"
+    )));
+    operations.push(Operation::Stop);
+    operations
+}
+
+#[test]
+fn test_create_access_list() {
+    let (mut env, mut db) = default_env_and_db_setup(access_operations());
+    env.tx.tx_type = 1;
+    env.tx.chain_id = Some(1);
+    env.tx.gas_limit = 1_000_000;
+    let contract = env.tx.kind.to().copied().unwrap();
+    let output = create_access_list(env, &mut db).unwrap();
+    assert_eq!(
+        output.access_list.0,
+        vec![
+            AccessListItem {
+                address: contract,
+                storage_keys: vec![B256::from(U256::from(1))],
+            },
+            AccessListItem {
+                address: OTHER,
+                storage_keys: vec![],
+            },
+        ]
+    );
+    assert!(output.result.is_success(), "{:?}", output.result);
+    // With the access list: the contract item and its slot (2400 + 1900), the other account item
+    // (2400), and the warm SLOAD and BALANCE (100 + 100). Without it: the cold SLOAD and BALANCE
+    // (2100 + 2600).
+    assert_eq!(
+        output.gas_used,
+        output.gas_used_without_access_list + 6900 - 4700
+    );
+}
+
+#[test]
+fn test_create_access_list_empty() {
+    let (mut env, mut db) = default_env_and_db_setup(vec![Operation::Stop]);
+    env.tx.gas_limit = 1_000_000;
+    let output = create_access_list(env, &mut db).unwrap();
+    assert!(output.access_list.0.is_empty());
+    assert_eq!(output.gas_used, output.gas_used_without_access_list);
+}
+
+#[test]
+fn test_create_access_list_coinbase() {
+    let operations = vec![
+        Operation::Coinbase,
+        Operation::Balance,
+        Operation::Pop,
+        Operation::Stop,
+    ];
+    for (spec_id, listed) in [(SpecId::MERGE, true), (SpecId::SHANGHAI, false)] {
+        let (mut env, mut db) = default_env_and_db_setup(operations.clone());
+        env.cfg.spec = spec_id;
+        env.tx.tx_type = 1;
+        env.tx.chain_id = Some(1);
+        env.tx.gas_limit = 1_000_000;
+        let coinbase = env.block.beneficiary;
+        let output = create_access_list(env, &mut db).unwrap();
+        // The coinbase is warm only since Shanghai (EIP-3651).
+        let expected = if listed {
+            vec![AccessListItem {
+                address: coinbase,
+                storage_keys: vec![],
+            }]
+        } else {
+            vec![]
+        };
+        assert_eq!(output.access_list.0, expected, "{spec_id:?}");
+    }
+}
@@ -0,0 +1,160 @@
+use dora_compiler::evm::{Program, program::Operation};
+use dora_primitives::{B256, Bytecode, Env, spec::SpecId};
+use dora_runtime::{
+    aot::{AotCache, AotCacheKey, AotCacheStats},
+    artifact::{Artifact, SymbolArtifact},
+    context::{Contract, RuntimeContext},
+    db::MemoryDB,
+    executor::ExecuteKind,
+    host::DummyHost,
+};
+use std::{fs, path::PathBuf, process::Command};
+
+use crate::{EVMCompileOptions, build_artifact_with_aot_cache};
+
+use super::INIT_GAS;
+
+fn temp_cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dora-aot-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Returns whether the default linker of the AOT cache is available, otherwise the cached object
+/// code can't be loaded.
+fn has_linker() -> bool {
+    cfg!(target_os = "linux")
+        && Command::new("cc")
+            .arg("--version")
+            .output()
+            .is_ok_and(|output| output.status.success())
+}
+
+/// Executes the artifact of the [`add_bytecode`] and checks its output.
+fn assert_add_artifact(artifact: SymbolArtifact) {
+    let env = Env::default();
+    let contract = Contract::new_with_env(&env, add_bytecode(), None);
+    let mut host = DummyHost::new(env);
+    let context = RuntimeContext::new(
+        contract,
+        1,
+        false,
+        false,
+        &mut host,
+        SpecId::CANCUN,
+        INIT_GAS,
+    );
+    let result = artifact.execute(context).unwrap();
+    assert!(result.status.is_ok());
+    assert_eq!(result.output[31], 3);
+}
+
+fn add_bytecode() -> Bytecode {
+    let operations = vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Add,
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Return,
+    ];
+    Bytecode::new_raw(Program::operations_to_opcode(&operations).into())
+}
+
+#[test]
+fn test_aot_cache_store_and_load() {
+    let dir = temp_cache_dir("store-and-load");
+    let cache = AotCache::new(&dir).unwrap().with_linker("cc");
+    let code = add_bytecode();
+    let code_hash = B256::repeat_byte(0x11);
+    let key = AotCacheKey::new(
+        code_hash,
+        SpecId::CANCUN,
+        EVMCompileOptions::default().cache_key(),
+    );
+    assert!(!cache.contains(&key));
+    // Compile and save the object code.
+    build_artifact_with_aot_cache::<MemoryDB>(&code, code_hash, SpecId::CANCUN, &cache).unwrap();
+    assert!(cache.contains(&key));
+    assert_eq!(cache.stats(), AotCacheStats { hits: 0, misses: 1 });
+    // Load the object code and execute it, which is compiled again without the linker.
+    let artifact =
+        build_artifact_with_aot_cache::<MemoryDB>(&code, code_hash, SpecId::CANCUN, &cache)
+            .unwrap();
+    let hits = u64::from(has_linker());
+    assert_eq!(
+        cache.stats(),
+        AotCacheStats {
+            hits,
+            misses: 2 - hits
+        }
+    );
+    assert_add_artifact(artifact);
+    // The other specs use the different entries.
+    let key = AotCacheKey::new(
+        code_hash,
+        SpecId::PRAGUE,
+        EVMCompileOptions::default().cache_key(),
+    );
+    assert!(!cache.contains(&key));
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_aot_cache_without_linker() {
+    let dir = temp_cache_dir("without-linker");
+    let cache = AotCache::new(&dir)
+        .unwrap()
+        .with_linker("dora-missing-linker");
+    let code = add_bytecode();
+    let code_hash = B256::repeat_byte(0x44);
+    let key = AotCacheKey::new(
+        code_hash,
+        SpecId::CANCUN,
+        EVMCompileOptions::default().cache_key(),
+    );
+    build_artifact_with_aot_cache::<MemoryDB>(&code, code_hash, SpecId::CANCUN, &cache).unwrap();
+    // The entry is kept, while it can't be loaded and the code is compiled again.
+    assert!(cache.contains(&key));
+    assert!(cache.load(&key, ExecuteKind::EVM).is_none());
+    let artifact =
+        build_artifact_with_aot_cache::<MemoryDB>(&code, code_hash, SpecId::CANCUN, &cache)
+            .unwrap();
+    assert_add_artifact(artifact);
+    assert_eq!(cache.stats(), AotCacheStats { hits: 0, misses: 3 });
+    assert!(cache.contains(&key));
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_aot_cache_corrupted_entry() {
+    let dir = temp_cache_dir("corrupted-entry");
+    let cache = AotCache::new(&dir).unwrap();
+    let code = add_bytecode();
+    let code_hash = B256::repeat_byte(0x22);
+    let key = AotCacheKey::new(
+        code_hash,
+        SpecId::CANCUN,
+        EVMCompileOptions::default().cache_key(),
+    );
+    build_artifact_with_aot_cache::<MemoryDB>(&code, code_hash, SpecId::CANCUN, &cache).unwrap();
+    for entry in fs::read_dir(cache.dir()).unwrap() {
+        fs::write(entry.unwrap().path(), b"corrupted").unwrap();
+    }
+    assert!(cache.load(&key, ExecuteKind::EVM).is_none());
+    assert!(!cache.contains(&key));
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_aot_cache_eviction() {
+    let dir = temp_cache_dir("eviction");
+    let cache = AotCache::new(&dir).unwrap().with_max_size(0);
+    let code = add_bytecode();
+    let code_hash = B256::repeat_byte(0x33);
+    build_artifact_with_aot_cache::<MemoryDB>(&code, code_hash, SpecId::CANCUN, &cache).unwrap();
+    assert_eq!(cache.size().unwrap(), 0);
+    let _ = fs::remove_dir_all(dir);
+}
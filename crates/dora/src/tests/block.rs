@@ -0,0 +1,362 @@
+use dora_compiler::evm::{Program, program::Operation};
+use dora_primitives::{
+    Address, B256, Bloom, Bytecode, Bytes, Log, LogData, SpecId, TxEnv, TxKind, U256, address,
+};
+use dora_runtime::{
+    block::{Block, BlockError, BlockExecutor, Ommer, Receipt, SystemCall, Withdrawal},
+    constants::SYSTEM_ADDRESS,
+    context::VMContext,
+    db::MemoryDB,
+    system::{
+        BEACON_ROOTS_ADDRESS, CONSOLIDATION_REQUEST_ADDRESS, DEPOSIT_EVENT_SIGNATURE,
+        HISTORY_STORAGE_ADDRESS, MAINNET_DEPOSIT_CONTRACT_ADDRESS, WITHDRAWAL_REQUEST_ADDRESS,
+        parse_deposit_requests,
+    },
+    trie::{EMPTY_ROOT_HASH, StateTrie, receipts_root},
+};
+
+use crate::compile_handler;
+use crate::tests::utils::default_env_and_db_setup;
+
+const SYSTEM_CONTRACT: Address = address!("0000000000000000000000000000000000001000");
+
+/// Increments the counter at the storage slot 0 and emits an empty log.
+fn counter_log_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push0,
+        Operation::SLoad,
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Add,
+        Operation::Push0,
+        Operation::SStore,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Log(0),
+        Operation::Stop,
+    ]
+}
+
+/// Stores the first word of the call data at the storage slot 0.
+fn system_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push0,
+        Operation::CalldataLoad,
+        Operation::Push0,
+        Operation::SStore,
+        Operation::Stop,
+    ]
+}
+
+/// Returns the two bytes `0xabcd`.
+fn request_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push((2_u8, 0xabcd_u32.into())),
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Push((1_u8, 30_u8.into())),
+        Operation::Return,
+    ]
+}
+
+fn block_executor_setup() -> (BlockExecutor<MemoryDB>, Block, Address) {
+    block_executor_setup_with_spec(SpecId::CANCUN)
+}
+
+fn block_executor_setup_with_spec(spec: SpecId) -> (BlockExecutor<MemoryDB>, Block, Address) {
+    let (mut env, db) = default_env_and_db_setup(counter_log_operations());
+    env.cfg.spec = spec;
+    let contract = env.tx.kind.to().copied().unwrap();
+    let system_code = Program::from_operations(system_operations(), false).to_opcode();
+    let db = db.with_contract(SYSTEM_CONTRACT, Bytecode::new_raw(system_code.into()));
+    let mut block = Block {
+        env: env.block.clone(),
+        ..Default::default()
+    };
+    block.env.gas_limit = 1_000_000;
+    let executor = BlockExecutor::new(VMContext::new(db, env, compile_handler()));
+    (executor, block, contract)
+}
+
+fn transaction(contract: Address, tx_type: u8, nonce: u64) -> TxEnv {
+    TxEnv {
+        tx_type,
+        kind: TxKind::Call(contract),
+        gas_limit: 100_000,
+        nonce,
+        chain_id: Some(1),
+        gas_priority_fee: (tx_type == 2).then_some(0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_block_executor_receipts() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    block.transactions = vec![
+        transaction(contract, 0, 0),
+        transaction(contract, 2, 1),
+        transaction(contract, 1, 2),
+    ];
+    let output = executor.execute_block(block).unwrap();
+    assert_eq!(output.receipts.len(), 3);
+    let tx_types = output
+        .receipts
+        .iter()
+        .map(|r| r.tx_type)
+        .collect::<Vec<_>>();
+    assert_eq!(tx_types, vec![0, 2, 1]);
+    let mut cumulative_gas_used = 0;
+    for (receipt, result) in output.receipts.iter().zip(&output.results) {
+        cumulative_gas_used += result.gas_used();
+        assert!(receipt.success);
+        assert_eq!(receipt.cumulative_gas_used, cumulative_gas_used);
+        assert_eq!(receipt.logs.len(), 1);
+        assert_eq!(receipt.logs[0].address, contract);
+        assert_ne!(receipt.logs_bloom, Bloom::ZERO);
+        assert_eq!(receipt.logs_bloom, output.logs_bloom);
+    }
+    assert_eq!(output.gas_used, cumulative_gas_used);
+    let slot = &output.state[&contract].storage[&U256::ZERO];
+    assert_eq!(slot.original_value, U256::ZERO);
+    assert_eq!(slot.present_value, U256::from(3));
+    assert_eq!(
+        executor
+            .context()
+            .journal
+            .database
+            .sload(contract, U256::ZERO),
+        U256::from(3)
+    );
+}
+
+#[test]
+fn test_block_executor_system_call() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    block.system_calls = vec![SystemCall::new(
+        SYSTEM_CONTRACT,
+        Bytes::from(U256::from(7).to_be_bytes::<32>()),
+    )];
+    block.transactions = vec![transaction(contract, 0, 0)];
+    let output = executor.execute_block(block).unwrap();
+    assert_eq!(
+        output.state[&SYSTEM_CONTRACT].storage[&U256::ZERO].present_value,
+        U256::from(7)
+    );
+    assert!(!output.state.contains_key(&SYSTEM_ADDRESS));
+    // The system call gas is not counted in the block.
+    assert_eq!(output.gas_used, output.receipts[0].cumulative_gas_used);
+}
+
+#[test]
+fn test_block_executor_gas_limit_exceeded() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    block.env.gas_limit = 120_000;
+    block.transactions = vec![transaction(contract, 0, 0), transaction(contract, 0, 1)];
+    let err = executor.execute_block(block).unwrap_err();
+    assert!(matches!(err, BlockError::GasLimitExceeded { index: 1, .. }));
+}
+
+#[test]
+fn test_block_executor_invalid_transaction() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    // The nonce of the second transaction is not increased.
+    block.transactions = vec![transaction(contract, 0, 0), transaction(contract, 0, 0)];
+    let err = executor.execute_block(block).unwrap_err();
+    assert!(matches!(err, BlockError::Transaction { index: 1, .. }));
+}
+
+#[test]
+fn test_block_state_root_incremental() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    block.transactions = vec![transaction(contract, 0, 0), transaction(contract, 2, 1)];
+    let mut trie = StateTrie::from_db(&executor.context().journal.database);
+    let root = trie.root();
+    let output = executor.execute_block(block).unwrap();
+    trie.apply(&output.state);
+    let expected = StateTrie::from_db(&executor.context().journal.database).root();
+    assert_ne!(root, expected);
+    assert_eq!(trie.root(), expected);
+    assert_ne!(trie.storage_root(&contract), EMPTY_ROOT_HASH);
+    assert_ne!(receipts_root(&output.receipts), EMPTY_ROOT_HASH);
+}
+
+#[test]
+fn test_block_executor_beacon_root_call() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    let system_code = Program::from_operations(system_operations(), false).to_opcode();
+    executor.context_mut().journal.database.insert_contract(
+        BEACON_ROOTS_ADDRESS,
+        Bytecode::new_raw(system_code.into()),
+        U256::ZERO,
+    );
+    let root = B256::repeat_byte(0x42);
+    block.env.number = 1;
+    block.parent_beacon_block_root = Some(root);
+    block.parent_hash = Some(B256::repeat_byte(0x43));
+    block.transactions = vec![transaction(contract, 0, 0)];
+    let output = executor.execute_block(block).unwrap();
+    assert_eq!(
+        output.state[&BEACON_ROOTS_ADDRESS].storage[&U256::ZERO].present_value,
+        U256::from_be_bytes(root.0)
+    );
+    // The block hash history is stored since Prague.
+    assert!(!output.state.contains_key(&HISTORY_STORAGE_ADDRESS));
+    assert!(output.requests.is_none());
+}
+
+#[test]
+fn test_block_executor_prague_requests() {
+    let (mut executor, mut block, contract) = block_executor_setup_with_spec(SpecId::PRAGUE);
+    let request_code = Program::from_operations(request_operations(), false).to_opcode();
+    for address in [WITHDRAWAL_REQUEST_ADDRESS, CONSOLIDATION_REQUEST_ADDRESS] {
+        executor.context_mut().journal.database.insert_contract(
+            address,
+            Bytecode::new_raw(request_code.clone().into()),
+            U256::ZERO,
+        );
+    }
+    // The pre-block calls are skipped without the system contracts.
+    block.env.number = 1;
+    block.parent_beacon_block_root = Some(B256::repeat_byte(0x42));
+    block.parent_hash = Some(B256::repeat_byte(0x43));
+    block.transactions = vec![transaction(contract, 0, 0)];
+    let output = executor.execute_block(block).unwrap();
+    assert!(!output.state.contains_key(&BEACON_ROOTS_ADDRESS));
+    assert!(!output.state.contains_key(&HISTORY_STORAGE_ADDRESS));
+    let requests = output.requests.unwrap();
+    assert_eq!(
+        requests.requests(),
+        &[
+            Bytes::from_static(&[0x01, 0xab, 0xcd]),
+            Bytes::from_static(&[0x02, 0xab, 0xcd]),
+        ]
+    );
+}
+
+#[test]
+fn test_block_executor_missing_request_contract() {
+    let (mut executor, mut block, contract) = block_executor_setup_with_spec(SpecId::PRAGUE);
+    block.transactions = vec![transaction(contract, 0, 0)];
+    let err = executor.execute_block(block).unwrap_err();
+    assert!(matches!(
+        err,
+        BlockError::SystemCall { address, .. } if address == WITHDRAWAL_REQUEST_ADDRESS
+    ));
+}
+
+#[test]
+fn test_parse_deposit_requests() {
+    // The ABI encoded pubkey, withdrawal credentials, amount, signature and index.
+    let fields: [(usize, usize, u8); 5] = [
+        (160, 48, 1),
+        (256, 32, 2),
+        (320, 8, 3),
+        (384, 96, 4),
+        (512, 8, 5),
+    ];
+    let mut data = vec![0_u8; 576];
+    let mut expected = Vec::new();
+    for (i, (offset, size, byte)) in fields.into_iter().enumerate() {
+        data[i * 32..(i + 1) * 32].copy_from_slice(&U256::from(offset).to_be_bytes::<32>());
+        data[offset..offset + 32].copy_from_slice(&U256::from(size).to_be_bytes::<32>());
+        data[offset + 32..offset + 32 + size].fill(byte);
+        expected.extend(std::iter::repeat_n(byte, size));
+    }
+    let log = |address, data: Vec<u8>| Log {
+        address,
+        data: LogData::new_unchecked(vec![DEPOSIT_EVENT_SIGNATURE], data.into()),
+    };
+    let receipt = |logs| Receipt {
+        tx_type: 0,
+        success: true,
+        cumulative_gas_used: 0,
+        logs,
+        logs_bloom: Bloom::ZERO,
+    };
+    let receipts = [receipt(vec![
+        log(MAINNET_DEPOSIT_CONTRACT_ADDRESS, data.clone()),
+        // The events of the other contracts are ignored.
+        log(SYSTEM_CONTRACT, data.clone()),
+    ])];
+    let requests = parse_deposit_requests(MAINNET_DEPOSIT_CONTRACT_ADDRESS, &receipts).unwrap();
+    assert_eq!(requests.len(), 192);
+    assert_eq!(requests, expected);
+
+    data[0] = 1;
+    let receipts = [receipt(vec![log(MAINNET_DEPOSIT_CONTRACT_ADDRESS, data)])];
+    assert!(matches!(
+        parse_deposit_requests(MAINNET_DEPOSIT_CONTRACT_ADDRESS, &receipts),
+        Err(BlockError::InvalidDepositEvent(_))
+    ));
+}
+
+#[test]
+fn test_block_executor_block_and_ommer_rewards() {
+    let (mut executor, mut block, contract) = block_executor_setup_with_spec(SpecId::LONDON);
+    let ether = U256::from(10).pow(U256::from(18));
+    let ommer = Address::left_padding_from(&[81]);
+    block.env.number = 10;
+    block.ommers = vec![Ommer {
+        number: 9,
+        beneficiary: ommer,
+    }];
+    block.transactions = vec![transaction(contract, 0, 0)];
+    let beneficiary = block.env.beneficiary;
+    let output = executor.execute_block(block).unwrap();
+    // The transaction pays no fee, the miner gets 2 ETH and 1/32 of it for the ommer.
+    assert_eq!(
+        output.state[&beneficiary].info.balance,
+        ether * U256::from(2) + ether / U256::from(16)
+    );
+    assert_eq!(
+        output.state[&ommer].info.balance,
+        ether * U256::from(2) * U256::from(7) / U256::from(8)
+    );
+}
+
+#[test]
+fn test_block_executor_withdrawals() {
+    let (mut executor, mut block, contract) = block_executor_setup();
+    let recipient = Address::left_padding_from(&[90]);
+    let empty = Address::left_padding_from(&[91]);
+    block.withdrawals = Some(vec![
+        Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: recipient,
+            amount: 5,
+        },
+        Withdrawal {
+            index: 1,
+            validator_index: 2,
+            address: recipient,
+            amount: 2,
+        },
+        Withdrawal {
+            index: 2,
+            validator_index: 3,
+            address: empty,
+            amount: 0,
+        },
+    ]);
+    block.transactions = vec![transaction(contract, 0, 0)];
+    let beneficiary = block.env.beneficiary;
+    let output = executor.execute_block(block).unwrap();
+    assert_eq!(
+        output.state[&recipient].info.balance,
+        U256::from(7_000_000_000_u64)
+    );
+    assert_eq!(
+        executor.context().journal.database.get_balance(recipient),
+        Some(U256::from(7_000_000_000_u64))
+    );
+    // No block reward since the Merge, and the zero withdrawals create no account.
+    assert!(
+        output
+            .state
+            .get(&beneficiary)
+            .is_none_or(|account| account.info.balance.is_zero())
+    );
+    assert!(!output.state.contains_key(&empty));
+}
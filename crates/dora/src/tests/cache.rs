@@ -0,0 +1,82 @@
+use std::{sync::Arc, thread};
+
+use dora_compiler::evm::program::Operation;
+use dora_primitives::spec::SpecId;
+use dora_runtime::{cache::ArtifactCache, context::VMContext, gas::GasSchedule, vm::VM};
+
+use crate::compile_handler_with_cache;
+use crate::tests::utils::default_env_and_db_setup;
+
+fn add_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Add,
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Return,
+    ]
+}
+
+#[test]
+fn test_artifact_cache_shared_by_vms() {
+    let cache = Arc::new(ArtifactCache::default());
+    let handles = (0..4)
+        .map(|_| {
+            let cache = cache.clone();
+            thread::spawn(move || {
+                let (env, db) = default_env_and_db_setup(add_operations());
+                let mut vm = VM::new(VMContext::new(db, env, compile_handler_with_cache(cache)));
+                let result = vm.transact().unwrap();
+                assert!(result.result.is_success(), "{:?}", result);
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 1);
+    assert_eq!((stats.hits, stats.misses), (3, 1));
+}
+
+#[test]
+fn test_artifact_cache_keyed_by_spec() {
+    let cache = Arc::new(ArtifactCache::default());
+    for spec_id in [SpecId::SHANGHAI, SpecId::CANCUN, SpecId::CANCUN] {
+        let (mut env, db) = default_env_and_db_setup(add_operations());
+        env.cfg.spec = spec_id;
+        let mut vm = VM::new(VMContext::new(
+            db,
+            env,
+            compile_handler_with_cache(cache.clone()),
+        ));
+        let result = vm.transact().unwrap();
+        assert!(result.result.is_success(), "{:?}", result);
+    }
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 2);
+    assert_eq!((stats.hits, stats.misses), (1, 2));
+}
+
+#[test]
+fn test_artifact_cache_keyed_by_gas_schedule() {
+    let cache = Arc::new(ArtifactCache::default());
+    let schedule = GasSchedule {
+        sstore_set: 40_000,
+        ..GasSchedule::new(SpecId::CANCUN)
+    };
+    for gas_schedule in [None, Some(schedule), Some(GasSchedule::new(SpecId::CANCUN))] {
+        let (env, db) = default_env_and_db_setup(add_operations());
+        let mut ctx = VMContext::new(db, env, compile_handler_with_cache(cache.clone()));
+        ctx.gas_schedule = gas_schedule;
+        let result = VM::new(ctx).transact().unwrap();
+        assert!(result.result.is_success(), "{:?}", result);
+    }
+    let stats = cache.stats();
+    // The Ethereum gas schedule of the spec shares the artifact with the default one.
+    assert_eq!(stats.entries, 2);
+    assert_eq!((stats.hits, stats.misses), (1, 2));
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use dora_compiler::{
+    evm::{EVMCompileOptions, Program, program::Operation},
+    wasm::WASMCompileOptions,
+};
+use dora_primitives::{Bytecode, CodegenOptions, OptimizationLevel};
+use dora_runtime::{
+    cache::ArtifactCache,
+    context::VMContext,
+    result::{ExecutionResult, VMError},
+    vm::VM,
+};
+
+use crate::tests::utils::default_env_and_db_setup;
+use crate::{
+    build_evm_object_file, compile_handler_with_codegen, pass, suspend_compile_handler_with_codegen,
+};
+
+/// Returns the code which returns `1 + 2`.
+fn add_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Add,
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Return,
+    ]
+}
+
+/// Compiles and runs the code which returns `1 + 2` with the code generation options.
+fn run_with_codegen(codegen: CodegenOptions) -> ExecutionResult {
+    let (env, db) = default_env_and_db_setup(add_operations());
+    // Use a new cache to always compile the code with the options.
+    let cache = Arc::new(ArtifactCache::default());
+    let handler = compile_handler_with_codegen(cache.clone(), codegen);
+    let result = VM::new(VMContext::new(db, env, handler))
+        .transact()
+        .unwrap();
+    assert_eq!(cache.stats().misses, 1);
+    result.result
+}
+
+#[test]
+fn test_codegen_opt_levels() {
+    let expected = run_with_codegen(CodegenOptions::default());
+    assert!(expected.is_success(), "{:?}", expected);
+    for opt_level in [
+        OptimizationLevel::None,
+        OptimizationLevel::Less,
+        OptimizationLevel::Aggressive,
+    ] {
+        let result = run_with_codegen(CodegenOptions::default().opt_level(opt_level));
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_codegen_target_cpu() {
+    let expected = run_with_codegen(CodegenOptions::default());
+    let result = run_with_codegen(CodegenOptions::default().target_cpu("generic"));
+    assert_eq!(result, expected);
+    #[cfg(target_arch = "x86_64")]
+    {
+        let result = run_with_codegen(
+            CodegenOptions::default()
+                .opt_level(OptimizationLevel::Aggressive)
+                .target_cpu("x86-64")
+                .target_features("+sse2, +cx16"),
+        );
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_codegen_host_target() {
+    let expected = run_with_codegen(CodegenOptions::default());
+    let result = run_with_codegen(CodegenOptions::default().target_cpu(pass::host_cpu_name()));
+    assert_eq!(result, expected);
+    // Disabling a feature is always safe.
+    let result = run_with_codegen(CodegenOptions::default().target_features("-dora-unknown"));
+    assert_eq!(result, expected);
+    assert!(pass::check_host_target(&CodegenOptions::default()).is_ok());
+}
+
+#[test]
+fn test_codegen_rejects_foreign_target() {
+    let foreign = CodegenOptions::default().target_features("+dora-unknown");
+    assert!(pass::check_host_target(&foreign).is_err());
+    assert!(
+        pass::check_host_target(&CodegenOptions::default().target_cpu("dora-unknown")).is_err()
+    );
+    let (env, db) = default_env_and_db_setup(add_operations());
+    let cache = Arc::new(ArtifactCache::default());
+    let handler = compile_handler_with_codegen(cache.clone(), foreign.clone());
+    let result = VM::new(VMContext::new(db.clone(), env.clone(), handler)).transact();
+    assert!(matches!(result, Err(VMError::Compile(_))), "{result:?}");
+    // The suspend handler compiles with the same options.
+    let handler = suspend_compile_handler_with_codegen(cache, foreign);
+    let result = VM::new(VMContext::new(db, env, handler)).transact();
+    assert!(matches!(result, Err(VMError::Compile(_))), "{result:?}");
+}
+
+#[test]
+fn test_codegen_foreign_object_file() {
+    let path = std::env::temp_dir().join(format!("dora-codegen-{}.o", std::process::id()));
+    let code = Program::from_operations(add_operations(), false).to_opcode();
+    // The object code is not executed, thus any target is allowed.
+    build_evm_object_file(
+        &Bytecode::new_raw(code.into()),
+        EVMCompileOptions::default().target_features("+dora-unknown"),
+        &path,
+    )
+    .unwrap();
+    assert!(std::fs::metadata(&path).unwrap().len() > 0);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_codegen_options() {
+    let codegen = CodegenOptions::default()
+        .target_cpu("native")
+        .target_features("native");
+    assert_eq!(codegen, CodegenOptions::default());
+    let codegen = codegen.target_features("+avx2, +bmi2,");
+    assert_eq!(codegen.target_feature_list(), vec!["+avx2", "+bmi2"]);
+    assert!(CodegenOptions::default().target_feature_list().is_empty());
+    assert_eq!("3".parse(), Ok(OptimizationLevel::Aggressive));
+    assert!("4".parse::<OptimizationLevel>().is_err());
+}
+
+#[test]
+fn test_codegen_cache_key() {
+    let default_key = EVMCompileOptions::default().cache_key();
+    assert_eq!(
+        EVMCompileOptions::default()
+            .target_cpu("native")
+            .cache_key(),
+        default_key
+    );
+    assert_ne!(
+        EVMCompileOptions::default()
+            .opt_level(OptimizationLevel::Aggressive)
+            .cache_key(),
+        default_key
+    );
+    assert_ne!(
+        EVMCompileOptions::default()
+            .target_features("+avx2")
+            .cache_key(),
+        default_key
+    );
+    assert_ne!(
+        WASMCompileOptions::default()
+            .opt_level(OptimizationLevel::None)
+            .cache_key(),
+        WASMCompileOptions::default().cache_key()
+    );
+}
+
+#[test]
+fn test_codegen_in_artifact_key() {
+    let cache = Arc::new(ArtifactCache::default());
+    for codegen in [
+        CodegenOptions::default().opt_level(OptimizationLevel::None),
+        CodegenOptions::default().opt_level(OptimizationLevel::Aggressive),
+        CodegenOptions::default().opt_level(OptimizationLevel::Aggressive),
+    ] {
+        let (env, db) = default_env_and_db_setup(add_operations());
+        let handler = compile_handler_with_codegen(cache.clone(), codegen);
+        let result = VM::new(VMContext::new(db, env, handler))
+            .transact()
+            .unwrap();
+        assert!(result.result.is_success(), "{:?}", result.result);
+    }
+    // The artifact compiled with other options is not reused.
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+}
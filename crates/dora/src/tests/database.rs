@@ -0,0 +1,106 @@
+use dora_compiler::evm::{Program, program::Operation};
+use dora_primitives::{AccountInfo, Address, B256, Bytecode, Env, U256};
+use dora_runtime::{
+    context::VMContext,
+    db::{DBErrorMarker, Database, DatabaseError, MemoryDB},
+    result::VMError,
+    vm::VM,
+};
+use num_bigint::BigUint;
+use std::fmt;
+
+use crate::compile_handler;
+use crate::tests::utils::default_env_and_db_setup;
+
+const CALLEE: u8 = 41;
+
+/// The error of the missing trie node of the broken account.
+#[derive(Debug)]
+struct MissingNode;
+
+impl fmt::Display for MissingNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("missing trie node")
+    }
+}
+
+impl std::error::Error for MissingNode {}
+
+impl DBErrorMarker for MissingNode {}
+
+/// A memory database whose storage of the broken account can't be read.
+struct FailingDB {
+    db: MemoryDB,
+    broken: Address,
+}
+
+impl Database for FailingDB {
+    type Error = MissingNode;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        Ok(self.db.basic(address).unwrap())
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        Ok(self.db.code_by_hash(code_hash).unwrap())
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        if address == self.broken {
+            return Err(MissingNode);
+        }
+        Ok(self.db.storage(address, index).unwrap())
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        Ok(self.db.block_hash(number).unwrap())
+    }
+}
+
+/// Loads the storage slot 0.
+fn sload_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push0,
+        Operation::SLoad,
+        Operation::Pop,
+        Operation::Stop,
+    ]
+}
+
+/// Asserts the transaction fails with the database error instead of a halt.
+fn assert_database_error(db: FailingDB, env: Env) {
+    let mut vm = VM::new(VMContext::new(db, env, compile_handler()));
+    let error = vm.transact().unwrap_err();
+    assert_eq!(
+        error,
+        VMError::Database(DatabaseError::new("missing trie node"))
+    );
+}
+
+#[test]
+fn test_host_error_fails_transaction() {
+    let (env, db) = default_env_and_db_setup(sload_operations());
+    let broken = env.tx.kind.to().copied().unwrap();
+    assert_database_error(FailingDB { db, broken }, env);
+}
+
+#[test]
+fn test_host_error_of_sub_call_fails_transaction() {
+    // The caller ignores the failed call, the database error still fails the transaction.
+    let callee = Address::left_padding_from(&[CALLEE]);
+    let (env, mut db) = default_env_and_db_setup(vec![
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push((20_u8, BigUint::from_bytes_be(callee.as_slice()))),
+        Operation::Gas,
+        Operation::Call,
+        Operation::Pop,
+        Operation::Stop,
+    ]);
+    let bytecode = Program::operations_to_opcode(&sload_operations());
+    db = db.with_contract(callee, Bytecode::new_raw(bytecode.into()));
+    assert_database_error(FailingDB { db, broken: callee }, env);
+}
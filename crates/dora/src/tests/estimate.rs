@@ -0,0 +1,115 @@
+use dora_compiler::evm::{Program, program::Operation};
+use dora_primitives::{Address, Bytecode, Bytes, Env, U256};
+use dora_runtime::{db::MemoryDB, simulate::Simulation};
+use num_bigint::BigUint;
+
+use crate::tests::utils::{counter_operations, default_env_and_db_setup};
+use crate::{EstimateGasError, estimate_gas, simulate};
+
+const CALLEE: u8 = 41;
+
+/// Calls the callee with all the gas left and reverts if the call fails.
+fn call_operations() -> Vec<Operation> {
+    let callee = Address::left_padding_from(&[CALLEE]);
+    let pc = 34;
+    vec![
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push((20_u8, BigUint::from_bytes_be(callee.as_slice()))),
+        Operation::Gas,
+        Operation::Call,
+        Operation::Push((1_u8, (pc as u8).into())),
+        Operation::JumpI,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Revert,
+        Operation::Jumpdest { pc },
+        Operation::Stop,
+    ]
+}
+
+fn is_success(env: &Env, db: &MemoryDB, gas_limit: u64) -> bool {
+    let mut env = env.clone();
+    env.tx.gas_limit = gas_limit;
+    simulate(env, db.clone(), Simulation::new().disable_nonce_check())
+        .is_ok_and(|result| result.result.is_success())
+}
+
+/// Asserts the estimated gas limit is the minimal one that succeeds.
+fn assert_minimal_gas(env: Env, mut db: MemoryDB) -> u64 {
+    let gas = estimate_gas(env.clone(), &mut db).unwrap();
+    assert!(is_success(&env, &db, gas));
+    assert!(!is_success(&env, &db, gas - 1));
+    gas
+}
+
+#[test]
+fn test_estimate_gas_counter() {
+    let (mut env, db) = default_env_and_db_setup(counter_operations());
+    env.tx.gas_limit = 1_000_000;
+    let gas = assert_minimal_gas(env, db);
+    // The intrinsic gas and the cold `SSTORE` from zero.
+    assert!(gas > 21_000 + 22_100);
+}
+
+#[test]
+fn test_estimate_gas_with_refund() {
+    let operations = vec![
+        Operation::Push0,
+        Operation::Push0,
+        Operation::SStore,
+        Operation::Stop,
+    ];
+    let (mut env, mut db) = default_env_and_db_setup(operations);
+    env.tx.gas_limit = 1_000_000;
+    let contract = env.tx.kind.to().copied().unwrap();
+    db.sstore(contract, U256::ZERO, U256::from(1));
+    assert_minimal_gas(env, db);
+}
+
+#[test]
+fn test_estimate_gas_all_gas_call() {
+    let (mut env, db) = default_env_and_db_setup(call_operations());
+    env.tx.gas_limit = 1_000_000;
+    let code = Program::from_operations(counter_operations(), false).to_opcode();
+    let db = db.with_contract(
+        Address::left_padding_from(&[CALLEE]),
+        Bytecode::new_raw(code.into()),
+    );
+    let gas = assert_minimal_gas(env.clone(), db.clone());
+    // The call retains 1/64 of the gas left, which is more than the gas used.
+    let mut env = env;
+    env.tx.gas_limit = gas;
+    let result = simulate(env, db, Simulation::new()).unwrap();
+    assert!(result.result.gas_used() < gas);
+}
+
+#[test]
+fn test_estimate_gas_revert() {
+    let operations = vec![
+        Operation::Push((1_u8, 0xaa_u8.into())),
+        Operation::Push0,
+        Operation::MStore8,
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push0,
+        Operation::Revert,
+    ];
+    let (mut env, mut db) = default_env_and_db_setup(operations);
+    env.tx.gas_limit = 1_000_000;
+    let err = estimate_gas(env, &mut db).unwrap_err();
+    assert!(matches!(
+        err,
+        EstimateGasError::Revert { output, .. } if output == Bytes::from_static(&[0xaa])
+    ));
+}
+
+#[test]
+fn test_estimate_gas_out_of_gas() {
+    let (mut env, mut db) = default_env_and_db_setup(counter_operations());
+    env.tx.gas_limit = 30_000;
+    let err = estimate_gas(env, &mut db).unwrap_err();
+    assert_eq!(err, EstimateGasError::OutOfGas { gas_limit: 30_000 });
+}
@@ -0,0 +1,157 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use dora_primitives::{Address, B256, Env, TxEnv, TxKind, U256};
+use dora_runtime::{
+    block::{Block, BlockExecutor},
+    context::VMContext,
+    db::{Database, FileDB},
+};
+
+use crate::compile_handler;
+use crate::tests::utils::{counter_operations, default_env_and_db_setup};
+
+/// Returns an empty directory for the database of the test.
+fn db_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dora-file-db-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Opens the database at the directory with the genesis state of the counter contract.
+fn file_db_setup(dir: &Path) -> (Env, FileDB, Address) {
+    let (env, genesis) = default_env_and_db_setup(counter_operations());
+    let contract = env.tx.kind.to().copied().unwrap();
+    let mut db = FileDB::open(dir).unwrap();
+    db.insert_alloc(genesis.dump());
+    db.flush_block(0, B256::with_last_byte(0)).unwrap();
+    (env, db, contract)
+}
+
+/// Executes a block of the counter transactions with the nonces and returns the database.
+fn execute_block(env: Env, db: FileDB, contract: Address, nonces: &[u64]) -> FileDB {
+    let mut block = Block {
+        env: env.block.clone(),
+        ..Default::default()
+    };
+    block.env.gas_limit = 1_000_000;
+    block.transactions = nonces
+        .iter()
+        .map(|nonce| TxEnv {
+            kind: TxKind::Call(contract),
+            gas_limit: 100_000,
+            nonce: *nonce,
+            ..Default::default()
+        })
+        .collect();
+    let mut executor = BlockExecutor::new(VMContext::new(db, env, compile_handler()));
+    executor.execute_block(block).unwrap();
+    executor.into_context().journal.database
+}
+
+#[test]
+fn test_file_db_reopen() {
+    let dir = db_dir("reopen");
+    let (env, db, contract) = file_db_setup(&dir);
+    let mut db = execute_block(env.clone(), db, contract, &[0, 1]);
+    assert!(db.has_pending());
+    db.flush_block(1, B256::with_last_byte(1)).unwrap();
+    drop(db);
+
+    let mut db = FileDB::open(&dir).unwrap();
+    assert_eq!(db.storage(contract, U256::ZERO).unwrap(), U256::from(2));
+    assert_eq!(db.basic(Address::ZERO).unwrap().unwrap().nonce, 2);
+    assert_eq!(db.block_hash(1).unwrap(), B256::with_last_byte(1));
+    let code_hash = db.basic(contract).unwrap().unwrap().code_hash;
+    assert!(!db.code_by_hash(code_hash).unwrap().is_empty());
+
+    // The replayed database executes the next block like the one which is not reopened.
+    let db = execute_block(env, db, contract, &[2]);
+    assert_eq!(db.state().sload(contract, U256::ZERO), U256::from(3));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_file_db_pending_changes_are_dropped() {
+    let dir = db_dir("rollback");
+    let (env, db, contract) = file_db_setup(&dir);
+    let mut db = execute_block(env, db, contract, &[0]);
+    assert_eq!(db.state().sload(contract, U256::ZERO), U256::from(1));
+    db.rollback().unwrap();
+    assert!(!db.has_pending());
+    assert_eq!(db.state().sload(contract, U256::ZERO), U256::ZERO);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_file_db_torn_record() {
+    let dir = db_dir("torn");
+    let (env, db, contract) = file_db_setup(&dir);
+    let mut db = execute_block(env.clone(), db, contract, &[0]);
+    db.flush_block(1, B256::with_last_byte(1)).unwrap();
+    drop(db);
+    // A crash while the next record is written leaves a partial record.
+    let mut wal = OpenOptions::new()
+        .append(true)
+        .open(dir.join("wal.log"))
+        .unwrap();
+    wal.write_all(&[0xff, 0x00, 0x00]).unwrap();
+    drop(wal);
+
+    let db = FileDB::open(&dir).unwrap();
+    assert_eq!(db.state().sload(contract, U256::ZERO), U256::from(1));
+    // The partial record is truncated, thus the later records are replayed.
+    let mut db = execute_block(env, db, contract, &[1]);
+    db.flush_block(2, B256::with_last_byte(2)).unwrap();
+    drop(db);
+    let db = FileDB::open(&dir).unwrap();
+    assert_eq!(db.state().sload(contract, U256::ZERO), U256::from(2));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_file_db_checkpoint() {
+    let dir = db_dir("checkpoint");
+    let (env, db, contract) = file_db_setup(&dir);
+    let mut db = execute_block(env, db, contract, &[0, 1, 2]);
+    assert!(db.checkpoint().is_err());
+    db.flush_block(1, B256::with_last_byte(1)).unwrap();
+    db.checkpoint().unwrap();
+    assert_eq!(fs::metadata(dir.join("wal.log")).unwrap().len(), 0);
+    let accounts = db.state().accounts().clone();
+    drop(db);
+
+    // The snapshot is loaded without the log records.
+    let db = FileDB::open(&dir).unwrap();
+    assert_eq!(db.state().accounts().len(), accounts.len());
+    for (address, account) in accounts {
+        let loaded = &db.state().accounts()[&address];
+        assert_eq!(loaded.nonce, account.nonce);
+        assert_eq!(loaded.balance, account.balance);
+        assert_eq!(loaded.bytecode_hash, account.bytecode_hash);
+        assert_eq!(loaded.storage, account.storage);
+    }
+    assert_eq!(db.state().sload(contract, U256::ZERO), U256::from(3));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_file_db_auto_checkpoint() {
+    let dir = db_dir("auto-checkpoint");
+    let (env, db, contract) = file_db_setup(&dir);
+    // Every flushed record passes the threshold and triggers a checkpoint.
+    let db = db.with_checkpoint_threshold(1);
+    let mut db = execute_block(env, db, contract, &[0, 1]);
+    db.flush_block(1, B256::with_last_byte(1)).unwrap();
+    assert_eq!(fs::metadata(dir.join("wal.log")).unwrap().len(), 0);
+    assert!(!dir.join("state.json.tmp").exists());
+    drop(db);
+
+    let mut db = FileDB::open(&dir).unwrap();
+    assert_eq!(db.storage(contract, U256::ZERO).unwrap(), U256::from(2));
+    assert_eq!(db.block_hash(1).unwrap(), B256::with_last_byte(1));
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,98 @@
+use dora_compiler::evm::{EVMCompileOptions, program::Operation};
+use dora_primitives::{Bytes, spec::SpecId};
+use dora_runtime::{context::VMContext, gas::GasSchedule, vm::VM};
+
+use crate::compile_handler;
+use crate::tests::utils::default_env_and_db_setup;
+
+/// Returns the gas used by the transaction of the operations and the calldata under the schedule.
+fn gas_used(operations: Vec<Operation>, data: Bytes, schedule: Option<GasSchedule>) -> u64 {
+    let (mut env, db) = default_env_and_db_setup(operations);
+    env.tx.data = data;
+    let mut ctx = VMContext::new(db, env, compile_handler());
+    ctx.gas_schedule = schedule;
+    let result = VM::new(ctx).transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    result.result.gas_used()
+}
+
+#[test]
+fn test_gas_schedule_log() {
+    let operations = vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Log(1),
+    ];
+    let schedule = GasSchedule {
+        log: 1000,
+        log_topic: 500,
+        log_data: 10,
+        ..GasSchedule::new(SpecId::CANCUN)
+    };
+    let default_gas = gas_used(operations.clone(), Bytes::new(), None);
+    let gas = gas_used(operations, Bytes::new(), Some(schedule));
+    assert_eq!(
+        gas - default_gas,
+        (1000 - 375) + (500 - 375) + 32 * (10 - 8)
+    );
+}
+
+#[test]
+fn test_gas_schedule_sstore() {
+    let operations = vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push0,
+        Operation::SStore,
+    ];
+    let schedule = GasSchedule {
+        sstore_set: 40_000,
+        ..GasSchedule::new(SpecId::CANCUN)
+    };
+    let default_gas = gas_used(operations.clone(), Bytes::new(), None);
+    let gas = gas_used(operations, Bytes::new(), Some(schedule));
+    assert_eq!(gas - default_gas, 40_000 - 20_000);
+}
+
+#[test]
+fn test_gas_schedule_calldata() {
+    let data = Bytes::from_static(&[0, 0, 1, 2, 3]);
+    let schedule = GasSchedule {
+        tx_data_zero: 1,
+        tx_data_non_zero: 100,
+        ..GasSchedule::new(SpecId::CANCUN)
+    };
+    let default_gas = gas_used(vec![Operation::Stop], data.clone(), None);
+    let gas = gas_used(vec![Operation::Stop], data, Some(schedule));
+    assert_eq!(default_gas, 21_000 + 2 * 4 + 3 * 16);
+    assert_eq!(gas, 21_000 + 2 + 3 * 100);
+}
+
+#[test]
+fn test_gas_schedule_of_spec() {
+    assert_eq!(GasSchedule::new(SpecId::CANCUN).tx_data_non_zero, 16);
+    assert_eq!(GasSchedule::new(SpecId::PETERSBURG).tx_data_non_zero, 68);
+    assert_eq!(GasSchedule::new(SpecId::CANCUN).log_cost(4), 5 * 375);
+}
+
+#[test]
+fn test_gas_schedule_cache_key() {
+    let default_key = EVMCompileOptions::default().cache_key();
+    let schedule = GasSchedule::new(SpecId::CANCUN);
+    assert_eq!(
+        EVMCompileOptions::default()
+            .gas_schedule(schedule)
+            .cache_key(),
+        default_key
+    );
+    let schedule = GasSchedule {
+        sstore_set: 40_000,
+        ..schedule
+    };
+    assert_ne!(
+        EVMCompileOptions::default()
+            .gas_schedule(schedule)
+            .cache_key(),
+        default_key
+    );
+}
@@ -0,0 +1,237 @@
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use dora_compiler::evm::program::Operation;
+use dora_primitives::{Address, B256, Bytes32, Log, U256};
+use dora_runtime::{
+    cache::ArtifactCache,
+    call::{CallMessage, CallResult},
+    context::VMContext,
+    db::MemoryDB,
+    inspector::{
+        CallTracer, CallTracerConfig, Inspector, PrestateFrame, PrestateTracer,
+        PrestateTracerConfig, StepState,
+    },
+    vm::VM,
+};
+
+use crate::tests::utils::default_env_and_db_setup;
+use crate::{compile_handler, compile_handler_with_cache};
+
+#[derive(Debug, Default)]
+struct CountInspector {
+    steps: Vec<(usize, u8)>,
+    step_ends: Vec<(usize, u8, u64)>,
+    stack_tops: Vec<Option<Bytes32>>,
+    calls: usize,
+    call_ends: usize,
+    logs: Vec<Log>,
+}
+
+impl Inspector<MemoryDB> for CountInspector {
+    fn step(&mut self, step: &StepState<'_>, _context: &mut VMContext<MemoryDB>) {
+        self.steps.push((step.pc, step.opcode));
+    }
+
+    fn step_end(&mut self, step: &StepState<'_>, _context: &mut VMContext<MemoryDB>) {
+        self.step_ends.push((step.pc, step.opcode, step.gas_cost));
+        self.stack_tops.push(step.stack.last().copied());
+    }
+
+    fn call(
+        &mut self,
+        _msg: &mut CallMessage,
+        _context: &mut VMContext<MemoryDB>,
+    ) -> Option<CallResult> {
+        self.calls += 1;
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        _msg: &CallMessage,
+        _result: &mut CallResult,
+        _context: &mut VMContext<MemoryDB>,
+    ) {
+        self.call_ends += 1;
+    }
+
+    fn log(&mut self, log: &Log, _context: &mut VMContext<MemoryDB>) {
+        self.logs.push(log.clone());
+    }
+}
+
+fn run_with_inspector(operations: Vec<Operation>) -> Rc<RefCell<CountInspector>> {
+    let (env, db) = default_env_and_db_setup(operations);
+    let inspector = Rc::new(RefCell::new(CountInspector::default()));
+    let mut vm =
+        VM::new(VMContext::new(db, env, compile_handler()).with_inspector(inspector.clone()));
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    inspector
+}
+
+#[test]
+fn test_inspector_step() {
+    let operations = vec![
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Add,
+        Operation::Stop,
+    ];
+    let inspector = run_with_inspector(operations);
+    let inspector = inspector.borrow();
+    assert_eq!(
+        inspector.steps,
+        vec![(0, 0x60), (2, 0x60), (4, 0x01), (5, 0x00)]
+    );
+    assert_eq!(inspector.step_ends.len(), inspector.steps.len());
+    assert_eq!(inspector.step_ends[2], (4, 0x01, 3));
+    assert_eq!(inspector.stack_tops[2], Some(Bytes32::from(3_u8)));
+    assert_eq!(inspector.calls, 1);
+    assert_eq!(inspector.call_ends, 1);
+}
+
+#[test]
+fn test_inspector_log() {
+    let operations = vec![
+        Operation::Push((1_u8, 0_u8.into())),
+        Operation::Push((1_u8, 0_u8.into())),
+        Operation::Log(0),
+        Operation::Stop,
+    ];
+    let inspector = run_with_inspector(operations);
+    let inspector = inspector.borrow();
+    assert_eq!(inspector.logs.len(), 1);
+    assert!(inspector.logs[0].topics().is_empty());
+}
+
+fn nested_call_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::SStore,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push((2_u8, 0x1000_u32.into())),
+        Operation::Push((2_u8, 0xFFFF_u32.into())),
+        Operation::Call,
+        Operation::Pop,
+        Operation::Stop,
+    ]
+}
+
+#[test]
+fn test_call_tracer() {
+    let (env, db) = default_env_and_db_setup(nested_call_operations());
+    let (caller, contract) = (env.tx.caller, env.tx.kind.into_to().unwrap());
+    let tracer = Rc::new(RefCell::new(CallTracer::new(CallTracerConfig::default())));
+    let mut vm = VM::new(VMContext::new(db, env, compile_handler()).with_inspector(tracer.clone()));
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    let frame = tracer
+        .take()
+        .into_call_frame(result.result.gas_used())
+        .unwrap();
+    assert_eq!(frame.typ, "CALL");
+    assert_eq!(frame.from, caller);
+    assert_eq!(frame.to, Some(contract));
+    assert_eq!(frame.gas_used, result.result.gas_used());
+    assert_eq!(frame.error, None);
+    assert_eq!(frame.calls.len(), 1);
+    assert_eq!(frame.calls[0].typ, "CALL");
+    assert_eq!(frame.calls[0].from, contract);
+    assert_eq!(
+        frame.calls[0].to,
+        Some(Address::left_padding_from(&[0x10, 0x00]))
+    );
+    assert_eq!(frame.calls[0].gas_used, 0);
+    let json = frame.to_json();
+    assert_eq!(json["type"], "CALL");
+    assert_eq!(json["calls"][0]["value"], "0x0");
+    assert!(json.get("output").is_none());
+}
+
+#[test]
+fn test_call_tracer_revert() {
+    let operations = vec![Operation::Push0, Operation::Push0, Operation::Revert];
+    let (env, db) = default_env_and_db_setup(operations);
+    let tracer = Rc::new(RefCell::new(CallTracer::new(
+        CallTracerConfig::default().only_top_call(true),
+    )));
+    let mut vm = VM::new(VMContext::new(db, env, compile_handler()).with_inspector(tracer.clone()));
+    let result = vm.transact().unwrap();
+    let frame = tracer
+        .take()
+        .into_call_frame(result.result.gas_used())
+        .unwrap();
+    assert_eq!(frame.error.as_deref(), Some("execution reverted"));
+    assert_eq!(frame.revert_reason, None);
+}
+
+#[test]
+fn test_prestate_tracer() {
+    let (env, db) = default_env_and_db_setup(nested_call_operations());
+    let contract = env.tx.kind.into_to().unwrap();
+    let tracer = Rc::new(RefCell::new(PrestateTracer::new(
+        PrestateTracerConfig::default(),
+    )));
+    let mut vm = VM::new(VMContext::new(db, env, compile_handler()).with_inspector(tracer.clone()));
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    let PrestateFrame::Prestate(accounts) = tracer.take().into_prestate_frame(&result.state) else {
+        panic!("expected the prestate mode");
+    };
+    let account = &accounts[&contract];
+    assert_eq!(account.balance, Some(U256::from(10)));
+    assert!(account.code.is_some());
+    assert_eq!(
+        account.storage.get(&B256::from(U256::from(1))),
+        Some(&B256::ZERO)
+    );
+    assert!(accounts.contains_key(&Address::left_padding_from(&[0x10, 0x00])));
+}
+
+#[test]
+fn test_prestate_tracer_diff_mode() {
+    let (env, db) = default_env_and_db_setup(nested_call_operations());
+    let contract = env.tx.kind.into_to().unwrap();
+    let tracer = Rc::new(RefCell::new(PrestateTracer::new(
+        PrestateTracerConfig::default().diff_mode(true),
+    )));
+    let mut vm = VM::new(VMContext::new(db, env, compile_handler()).with_inspector(tracer.clone()));
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    let PrestateFrame::Diff { pre, post } = tracer.take().into_prestate_frame(&result.state) else {
+        panic!("expected the diff mode");
+    };
+    let slot = B256::from(U256::from(1));
+    assert_eq!(pre[&contract].storage.get(&slot), Some(&B256::ZERO));
+    assert_eq!(pre[&contract].balance, Some(U256::from(10)));
+    assert_eq!(
+        post[&contract].storage.get(&slot),
+        Some(&B256::from(U256::from(2)))
+    );
+    assert_eq!(post[&contract].balance, None);
+    assert!(!post.contains_key(&Address::left_padding_from(&[0x10, 0x00])));
+}
+
+#[test]
+fn test_inspector_artifact_cache() {
+    let cache = Arc::new(ArtifactCache::default());
+    let operations = vec![Operation::Push0, Operation::Pop, Operation::Stop];
+    for inspecting in [true, true, false] {
+        let (env, db) = default_env_and_db_setup(operations.clone());
+        let mut ctx = VMContext::new(db, env, compile_handler_with_cache(cache.clone()));
+        if inspecting {
+            ctx = ctx.with_inspector(CountInspector::default());
+        }
+        let result = VM::new(ctx).transact().unwrap();
+        assert!(result.result.is_success(), "{:?}", result);
+    }
+    // The traced artifact is compiled once and never reused without the inspector.
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+}
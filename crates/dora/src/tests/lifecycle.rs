@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use dora_primitives::{Address, U256};
+use dora_runtime::{context::VMContext, db::MemoryDB, handler::LifecycleHandles, result::VMError};
+
+use crate::compile_handler;
+use crate::tests::utils::{GAS_PRICE, counter_vm};
+
+const VAULT: u8 = 90;
+
+/// Pays the whole fee of the used gas to the vault instead of the beneficiary.
+fn pay_vault(
+    ctx: &mut VMContext<MemoryDB>,
+    gas_used: u64,
+    gas_refunded: i64,
+) -> Result<(), VMError> {
+    let fee = U256::from(GAS_PRICE * (gas_used - gas_refunded as u64) as u128);
+    let vault = ctx
+        .journal
+        .load_account(Address::left_padding_from(&[VAULT]))
+        .map_err(VMError::database)?;
+    vault.data.mark_touch();
+    vault.data.info.balance += fee;
+    Ok(())
+}
+
+#[test]
+fn test_default_lifecycle_pays_beneficiary() {
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = counter_vm(caller, U256::from(10_000_000), compile_handler());
+    let beneficiary = vm.env.block.beneficiary;
+    let result = vm.transact().unwrap();
+    let fee = U256::from(GAS_PRICE * result.result.gas_used() as u128);
+    assert_eq!(result.state[&beneficiary].info.balance, fee);
+    assert_eq!(
+        result.state[&caller].info.balance,
+        U256::from(10_000_000) - fee
+    );
+}
+
+#[test]
+fn test_lifecycle_reward_beneficiary_hook() {
+    let caller = Address::left_padding_from(&[1]);
+    let handler = compile_handler().with_lifecycle(LifecycleHandles {
+        reward_beneficiary: Arc::new(pay_vault),
+        ..Default::default()
+    });
+    let mut vm = counter_vm(caller, U256::from(10_000_000), handler);
+    let beneficiary = vm.env.block.beneficiary;
+    let result = vm.transact().unwrap();
+    let fee = U256::from(GAS_PRICE * result.result.gas_used() as u128);
+    let vault = Address::left_padding_from(&[VAULT]);
+    assert_eq!(result.state[&vault].info.balance, fee);
+    assert!(!result.state.contains_key(&beneficiary));
+}
+
+#[test]
+fn test_lifecycle_fee_exempt_transaction() {
+    // The transactions of the system caller don't pay the fee, thus it needs no balance.
+    let system = Address::left_padding_from(&[0xff]);
+    let defaults = LifecycleHandles::<MemoryDB>::default();
+    let lifecycle = LifecycleHandles {
+        validate_tx_against_state: {
+            let default = defaults.validate_tx_against_state.clone();
+            Arc::new(move |ctx| {
+                if ctx.env.tx.caller == system {
+                    return Ok(());
+                }
+                default(ctx)
+            })
+        },
+        deduct_caller: {
+            let default = defaults.deduct_caller.clone();
+            Arc::new(move |ctx| {
+                if ctx.env.tx.caller == system {
+                    return Ok(());
+                }
+                default(ctx)
+            })
+        },
+        reimburse_caller: Arc::new(|_, _, _| Ok(())),
+        reward_beneficiary: Arc::new(|_, _, _| Ok(())),
+        ..defaults
+    };
+    let handler = compile_handler().with_lifecycle(lifecycle.clone());
+    let mut vm = counter_vm(system, U256::ZERO, handler);
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+    assert!(
+        result
+            .state
+            .get(&system)
+            .is_none_or(|account| account.info.balance.is_zero())
+    );
+
+    // The other callers are still validated.
+    let handler = compile_handler().with_lifecycle(lifecycle);
+    let mut vm = counter_vm(Address::left_padding_from(&[1]), U256::ZERO, handler);
+    assert!(matches!(vm.transact(), Err(VMError::Transaction(_))));
+}
+
+#[test]
+fn test_lifecycle_validate_env_hook() {
+    let caller = Address::left_padding_from(&[1]);
+    let handler = compile_handler().with_lifecycle(LifecycleHandles {
+        validate_env: Arc::new(|ctx| {
+            if ctx.env.tx.gas_price > 1 {
+                return Err(VMError::Handler("gas price is too high".to_string()));
+            }
+            VM::validate_env(ctx)
+        }),
+        ..Default::default()
+    });
+    let mut vm = counter_vm(caller, U256::from(10_000_000), handler);
+    assert_eq!(
+        vm.transact().unwrap_err(),
+        VMError::Handler("gas price is too high".to_string())
+    );
+}
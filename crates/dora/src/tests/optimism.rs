@@ -0,0 +1,156 @@
+use dora_primitives::{Address, Bytes, U256};
+use dora_runtime::{
+    db::MemoryDB,
+    handler::Handler,
+    optimism::{
+        self, BASE_FEE_VAULT, DEPOSIT_TRANSACTION_TYPE, L1_BLOCK_CONTRACT, L1_FEE_VAULT, OpSpecId,
+    },
+    result::{ExecutionResult, VMError},
+    vm::VM,
+};
+use hex_literal::hex;
+
+use crate::compile_handler;
+use crate::tests::utils::{GAS_PRICE, counter_vm};
+
+const BASE_FEE: u64 = 1;
+const MINT: u128 = 1_000_000;
+
+fn optimism_handler() -> Handler<MemoryDB> {
+    compile_handler().with_lifecycle(optimism::lifecycle())
+}
+
+/// Returns the VM of the counter transaction from the caller at the OP-stack hardfork, the L1
+/// block contract stores the Ecotone fee parameters.
+fn optimism_vm(caller: Address, balance: U256, spec: OpSpecId) -> VM<MemoryDB> {
+    let mut vm = counter_vm(caller, balance, optimism_handler());
+    vm.env.block.basefee = BASE_FEE;
+    vm.optimism.spec = spec;
+    let db = &mut vm.journal.database;
+    // The L1 base fee, the blob base fee and their scalars are all 1000.
+    db.sstore(L1_BLOCK_CONTRACT, U256::from(1), U256::from(1_000));
+    db.sstore(L1_BLOCK_CONTRACT, U256::from(7), U256::from(1_000));
+    db.sstore(
+        L1_BLOCK_CONTRACT,
+        U256::from(3),
+        (U256::from(1_000) << 96) | (U256::from(1_000) << 64),
+    );
+    vm
+}
+
+/// Turns the transaction of the VM into a deposit which mints [`MINT`] to the caller.
+fn into_deposit(vm: &mut VM<MemoryDB>) {
+    vm.env.tx.tx_type = DEPOSIT_TRANSACTION_TYPE;
+    vm.env.tx.gas_price = 0;
+    vm.optimism.tx.deposit.mint = Some(MINT);
+}
+
+#[test]
+fn test_optimism_transaction_pays_fee_vaults() {
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = optimism_vm(caller, U256::from(10_000_000), OpSpecId::Ecotone);
+    vm.optimism.tx.enveloped_tx = Some(Bytes::from_static(&hex!("00facade")));
+    let beneficiary = vm.env.block.beneficiary;
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let gas_used = result.result.gas_used() as u128;
+    // (4 + 3 * 16) * (1000 * 16 * 1000 + 1000 * 1000) / 16e6
+    let l1_cost = U256::from(55);
+    assert_eq!(result.state[&L1_FEE_VAULT].info.balance, l1_cost);
+    assert_eq!(
+        result.state[&BASE_FEE_VAULT].info.balance,
+        U256::from(BASE_FEE as u128 * gas_used)
+    );
+    assert_eq!(
+        result.state[&beneficiary].info.balance,
+        U256::from((GAS_PRICE - BASE_FEE as u128) * gas_used)
+    );
+    assert_eq!(
+        result.state[&caller].info.balance,
+        U256::from(10_000_000 - GAS_PRICE * gas_used) - l1_cost
+    );
+}
+
+#[test]
+fn test_optimism_transaction_without_enveloped_tx() {
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = optimism_vm(caller, U256::from(10_000_000), OpSpecId::Ecotone);
+    assert!(matches!(vm.transact(), Err(VMError::Handler(_))));
+}
+
+#[test]
+fn test_optimism_transaction_lacks_l1_fee() {
+    // The balance covers the gas fee but not the L1 data fee.
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = optimism_vm(
+        caller,
+        U256::from(GAS_PRICE * 100_000 + 54),
+        OpSpecId::Ecotone,
+    );
+    vm.optimism.tx.enveloped_tx = Some(Bytes::from_static(&hex!("00facade")));
+    assert!(matches!(vm.transact(), Err(VMError::Transaction(_))));
+}
+
+#[test]
+fn test_deposit_mints_without_fee() {
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = optimism_vm(caller, U256::ZERO, OpSpecId::Ecotone);
+    into_deposit(&mut vm);
+    let contract = vm.env.tx.kind.to().copied().unwrap();
+    let beneficiary = vm.env.block.beneficiary;
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+    assert_eq!(result.state[&caller].info.balance, U256::from(MINT));
+    assert_eq!(result.state[&caller].info.nonce, 1);
+    assert_eq!(
+        result.state[&contract].storage[&U256::ZERO].present_value,
+        U256::from(1)
+    );
+    for address in [beneficiary, BASE_FEE_VAULT, L1_FEE_VAULT] {
+        assert!(!result.state.contains_key(&address));
+    }
+}
+
+#[test]
+fn test_pre_regolith_deposit_uses_gas_limit() {
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = optimism_vm(caller, U256::ZERO, OpSpecId::Bedrock);
+    into_deposit(&mut vm);
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+    assert_eq!(result.result.gas_used(), 100_000);
+
+    // The successful system deposits use no gas.
+    let mut vm = optimism_vm(caller, U256::ZERO, OpSpecId::Bedrock);
+    into_deposit(&mut vm);
+    vm.optimism.tx.deposit.is_system_transaction = true;
+    let result = vm.transact().unwrap();
+    assert_eq!(result.result.gas_used(), 0);
+
+    // The system deposits are disabled since Regolith.
+    let mut vm = optimism_vm(caller, U256::ZERO, OpSpecId::Regolith);
+    into_deposit(&mut vm);
+    vm.optimism.tx.deposit.is_system_transaction = true;
+    assert!(matches!(vm.transact(), Err(VMError::Handler(_))));
+}
+
+#[test]
+fn test_failed_deposit_keeps_mint_and_nonce() {
+    // The gas limit doesn't cover the intrinsic gas, the deposit is still included.
+    let caller = Address::left_padding_from(&[1]);
+    let mut vm = optimism_vm(caller, U256::ZERO, OpSpecId::Ecotone);
+    into_deposit(&mut vm);
+    vm.env.tx.gas_limit = 1_000;
+    let contract = vm.env.tx.kind.to().copied().unwrap();
+    let result = vm.transact().unwrap();
+    assert_eq!(
+        result.result,
+        ExecutionResult::Revert {
+            output: Bytes::new(),
+            gas_used: 1_000,
+        }
+    );
+    assert_eq!(result.state[&caller].info.balance, U256::from(MINT));
+    assert_eq!(result.state[&caller].info.nonce, 1);
+    assert!(!result.state.contains_key(&contract));
+}
@@ -0,0 +1,183 @@
+use dora_compiler::evm::program::{Operation, Program};
+use dora_primitives::{Bytes, TxKind, spec::SpecId};
+use dora_runtime::{
+    constants::{
+        TX_GAS_LIMIT_CAP,
+        gas_cost::{MAX_CODE_SIZE, OSAKA_MAX_CODE_SIZE},
+    },
+    context::VMContext,
+    gas::{large_contract_cost, max_code_size},
+    precompile::{MODEXP_MAX_INPUT_LEN, osaka_modexp},
+    result::VMError,
+    vm::VM,
+};
+use num_bigint::BigUint;
+
+use crate::tests::utils::{
+    default_env_and_db_setup, run_program_assert_num_result, run_result_with_spec,
+};
+use crate::{compile_handler, run};
+
+/// Returns the leading zero bits of the value.
+fn clz_operations(value: BigUint) -> Vec<Operation> {
+    vec![
+        Operation::Push((32_u8, value)),
+        Operation::Clz,
+        // Return result
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Return,
+    ]
+}
+
+/// Returns the MODEXP input of the lengths followed by the data.
+fn modexp_input(base_len: usize, exp_len: usize, mod_len: usize, data: &[u8]) -> Vec<u8> {
+    let mut input = Vec::new();
+    for len in [base_len, exp_len, mod_len] {
+        let mut word = [0_u8; 32];
+        word[24..].copy_from_slice(&(len as u64).to_be_bytes());
+        input.extend_from_slice(&word);
+    }
+    input.extend_from_slice(data);
+    input
+}
+
+#[test]
+fn test_clz() {
+    for (value, expected) in [
+        (BigUint::ZERO, 256_u32),
+        (BigUint::from(1_u8), 255),
+        (BigUint::from(0xff_u8), 248),
+        (BigUint::from(1_u8) << 255, 0),
+    ] {
+        let (mut env, db) = default_env_and_db_setup(clz_operations(value));
+        env.cfg.spec = SpecId::OSAKA;
+        env.tx.gas_limit = TX_GAS_LIMIT_CAP;
+        run_program_assert_num_result(env, db, expected.into());
+    }
+}
+
+#[test]
+fn test_clz_gas() {
+    let operations = vec![Operation::Push0, Operation::Clz];
+    let result = run_result_with_spec(operations, SpecId::OSAKA);
+    assert!(result.status.is_ok());
+    assert_eq!(result.gas_used(), 2 + 5);
+}
+
+#[test]
+fn test_clz_not_found_before_osaka() {
+    let operations = vec![Operation::Push0, Operation::Clz];
+    let result = run_result_with_spec(operations, SpecId::PRAGUE);
+    assert!(result.status.is_opcode_not_found());
+}
+
+#[test]
+fn test_tx_gas_limit_cap() {
+    for (spec_id, gas_limit, capped) in [
+        (SpecId::OSAKA, TX_GAS_LIMIT_CAP, false),
+        (SpecId::OSAKA, TX_GAS_LIMIT_CAP + 1, true),
+        (SpecId::PRAGUE, TX_GAS_LIMIT_CAP + 1, false),
+    ] {
+        let (mut env, db) = default_env_and_db_setup(vec![Operation::Stop]);
+        env.cfg.spec = spec_id;
+        env.tx.gas_limit = gas_limit;
+        let result = VM::new(VMContext::new(db, env, compile_handler())).transact();
+        if capped {
+            assert_eq!(
+                result.unwrap_err(),
+                VMError::TxGasLimitGreaterThanCap {
+                    gas_limit,
+                    cap: TX_GAS_LIMIT_CAP
+                }
+            );
+        } else {
+            assert!(result.unwrap().result.is_success());
+        }
+    }
+}
+
+#[test]
+fn test_osaka_modexp() {
+    // 3 ** 2 % 5 at the minimum gas cost.
+    let input = modexp_input(1, 1, 1, &[3, 2, 5]);
+    let output = osaka_modexp(&input, 500).unwrap();
+    assert_eq!(output.gas_used, 500);
+    assert_eq!(output.bytes, Bytes::from_static(&[4]));
+    assert!(osaka_modexp(&input, 499).unwrap_err().is_oog());
+    // The zero modulus results in zeros.
+    let output = osaka_modexp(&modexp_input(1, 1, 2, &[3, 2]), 500).unwrap();
+    assert_eq!(output.bytes, Bytes::from_static(&[0, 0]));
+}
+
+#[test]
+fn test_osaka_modexp_gas() {
+    // The multiplication complexity is 2 * (64 / 8) ** 2 and the iteration count of the 33-byte
+    // exponent 0x01_00 is 16 * (33 - 32).
+    let mut data = vec![0_u8; 64 + 33 + 64];
+    data[63] = 2;
+    data[64 + 31] = 1;
+    data[64 + 33 + 63] = 7;
+    let input = modexp_input(64, 33, 64, &data);
+    let output = osaka_modexp(&input, 2048).unwrap();
+    assert_eq!(output.gas_used, 2 * 8 * 8 * 16);
+    // 2 ** 256 % 7 = 2
+    assert_eq!(output.bytes.len(), 64);
+    assert_eq!(output.bytes[63], 2);
+    assert!(osaka_modexp(&input, 2047).unwrap_err().is_oog());
+}
+
+#[test]
+fn test_osaka_modexp_input_bound() {
+    let input = modexp_input(MODEXP_MAX_INPUT_LEN, 1, 1, &[]);
+    assert!(osaka_modexp(&input, u64::MAX).is_ok());
+    let max_len = MODEXP_MAX_INPUT_LEN + 1;
+    for (base_len, exp_len, mod_len) in [(max_len, 0, 0), (0, max_len, 0), (0, 0, max_len)] {
+        let input = modexp_input(base_len, exp_len, mod_len, &[]);
+        assert!(!osaka_modexp(&input, u64::MAX).unwrap_err().is_oog());
+    }
+}
+
+#[test]
+fn test_large_contract_cost() {
+    assert_eq!(max_code_size(SpecId::PRAGUE), MAX_CODE_SIZE);
+    assert_eq!(max_code_size(SpecId::OSAKA), OSAKA_MAX_CODE_SIZE);
+    assert_eq!(large_contract_cost(SpecId::OSAKA, MAX_CODE_SIZE, true), 0);
+    assert_eq!(
+        large_contract_cost(SpecId::OSAKA, MAX_CODE_SIZE + 1, true),
+        2
+    );
+    assert_eq!(
+        large_contract_cost(SpecId::OSAKA, MAX_CODE_SIZE + 64, true),
+        4
+    );
+    assert_eq!(
+        large_contract_cost(SpecId::OSAKA, MAX_CODE_SIZE + 64, false),
+        0
+    );
+    assert_eq!(
+        large_contract_cost(SpecId::PRAGUE, MAX_CODE_SIZE + 64, true),
+        0
+    );
+}
+
+#[test]
+fn test_create_large_contract() {
+    // Returns the code of one byte beyond the EIP-170 limit.
+    let init_code = Program::operations_to_opcode(&[
+        Operation::Push((2_u8, (MAX_CODE_SIZE + 1).into())),
+        Operation::Push0,
+        Operation::Return,
+    ]);
+    for (spec_id, success) in [(SpecId::PRAGUE, false), (SpecId::OSAKA, true)] {
+        let (mut env, db) = default_env_and_db_setup(vec![]);
+        env.cfg.spec = spec_id;
+        env.tx.gas_limit = TX_GAS_LIMIT_CAP;
+        env.tx.kind = TxKind::Create;
+        env.tx.data = init_code.clone().into();
+        let result = run(env, db).unwrap();
+        assert_eq!(result.is_success(), success, "{:?}", result);
+    }
+}
@@ -0,0 +1,148 @@
+use dora_primitives::{Address, Env, TxEnv, TxKind, U256};
+use dora_runtime::{
+    block::{Block, BlockError, BlockExecutor, BlockOutput},
+    context::VMContext,
+    db::MemoryDB,
+    parallel::ParallelBlockExecutor,
+};
+
+use crate::compile_handler;
+use crate::tests::utils::{counter_operations, default_env_and_db_setup};
+
+fn account(index: u8) -> Address {
+    Address::left_padding_from(&[1, index])
+}
+
+fn transaction(caller: Address, to: Address, value: u64, nonce: u64) -> TxEnv {
+    TxEnv {
+        caller,
+        kind: TxKind::Call(to),
+        value: U256::from(value),
+        gas_limit: 100_000,
+        gas_price: 10,
+        nonce,
+        chain_id: Some(1),
+        ..Default::default()
+    }
+}
+
+/// Executes the block with the sequential and parallel executors and checks the outputs and the
+/// committed states are the same.
+fn assert_parallel_block(
+    env: Env,
+    db: MemoryDB,
+    block: Block,
+    accounts: &[Address],
+) -> BlockOutput {
+    let mut sequential =
+        BlockExecutor::new(VMContext::new(db.clone(), env.clone(), compile_handler()));
+    let expected = sequential.execute_block(block.clone()).unwrap();
+    let mut parallel = ParallelBlockExecutor::new(db, env.cfg, compile_handler, 4).unwrap();
+    let output = parallel.execute_block(block).unwrap();
+    assert_eq!(output.receipts, expected.receipts);
+    assert_eq!(output.results, expected.results);
+    assert_eq!(output.gas_used, expected.gas_used);
+    assert_eq!(output.logs_bloom, expected.logs_bloom);
+
+    let expected_db = sequential.into_context().journal.database;
+    let db = parallel.into_db();
+    for address in accounts {
+        assert_eq!(db.get_balance(*address), expected_db.get_balance(*address));
+        assert_eq!(
+            db.sload(*address, U256::ZERO),
+            expected_db.sload(*address, U256::ZERO)
+        );
+    }
+    output
+}
+
+fn funded_setup(callers: u8) -> (Env, MemoryDB, Address) {
+    let (mut env, mut db) = default_env_and_db_setup(counter_operations());
+    env.block.gas_limit = 10_000_000;
+    let contract = env.tx.kind.to().copied().unwrap();
+    for index in 0..callers {
+        db.set_balance(account(index), U256::from(10_000_000_000_u64));
+    }
+    (env, db, contract)
+}
+
+#[test]
+fn test_parallel_independent_transfers() {
+    let (env, db, _) = funded_setup(8);
+    let transactions = (0..8)
+        .map(|index| transaction(account(index), account(100 + index), 1000, 0))
+        .collect::<Vec<_>>();
+    let mut accounts = (0..8)
+        .flat_map(|index| [account(index), account(100 + index)])
+        .collect::<Vec<_>>();
+    accounts.push(env.block.beneficiary);
+    let block = Block {
+        env: env.block.clone(),
+        transactions,
+        ..Default::default()
+    };
+    let output = assert_parallel_block(env, db, block, &accounts);
+    assert_eq!(output.receipts.len(), 8);
+    assert!(output.receipts.iter().all(|receipt| receipt.success));
+}
+
+#[test]
+fn test_parallel_conflicting_counter() {
+    let (env, db, contract) = funded_setup(8);
+    let transactions = (0..8)
+        .map(|index| transaction(account(index), contract, 0, 0))
+        .collect::<Vec<_>>();
+    let block = Block {
+        env: env.block.clone(),
+        transactions,
+        ..Default::default()
+    };
+    let output = assert_parallel_block(env, db, block, &[contract]);
+    assert_eq!(
+        output.state[&contract].storage[&U256::ZERO].present_value,
+        U256::from(8)
+    );
+}
+
+#[test]
+fn test_parallel_dependent_transfers() {
+    let (env, db, contract) = funded_setup(1);
+    // Each account spends the value received from the previous transaction.
+    let mut transactions = (0..6)
+        .map(|index| transaction(account(index), account(index + 1), 1_000_000_000, 0))
+        .collect::<Vec<_>>();
+    for (index, tx) in transactions.iter_mut().enumerate().skip(1) {
+        tx.value = U256::from(1_000_000_000 - 10_000_000 * index as u64);
+    }
+    // The same sender with increasing nonces.
+    transactions.extend((1..4).map(|nonce| transaction(account(0), contract, 0, nonce)));
+    let mut accounts = (0..7).map(account).collect::<Vec<_>>();
+    accounts.push(contract);
+    accounts.push(env.block.beneficiary);
+    let block = Block {
+        env: env.block.clone(),
+        transactions,
+        ..Default::default()
+    };
+    let output = assert_parallel_block(env, db, block, &accounts);
+    assert!(output.receipts.iter().all(|receipt| receipt.success));
+}
+
+#[test]
+fn test_parallel_invalid_transaction() {
+    let (env, db, contract) = funded_setup(2);
+    let block = Block {
+        env: env.block.clone(),
+        transactions: vec![
+            transaction(account(0), contract, 0, 0),
+            transaction(account(1), contract, 0, 0),
+            // The nonce of the sender is already increased.
+            transaction(account(0), contract, 0, 0),
+        ],
+        ..Default::default()
+    };
+    let mut executor = ParallelBlockExecutor::new(db, env.cfg, compile_handler, 4).unwrap();
+    let err = executor.execute_block(block).unwrap_err();
+    assert!(matches!(err, BlockError::Transaction { index: 2, .. }));
+    assert_eq!(executor.db().sload(contract, U256::ZERO), U256::from(2));
+}
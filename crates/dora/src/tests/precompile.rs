@@ -0,0 +1,280 @@
+use dora_compiler::evm::program::Operation;
+use dora_primitives::{
+    Address, Bytes, JournalTr, Log, LogData, PrecompileError, PrecompileOutput, U256, address,
+};
+use dora_runtime::{
+    call::{CallKind, CallMessage},
+    context::VMContext,
+    precompile::{ContextPrecompile, PrecompileInput},
+    result::VMError,
+    vm::VM,
+};
+use num_bigint::BigUint;
+
+use crate::compile_handler;
+use crate::tests::utils::default_env_and_db_setup;
+
+const PRECOMPILE: Address = address!("0000000000000000000000000000000000000100");
+
+/// Calls the precompile with the 32-byte `input` and returns the call status followed by the
+/// 32-byte output.
+fn call_precompile_operations(address: Address, input: u8) -> Vec<Operation> {
+    vec![
+        Operation::Push((1_u8, input.into())),
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push((20_u8, BigUint::from_bytes_be(address.as_slice()))),
+        Operation::Gas,
+        Operation::Call,
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 64_u8.into())),
+        Operation::Push0,
+        Operation::Return,
+    ]
+}
+
+/// Static calls the precompile like [`call_precompile_operations`].
+fn static_call_precompile_operations(address: Address, input: u8) -> Vec<Operation> {
+    let mut operations = call_precompile_operations(address, input);
+    // Remove the call value and replace the call.
+    operations.remove(7);
+    let call = operations
+        .iter()
+        .position(|op| matches!(op, Operation::Call))
+        .unwrap();
+    operations[call] = Operation::Staticcall;
+    operations
+}
+
+fn run_with_precompile(
+    address: Address,
+    input: u8,
+    precompile: ContextPrecompile,
+) -> dora_primitives::ResultAndState {
+    run_operations_with_precompile(
+        call_precompile_operations(address, input),
+        address,
+        precompile,
+    )
+}
+
+fn run_operations_with_precompile(
+    operations: Vec<Operation>,
+    address: Address,
+    precompile: ContextPrecompile,
+) -> dora_primitives::ResultAndState {
+    let (env, mut db) = default_env_and_db_setup(operations);
+    // Keep the precompile account non-empty to inspect its storage.
+    db.set_balance(address, U256::from(1));
+    let ctx = VMContext::new(db, env, compile_handler()).with_precompile(address, precompile);
+    VM::new(ctx).transact().unwrap()
+}
+
+fn output_words(output: &Bytes) -> (U256, U256) {
+    (
+        U256::from_be_slice(&output[..32]),
+        U256::from_be_slice(&output[32..64]),
+    )
+}
+
+#[test]
+fn test_stateless_precompile() {
+    let precompile = ContextPrecompile::stateless(|input, _gas_limit| {
+        let value = U256::from_be_slice(input) * U256::from(2);
+        Ok(PrecompileOutput::new(
+            100,
+            Bytes::from(value.to_be_bytes::<32>()),
+        ))
+    });
+    let result = run_with_precompile(PRECOMPILE, 21, precompile);
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output), (U256::from(1), U256::from(42)));
+}
+
+#[test]
+fn test_stateless_precompile_overrides_builtin() {
+    // The identity precompile at 0x04 is replaced.
+    let identity = address!("0000000000000000000000000000000000000004");
+    let precompile = ContextPrecompile::stateless(|_input, _gas_limit| {
+        Ok(PrecompileOutput::new(
+            15,
+            Bytes::from(U256::from(7).to_be_bytes::<32>()),
+        ))
+    });
+    let result = run_with_precompile(identity, 1, precompile);
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output), (U256::from(1), U256::from(7)));
+}
+
+#[test]
+fn test_precompile_out_of_gas() {
+    let precompile = ContextPrecompile::stateless(|_input, gas_limit| {
+        Ok(PrecompileOutput::new(gas_limit + 1, Bytes::new()))
+    });
+    let result = run_with_precompile(PRECOMPILE, 1, precompile);
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output).0, U256::ZERO);
+
+    let precompile =
+        ContextPrecompile::stateless(|_input, _gas_limit| Err(PrecompileError::OutOfGas));
+    let result = run_with_precompile(PRECOMPILE, 1, precompile);
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output).0, U256::ZERO);
+}
+
+#[test]
+fn test_stateful_precompile() {
+    let precompile = ContextPrecompile::stateful(|input: &PrecompileInput<'_>, host| {
+        let address = input.target_address;
+        let stored = host.sload(address, U256::ZERO).unwrap().data;
+        let value = stored + U256::from_be_slice(input.data);
+        host.sstore(address, U256::ZERO, value).unwrap();
+        host.log(Log {
+            address,
+            data: LogData::new_unchecked(vec![], input.data.clone()),
+        });
+        Ok(PrecompileOutput::new(
+            5000,
+            Bytes::from(value.to_be_bytes::<32>()),
+        ))
+    });
+    let result = run_with_precompile(PRECOMPILE, 42, precompile);
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output), (U256::from(1), U256::from(42)));
+    assert_eq!(result.result.logs().len(), 1);
+    assert_eq!(result.result.logs()[0].address, PRECOMPILE);
+    assert_eq!(
+        result.state[&PRECOMPILE].storage[&U256::ZERO].present_value,
+        U256::from(42)
+    );
+}
+
+#[test]
+fn test_stateful_precompile_error_reverts_state() {
+    let precompile = ContextPrecompile::stateful(|input: &PrecompileInput<'_>, host| {
+        host.sstore(input.target_address, U256::ZERO, U256::from(1))
+            .unwrap();
+        Err(PrecompileError::Other("failed".to_string()))
+    });
+    let result = run_with_precompile(PRECOMPILE, 1, precompile);
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output).0, U256::ZERO);
+    assert!(
+        result.state[&PRECOMPILE]
+            .storage
+            .get(&U256::ZERO)
+            .is_none_or(|slot| slot.present_value.is_zero())
+    );
+}
+
+#[test]
+fn test_stateful_precompile_static_call() {
+    // The read-only precompile can be static called.
+    let precompile = ContextPrecompile::stateful(|input: &PrecompileInput<'_>, host| {
+        assert!(input.is_static);
+        let value = host.balance(input.target_address).unwrap().data;
+        Ok(PrecompileOutput::new(
+            100,
+            Bytes::from(value.to_be_bytes::<32>()),
+        ))
+    });
+    let result = run_operations_with_precompile(
+        static_call_precompile_operations(PRECOMPILE, 1),
+        PRECOMPILE,
+        precompile,
+    );
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output), (U256::from(1), U256::from(1)));
+}
+
+#[test]
+fn test_stateful_precompile_static_call_state_change() {
+    let precompile = ContextPrecompile::stateful(|input: &PrecompileInput<'_>, host| {
+        host.sstore(input.target_address, U256::ZERO, U256::from(1))
+            .map_err(|err| PrecompileError::Other(err.to_string()))?;
+        Ok(PrecompileOutput::new(0, Bytes::new()))
+    });
+    let result = run_operations_with_precompile(
+        static_call_precompile_operations(PRECOMPILE, 1),
+        PRECOMPILE,
+        precompile,
+    );
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let output = result.result.output().unwrap();
+    assert_eq!(output_words(output).0, U256::ZERO);
+    assert!(
+        result.state[&PRECOMPILE]
+            .storage
+            .get(&U256::ZERO)
+            .is_none_or(|slot| slot.present_value.is_zero())
+    );
+}
+
+#[test]
+fn test_custom_precompile_is_warm() {
+    let (env, db) = default_env_and_db_setup(vec![Operation::Stop]);
+    let precompile = ContextPrecompile::stateless(|_input, _gas_limit| {
+        Ok(PrecompileOutput::new(0, Bytes::new()))
+    });
+    let mut ctx =
+        VMContext::new(db, env, compile_handler()).with_precompile(PRECOMPILE, precompile);
+    ctx.set_precompiles();
+    assert!(ctx.journal.warm_preloaded_addresses.contains(&PRECOMPILE));
+    assert!(
+        ctx.journal
+            .warm_preloaded_addresses
+            .contains(&address!("0000000000000000000000000000000000000001"))
+    );
+}
+
+#[test]
+fn test_stateful_precompile_fatal_error_reverts_state() {
+    let precompile = ContextPrecompile::stateful(|input: &PrecompileInput<'_>, host| {
+        host.sstore(input.target_address, U256::ZERO, U256::from(1))
+            .unwrap();
+        Err(PrecompileError::Fatal("fatal".to_string()))
+    });
+    let (env, db) = default_env_and_db_setup(vec![Operation::Stop]);
+    let caller = env.tx.kind.to().copied().unwrap();
+    let mut ctx =
+        VMContext::new(db, env, compile_handler()).with_precompile(PRECOMPILE, precompile);
+    let msg = CallMessage {
+        kind: CallKind::Call,
+        input: Bytes::new(),
+        init_code: Bytes::new(),
+        value: U256::from(1),
+        depth: 0,
+        gas_limit: 100_000,
+        caller,
+        recipient: PRECOMPILE,
+        salt: None,
+        code_address: PRECOMPILE,
+        is_static: false,
+        is_eof_init: false,
+        validate_eof: true,
+    };
+    let result = ctx.call(msg);
+    assert!(
+        matches!(result, Err(VMError::Precompile(_))),
+        "{:?}",
+        result
+    );
+    // The value transfer and the storage write are reverted.
+    assert_eq!(ctx.journal.depth(), 0);
+    assert_eq!(ctx.balance(caller).unwrap().data, U256::from(10));
+    assert_eq!(ctx.balance(PRECOMPILE).unwrap().data, U256::ZERO);
+    assert_eq!(ctx.sload(PRECOMPILE, U256::ZERO).unwrap().data, U256::ZERO);
+}
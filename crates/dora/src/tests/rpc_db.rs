@@ -0,0 +1,214 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+use dora_compiler::evm::Program;
+use dora_primitives::{Address, Env, TxKind, U256, keccak256};
+use dora_runtime::{
+    context::VMContext,
+    db::{Database, RpcDB},
+    result::VMError,
+    vm::VM,
+};
+use serde_json::{Value, json};
+
+use crate::compile_handler;
+use crate::tests::utils::counter_operations;
+
+const BLOCK: u64 = 16;
+const CONTRACT: u8 = 40;
+
+/// Answers the request with the state at the block [`BLOCK`], the counter contract has the
+/// value 5 at the storage slot 0.
+fn answer(method: &str, params: &[Value]) -> Result<Value, &'static str> {
+    let contract = Address::left_padding_from(&[CONTRACT]);
+    let tag = json!(format!("{BLOCK:#x}"));
+    let address = || serde_json::from_value::<Address>(params[0].clone()).unwrap();
+    match method {
+        "eth_getBlockByNumber" => {
+            let number = params[0].as_str().unwrap();
+            Ok(json!({ "hash": keccak256(number.as_bytes()) }))
+        }
+        _ if params.last() != Some(&tag) => Err("header not found"),
+        "eth_getBalance" | "eth_getTransactionCount" => Ok(json!("0x0")),
+        "eth_getCode" if address() == contract => Ok(json!(format!(
+            "0x{}",
+            hex::encode(Program::operations_to_opcode(&counter_operations()))
+        ))),
+        "eth_getCode" => Ok(json!("0x")),
+        "eth_getStorageAt" if address() == contract => Ok(json!("0x5")),
+        "eth_getStorageAt" => Ok(json!("0x0")),
+        _ => Err("method not found"),
+    }
+}
+
+fn respond(request: &Value) -> Value {
+    let params = request["params"].as_array().unwrap();
+    match answer(request["method"].as_str().unwrap(), params) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+        Err(message) => json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "error": { "code": -32000, "message": message },
+        }),
+    }
+}
+
+/// Serves the HTTP requests of the connection until it is closed.
+fn serve(stream: TcpStream, requests: Arc<AtomicUsize>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut stream = stream;
+    loop {
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        requests.fetch_add(1, Ordering::SeqCst);
+        let body = match serde_json::from_slice(&body).unwrap() {
+            Value::Array(batch) => Value::Array(batch.iter().map(respond).collect()),
+            request => respond(&request),
+        }
+        .to_string();
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+    }
+}
+
+/// Starts the mock JSON-RPC server and returns its URL and the number of the served requests.
+fn mock_server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let requests = counter.clone();
+            thread::spawn(move || serve(stream, requests));
+        }
+    });
+    (url, requests)
+}
+
+/// Returns an empty directory for the cache of the test.
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dora-rpc-db-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn counter_env() -> Env {
+    let mut env = Env::default();
+    env.tx.kind = TxKind::Call(Address::left_padding_from(&[CONTRACT]));
+    env.tx.gas_limit = 100_000;
+    env.block.gas_limit = 1_000_000;
+    env.block.number = BLOCK + 1;
+    env
+}
+
+/// Executes the counter transaction and returns the stored counter and the database.
+fn execute_counter(db: RpcDB) -> (U256, RpcDB) {
+    let contract = Address::left_padding_from(&[CONTRACT]);
+    let mut vm = VM::new(VMContext::new(db, counter_env(), compile_handler()));
+    let result = vm.transact_commit().unwrap();
+    assert!(result.is_success(), "{result:?}");
+    let mut db = vm.context.journal.database;
+    (db.storage(contract, U256::ZERO).unwrap(), db)
+}
+
+#[test]
+fn test_rpc_db_fetches_state_at_block() {
+    let (url, requests) = mock_server();
+    let (value, mut db) = execute_counter(RpcDB::new(url, BLOCK));
+    assert_eq!(value, U256::from(6));
+    let served = requests.load(Ordering::SeqCst);
+    assert!(served > 0);
+    assert_eq!(
+        db.block_hash(BLOCK).unwrap(),
+        keccak256(format!("{BLOCK:#x}").as_bytes())
+    );
+    // The block hash is fetched once.
+    db.block_hash(BLOCK).unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), served + 1);
+}
+
+#[test]
+fn test_rpc_db_missing_account() {
+    let (url, requests) = mock_server();
+    let mut db = RpcDB::new(url, BLOCK);
+    let address = Address::left_padding_from(&[CONTRACT + 1]);
+    assert_eq!(db.basic(address).unwrap(), None);
+    let served = requests.load(Ordering::SeqCst);
+    // The missing account is not refetched and has no storage.
+    assert_eq!(db.basic(address).unwrap(), None);
+    assert_eq!(db.storage(address, U256::ZERO).unwrap(), U256::ZERO);
+    assert_eq!(requests.load(Ordering::SeqCst), served);
+}
+
+#[test]
+fn test_rpc_db_cache_serves_offline() {
+    let dir = cache_dir("offline");
+    let (url, requests) = mock_server();
+    let db = RpcDB::new(url, BLOCK).with_cache_dir(&dir).unwrap();
+    let (_, mut db) = execute_counter(db);
+    db.block_hash(BLOCK).unwrap();
+    db.flush_cache().unwrap();
+    drop(db);
+    let served = requests.load(Ordering::SeqCst);
+
+    // The endpoint is unreachable, thus all the state is read from the cache, and the committed
+    // changes of the previous run are not cached.
+    let db = RpcDB::new("http://127.0.0.1:1", BLOCK)
+        .with_cache_dir(&dir)
+        .unwrap();
+    let (value, mut db) = execute_counter(db);
+    assert_eq!(value, U256::from(6));
+    assert_eq!(
+        db.block_hash(BLOCK).unwrap(),
+        keccak256(format!("{BLOCK:#x}").as_bytes())
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), served);
+    // The uncached state still needs the endpoint.
+    assert!(db.block_hash(BLOCK - 1).is_err());
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_rpc_db_error_fails_transaction() {
+    let (url, _) = mock_server();
+    let mut vm = VM::new(VMContext::new(
+        RpcDB::new(url, BLOCK + 1),
+        counter_env(),
+        compile_handler(),
+    ));
+    let VMError::Database(error) = vm.transact().unwrap_err() else {
+        panic!("expected a database error");
+    };
+    assert!(error.to_string().contains("header not found"), "{error}");
+}
@@ -0,0 +1,193 @@
+use dora_compiler::evm::{Program, program::Operation};
+use dora_primitives::{Address, Bytecode, Bytes, InvalidTransaction, TxKind, U256};
+use dora_runtime::{
+    result::VMError,
+    simulate::{AccountOverride, BlockOverrides, Simulation, StorageOverride},
+};
+
+use crate::simulate;
+use crate::tests::utils::default_env_and_db_setup;
+
+const WORDS: u8 = 7;
+
+/// Returns the storage slots 0 and 1, the block number, timestamp, base fee, coinbase and the
+/// contract balance.
+fn inspect_operations() -> Vec<Operation> {
+    let values = [
+        vec![Operation::Push0, Operation::SLoad],
+        vec![Operation::Push((1_u8, 1_u8.into())), Operation::SLoad],
+        vec![Operation::Number],
+        vec![Operation::Timestamp],
+        vec![Operation::BaseFee],
+        vec![Operation::Coinbase],
+        vec![Operation::SelfBalance],
+    ];
+    let mut operations = Vec::new();
+    for (index, value) in values.into_iter().enumerate() {
+        operations.extend(value);
+        operations.push(Operation::Push((1_u8, (index as u8 * 32).into())));
+        operations.push(Operation::MStore);
+    }
+    operations.extend([
+        Operation::Push((1_u8, (WORDS * 32).into())),
+        Operation::Push0,
+        Operation::Return,
+    ]);
+    operations
+}
+
+fn output_words(output: &Bytes) -> Vec<U256> {
+    output.chunks(32).map(U256::from_be_slice).collect()
+}
+
+fn run_simulation(simulation: Simulation) -> Result<Vec<U256>, VMError> {
+    let (env, mut db) = default_env_and_db_setup(inspect_operations());
+    let contract = env.tx.kind.to().copied().unwrap();
+    db.sstore(contract, U256::ZERO, U256::from(1));
+    db.sstore(contract, U256::from(1), U256::from(2));
+    let result = simulate(env, db, simulation)?;
+    assert!(result.result.is_success(), "{:?}", result.result);
+    Ok(output_words(result.result.output().unwrap()))
+}
+
+fn contract() -> Address {
+    Address::left_padding_from(&[40])
+}
+
+#[test]
+fn test_simulate_without_overrides() {
+    let words = run_simulation(Simulation::new()).unwrap();
+    assert_eq!(words[..2], [U256::from(1), U256::from(2)]);
+    assert_eq!(words[6], U256::from(10));
+}
+
+#[test]
+fn test_simulate_storage_overrides() {
+    let slots = [(U256::ZERO, U256::from(5))].into_iter().collect();
+    let words = run_simulation(Simulation::new().with_account_override(
+        contract(),
+        AccountOverride {
+            storage: Some(StorageOverride::Diff(slots)),
+            ..Default::default()
+        },
+    ))
+    .unwrap();
+    assert_eq!(words[..2], [U256::from(5), U256::from(2)]);
+
+    let slots = [(U256::ZERO, U256::from(5))].into_iter().collect();
+    let words = run_simulation(Simulation::new().with_account_override(
+        contract(),
+        AccountOverride {
+            balance: Some(U256::from(7)),
+            storage: Some(StorageOverride::Replace(slots)),
+            ..Default::default()
+        },
+    ))
+    .unwrap();
+    assert_eq!(words[..2], [U256::from(5), U256::ZERO]);
+    assert_eq!(words[6], U256::from(7));
+}
+
+#[test]
+fn test_simulate_block_overrides() {
+    let coinbase = Address::left_padding_from(&[99]);
+    let overrides = BlockOverrides {
+        number: Some(100),
+        timestamp: Some(200),
+        basefee: Some(300),
+        coinbase: Some(coinbase),
+    };
+    // The gas price of the call is below the overridden base fee.
+    let err = run_simulation(Simulation::new().with_block_overrides(overrides.clone()));
+    assert!(matches!(
+        err,
+        Err(VMError::Transaction(
+            InvalidTransaction::GasPriceLessThanBasefee
+        ))
+    ));
+    let words = run_simulation(
+        Simulation::new()
+            .with_block_overrides(overrides)
+            .disable_base_fee(),
+    )
+    .unwrap();
+    assert_eq!(
+        words[2..6],
+        [
+            U256::from(100),
+            U256::from(200),
+            U256::from(300),
+            U256::from_be_bytes(coinbase.into_word().0)
+        ]
+    );
+}
+
+#[test]
+fn test_simulate_relaxed_checks() {
+    let (mut env, db) = default_env_and_db_setup(inspect_operations());
+    env.tx.nonce = 3;
+    env.tx.value = U256::from(1000);
+    let err = simulate(env.clone(), db.clone(), Simulation::new()).unwrap_err();
+    assert!(matches!(
+        err,
+        VMError::Transaction(InvalidTransaction::NonceTooHigh { .. })
+    ));
+    let err = simulate(
+        env.clone(),
+        db.clone(),
+        Simulation::new().disable_nonce_check(),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        VMError::Transaction(InvalidTransaction::LackOfFundForMaxFee { .. })
+    ));
+    let result = simulate(
+        env.clone(),
+        db.clone(),
+        Simulation::new()
+            .disable_nonce_check()
+            .disable_balance_check(),
+    )
+    .unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+
+    // The overridden caller nonce and balance pass the checks.
+    let caller = AccountOverride {
+        balance: Some(U256::from(1000)),
+        nonce: Some(3),
+        ..Default::default()
+    };
+    let result = simulate(
+        env,
+        db,
+        Simulation::new().with_account_override(Address::ZERO, caller),
+    )
+    .unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+}
+
+#[test]
+fn test_simulate_code_override() {
+    let (mut env, db) = default_env_and_db_setup(vec![Operation::Stop]);
+    let target = Address::left_padding_from(&[41]);
+    env.tx.kind = TxKind::Call(target);
+    let code = Program::from_operations(inspect_operations(), false).to_opcode();
+    let result = simulate(
+        env,
+        db,
+        Simulation::new().with_account_override(
+            target,
+            AccountOverride {
+                balance: Some(U256::from(9)),
+                code: Some(Bytecode::new_raw(code.into())),
+                ..Default::default()
+            },
+        ),
+    )
+    .unwrap();
+    assert!(result.result.is_success(), "{:?}", result.result);
+    let words = output_words(result.result.output().unwrap());
+    assert_eq!(words.len(), WORDS as usize);
+    assert_eq!(words[6], U256::from(9));
+}
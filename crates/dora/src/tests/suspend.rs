@@ -0,0 +1,151 @@
+use std::{sync::Arc, thread};
+
+use dora_compiler::evm::program::Operation;
+use dora_primitives::{Address, Bytes, Env, U256};
+use dora_runtime::{
+    RUNTIME_STACK_SIZE,
+    call::{CallKind, CallMessage},
+    context::VMContext,
+    db::MemoryDB,
+    handler::Handler,
+    result::{ResultAndState, VMError},
+    scheduler::FrameScheduler,
+    vm::VM,
+};
+
+use crate::tests::utils::default_env_and_db_setup;
+use crate::{compile_handler, suspend_compile_handler};
+
+const RECURSION_GAS: u64 = 1_000_000_000_000;
+
+/// Increments the storage slot 0 and then calls itself with all the gas.
+fn recursion_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push0,
+        Operation::SLoad,
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Add,
+        Operation::Push0,
+        Operation::SStore,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Address,
+        Operation::Gas,
+        Operation::Call,
+        Operation::Stop,
+    ]
+}
+
+fn recursion_setup() -> (Env, MemoryDB, Address) {
+    let (mut env, db) = default_env_and_db_setup(recursion_operations());
+    env.tx.gas_limit = RECURSION_GAS;
+    env.block.gas_limit = RECURSION_GAS;
+    let address = env.tx.kind.to().copied().unwrap();
+    (env, db, address)
+}
+
+fn run_recursion(handler: fn() -> Handler<MemoryDB>, stack_size: usize) -> ResultAndState {
+    thread::Builder::new()
+        .stack_size(stack_size)
+        .spawn(move || {
+            let (env, db, _) = recursion_setup();
+            VM::new(VMContext::new(db, env, handler()))
+                .transact()
+                .unwrap()
+        })
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+fn counter(result: &ResultAndState, address: Address) -> U256 {
+    result.state[&address].storage[&U256::ZERO].present_value
+}
+
+#[test]
+fn test_suspend_deep_recursion() {
+    let (_, _, address) = recursion_setup();
+    // The frames are driven in a loop thus a small native stack is enough.
+    let suspended = run_recursion(suspend_compile_handler, 8 * 1024 * 1024);
+    let recursive = run_recursion(compile_handler, RUNTIME_STACK_SIZE);
+    assert!(suspended.result.is_success(), "{:?}", suspended.result);
+    assert!(counter(&suspended, address) > U256::from(1000));
+    assert_eq!(counter(&suspended, address), counter(&recursive, address));
+    assert_eq!(suspended.result.gas_used(), recursive.result.gas_used());
+}
+
+#[test]
+fn test_suspend_frame_scheduler_step() {
+    let (env, db, address) = recursion_setup();
+    let mut ctx = VMContext::new(db, env, suspend_compile_handler());
+    let msg = CallMessage {
+        kind: CallKind::Call,
+        input: Bytes::new(),
+        init_code: Bytes::new(),
+        value: U256::ZERO,
+        depth: 0,
+        gas_limit: 10_000_000,
+        caller: ctx.env.tx.caller,
+        recipient: address,
+        salt: None,
+        code_address: address,
+        is_static: false,
+        is_eof_init: false,
+        validate_eof: true,
+    };
+    let mut scheduler = FrameScheduler::new();
+    let mut result = scheduler.start(&mut ctx, msg).unwrap();
+    let mut steps = 0;
+    while result.is_none() {
+        steps += 1;
+        let frames = scheduler.frames();
+        assert_eq!(frames.len(), steps);
+        let frame = frames.last().unwrap();
+        assert_eq!(frame.contract().target_address, address);
+        assert!(frame.resume_at() > 0);
+        // Only the placeholder result of the suspended `CALL` is left on the stack.
+        assert_eq!(frame.stack().len(), 1);
+        assert_eq!(scheduler.next_call().unwrap().recipient, address);
+        result = scheduler.step(&mut ctx).unwrap();
+    }
+    assert!(scheduler.frames().is_empty());
+    assert!(steps > 1);
+    assert!(result.unwrap().status.is_ok());
+}
+
+/// Fails the suspend handler of the nested frames with the error.
+fn failing_suspend_handler(error: VMError) -> Handler<MemoryDB> {
+    let mut handler = suspend_compile_handler();
+    let suspend_handler = handler.suspend_handler.clone().unwrap();
+    handler.suspend_handler = Some(Arc::new(move |frame, ctx| {
+        if frame.depth > 1 {
+            Err(error.clone())
+        } else {
+            suspend_handler(frame, ctx)
+        }
+    }));
+    handler
+}
+
+#[test]
+fn test_suspend_nested_frame_error() {
+    let (env, db, address) = recursion_setup();
+    let handler = failing_suspend_handler(VMError::Handler("compile error".to_string()));
+    let result = VM::new(VMContext::new(db, env.clone(), handler))
+        .transact()
+        .unwrap();
+    // The nested call fails and consumes all its gas, only the outermost frame increments the
+    // counter.
+    assert!(result.result.is_success(), "{:?}", result.result);
+    assert_eq!(counter(&result, address), U256::from(1));
+    assert!(result.result.gas_used() > RECURSION_GAS / 2);
+
+    // The database error of the nested frame fails the transaction.
+    let (_, db, _) = recursion_setup();
+    let handler = failing_suspend_handler(VMError::database("database error"));
+    let result = VM::new(VMContext::new(db, env, handler)).transact();
+    assert!(matches!(result, Err(VMError::Database(_))), "{:?}", result);
+}
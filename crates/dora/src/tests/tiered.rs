@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use dora_compiler::evm::{Program, program::Operation};
+use dora_primitives::{Bytes, keccak256, spec::SpecId};
+use dora_runtime::{
+    cache::{ArtifactCache, ArtifactKey},
+    context::VMContext,
+    db::MemoryDB,
+    vm::VM,
+};
+
+use crate::tests::utils::default_env_and_db_setup;
+use crate::{TieredCompiler, TieredConfig, compile_handler_with_cache, tiered_compile_handler};
+
+fn store_and_call_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push((1_u8, 2_u8.into())),
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::SStore,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push0,
+        Operation::Push((2_u8, 0x1000_u32.into())),
+        Operation::Push((2_u8, 0xFFFF_u32.into())),
+        Operation::Call,
+        Operation::Push0,
+        Operation::MStore,
+        Operation::Push((1_u8, 32_u8.into())),
+        Operation::Push0,
+        Operation::Return,
+    ]
+}
+
+fn transact(tiered: &Arc<TieredCompiler>) -> (u64, Bytes) {
+    let (env, db) = default_env_and_db_setup(store_and_call_operations());
+    let mut vm = VM::new(VMContext::new(
+        db,
+        env,
+        tiered_compile_handler::<MemoryDB>(tiered.clone()),
+    ));
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    (
+        result.result.gas_used(),
+        result.result.output().cloned().unwrap_or_default(),
+    )
+}
+
+fn contract_key() -> ArtifactKey {
+    let code = Program::from_operations(store_and_call_operations(), false).to_opcode();
+    ArtifactKey::new(keccak256(code), SpecId::CANCUN)
+}
+
+#[test]
+fn test_tiered_interpreter_matches_compiled_code() {
+    let cache = Arc::new(ArtifactCache::default());
+    // Never compile the code, all the executions run on the interpreter.
+    let tiered = Arc::new(
+        TieredCompiler::new(
+            TieredConfig::default()
+                .compile_threshold(u64::MAX)
+                .interpret_threshold(u64::MAX),
+            cache.clone(),
+        )
+        .unwrap(),
+    );
+    let (gas_used, output) = transact(&tiered);
+    assert!(cache.is_empty());
+    // The later executions on the interpreter are not counted as the cache misses.
+    let stats = cache.stats();
+    transact(&tiered);
+    transact(&tiered);
+    assert_eq!(cache.stats(), stats);
+    let (env, db) = default_env_and_db_setup(store_and_call_operations());
+    let mut vm = VM::new(VMContext::new(
+        db,
+        env,
+        compile_handler_with_cache(Arc::new(ArtifactCache::default())),
+    ));
+    let result = vm.transact().unwrap();
+    assert!(result.result.is_success(), "{:?}", result);
+    assert_eq!(gas_used, result.result.gas_used());
+    assert_eq!(&output, result.result.output().unwrap());
+    assert_eq!(output[31], 1);
+}
+
+#[test]
+fn test_tiered_switch_to_compiled_code() {
+    let cache = Arc::new(ArtifactCache::default());
+    let tiered = Arc::new(
+        TieredCompiler::new(
+            TieredConfig::default()
+                .compile_threshold(1)
+                .interpret_threshold(1)
+                .workers(1),
+            cache.clone(),
+        )
+        .unwrap(),
+    );
+    let key = contract_key();
+    // The first execution runs on the interpreter and submits the code to the compiler.
+    let (first_gas_used, _) = transact(&tiered);
+    // The second execution waits for the compiled artifact.
+    let (second_gas_used, _) = transact(&tiered);
+    assert!(cache.contains(&key));
+    assert_eq!(first_gas_used, second_gas_used);
+    let stats = cache.stats();
+    assert_eq!(stats.entries, 1);
+}
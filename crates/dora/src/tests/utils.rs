@@ -2,9 +2,11 @@ use dora_compiler::evm::{Program, program::Operation};
 use dora_primitives::{Address, Bytecode, Bytes, Env, Log, TxKind, U256, spec::SpecId};
 use dora_runtime::{
     ExitStatusCode,
-    context::{Contract, RuntimeContext},
+    context::{Contract, RuntimeContext, VMContext},
     db::MemoryDB,
+    handler::Handler,
     host::{DummyHost, Host},
+    vm::VM,
 };
 use num_bigint::{BigInt, BigUint};
 
@@ -12,6 +14,9 @@ use crate::{run, run_with_context};
 
 use super::INIT_GAS;
 
+/// The gas price of the counter transaction of [`counter_vm`].
+pub(crate) const GAS_PRICE: u128 = 10;
+
 #[derive(Debug, Clone)]
 pub(crate) struct TestResult {
     pub status: ExitStatusCode,
@@ -85,6 +90,33 @@ pub(crate) fn run_result_with_spec(operations: Vec<Operation>, spec_id: SpecId)
     }
 }
 
+/// Increments the counter at the storage slot 0.
+pub(crate) fn counter_operations() -> Vec<Operation> {
+    vec![
+        Operation::Push0,
+        Operation::SLoad,
+        Operation::Push((1_u8, 1_u8.into())),
+        Operation::Add,
+        Operation::Push0,
+        Operation::SStore,
+        Operation::Stop,
+    ]
+}
+
+/// Returns the VM of the counter transaction from the caller with the balance and the handler.
+pub(crate) fn counter_vm(
+    caller: Address,
+    balance: U256,
+    handler: Handler<MemoryDB>,
+) -> VM<MemoryDB> {
+    let (mut env, mut db) = default_env_and_db_setup(counter_operations());
+    env.tx.caller = caller;
+    env.tx.gas_limit = 100_000;
+    env.tx.gas_price = GAS_PRICE;
+    db.set_balance(caller, balance);
+    VM::new(VMContext::new(db, env, handler))
+}
+
 pub(crate) fn default_env_and_db_setup(operations: Vec<Operation>) -> (Env, MemoryDB) {
     let mut env = Env::default();
     env.tx.gas_limit = INIT_GAS;
@@ -0,0 +1,192 @@
+//! Tiered execution, the cold code runs on the interpreter while it is compiled in the background.
+//!
+//! Compiling a contract with MLIR and LLVM takes much longer than interpreting it a few times, so
+//! the first executions of a code hash run on the revm interpreter and the code is submitted to a
+//! pool of background compiler workers. Once the native artifact is ready, it is saved into the
+//! artifact cache and all the later executions switch to it.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use dora_primitives::{Bytecode, CodegenOptions};
+use dora_runtime::{
+    artifact::SymbolArtifact,
+    cache::{ArtifactCache, ArtifactKey},
+};
+
+use crate::compile_artifact;
+
+/// The configuration of the [`TieredCompiler`].
+#[derive(Debug, Clone)]
+pub struct TieredConfig {
+    /// The number of executions of a code hash after which it is submitted to the background
+    /// compiler, `1` submits the code on its first execution.
+    pub compile_threshold: u64,
+    /// The max number of executions of a code hash on the interpreter, the later executions wait
+    /// for the background compilation to finish instead of interpreting the code.
+    pub interpret_threshold: u64,
+    /// The number of the background compiler workers.
+    pub workers: usize,
+    /// The LLVM code generation options of the background compiler, e.g., the hot code can be
+    /// compiled with the aggressive optimizations.
+    pub codegen: CodegenOptions,
+}
+
+impl Default for TieredConfig {
+    fn default() -> Self {
+        Self {
+            compile_threshold: 1,
+            interpret_threshold: 1000,
+            workers: std::thread::available_parallelism()
+                .map(|n| (n.get() / 2).max(1))
+                .unwrap_or(1),
+            codegen: CodegenOptions::default(),
+        }
+    }
+}
+
+impl TieredConfig {
+    /// Sets the number of executions after which the code is compiled.
+    pub fn compile_threshold(mut self, compile_threshold: u64) -> Self {
+        self.compile_threshold = compile_threshold;
+        self
+    }
+
+    /// Sets the max number of executions on the interpreter.
+    pub fn interpret_threshold(mut self, interpret_threshold: u64) -> Self {
+        self.interpret_threshold = interpret_threshold;
+        self
+    }
+
+    /// Sets the number of the background compiler workers.
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers;
+        self
+    }
+
+    /// Sets the LLVM code generation options of the background compiler.
+    pub fn codegen(mut self, codegen: CodegenOptions) -> Self {
+        self.codegen = codegen;
+        self
+    }
+}
+
+/// The execution state of a code hash which is not compiled yet.
+#[derive(Debug, Default)]
+struct TierState {
+    /// The number of the executions on the interpreter.
+    executions: u64,
+    /// Whether the code is submitted to the background compiler.
+    submitted: bool,
+}
+
+/// Decides which tier runs a code hash and compiles the hot code in the background, it can be
+/// shared by multiple VMs with [`tiered_compile_handler`](crate::tiered_compile_handler).
+pub struct TieredCompiler {
+    config: TieredConfig,
+    cache: Arc<ArtifactCache>,
+    states: Arc<DashMap<ArtifactKey, TierState>>,
+    pool: rayon::ThreadPool,
+}
+
+impl std::fmt::Debug for TieredCompiler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TieredCompiler")
+            .field("config", &self.config)
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TieredCompiler {
+    /// Creates a new tiered compiler which saves the compiled artifacts into the cache.
+    pub fn new(config: TieredConfig, cache: Arc<ArtifactCache>) -> anyhow::Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(config.workers.max(1))
+            .thread_name(|index| format!("dora-compiler-{index}"))
+            .build()?;
+        Ok(Self {
+            config,
+            cache,
+            states: Default::default(),
+            pool,
+        })
+    }
+
+    /// Returns the config of the tiered compiler.
+    #[inline]
+    pub fn config(&self) -> &TieredConfig {
+        &self.config
+    }
+
+    /// Returns the artifact cache of the tiered compiler.
+    #[inline]
+    pub fn cache(&self) -> &Arc<ArtifactCache> {
+        &self.cache
+    }
+
+    /// Returns the native artifact of the code if it is ready, or `None` when the code should run
+    /// on the interpreter. The code is submitted to the background compiler when it reaches the
+    /// compile threshold.
+    pub fn artifact(
+        &self,
+        key: ArtifactKey,
+        code: &Bytecode,
+    ) -> anyhow::Result<Option<SymbolArtifact>> {
+        // The code which is still interpreted only checks whether its background compilation is
+        // finished, so that its executions are not counted as the cache misses.
+        let artifact = if self.states.contains_key(&key) {
+            self.cache.peek(&key)
+        } else {
+            self.cache.get(&key)
+        };
+        if let Some(artifact) = artifact {
+            return Ok(Some(artifact));
+        }
+        let (executions, submit) = {
+            let mut state = self.states.entry(key).or_default();
+            state.executions += 1;
+            let submit = !state.submitted && state.executions >= self.config.compile_threshold;
+            state.submitted |= submit;
+            (state.executions, submit)
+        };
+        if submit {
+            self.submit(key, code.clone());
+        }
+        if executions > self.config.interpret_threshold {
+            // Wait for the background compilation, or compile the code when it is not started.
+            let artifact = self.cache.get_or_try_insert_with(
+                key,
+                ArtifactCache::estimated_size(code),
+                || compile_artifact(code, key, &self.config.codegen),
+            )?;
+            self.states.remove(&key);
+            return Ok(Some(artifact));
+        }
+        Ok(None)
+    }
+
+    /// Compiles the code on the background workers.
+    fn submit(&self, key: ArtifactKey, code: Bytecode) {
+        let cache = self.cache.clone();
+        let states = self.states.clone();
+        let codegen = self.config.codegen.clone();
+        self.pool.spawn(move || {
+            let result =
+                cache.get_or_try_insert_with(key, ArtifactCache::estimated_size(&code), || {
+                    compile_artifact(&code, key, &codegen)
+                });
+            match result {
+                Ok(_) => {
+                    states.remove(&key);
+                }
+                // Submit the code again on the next execution.
+                Err(_) => {
+                    if let Some(mut state) = states.get_mut(&key) {
+                        state.submitted = false;
+                    }
+                }
+            }
+        });
+    }
+}
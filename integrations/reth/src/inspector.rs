@@ -0,0 +1,504 @@
+use std::{
+    cell::RefCell,
+    mem::{self, ManuallyDrop},
+    ops::Range,
+    ptr::{self, NonNull},
+    rc::Rc,
+};
+
+use dora::{
+    VM, VMContext,
+    primitives::{Address, Bytecode, Bytes, Log, SpecId, U256},
+    runtime::{
+        CallKind, CallMessage, CallResult, Database, ExitStatusCode,
+        inspector::{Inspector as DoraInspector, StepState},
+    },
+};
+use revm::{
+    Context, Inspector,
+    interpreter::{
+        CallInputs, CallOutcome, CallScheme, CallValue, CreateInputs, CreateOutcome, CreateScheme,
+        Gas, InputsImpl, InstructionResult, Interpreter, InterpreterResult, SharedMemory,
+        interpreter::{ExtBytecode, ReturnDataImpl},
+        interpreter_types::Jumps,
+    },
+};
+
+use crate::EthEvmContext;
+
+/// Forwards the Dora inspector callbacks to a revm [`Inspector`] e.g., the reth tracing inspectors.
+///
+/// The revm inspector API works on the revm interpreter and context types, thus every callback
+/// builds a temporary interpreter view from the Dora execution state and lends the journal of the
+/// [`VMContext`] to a revm context during the callback. Modifications to the interpreter in the
+/// instruction hooks are not written back to the compiled code, while the outcome of call hooks is.
+pub(crate) struct RevmInspector<'a, I> {
+    inspector: &'a mut I,
+    /// The interpreter view of the instruction hooks, which is reused by all the steps to avoid
+    /// allocating the stack and memory buffers on every step.
+    interp: Option<Interpreter>,
+}
+
+impl<'a, I> RevmInspector<'a, I> {
+    pub(crate) fn new(inspector: &'a mut I) -> Self {
+        Self {
+            inspector,
+            interp: None,
+        }
+    }
+}
+
+/// A type-erased pointer to a borrowed Dora inspector, which is attached to the [`VM`] only for
+/// the duration of the borrow with [`ScopedInspector::attach`].
+///
+/// The inspector field of the [`VMContext`] requires a `'static` inspector, while the revm
+/// inspectors and databases of reth are usually borrowed. The pointer erases their types
+/// instead of extending the borrow, and the scope always detaches it before the borrow ends.
+pub(crate) struct ScopedInspector(NonNull<()>);
+
+impl ScopedInspector {
+    /// Attaches the inspector to the VM for the closure, it is detached when the closure returns
+    /// or unwinds, and the inspector which was attached before, e.g., the `DORA_TRACING` tracer,
+    /// is restored.
+    pub(crate) fn attach<DB: Database, R>(
+        vm: &mut VM<DB>,
+        mut inspector: &mut dyn DoraInspector<DB>,
+        f: impl FnOnce(&mut VM<DB>) -> R,
+    ) -> R {
+        /// Detaches the scoped inspector from the VM and restores the previous one on drop.
+        struct Detach<'a, DB: Database> {
+            vm: &'a mut VM<DB>,
+            previous: Option<Box<dyn DoraInspector<DB>>>,
+        }
+
+        impl<DB: Database> Drop for Detach<'_, DB> {
+            fn drop(&mut self) {
+                self.vm.context.inspector = self.previous.take();
+            }
+        }
+
+        let scoped = Self(NonNull::from(&mut inspector).cast());
+        let previous = vm.context.inspector.replace(Box::new(scoped));
+        let guard = Detach { vm, previous };
+        f(guard.vm)
+    }
+
+    /// Returns the borrowed inspector. The pointer is only created by [`ScopedInspector::attach`]
+    /// for the VM with the same database type, and the inspector outlives the attachment.
+    #[inline]
+    fn get<DB: Database>(&mut self) -> &mut dyn DoraInspector<DB> {
+        // SAFETY: See above, the inspector is not accessed by anything else during the attachment.
+        unsafe { &mut **self.0.cast::<&mut dyn DoraInspector<DB>>().as_mut() }
+    }
+}
+
+impl<DB: Database> DoraInspector<DB> for ScopedInspector {
+    #[inline]
+    fn step(&mut self, step: &StepState<'_>, context: &mut VMContext<DB>) {
+        self.get().step(step, context)
+    }
+
+    #[inline]
+    fn step_end(&mut self, step: &StepState<'_>, context: &mut VMContext<DB>) {
+        self.get().step_end(step, context)
+    }
+
+    #[inline]
+    fn call(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.get().call(msg, context)
+    }
+
+    #[inline]
+    fn call_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        self.get().call_end(msg, result, context)
+    }
+
+    #[inline]
+    fn create(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        self.get().create(msg, context)
+    }
+
+    #[inline]
+    fn create_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        self.get().create_end(msg, result, context)
+    }
+
+    #[inline]
+    fn log(&mut self, log: &Log, context: &mut VMContext<DB>) {
+        self.get().log(log, context)
+    }
+
+    #[inline]
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        DoraInspector::<DB>::selfdestruct(self.get::<DB>(), contract, target, value)
+    }
+}
+
+impl<DB, I> DoraInspector<DB> for RevmInspector<'_, I>
+where
+    DB: Database,
+    I: Inspector<EthEvmContext<DB>>,
+{
+    fn step(&mut self, step: &StepState<'_>, context: &mut VMContext<DB>) {
+        let interp = step_interpreter(&mut self.interp, step, context.spec_id());
+        with_revm_context(context, |ctx| self.inspector.step(interp, ctx));
+    }
+
+    fn step_end(&mut self, step: &StepState<'_>, context: &mut VMContext<DB>) {
+        let interp = step_interpreter(&mut self.interp, step, context.spec_id());
+        with_revm_context(context, |ctx| self.inspector.step_end(interp, ctx));
+    }
+
+    fn call(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        let mut inputs = call_inputs(msg);
+        let outcome = with_revm_context(context, |ctx| self.inspector.call(ctx, &mut inputs))?;
+        let mut result = CallResult::new_with_gas_limit(msg.gas_limit);
+        apply_interpreter_result(&outcome.result, &mut result);
+        Some(result)
+    }
+
+    fn call_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        let inputs = call_inputs(msg);
+        let mut outcome = CallOutcome::new(interpreter_result(result), empty_range());
+        with_revm_context(context, |ctx| {
+            self.inspector.call_end(ctx, &inputs, &mut outcome)
+        });
+        apply_interpreter_result(&outcome.result, result);
+    }
+
+    fn create(&mut self, msg: &mut CallMessage, context: &mut VMContext<DB>) -> Option<CallResult> {
+        // Note: EOF create frames are not forwarded because EOF is not activated on the Ethereum
+        // networks supported by reth.
+        let mut inputs = create_inputs(msg)?;
+        let outcome = with_revm_context(context, |ctx| self.inspector.create(ctx, &mut inputs))?;
+        let mut result = CallResult::new_with_gas_limit(msg.gas_limit);
+        apply_interpreter_result(&outcome.result, &mut result);
+        result.create_address = outcome.address;
+        Some(result)
+    }
+
+    fn create_end(
+        &mut self,
+        msg: &CallMessage,
+        result: &mut CallResult,
+        context: &mut VMContext<DB>,
+    ) {
+        let Some(inputs) = create_inputs(msg) else {
+            return;
+        };
+        let mut outcome = CreateOutcome::new(interpreter_result(result), result.create_address);
+        with_revm_context(context, |ctx| {
+            self.inspector.create_end(ctx, &inputs, &mut outcome)
+        });
+        apply_interpreter_result(&outcome.result, result);
+        result.create_address = outcome.address;
+    }
+
+    fn log(&mut self, log: &Log, context: &mut VMContext<DB>) {
+        let mut interp = new_interpreter(
+            Bytecode::default(),
+            InputsImpl::default(),
+            context.spec_id(),
+            0,
+        );
+        with_revm_context(context, |ctx| {
+            self.inspector.log(&mut interp, ctx, log.clone())
+        });
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        <I as Inspector<EthEvmContext<DB>>>::selfdestruct(self.inspector, contract, target, value);
+    }
+}
+
+/// Lends the environment and the journal of the [`VMContext`] to a revm context for the closure.
+fn with_revm_context<DB: Database, R>(
+    context: &mut VMContext<DB>,
+    f: impl FnOnce(&mut EthEvmContext<DB>) -> R,
+) -> R {
+    let mut lent = LentContext::new(context);
+    f(&mut lent.ctx)
+}
+
+/// A revm context which borrows the environment and the journal of the [`VMContext`], they are
+/// moved back to the [`VMContext`] on drop, including when a revm inspector panics.
+struct LentContext<'a, DB: Database> {
+    context: &'a mut VMContext<DB>,
+    /// The revm context is never dropped, its journal is moved back instead.
+    ctx: ManuallyDrop<EthEvmContext<DB>>,
+}
+
+impl<'a, DB: Database> LentContext<'a, DB> {
+    fn new(context: &'a mut VMContext<DB>) -> Self {
+        // SAFETY: The journal is moved into the revm context and moved back on drop, the stale
+        // journal left in the VM context is neither read nor dropped meanwhile because the VM
+        // context is exclusively borrowed by the lent context.
+        let journaled_state = unsafe { ptr::read(&context.journal) };
+        let ctx = Context {
+            block: mem::take(&mut context.env.block),
+            tx: mem::take(&mut context.env.tx),
+            cfg: mem::take(&mut context.env.cfg),
+            journaled_state,
+            chain: (),
+            error: Ok(()),
+        };
+        Self {
+            context,
+            ctx: ManuallyDrop::new(ctx),
+        }
+    }
+}
+
+impl<DB: Database> Drop for LentContext<'_, DB> {
+    fn drop(&mut self) {
+        mem::swap(&mut self.context.env.block, &mut self.ctx.block);
+        mem::swap(&mut self.context.env.tx, &mut self.ctx.tx);
+        mem::swap(&mut self.context.env.cfg, &mut self.ctx.cfg);
+        drop(mem::replace(&mut self.ctx.error, Ok(())));
+        // SAFETY: The journal of the revm context is moved back over the stale one without
+        // dropping it, and the revm context is never dropped, thus the journal has a single
+        // owner again.
+        unsafe {
+            ptr::write(
+                &mut self.context.journal,
+                ptr::read(&self.ctx.journaled_state),
+            )
+        };
+    }
+}
+
+fn new_interpreter(
+    code: Bytecode,
+    inputs: InputsImpl,
+    spec_id: SpecId,
+    gas_limit: u64,
+) -> Interpreter {
+    Interpreter::new(
+        Rc::new(RefCell::new(SharedMemory::new())),
+        ExtBytecode::new(code),
+        inputs,
+        false,
+        false,
+        spec_id,
+        gas_limit,
+    )
+}
+
+/// Updates the revm interpreter view to the Dora execution state of the instruction. The view is
+/// reused by all the steps, the code and the inputs are shared with the contract, while the stack
+/// and memory are written into the buffers of the view.
+fn step_interpreter<'a>(
+    interp: &'a mut Option<Interpreter>,
+    step: &StepState<'_>,
+    spec_id: SpecId,
+) -> &'a mut Interpreter {
+    let contract = step.contract;
+    let interp = interp.get_or_insert_with(|| {
+        new_interpreter(Bytecode::default(), InputsImpl::default(), spec_id, 0)
+    });
+    interp.bytecode = ExtBytecode::new(contract.code.clone());
+    interp.bytecode.absolute_jump(step.pc);
+    interp.input = InputsImpl {
+        target_address: contract.target_address,
+        caller_address: contract.caller,
+        input: contract.input.clone(),
+        call_value: contract.call_value,
+    };
+    let stack = interp.stack.data_mut();
+    stack.clear();
+    stack.extend(step.stack.iter().map(|item| item.to_u256()));
+    {
+        let mut memory = interp.memory.borrow_mut();
+        memory.resize(step.memory.len());
+        memory.set(0, step.memory);
+    }
+    interp.return_data = ReturnDataImpl(Bytes::copy_from_slice(step.return_data));
+    interp.control.gas = Gas::new(step.gas_remaining);
+    interp.control.gas.record_refund(step.gas_refunded);
+    interp
+}
+
+#[inline]
+fn empty_range() -> Range<usize> {
+    0..0
+}
+
+fn call_inputs(msg: &CallMessage) -> CallInputs {
+    let (scheme, is_eof) = match msg.kind {
+        CallKind::Callcode => (CallScheme::CallCode, false),
+        CallKind::Delegatecall => (CallScheme::DelegateCall, false),
+        CallKind::Staticcall => (CallScheme::StaticCall, false),
+        CallKind::ExtCall => (CallScheme::ExtCall, true),
+        CallKind::ExtStaticcall => (CallScheme::ExtStaticCall, true),
+        CallKind::ExtDelegatecall => (CallScheme::ExtDelegateCall, true),
+        _ => (CallScheme::Call, false),
+    };
+    let value = match msg.kind {
+        CallKind::Delegatecall | CallKind::ExtDelegatecall => CallValue::Apparent(msg.value),
+        _ => CallValue::Transfer(msg.value),
+    };
+    CallInputs {
+        input: msg.input.clone(),
+        return_memory_offset: empty_range(),
+        gas_limit: msg.gas_limit,
+        bytecode_address: msg.code_address,
+        target_address: msg.recipient,
+        caller: msg.caller,
+        value,
+        scheme,
+        is_static: msg.is_static,
+        is_eof,
+    }
+}
+
+fn create_inputs(msg: &CallMessage) -> Option<CreateInputs> {
+    let scheme = match msg.kind {
+        CallKind::Create => CreateScheme::Create,
+        CallKind::Create2 => CreateScheme::Create2 {
+            salt: U256::from_be_bytes(msg.salt.unwrap_or_default().0),
+        },
+        _ => return None,
+    };
+    Some(CreateInputs {
+        caller: msg.caller,
+        scheme,
+        value: msg.value,
+        init_code: msg.input.clone(),
+        gas_limit: msg.gas_limit,
+    })
+}
+
+fn interpreter_result(result: &CallResult) -> InterpreterResult {
+    let mut gas = Gas::new_spent(result.gas_limit);
+    gas.erase_cost(result.gas_remaining);
+    gas.record_refund(result.gas_refunded);
+    InterpreterResult::new(
+        exit_status_to_instruction_result(&result.status),
+        result.output.clone(),
+        gas,
+    )
+}
+
+fn apply_interpreter_result(interp_result: &InterpreterResult, result: &mut CallResult) {
+    result.status = instruction_result_to_exit_status(interp_result.result);
+    result.output = interp_result.output.clone();
+    result.gas_remaining = interp_result.gas.remaining();
+    result.gas_refunded = interp_result.gas.refunded();
+}
+
+fn exit_status_to_instruction_result(status: &ExitStatusCode) -> InstructionResult {
+    match status {
+        ExitStatusCode::Continue => InstructionResult::Continue,
+        ExitStatusCode::Return => InstructionResult::Return,
+        ExitStatusCode::Stop => InstructionResult::Stop,
+        ExitStatusCode::SelfDestruct => InstructionResult::SelfDestruct,
+        ExitStatusCode::Suspend => InstructionResult::CallOrCreate,
+        ExitStatusCode::Revert => InstructionResult::Revert,
+        ExitStatusCode::CallTooDeep => InstructionResult::CallTooDeep,
+        ExitStatusCode::OutOfFunds => InstructionResult::OutOfFunds,
+        ExitStatusCode::CreateInitCodeStartingEF00 => InstructionResult::CreateInitCodeStartingEF00,
+        ExitStatusCode::InvalidEOFInitCode => InstructionResult::InvalidEOFInitCode,
+        ExitStatusCode::InvalidExtDelegatecallTarget => {
+            InstructionResult::InvalidExtDelegateCallTarget
+        }
+        ExitStatusCode::OutOfGas => InstructionResult::OutOfGas,
+        ExitStatusCode::MemoryOOG => InstructionResult::MemoryOOG,
+        ExitStatusCode::MemoryLimitOOG => InstructionResult::MemoryLimitOOG,
+        ExitStatusCode::PrecompileOOG => InstructionResult::PrecompileOOG,
+        ExitStatusCode::InvalidOperandOOG => InstructionResult::InvalidOperandOOG,
+        ExitStatusCode::OpcodeNotFound => InstructionResult::OpcodeNotFound,
+        ExitStatusCode::CallNotAllowedInsideStatic => InstructionResult::CallNotAllowedInsideStatic,
+        ExitStatusCode::StateChangeDuringStaticCall => {
+            InstructionResult::StateChangeDuringStaticCall
+        }
+        ExitStatusCode::InvalidFEOpcode => InstructionResult::InvalidFEOpcode,
+        ExitStatusCode::InvalidJump => InstructionResult::InvalidJump,
+        ExitStatusCode::NotActivated => InstructionResult::NotActivated,
+        ExitStatusCode::StackUnderflow => InstructionResult::StackUnderflow,
+        ExitStatusCode::StackOverflow => InstructionResult::StackOverflow,
+        ExitStatusCode::OutOfOffset => InstructionResult::OutOfOffset,
+        ExitStatusCode::CreateCollision => InstructionResult::CreateCollision,
+        ExitStatusCode::OverflowPayment => InstructionResult::OverflowPayment,
+        ExitStatusCode::PrecompileError => InstructionResult::PrecompileError,
+        ExitStatusCode::NonceOverflow => InstructionResult::NonceOverflow,
+        ExitStatusCode::CreateContractSizeLimit => InstructionResult::CreateContractSizeLimit,
+        ExitStatusCode::CreateContractStartingWithEF => {
+            InstructionResult::CreateContractStartingWithEF
+        }
+        ExitStatusCode::CreateInitCodeSizeLimit => InstructionResult::CreateInitCodeSizeLimit,
+        ExitStatusCode::ReturnContractInNotInitEOF => InstructionResult::ReturnContractInNotInitEOF,
+        ExitStatusCode::EOFOpcodeDisabledInLegacy => InstructionResult::EOFOpcodeDisabledInLegacy,
+        ExitStatusCode::EOFFunctionStackOverflow => InstructionResult::SubRoutineStackOverflow,
+        ExitStatusCode::EofAuxDataOverflow => InstructionResult::EofAuxDataOverflow,
+        ExitStatusCode::EofAuxDataTooSmall => InstructionResult::EofAuxDataTooSmall,
+        ExitStatusCode::InvalidExtCallTarget => InstructionResult::InvalidEXTCALLTarget,
+        ExitStatusCode::FatalExternalError => InstructionResult::FatalExternalError,
+    }
+}
+
+fn instruction_result_to_exit_status(result: InstructionResult) -> ExitStatusCode {
+    match result {
+        InstructionResult::Continue => ExitStatusCode::Continue,
+        InstructionResult::Return | InstructionResult::ReturnContract => ExitStatusCode::Return,
+        InstructionResult::Stop => ExitStatusCode::Stop,
+        InstructionResult::SelfDestruct => ExitStatusCode::SelfDestruct,
+        InstructionResult::CallOrCreate => ExitStatusCode::Suspend,
+        InstructionResult::Revert => ExitStatusCode::Revert,
+        InstructionResult::CallTooDeep => ExitStatusCode::CallTooDeep,
+        InstructionResult::OutOfFunds => ExitStatusCode::OutOfFunds,
+        InstructionResult::CreateInitCodeStartingEF00 => ExitStatusCode::CreateInitCodeStartingEF00,
+        InstructionResult::InvalidEOFInitCode => ExitStatusCode::InvalidEOFInitCode,
+        InstructionResult::InvalidExtDelegateCallTarget => {
+            ExitStatusCode::InvalidExtDelegatecallTarget
+        }
+        InstructionResult::OutOfGas | InstructionResult::ReentrancySentryOOG => {
+            ExitStatusCode::OutOfGas
+        }
+        InstructionResult::MemoryOOG => ExitStatusCode::MemoryOOG,
+        InstructionResult::MemoryLimitOOG => ExitStatusCode::MemoryLimitOOG,
+        InstructionResult::PrecompileOOG => ExitStatusCode::PrecompileOOG,
+        InstructionResult::InvalidOperandOOG => ExitStatusCode::InvalidOperandOOG,
+        InstructionResult::OpcodeNotFound => ExitStatusCode::OpcodeNotFound,
+        InstructionResult::CallNotAllowedInsideStatic => ExitStatusCode::CallNotAllowedInsideStatic,
+        InstructionResult::StateChangeDuringStaticCall => {
+            ExitStatusCode::StateChangeDuringStaticCall
+        }
+        InstructionResult::InvalidFEOpcode => ExitStatusCode::InvalidFEOpcode,
+        InstructionResult::InvalidJump => ExitStatusCode::InvalidJump,
+        InstructionResult::NotActivated => ExitStatusCode::NotActivated,
+        InstructionResult::StackUnderflow => ExitStatusCode::StackUnderflow,
+        InstructionResult::StackOverflow => ExitStatusCode::StackOverflow,
+        InstructionResult::OutOfOffset => ExitStatusCode::OutOfOffset,
+        InstructionResult::CreateCollision => ExitStatusCode::CreateCollision,
+        InstructionResult::OverflowPayment => ExitStatusCode::OverflowPayment,
+        InstructionResult::PrecompileError => ExitStatusCode::PrecompileError,
+        InstructionResult::NonceOverflow => ExitStatusCode::NonceOverflow,
+        InstructionResult::CreateContractSizeLimit => ExitStatusCode::CreateContractSizeLimit,
+        InstructionResult::CreateContractStartingWithEF => {
+            ExitStatusCode::CreateContractStartingWithEF
+        }
+        InstructionResult::CreateInitCodeSizeLimit => ExitStatusCode::CreateInitCodeSizeLimit,
+        InstructionResult::ReturnContractInNotInitEOF => ExitStatusCode::ReturnContractInNotInitEOF,
+        InstructionResult::EOFOpcodeDisabledInLegacy => ExitStatusCode::EOFOpcodeDisabledInLegacy,
+        InstructionResult::SubRoutineStackOverflow => ExitStatusCode::EOFFunctionStackOverflow,
+        InstructionResult::EofAuxDataOverflow => ExitStatusCode::EofAuxDataOverflow,
+        InstructionResult::EofAuxDataTooSmall => ExitStatusCode::EofAuxDataTooSmall,
+        InstructionResult::InvalidEXTCALLTarget => ExitStatusCode::InvalidExtCallTarget,
+        InstructionResult::FatalExternalError => ExitStatusCode::FatalExternalError,
+    }
+}
@@ -6,21 +6,66 @@ use dora::{
         Address, BlockEnv, Bytes, CfgEnv, HaltReason, Journal, ResultAndState, SpecId, TxEnv,
         TxKind, U256,
     },
-    runtime::constants::env::DORA_TRACING,
 };
 use revm::{Context, Inspector, context_interface::result::EVMError, inspector::NoOpInspector};
 
+mod inspector;
+#[cfg(test)]
+mod tests;
+
+use inspector::{RevmInspector, ScopedInspector};
+
 /// The Ethereum EVM context type.
 pub type EthEvmContext<DB> = Context<BlockEnv, TxEnv, CfgEnv, DB>;
 
 /// EVM/WASM instance containing internal the VM context and the compiler handler.
-pub struct DoraVM<DB: Database> {
+pub struct DoraVM<DB: Database, I = NoOpInspector> {
     vm: VM<DB>,
+    /// The revm inspector which receives the execution callbacks when inspecting.
+    inspector: I,
+    /// Whether to forward the execution callbacks to the inspector.
+    inspect: bool,
 }
 
-impl<DB> Evm for DoraVM<DB>
+impl<DB, I> DoraVM<DB, I>
 where
     DB: Database,
+    I: Inspector<EthEvmContext<DB>>,
+{
+    /// Creates a new VM with the inspector.
+    pub fn new(vm: VM<DB>, inspector: I, inspect: bool) -> Self {
+        Self {
+            vm,
+            inspector,
+            inspect,
+        }
+    }
+
+    /// Returns a reference to the inspector.
+    pub fn inspector(&self) -> &I {
+        &self.inspector
+    }
+
+    /// Returns a mutable reference to the inspector.
+    pub fn inspector_mut(&mut self) -> &mut I {
+        &mut self.inspector
+    }
+
+    /// Executes the transaction, forwarding the execution callbacks to the inspector when inspecting.
+    fn transact_inspect(&mut self) -> Result<ResultAndState, dora::VMError> {
+        if !self.inspect {
+            return self.vm.transact();
+        }
+        // The proxy borrows the inspector only during this transaction.
+        let mut proxy = RevmInspector::new(&mut self.inspector);
+        ScopedInspector::attach(&mut self.vm, &mut proxy, |vm| vm.transact())
+    }
+}
+
+impl<DB, I> Evm for DoraVM<DB, I>
+where
+    DB: Database,
+    I: Inspector<EthEvmContext<DB>>,
 {
     type DB = DB;
     type Tx = TxEnv;
@@ -34,7 +79,8 @@ where
 
     fn transact_raw(&mut self, tx: Self::Tx) -> Result<ResultAndState, Self::Error> {
         self.vm.env.tx = tx;
-        self.vm.transact().map_err(dora_vm_error_to_evm_error::<DB>)
+        self.transact_inspect()
+            .map_err(dora_vm_error_to_evm_error::<DB>)
     }
 
     fn transact_system_call(
@@ -126,11 +172,7 @@ where
     }
 
     fn set_inspector_enabled(&mut self, enabled: bool) {
-        if enabled {
-            unsafe { std::env::set_var(DORA_TRACING, "1") };
-        } else {
-            unsafe { std::env::remove_var(DORA_TRACING) };
-        }
+        self.inspect = enabled;
     }
 
     fn chain_id(&self) -> u64 {
@@ -142,7 +184,7 @@ where
 pub struct DoraVMFactory;
 
 impl EvmFactory for DoraVMFactory {
-    type Evm<DB: Database, I: Inspector<EthEvmContext<DB>>> = DoraVM<DB>;
+    type Evm<DB: Database, I: Inspector<EthEvmContext<DB>>> = DoraVM<DB, I>;
     type Context<DB: Database> = Context<BlockEnv, TxEnv, CfgEnv, DB>;
     type Tx = TxEnv;
     type Error<DBError: core::error::Error + Send + Sync + 'static> = EVMError<DBError>;
@@ -150,30 +192,32 @@ impl EvmFactory for DoraVMFactory {
     type Spec = SpecId;
 
     fn create_evm<DB: Database>(&self, db: DB, input: EvmEnv) -> Self::Evm<DB, NoOpInspector> {
-        DoraVM {
-            vm: VM::new(VMContext::new(
-                db,
-                Env {
-                    cfg: input.cfg_env,
-                    block: input.block_env,
-                    ..Default::default()
-                },
-                compile_handler(),
-            )),
-        }
+        DoraVM::new(new_vm(db, input), NoOpInspector, false)
     }
 
     fn create_evm_with_inspector<DB: Database, I: Inspector<Self::Context<DB>>>(
         &self,
         db: DB,
         input: EvmEnv,
-        _inspector: I,
+        inspector: I,
     ) -> Self::Evm<DB, I> {
-        unsafe { std::env::set_var(DORA_TRACING, "1") };
-        self.create_evm(db, input)
+        DoraVM::new(new_vm(db, input), inspector, true)
     }
 }
 
+#[inline]
+fn new_vm<DB: Database>(db: DB, input: EvmEnv) -> VM<DB> {
+    VM::new(VMContext::new(
+        db,
+        Env {
+            cfg: input.cfg_env,
+            block: input.block_env,
+            ..Default::default()
+        },
+        compile_handler(),
+    ))
+}
+
 #[inline]
 pub fn dora_vm_error_to_evm_error<DB: Database>(err: dora::VMError) -> EVMError<DB::Error> {
     match err {
@@ -181,6 +225,7 @@ pub fn dora_vm_error_to_evm_error<DB: Database>(err: dora::VMError) -> EVMError<
             EVMError::Transaction(invalid_transaction)
         }
         dora::VMError::Header(invalid_header) => EVMError::Header(invalid_header),
+        err @ dora::VMError::TxGasLimitGreaterThanCap { .. } => EVMError::Custom(err.to_string()),
         dora::VMError::Database(database_error) => EVMError::Custom(database_error.to_string()),
         dora::VMError::Compile(err)
         | dora::VMError::Precompile(err)
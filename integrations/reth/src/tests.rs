@@ -0,0 +1,163 @@
+use std::{cell::Cell, rc::Rc};
+
+use dora::{
+    MemoryDB,
+    primitives::{Address, Bytecode, Bytes, TxEnv, TxKind, U256, address},
+    runtime::{CallMessage, CallResult, inspector::Inspector as DoraInspector},
+};
+use revm::{
+    Inspector,
+    interpreter::{CallInputs, CallOutcome, Interpreter, interpreter_types::Jumps},
+};
+
+use crate::*;
+
+const CALLER: Address = address!("0000000000000000000000000000000000000001");
+const CONTRACT: Address = address!("0000000000000000000000000000000000000040");
+
+/// Records the revm interpreter views and the call frames forwarded by the bridge.
+#[derive(Debug, Default)]
+struct RecordInspector {
+    /// The opcode, the stack length and the memory size of each step.
+    steps: Vec<(u8, usize, usize)>,
+    step_ends: usize,
+    calls: Vec<Address>,
+    call_ends: usize,
+}
+
+impl Inspector<EthEvmContext<MemoryDB>> for RecordInspector {
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EthEvmContext<MemoryDB>) {
+        // The environment is lent to the revm context.
+        assert_eq!(context.tx.caller, CALLER);
+        self.steps.push((
+            interp.bytecode.opcode(),
+            interp.stack.len(),
+            interp.memory.borrow().len(),
+        ));
+    }
+
+    fn step_end(&mut self, _interp: &mut Interpreter, _context: &mut EthEvmContext<MemoryDB>) {
+        self.step_ends += 1;
+    }
+
+    fn call(
+        &mut self,
+        _context: &mut EthEvmContext<MemoryDB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        self.calls.push(inputs.target_address);
+        None
+    }
+
+    fn call_end(
+        &mut self,
+        _context: &mut EthEvmContext<MemoryDB>,
+        _inputs: &CallInputs,
+        _outcome: &mut CallOutcome,
+    ) {
+        self.call_ends += 1;
+    }
+}
+
+/// Returns the EVM whose contract returns `1 + 2`.
+fn new_evm() -> DoraVM<MemoryDB, RecordInspector> {
+    let code = Bytes::from_static(&[
+        0x60, 0x01, // PUSH1 1
+        0x60, 0x02, // PUSH1 2
+        0x01, // ADD
+        0x5f, // PUSH0
+        0x52, // MSTORE
+        0x60, 0x20, // PUSH1 32
+        0x5f, // PUSH0
+        0xf3, // RETURN
+    ]);
+    let db = MemoryDB::new().with_contract(CONTRACT, Bytecode::new_raw(code));
+    DoraVMFactory.create_evm_with_inspector(db, EvmEnv::default(), RecordInspector::default())
+}
+
+fn tx() -> TxEnv {
+    TxEnv {
+        caller: CALLER,
+        kind: TxKind::Call(CONTRACT),
+        gas_limit: 1_000_000,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_traced_transaction() {
+    let mut evm = new_evm();
+    let result = evm.transact_raw(tx()).unwrap().result;
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(
+        result.output().cloned(),
+        Some(Bytes::from(U256::from(3).to_be_bytes::<32>()))
+    );
+    let inspector = evm.inspector();
+    assert_eq!(
+        inspector.steps,
+        vec![
+            (0x60, 0, 0),
+            (0x60, 1, 0),
+            (0x01, 2, 0),
+            (0x5f, 1, 0),
+            (0x52, 2, 0),
+            (0x60, 0, 32),
+            (0x5f, 1, 32),
+            (0xf3, 2, 32),
+        ]
+    );
+    assert_eq!(inspector.step_ends, inspector.steps.len());
+    assert_eq!(inspector.calls, vec![CONTRACT]);
+    assert_eq!(inspector.call_ends, 1);
+}
+
+#[test]
+fn test_traced_transaction_repeated() {
+    let mut evm = new_evm();
+    // The inspector is detached after each transaction and attached again for the next one.
+    for n in 1..=2 {
+        let result = evm.transact_raw(tx()).unwrap().result;
+        assert!(result.is_success(), "{:?}", result);
+        assert_eq!(evm.inspector().calls.len(), n);
+        assert_eq!(evm.inspector().steps.len(), 8 * n);
+    }
+    evm.set_inspector_enabled(false);
+    let result = evm.transact_raw(tx()).unwrap().result;
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(evm.inspector().calls.len(), 2);
+    assert_eq!(evm.inspector().steps.len(), 16);
+}
+
+/// Counts the calls seen by the Dora inspector which is attached to the VM context.
+#[derive(Debug, Default)]
+struct CallCounter(Rc<Cell<usize>>);
+
+impl DoraInspector<MemoryDB> for CallCounter {
+    fn call(
+        &mut self,
+        _msg: &mut CallMessage,
+        _context: &mut VMContext<MemoryDB>,
+    ) -> Option<CallResult> {
+        self.0.set(self.0.get() + 1);
+        None
+    }
+}
+
+#[test]
+fn test_traced_transaction_restores_inspector() {
+    let mut evm = new_evm();
+    let calls = Rc::new(Cell::new(0));
+    evm.vm.context.set_inspector(CallCounter(calls.clone()));
+    let result = evm.transact_raw(tx()).unwrap().result;
+    assert!(result.is_success(), "{:?}", result);
+    // The revm inspector replaces the attached one during the traced transaction.
+    assert_eq!(evm.inspector().calls.len(), 1);
+    assert_eq!(calls.get(), 0);
+    assert!(evm.vm.context.is_inspecting());
+
+    evm.set_inspector_enabled(false);
+    let result = evm.transact_raw(tx()).unwrap().result;
+    assert!(result.is_success(), "{:?}", result);
+    assert_eq!(calls.get(), 1);
+}